@@ -0,0 +1,206 @@
+//! This module implements the built-ins of the web compatibility features of Annex B of the
+//! specification.
+//!
+//! They are the global `escape` and `unescape` functions, the `substr` and HTML methods of
+//! `String.prototype`, and the `__proto__` accessor and the legacy accessor methods of
+//! `Object.prototype`. A context only has them while its Annex B option is enabled.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-additional-built-in-properties
+
+use crate::{
+    builtins::{function::NativeFunction, BuiltInObjectObject, String},
+    object::{FunctionBuilder, GcObject},
+    property::{AccessorDescriptor, Attribute, PropertyKey},
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The functions of the global object.
+const GLOBAL_FUNCTIONS: [(NativeFunction, &str, usize); 2] =
+    [(escape, "escape", 1), (unescape, "unescape", 1)];
+
+/// The methods of `String.prototype`.
+const STRING_METHODS: [(NativeFunction, &str, usize); 14] = [
+    (String::substr, "substr", 2),
+    (String::anchor, "anchor", 1),
+    (String::big, "big", 0),
+    (String::blink, "blink", 0),
+    (String::bold, "bold", 0),
+    (String::fixed, "fixed", 0),
+    (String::fontcolor, "fontcolor", 1),
+    (String::fontsize, "fontsize", 1),
+    (String::italics, "italics", 0),
+    (String::link, "link", 1),
+    (String::small, "small", 0),
+    (String::strike, "strike", 0),
+    (String::sub, "sub", 0),
+    (String::sup, "sup", 0),
+];
+
+/// The methods of `Object.prototype`, it also gets the `__proto__` accessor.
+const OBJECT_METHODS: [(NativeFunction, &str, usize); 4] = [
+    (BuiltInObjectObject::define_getter, "__defineGetter__", 2),
+    (BuiltInObjectObject::define_setter, "__defineSetter__", 2),
+    (BuiltInObjectObject::lookup_getter, "__lookupGetter__", 1),
+    (BuiltInObjectObject::lookup_setter, "__lookupSetter__", 1),
+];
+
+/// The functions of an object, with their names and lengths.
+type Functions = &'static [(NativeFunction, &'static str, usize)];
+
+/// Gets the objects that get the built-ins, with their functions.
+fn targets(context: &Context) -> [(GcObject, Functions); 3] {
+    let global = match context.global_object() {
+        Value::Object(ref global) => global.clone(),
+        _ => unreachable!("global object should always be an object"),
+    };
+    let standard_objects = context.standard_objects();
+    [
+        (global, &GLOBAL_FUNCTIONS),
+        (
+            standard_objects.string_object().prototype(),
+            &STRING_METHODS,
+        ),
+        (
+            standard_objects.object_object().prototype(),
+            &OBJECT_METHODS,
+        ),
+    ]
+}
+
+/// Adds the Annex B built-ins to the global object and to the standard prototypes.
+pub(crate) fn init(context: &mut Context) {
+    let _timer = BoaProfiler::global().start_event("Annex B", "init");
+
+    for (object, functions) in targets(context).iter() {
+        for (function, name, length) in functions.iter() {
+            let function = FunctionBuilder::new(context, *function)
+                .name(name)
+                .length(*length)
+                .callable(true)
+                .constructable(false)
+                .build();
+            object.borrow_mut().insert_property(
+                *name,
+                function,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            );
+        }
+    }
+
+    let get = FunctionBuilder::new(context, BuiltInObjectObject::get_proto)
+        .name("get __proto__")
+        .build();
+    let set = FunctionBuilder::new(context, BuiltInObjectObject::set_proto)
+        .name("set __proto__")
+        .length(1)
+        .build();
+    context
+        .standard_objects()
+        .object_object()
+        .prototype()
+        .borrow_mut()
+        .insert(
+            "__proto__",
+            AccessorDescriptor::new(Some(get), Some(set), Attribute::CONFIGURABLE),
+        );
+}
+
+/// Removes the Annex B built-ins that [`init`] added.
+pub(crate) fn remove(context: &mut Context) {
+    for (object, functions) in targets(context).iter() {
+        for (_, name, _) in functions.iter() {
+            object
+                .borrow_mut()
+                .remove_property(&PropertyKey::from(*name));
+        }
+    }
+    context
+        .standard_objects()
+        .object_object()
+        .prototype()
+        .borrow_mut()
+        .remove_property(&"__proto__".into());
+}
+
+/// Checks if the code unit is kept as is by `escape`.
+fn is_unescaped(unit: u16) -> bool {
+    unit < 128
+        && matches!(unit as u8, b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'@' | b'*' | b'_' | b'+' | b'-' | b'.' | b'/')
+}
+
+/// `escape( string )`
+///
+/// Replaces the code units of the string that are not ASCII letters, digits or one of `@*_+-./`
+/// by a `%XX` escape sequence, or by a `%uXXXX` escape sequence if they are above `0xFF`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-escape-string
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/escape
+pub(crate) fn escape(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+    let string = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+
+    let mut escaped = std::string::String::with_capacity(string.len());
+    for unit in string.encode_utf16() {
+        if is_unescaped(unit) {
+            escaped.push(unit as u8 as char);
+        } else if unit < 256 {
+            escaped.push_str(&format!("%{:02X}", unit));
+        } else {
+            escaped.push_str(&format!("%u{:04X}", unit));
+        }
+    }
+    Ok(escaped.into())
+}
+
+/// Gets the value of the hexadecimal digits in `units`, if they are all hexadecimal digits.
+fn hex_value(units: &[u16]) -> Option<u16> {
+    units.iter().try_fold(0, |value, &unit| {
+        let digit = std::char::from_u32(unit.into())?.to_digit(16)?;
+        Some(value * 16 + digit as u16)
+    })
+}
+
+/// `unescape( string )`
+///
+/// Replaces the `%XX` and `%uXXXX` escape sequences of the string by the code units they stand
+/// for. Anything that is not a valid escape sequence is kept as is.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-unescape-string
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/unescape
+pub(crate) fn unescape(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+    let string = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+    let units: Vec<u16> = string.encode_utf16().collect();
+
+    let mut unescaped = Vec::with_capacity(units.len());
+    let mut index = 0;
+    while index < units.len() {
+        let mut unit = units[index];
+        if unit == u16::from(b'%') {
+            if units.get(index + 1) == Some(&u16::from(b'u')) {
+                if let Some(value) = units.get(index + 2..index + 6).and_then(hex_value) {
+                    unit = value;
+                    index += 5;
+                }
+            } else if let Some(value) = units.get(index + 1..index + 3).and_then(hex_value) {
+                unit = value;
+                index += 2;
+            }
+        }
+        unescaped.push(unit);
+        index += 1;
+    }
+    Ok(std::string::String::from_utf16_lossy(&unescaped).into())
+}
@@ -0,0 +1,139 @@
+use crate::{forward, Context};
+
+#[test]
+fn escape() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "escape('AZaz09@*_+-./')"),
+        "\"AZaz09@*_+-./\""
+    );
+    assert_eq!(
+        forward(&mut engine, "escape('a b,ä€')"),
+        "\"a%20b%2C%E4%u20AC\""
+    );
+    assert_eq!(forward(&mut engine, "escape.length"), "1");
+}
+
+#[test]
+fn unescape() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "unescape('a%20b%2c%E4%u20AC')"),
+        "\"a b,ä€\""
+    );
+    assert_eq!(
+        forward(&mut engine, "unescape('%zz%u12%2%')"),
+        "\"%zz%u12%2%\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "unescape(escape('\\x00ÿ\u{ffff}')) === '\\x00ÿ\u{ffff}'"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn string_html_methods() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "'a'.anchor('x\"y')"),
+        "\"<a name=\"x&quot;y\">a</a>\""
+    );
+    assert_eq!(forward(&mut engine, "'a'.bold()"), "\"<b>a</b>\"");
+    assert_eq!(forward(&mut engine, "'a'.fixed()"), "\"<tt>a</tt>\"");
+    assert_eq!(
+        forward(&mut engine, "'a'.fontsize(7)"),
+        "\"<font size=\"7\">a</font>\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'a'.link()"),
+        "\"<a href=\"undefined\">a</a>\""
+    );
+    assert_eq!(
+        forward(&mut engine, "String.prototype.sup.call(1)"),
+        "\"<sup>1</sup>\""
+    );
+    assert!(forward(&mut engine, "String.prototype.big.call(null)")
+        .starts_with("Uncaught \"TypeError\""));
+}
+
+#[test]
+fn object_proto_accessor() {
+    let mut engine = Context::new();
+    let init = r#"
+        var proto = { x: 1 };
+        var obj = {};
+        obj.__proto__ = proto;
+        obj.__proto__ = 42;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "obj.x"), "1");
+    assert_eq!(forward(&mut engine, "obj.__proto__ === proto"), "true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.prototype.propertyIsEnumerable('__proto__')"
+        ),
+        "false"
+    );
+    assert!(forward(&mut engine, "proto.__proto__ = obj").starts_with("Uncaught \"TypeError\""));
+}
+
+#[test]
+fn object_legacy_accessor_methods() {
+    let mut engine = Context::new();
+    let init = r#"
+        var value = 0;
+        var parent = {};
+        parent.__defineGetter__("x", function() { return value; });
+        parent.__defineSetter__("x", function(v) { value = v * 2; });
+        var child = Object.create(parent);
+        child.x = 21;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "child.x"), "42");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "child.__lookupGetter__('x') === parent.__lookupGetter__('x')"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "typeof child.__lookupSetter__('x')"),
+        "\"function\""
+    );
+    assert_eq!(
+        forward(&mut engine, "child.__lookupGetter__('y')"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut engine, "parent.propertyIsEnumerable('x')"),
+        "true"
+    );
+    assert!(forward(&mut engine, "parent.__defineGetter__('y', 1)")
+        .starts_with("Uncaught \"TypeError\""));
+}
+
+#[test]
+fn disabled_annex_b_removes_builtins() {
+    let mut engine = Context::new();
+    engine.set_annex_b(false);
+    assert_eq!(forward(&mut engine, "typeof this.escape"), "\"undefined\"");
+    assert_eq!(forward(&mut engine, "typeof 'a'.substr"), "\"undefined\"");
+    assert_eq!(forward(&mut engine, "typeof 'a'.anchor"), "\"undefined\"");
+    assert_eq!(
+        forward(&mut engine, "typeof ({}).__defineGetter__"),
+        "\"undefined\""
+    );
+    assert_eq!(forward(&mut engine, "'__proto__' in {}"), "false");
+
+    engine.set_annex_b(true);
+    assert_eq!(forward(&mut engine, "'abc'.substr(1)"), "\"bc\"");
+    assert_eq!(
+        forward(&mut engine, "({}).__proto__ === Object.prototype"),
+        "true"
+    );
+}
@@ -16,7 +16,9 @@ mod tests;
 use crate::{
     builtins::array::array_iterator::{ArrayIterationKind, ArrayIterator},
     builtins::BuiltIn,
-    object::{ConstructorBuilder, FunctionBuilder, ObjectData, PROTOTYPE},
+    object::{
+        ConstructorBuilder, FunctionBuilder, GcObject, ObjectData, ObjectInitializer, PROTOTYPE,
+    },
     property::{Attribute, DataDescriptor},
     value::{same_value_zero, Value},
     BoaProfiler, Context, Result,
@@ -38,12 +40,15 @@ impl BuiltIn for Array {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
         let symbol_iterator = context.well_known_symbols().iterator_symbol();
-        let values_function = FunctionBuilder::new(context, Self::values)
+        let symbol_unscopables = context.well_known_symbols().unscopables_symbol();
+        let unscopables = Self::unscopables_object(context);
+        let values_function = context.standard_objects().array_values_function().clone();
+        FunctionBuilder::new(context, Self::values)
             .name("values")
             .length(0)
             .callable(true)
             .constructable(false)
-            .build();
+            .build_standard_function(&values_function);
         let array = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -62,6 +67,11 @@ impl BuiltIn for Array {
             values_function,
             Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
         )
+        .property(
+            symbol_unscopables,
+            unscopables,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
         .method(Self::concat, "concat", 1)
         .method(Self::push, "push", 1)
         .method(Self::index_of, "indexOf", 1)
@@ -97,7 +107,43 @@ impl BuiltIn for Array {
 impl Array {
     const LENGTH: usize = 1;
 
+    /// Creates the `Array.prototype[@@unscopables]` object, which lists the methods that are not
+    /// bindings in a `with` statement, so that code written before they were added keeps working.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype-@@unscopables
+    fn unscopables_object(context: &mut Context) -> GcObject {
+        let mut unscopables = ObjectInitializer::new(context);
+        for name in &[
+            "copyWithin",
+            "entries",
+            "fill",
+            "find",
+            "findIndex",
+            "flat",
+            "flatMap",
+            "includes",
+            "keys",
+            "values",
+        ] {
+            unscopables.property(*name, true, Attribute::all());
+        }
+        let unscopables = unscopables.build();
+        unscopables
+            .borrow_mut()
+            .set_prototype_instance(Value::null());
+        unscopables
+    }
+
     fn constructor(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // `Array(...)` is the same as `new Array(...)`.
+        if !this.is_object() {
+            let constructor = context.standard_objects().array_object().constructor();
+            return constructor.construct(args, &constructor.clone().into(), context);
+        }
+
         // Set Prototype
         let prototype = context.standard_objects().array_object().prototype();
 
@@ -115,7 +161,7 @@ impl Array {
                 length = args[0].as_number().unwrap() as i32;
                 // TODO: It should not create an array of undefineds, but an empty array ("holy" array in V8) with length `n`.
                 for n in 0..length {
-                    this.set_field(n, Value::undefined());
+                    this.set_field(n, Value::undefined(), context)?;
                 }
             }
             1 if args[0].is_double() => {
@@ -123,7 +169,7 @@ impl Array {
             }
             _ => {
                 for (n, value) in args.iter().enumerate() {
-                    this.set_field(n, value.clone());
+                    this.set_field(n, value.clone(), context)?;
                 }
             }
         }
@@ -153,7 +199,11 @@ impl Array {
             .as_object_mut()
             .expect("array object")
             .set_prototype_instance(context.standard_objects().array_object().prototype().into());
-        array.set_field("length", Value::from(0));
+        let length = DataDescriptor::new(
+            Value::from(0),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+        array.set_property("length", length);
         Ok(array)
     }
 
@@ -161,11 +211,15 @@ impl Array {
     ///
     /// `array_obj` can be any array with prototype already set (it will be wiped and
     /// recreated from `array_contents`)
-    pub(crate) fn construct_array(array_obj: &Value, array_contents: &[Value]) -> Result<Value> {
+    pub(crate) fn construct_array(
+        array_obj: &Value,
+        array_contents: &[Value],
+        ctx: &mut Context,
+    ) -> Result<Value> {
         let array_obj_ptr = array_obj.clone();
 
         // Wipe existing contents of the array object
-        let orig_length = array_obj.get_field("length").as_number().unwrap() as i32;
+        let orig_length = array_obj.get_field("length", ctx)?.as_number().unwrap() as i32;
         for n in 0..orig_length {
             array_obj_ptr.remove_property(n);
         }
@@ -178,25 +232,30 @@ impl Array {
         array_obj_ptr.set_property("length".to_string(), length);
 
         for (n, value) in array_contents.iter().enumerate() {
-            array_obj_ptr.set_field(n, value);
+            array_obj_ptr.set_field(n, value, ctx)?;
         }
         Ok(array_obj_ptr)
     }
 
     /// Utility function which takes an existing array object and puts additional
     /// values on the end, correctly rewriting the length
-    pub(crate) fn add_to_array_object(array_ptr: &Value, add_values: &[Value]) -> Result<Value> {
-        let orig_length = array_ptr.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn add_to_array_object(
+        array_ptr: &Value,
+        add_values: &[Value],
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let orig_length = array_ptr.get_field("length", ctx)?.as_number().unwrap() as i32;
 
         for (n, value) in add_values.iter().enumerate() {
             let new_index = orig_length.wrapping_add(n as i32);
-            array_ptr.set_field(new_index, value);
+            array_ptr.set_field(new_index, value, ctx)?;
         }
 
         array_ptr.set_field(
             "length",
             Value::from(orig_length.wrapping_add(add_values.len() as i32)),
-        );
+            ctx,
+        )?;
 
         Ok(array_ptr.clone())
     }
@@ -235,7 +294,7 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.concat
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/concat
-    pub(crate) fn concat(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+    pub(crate) fn concat(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         if args.is_empty() {
             // If concat is called with no arguments, it returns the original array
             return Ok(this.clone());
@@ -245,19 +304,19 @@ impl Array {
         // one)
         let mut new_values: Vec<Value> = Vec::new();
 
-        let this_length = this.get_field("length").as_number().unwrap() as i32;
+        let this_length = this.get_field("length", ctx)?.as_number().unwrap() as i32;
         for n in 0..this_length {
-            new_values.push(this.get_field(n));
+            new_values.push(this.get_field(n, ctx)?);
         }
 
         for concat_array in args {
-            let concat_length = concat_array.get_field("length").as_number().unwrap() as i32;
+            let concat_length = concat_array.get_field("length", ctx)?.as_number().unwrap() as i32;
             for n in 0..concat_length {
-                new_values.push(concat_array.get_field(n));
+                new_values.push(concat_array.get_field(n, ctx)?);
             }
         }
 
-        Self::construct_array(this, &new_values)
+        Self::construct_array(this, &new_values, ctx)
     }
 
     /// `Array.prototype.push( ...items )`
@@ -272,9 +331,9 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.push
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/push
-    pub(crate) fn push(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let new_array = Self::add_to_array_object(this, args)?;
-        Ok(new_array.get_field("length"))
+    pub(crate) fn push(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let new_array = Self::add_to_array_object(this, args, ctx)?;
+        new_array.get_field("length", ctx)
     }
 
     /// `Array.prototype.pop()`
@@ -287,16 +346,16 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.pop
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/pop
-    pub(crate) fn pop(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        let curr_length = this.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn pop(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let curr_length = this.get_field("length", ctx)?.as_number().unwrap() as i32;
 
         if curr_length < 1 {
             return Ok(Value::undefined());
         }
         let pop_index = curr_length.wrapping_sub(1);
-        let pop_value: Value = this.get_field(pop_index.to_string());
+        let pop_value: Value = this.get_field(pop_index.to_string(), ctx)?;
         this.remove_property(pop_index);
-        this.set_field("length", Value::from(pop_index));
+        this.set_field("length", Value::from(pop_index), ctx)?;
         Ok(pop_value)
     }
 
@@ -318,10 +377,10 @@ impl Array {
         let callback_arg = args.get(0).expect("Could not get `callbackFn` argument.");
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this.get_field("length", ctx)?.as_number().unwrap() as i32;
 
         for i in 0..length {
-            let element = this.get_field(i);
+            let element = this.get_field(i, ctx)?;
             let arguments = [element, Value::from(i), this.clone()];
 
             ctx.call(callback_arg, &this_arg, &arguments)?;
@@ -353,9 +412,9 @@ impl Array {
         };
 
         let mut elem_strs = Vec::new();
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this.get_field("length", ctx)?.as_number().unwrap() as i32;
         for n in 0..length {
-            let elem_str = this.get_field(n).to_string(ctx)?.to_string();
+            let elem_str = this.get_field(n, ctx)?.to_string(ctx)?.to_string();
             elem_strs.push(elem_str);
         }
 
@@ -379,14 +438,14 @@ impl Array {
         let method_name = "join";
         let mut arguments = vec![Value::from(",")];
         // 2.
-        let mut method = this.get_field(method_name);
+        let mut method = this.get_field(method_name, ctx)?;
         // 3.
         if !method.is_function() {
-            method = ctx
-                .global_object()
-                .get_field("Object")
-                .get_field(PROTOTYPE)
-                .get_field("toString");
+            let global = ctx.global_object().clone();
+            method = global
+                .get_field("Object", ctx)?
+                .get_field(PROTOTYPE, ctx)?
+                .get_field("toString", ctx)?;
 
             arguments = Vec::new();
         }
@@ -414,8 +473,8 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.reverse
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/reverse
     #[allow(clippy::else_if_without_else)]
-    pub(crate) fn reverse(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        let len = this.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn reverse(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let len = this.get_field("length", ctx)?.as_number().unwrap() as i32;
 
         let middle: i32 = len.wrapping_div(2);
 
@@ -425,17 +484,17 @@ impl Array {
             let upper_exists = this.has_field(upper);
             let lower_exists = this.has_field(lower);
 
-            let upper_value = this.get_field(upper);
-            let lower_value = this.get_field(lower);
+            let upper_value = this.get_field(upper, ctx)?;
+            let lower_value = this.get_field(lower, ctx)?;
 
             if upper_exists && lower_exists {
-                this.set_field(upper, lower_value);
-                this.set_field(lower, upper_value);
+                this.set_field(upper, lower_value, ctx)?;
+                this.set_field(lower, upper_value, ctx)?;
             } else if upper_exists {
-                this.set_field(lower, upper_value);
+                this.set_field(lower, upper_value, ctx)?;
                 this.remove_property(upper);
             } else if lower_exists {
-                this.set_field(upper, lower_value);
+                this.set_field(upper, lower_value, ctx)?;
                 this.remove_property(lower);
             }
         }
@@ -453,31 +512,31 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.shift
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/shift
-    pub(crate) fn shift(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        let len = this.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn shift(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let len = this.get_field("length", ctx)?.as_number().unwrap() as i32;
 
         if len == 0 {
-            this.set_field("length", 0);
+            this.set_field("length", 0, ctx)?;
             return Ok(Value::undefined());
         }
 
-        let first: Value = this.get_field(0);
+        let first: Value = this.get_field(0, ctx)?;
 
         for k in 1..len {
             let from = k;
             let to = k.wrapping_sub(1);
 
-            let from_value = this.get_field(from);
+            let from_value = this.get_field(from, ctx)?;
             if from_value.is_undefined() {
                 this.remove_property(to);
             } else {
-                this.set_field(to, from_value);
+                this.set_field(to, from_value, ctx)?;
             }
         }
 
         let final_index = len.wrapping_sub(1);
         this.remove_property(final_index);
-        this.set_field("length", Value::from(final_index));
+        this.set_field("length", Value::from(final_index), ctx)?;
 
         Ok(first)
     }
@@ -494,8 +553,8 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.unshift
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/unshift
-    pub(crate) fn unshift(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let len = this.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn unshift(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let len = this.get_field("length", ctx)?.as_number().unwrap() as i32;
 
         let arg_c: i32 = args.len() as i32;
 
@@ -504,11 +563,11 @@ impl Array {
                 let from = k.wrapping_sub(1);
                 let to = k.wrapping_add(arg_c).wrapping_sub(1);
 
-                let from_value = this.get_field(from);
+                let from_value = this.get_field(from, ctx)?;
                 if from_value.is_undefined() {
                     this.remove_property(to);
                 } else {
-                    this.set_field(to, from_value);
+                    this.set_field(to, from_value, ctx)?;
                 }
             }
             for j in 0..arg_c {
@@ -517,12 +576,13 @@ impl Array {
                     args.get(j as usize)
                         .expect("Could not get argument")
                         .clone(),
-                );
+                    ctx,
+                )?;
             }
         }
 
         let temp = len.wrapping_add(arg_c);
-        this.set_field("length", Value::from(temp));
+        this.set_field("length", Value::from(temp), ctx)?;
         Ok(Value::from(temp))
     }
 
@@ -552,10 +612,10 @@ impl Array {
             Value::undefined()
         };
         let mut i = 0;
-        let max_len = this.get_field("length").as_number().unwrap() as i32;
+        let max_len = this.get_field("length", interpreter)?.as_number().unwrap() as i32;
         let mut len = max_len;
         while i < len {
-            let element = this.get_field(i);
+            let element = this.get_field(i, interpreter)?;
             let arguments = [element, Value::from(i), this.clone()];
             let result = interpreter.call(callback, &this_arg, &arguments)?;
             if !result.to_boolean() {
@@ -563,7 +623,7 @@ impl Array {
             }
             len = min(
                 max_len,
-                this.get_field("length").as_number().unwrap() as i32,
+                this.get_field("length", interpreter)?.as_number().unwrap() as i32,
             );
             i += 1;
         }
@@ -591,7 +651,7 @@ impl Array {
         let callback = args.get(0).cloned().unwrap_or_else(Value::undefined);
         let this_val = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
-        let length = this.get_field("length").to_length(context)?;
+        let length = this.get_field("length", context)?.to_length(context)?;
 
         if length > 2usize.pow(32) - 1 {
             return context.throw_range_error("Invalid array length");
@@ -599,18 +659,19 @@ impl Array {
 
         let new = Self::new_array(context)?;
 
-        let values: Vec<Value> = (0..length)
-            .map(|idx| {
-                let element = this.get_field(idx);
-                let args = [element, Value::from(idx), new.clone()];
+        let mut values = Vec::with_capacity(length);
+        for idx in 0..length {
+            let element = this.get_field(idx, context)?;
+            let args = [element, Value::from(idx), new.clone()];
 
+            values.push(
                 context
                     .call(&callback, &this_val, &args)
-                    .unwrap_or_else(|_| Value::undefined())
-            })
-            .collect();
+                    .unwrap_or_else(|_| Value::undefined()),
+            );
+        }
 
-        Self::construct_array(&new, &values)
+        Self::construct_array(&new, &values, context)
     }
 
     /// `Array.prototype.indexOf( searchElement[, fromIndex ] )`
@@ -632,14 +693,14 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.indexof
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/indexOf
-    pub(crate) fn index_of(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+    pub(crate) fn index_of(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         // If no arguments, return -1. Not described in spec, but is what chrome does.
         if args.is_empty() {
             return Ok(Value::from(-1));
         }
 
         let search_element = args[0].clone();
-        let len = this.get_field("length").as_number().unwrap() as i32;
+        let len = this.get_field("length", ctx)?.as_number().unwrap() as i32;
 
         let mut idx = match args.get(1) {
             Some(from_idx_ptr) => {
@@ -655,7 +716,7 @@ impl Array {
         };
 
         while idx < len {
-            let check_element = this.get_field(idx).clone();
+            let check_element = this.get_field(idx, ctx)?.clone();
 
             if check_element.strict_equals(&search_element) {
                 return Ok(Value::from(idx));
@@ -685,7 +746,7 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.lastindexof
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/lastIndexOf
-    pub(crate) fn last_index_of(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+    pub(crate) fn last_index_of(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         // If no arguments, return -1. Not described in spec, but is what chrome does.
         if args.is_empty() {
             return Ok(Value::from(-1));
@@ -693,7 +754,7 @@ impl Array {
 
         let search_element = args[0].clone();
         let len = this
-            .get_field("length")
+            .get_field("length", ctx)?
             .as_number()
             .expect("length was not a number") as i32;
 
@@ -711,7 +772,7 @@ impl Array {
         };
 
         while idx >= 0 {
-            let check_element = this.get_field(idx).clone();
+            let check_element = this.get_field(idx, ctx)?.clone();
 
             if check_element.strict_equals(&search_element) {
                 return Ok(Value::from(idx));
@@ -743,9 +804,9 @@ impl Array {
         }
         let callback = &args[0];
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
-        let len = this.get_field("length").as_number().unwrap() as i32;
+        let len = this.get_field("length", interpreter)?.as_number().unwrap() as i32;
         for i in 0..len {
-            let element = this.get_field(i);
+            let element = this.get_field(i, interpreter)?;
             let arguments = [element.clone(), Value::from(i), this.clone()];
             let result = interpreter.call(callback, &this_arg, &arguments)?;
             if result.to_boolean() {
@@ -782,10 +843,10 @@ impl Array {
 
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this.get_field("length", interpreter)?.as_number().unwrap() as i32;
 
         for i in 0..length {
-            let element = this.get_field(i);
+            let element = this.get_field(i, interpreter)?;
             let arguments = [element, Value::from(i), this.clone()];
 
             let result = interpreter.call(predicate_arg, &this_arg, &arguments)?;
@@ -810,7 +871,7 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.fill
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fill
     pub(crate) fn fill(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let len: i32 = this.get_field("length").as_number().unwrap() as i32;
+        let len: i32 = this.get_field("length", ctx)?.as_number().unwrap() as i32;
 
         let default_value = Value::undefined();
         let value = args.get(0).unwrap_or(&default_value);
@@ -833,7 +894,7 @@ impl Array {
         };
 
         for i in start..fin {
-            this.set_field(i, value.clone());
+            this.set_field(i, value.clone(), ctx)?;
         }
 
         Ok(this.clone())
@@ -849,13 +910,13 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.includes
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/includes
-    pub(crate) fn includes_value(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+    pub(crate) fn includes_value(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         let search_element = args.get(0).cloned().unwrap_or_else(Value::undefined);
 
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this.get_field("length", ctx)?.as_number().unwrap() as i32;
 
         for idx in 0..length {
-            let check_element = this.get_field(idx).clone();
+            let check_element = this.get_field(idx, ctx)?.clone();
 
             if same_value_zero(&check_element, &search_element) {
                 return Ok(Value::from(true));
@@ -881,7 +942,7 @@ impl Array {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/slice
     pub(crate) fn slice(this: &Value, args: &[Value], interpreter: &mut Context) -> Result<Value> {
         let new_array = Self::new_array(interpreter)?;
-        let len = this.get_field("length").as_number().unwrap() as i32;
+        let len = this.get_field("length", interpreter)?.as_number().unwrap() as i32;
 
         let start = match args.get(0) {
             Some(v) => v.as_number().unwrap() as i32,
@@ -906,10 +967,11 @@ impl Array {
         let span = max(to.wrapping_sub(from), 0);
         let mut new_array_len: i32 = 0;
         for i in from..from.wrapping_add(span) {
-            new_array.set_field(new_array_len, this.get_field(i));
+            let element = this.get_field(i, interpreter)?;
+            new_array.set_field(new_array_len, element, interpreter)?;
             new_array_len = new_array_len.wrapping_add(1);
         }
-        new_array.set_field("length", Value::from(new_array_len));
+        new_array.set_field("length", Value::from(new_array_len), interpreter)?;
         Ok(new_array)
     }
 
@@ -934,29 +996,26 @@ impl Array {
         let callback = args.get(0).cloned().unwrap_or_else(Value::undefined);
         let this_val = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this.get_field("length", interpreter)?.as_number().unwrap() as i32;
 
         let new = Self::new_array(interpreter)?;
 
-        let values = (0..length)
-            .filter_map(|idx| {
-                let element = this.get_field(idx);
+        let mut values = Vec::new();
+        for idx in 0..length {
+            let element = this.get_field(idx, interpreter)?;
 
-                let args = [element.clone(), Value::from(idx), new.clone()];
+            let args = [element.clone(), Value::from(idx), new.clone()];
 
-                let callback_result = interpreter
-                    .call(&callback, &this_val, &args)
-                    .unwrap_or_else(|_| Value::undefined());
+            let callback_result = interpreter
+                .call(&callback, &this_val, &args)
+                .unwrap_or_else(|_| Value::undefined());
 
-                if callback_result.to_boolean() {
-                    Some(element)
-                } else {
-                    None
-                }
-            })
-            .collect::<Vec<Value>>();
+            if callback_result.to_boolean() {
+                values.push(element);
+            }
+        }
 
-        Self::construct_array(&new, &values)
+        Self::construct_array(&new, &values, interpreter)
     }
 
     /// Array.prototype.some ( callbackfn [ , thisArg ] )
@@ -987,10 +1046,10 @@ impl Array {
             Value::undefined()
         };
         let mut i = 0;
-        let max_len = this.get_field("length").as_number().unwrap() as i32;
+        let max_len = this.get_field("length", interpreter)?.as_number().unwrap() as i32;
         let mut len = max_len;
         while i < len {
-            let element = this.get_field(i);
+            let element = this.get_field(i, interpreter)?;
             let arguments = [element, Value::from(i), this.clone()];
             let result = interpreter.call(callback, &this_arg, &arguments)?;
             if result.to_boolean() {
@@ -999,7 +1058,7 @@ impl Array {
             // the length of the array must be updated because the callback can mutate it.
             len = min(
                 max_len,
-                this.get_field("length").as_number().unwrap() as i32,
+                this.get_field("length", interpreter)?.as_number().unwrap() as i32,
             );
             i += 1;
         }
@@ -1024,7 +1083,9 @@ impl Array {
             _ => return interpreter.throw_type_error("Reduce was called without a callback"),
         };
         let initial_value = args.get(1).cloned().unwrap_or_else(Value::undefined);
-        let mut length = this.get_field("length").to_length(interpreter)?;
+        let mut length = this
+            .get_field("length", interpreter)?
+            .to_length(interpreter)?;
         if length == 0 && initial_value.is_undefined() {
             return interpreter
                 .throw_type_error("Reduce was called on an empty array and with no initial value");
@@ -1044,7 +1105,7 @@ impl Array {
                     "Reduce was called on an empty array and with no initial value",
                 );
             }
-            let result = this.get_field(k);
+            let result = this.get_field(k, interpreter)?;
             k += 1;
             result
         } else {
@@ -1052,12 +1113,21 @@ impl Array {
         };
         while k < length {
             if this.has_field(k) {
-                let arguments = [accumulator, this.get_field(k), Value::from(k), this.clone()];
+                let arguments = [
+                    accumulator,
+                    this.get_field(k, interpreter)?,
+                    Value::from(k),
+                    this.clone(),
+                ];
                 accumulator = interpreter.call(&callback, &Value::undefined(), &arguments)?;
                 /* We keep track of possibly shortened length in order to prevent unnecessary iteration.
                 It may also be necessary to do this since shortening the array length does not
                 delete array elements. See: https://github.com/boa-dev/boa/issues/557 */
-                length = min(length, this.get_field("length").to_length(interpreter)?);
+                length = min(
+                    length,
+                    this.get_field("length", interpreter)?
+                        .to_length(interpreter)?,
+                );
             }
             k += 1;
         }
@@ -1086,7 +1156,9 @@ impl Array {
             _ => return interpreter.throw_type_error("reduceRight was called without a callback"),
         };
         let initial_value = args.get(1).cloned().unwrap_or_else(Value::undefined);
-        let mut length = this.get_field("length").to_length(interpreter)?;
+        let mut length = this
+            .get_field("length", interpreter)?
+            .to_length(interpreter)?;
         if length == 0 {
             if initial_value.is_undefined() {
                 return interpreter.throw_type_error(
@@ -1116,7 +1188,7 @@ impl Array {
                     "reduceRight was called on an empty array and with no initial value",
                 );
             }
-            let result = this.get_field(k);
+            let result = this.get_field(k, interpreter)?;
             k = k.overflowing_sub(1).0;
             result
         } else {
@@ -1125,12 +1197,21 @@ impl Array {
         // usize::MAX is bigger than the maximum array size so we can use it check for integer undeflow
         while k != usize::MAX {
             if this.has_field(k) {
-                let arguments = [accumulator, this.get_field(k), Value::from(k), this.clone()];
+                let arguments = [
+                    accumulator,
+                    this.get_field(k, interpreter)?,
+                    Value::from(k),
+                    this.clone(),
+                ];
                 accumulator = interpreter.call(&callback, &Value::undefined(), &arguments)?;
                 /* We keep track of possibly shortened length in order to prevent unnecessary iteration.
                 It may also be necessary to do this since shortening the array length does not
                 delete array elements. See: https://github.com/boa-dev/boa/issues/557 */
-                length = min(length, this.get_field("length").to_length(interpreter)?);
+                length = min(
+                    length,
+                    this.get_field("length", interpreter)?
+                        .to_length(interpreter)?,
+                );
 
                 // move k to the last defined element if necessary or return if the length was set to 0
                 if k >= length {
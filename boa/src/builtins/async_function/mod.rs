@@ -0,0 +1,130 @@
+//! This module implements the evaluation of async functions.
+//!
+//! Calling an async function runs its body until the first `await`, and returns a promise that
+//! is settled once the body completes. Each `await` suspends the body, it's resumed by a job once
+//! the awaited value is settled.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-async-function-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+
+use crate::{
+    builtins::{
+        function::{bind_parameters, captured_mut},
+        generator::{Completion, SuspendedBody},
+        promise::{Promise, PromiseCapability},
+    },
+    environment::lexical_environment::Environment,
+    exec::ResumeAction,
+    object::{FunctionBuilder, Object},
+    syntax::ast::node::{FormalParameter, RcStatementList},
+    Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a call to an async function, while its body is suspended at an `await`.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct AsyncFunction {
+    body: SuspendedBody,
+    capability: PromiseCapability,
+}
+
+impl AsyncFunction {
+    /// Starts running the body of an async function, and returns the promise of the call.
+    ///
+    /// The parameters are bound in `environment`, the function environment of the call. Like any
+    /// error thrown by the body, an error binding them rejects the promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-async-functions-abstract-operations-async-function-start
+    pub(crate) fn start(
+        body: RcStatementList,
+        params: &[FormalParameter],
+        args: &[Value],
+        environment: Environment,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let capability = Promise::new_capability(ctx);
+        if let Err(exception) = bind_parameters(params, &body, args, &environment, ctx) {
+            return capability.reject_with(exception, ctx);
+        }
+        let promise = capability.promise().clone();
+        let state = Object::native_object(Self {
+            body: SuspendedBody::new(body, environment),
+            capability,
+        })
+        .into();
+        Self::resume(&state, None, ctx)?;
+        Ok(promise)
+    }
+
+    /// Runs the body until the next `await`, or until it completes and settles the promise of
+    /// the call.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#await
+    fn resume(state: &Value, mut action: Option<ResumeAction>, ctx: &mut Context) -> Result<Value> {
+        loop {
+            let body = captured_mut(state, |function: &mut Self| function.body.take());
+            let (body, completion) = body.run(action, ctx);
+            let capability = captured_mut(state, |function: &mut Self| {
+                function.body = body;
+                function.capability.clone()
+            });
+
+            let value = match completion {
+                Completion::Await(value) => value,
+                Completion::Return(value) => return capability.resolve(value, ctx),
+                Completion::Throw(exception) => return capability.reject(exception, ctx),
+                Completion::Yield(_) => unreachable!("`yield` is not allowed in async functions"),
+            };
+
+            let constructor = ctx.standard_objects().promise_object().constructor();
+            let promise = match Promise::promise_resolve(&constructor.into(), value, ctx) {
+                Ok(Value::Object(ref promise)) => promise.clone(),
+                Ok(_) => unreachable!("the Promise constructor creates objects"),
+                // The `await` throws if the value is a promise with a broken `constructor`.
+                Err(exception) => {
+                    action = Some(ResumeAction::Throw(exception));
+                    continue;
+                }
+            };
+            let on_fulfilled = FunctionBuilder::closure(ctx, Self::on_fulfilled, state.clone())
+                .length(1)
+                .build();
+            let on_rejected = FunctionBuilder::closure(ctx, Self::on_rejected, state.clone())
+                .length(1)
+                .build();
+            Promise::perform_promise_then(
+                &promise,
+                &on_fulfilled.into(),
+                &on_rejected.into(),
+                None,
+                ctx,
+            );
+            return Ok(Value::undefined());
+        }
+    }
+
+    /// Resumes the body once the awaited value is fulfilled, the `await` evaluates to it.
+    fn on_fulfilled(_: &Value, args: &[Value], state: &Value, ctx: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::resume(state, Some(ResumeAction::Next(value)), ctx)
+    }
+
+    /// Resumes the body once the awaited value is rejected, the `await` throws the reason.
+    fn on_rejected(_: &Value, args: &[Value], state: &Value, ctx: &mut Context) -> Result<Value> {
+        let reason = args.get(0).cloned().unwrap_or_default();
+        Self::resume(state, Some(ResumeAction::Throw(reason)), ctx)
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{forward, Context};
+
+#[test]
+fn returns_promise() {
+    let mut engine = Context::new();
+    let init = r#"
+        var log = [];
+        async function f(x) {
+            log.push("start " + x);
+            return x * 2;
+        }
+        var p = f(1);
+        log.push("sync");
+        p.then(v => log.push("result " + v));
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "typeof p.then"), "\"function\"");
+    assert_eq!(forward(&mut engine, "log.join()"), "\"start 1,sync\"");
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut engine, "log.join()"),
+        "\"start 1,sync,result 2\""
+    );
+}
+
+#[test]
+fn await_suspends() {
+    let mut engine = Context::new();
+    let init = r#"
+        var log = [];
+        async function f() {
+            log.push("before");
+            var a = await 1;
+            var b = await Promise.resolve(2);
+            log.push("after " + (a + b));
+            return a + b;
+        }
+        var result;
+        f().then(v => { result = v; });
+        log.push("sync");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "log.join()"), "\"before,sync\"");
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut engine, "log.join()"),
+        "\"before,sync,after 3\""
+    );
+    assert_eq!(forward(&mut engine, "result"), "3");
+}
+
+#[test]
+fn await_rejection_throws() {
+    let mut engine = Context::new();
+    let init = r#"
+        var caught;
+        async function f() {
+            try {
+                await Promise.reject("boom");
+            } catch (e) {
+                caught = e;
+            }
+            await Promise.reject("unhandled");
+        }
+        var reason;
+        f().catch(e => { reason = e; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "caught"), "\"boom\"");
+    assert_eq!(forward(&mut engine, "reason"), "\"unhandled\"");
+}
+
+#[test]
+fn throw_rejects() {
+    let mut engine = Context::new();
+    let init = r#"
+        var reason;
+        (async function () { throw "thrown"; })().catch(e => { reason = e; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "reason"), "\"thrown\"");
+}
+
+#[test]
+fn async_arrow_functions() {
+    let mut engine = Context::new();
+    let init = r#"
+        var sum, doubled;
+        var add = async (a, b) => (await a) + b;
+        var double = async x => x * 2;
+        add(1, 2).then(v => { sum = v; });
+        double(4).then(v => { doubled = v; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "sum"), "3");
+    assert_eq!(forward(&mut engine, "doubled"), "8");
+}
+
+#[test]
+fn async_methods() {
+    let mut engine = Context::new();
+    let init = r#"
+        var value, count;
+        var obj = { value: 1, async get() { return await this.value; } };
+        class Counter {
+            constructor() { this.count = 2; }
+            async next() { return ++this.count; }
+        }
+        obj.get().then(v => { value = v; });
+        new Counter().next().then(v => { count = v; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "value"), "1");
+    assert_eq!(forward(&mut engine, "count"), "3");
+}
+
+#[test]
+fn async_is_an_identifier() {
+    let mut engine = Context::new();
+    let init = r#"
+        var async = function (x) { return x + 1; };
+        var value = async(1);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "value"), "2");
+}
+
+#[test]
+fn not_a_constructor() {
+    let mut engine = Context::new();
+    let init = r#"
+        async function f() {}
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "f.hasOwnProperty('prototype')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { new f() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
@@ -324,7 +324,7 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date-constructor
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/Date
     pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        if this.is_global() {
+        if !this.is_object() {
             Self::make_date_string()
         } else if args.is_empty() {
             Self::make_date_now(this)
@@ -0,0 +1,100 @@
+//! This module implements the global `AggregateError` object.
+//!
+//! The `AggregateError` object represents an error when several errors need to be wrapped in a
+//! single error, like when every promise given to `Promise.any` is rejected.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
+
+use crate::{
+    builtins::{iterable::get_iterator, Array, BuiltIn},
+    object::{ConstructorBuilder, ObjectData},
+    profiler::BoaProfiler,
+    property::{Attribute, DataDescriptor},
+    Context, Result, Value,
+};
+
+/// JavaScript `AggregateError` implementation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AggregateError;
+
+impl BuiltIn for AggregateError {
+    const NAME: &'static str = "AggregateError";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let error_prototype = context.standard_objects().error_object().prototype();
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let aggregate_error_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().aggregate_error_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .inherit(error_prototype.into())
+        .property("name", Self::NAME, attribute)
+        .property("message", "", attribute)
+        .build();
+
+        (Self::NAME, aggregate_error_object.into(), Self::attribute())
+    }
+}
+
+impl AggregateError {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 2;
+
+    /// `AggregateError( errors, message )`
+    ///
+    /// Create a new error object, `errors` is an iterable of the wrapped errors.
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        // `AggregateError(...)` is the same as `new AggregateError(...)`.
+        if !this.is_object() {
+            let constructor = ctx
+                .standard_objects()
+                .aggregate_error_object()
+                .constructor();
+            return constructor.construct(args, &constructor.clone().into(), ctx);
+        }
+
+        if let Some(message) = args.get(1) {
+            if !message.is_undefined() {
+                this.set_field("message", message.to_string(ctx)?, ctx)?;
+            }
+        }
+
+        let iterator = get_iterator(ctx, args.get(0).cloned().unwrap_or_default())?;
+        let mut errors = Vec::new();
+        loop {
+            let next = iterator.next(ctx)?;
+            if next.is_done() {
+                break;
+            }
+            errors.push(next.value());
+        }
+        let errors_array = Array::new_array(ctx)?;
+        Array::add_to_array_object(&errors_array, &errors, ctx)?;
+        this.set_property(
+            "errors",
+            DataDescriptor::new(
+                errors_array,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
+
+        // This value is used by console.log and other routines to match Object type
+        // to its Javascript Identifier (global constructor method name)
+        this.set_data(ObjectData::Error);
+        Ok(this.clone())
+    }
+}
@@ -18,6 +18,7 @@ use crate::{
     Context, Result, Value,
 };
 
+pub(crate) mod aggregate;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
@@ -28,6 +29,7 @@ pub(crate) mod uri;
 #[cfg(test)]
 mod tests;
 
+pub(crate) use self::aggregate::AggregateError;
 pub(crate) use self::eval::EvalError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
@@ -74,8 +76,14 @@ impl Error {
     ///
     /// Create a new error object.
     pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        // `Error(...)` is the same as `new Error(...)`.
+        if !this.is_object() {
+            let constructor = ctx.standard_objects().error_object().constructor();
+            return constructor.construct(args, &constructor.clone().into(), ctx);
+        }
+
         if let Some(message) = args.get(0) {
-            this.set_field("message", message.to_string(ctx)?);
+            this.set_field("message", message.to_string(ctx)?, ctx)?;
         }
 
         // This value is used by console.log and other routines to match Object type
@@ -99,7 +107,7 @@ impl Error {
         if !this.is_object() {
             return context.throw_type_error("'this' is not an Object");
         }
-        let name = this.get_field("name");
+        let name = this.get_field("name", context)?;
         let name_to_string;
         let name = if name.is_undefined() {
             "Error"
@@ -108,7 +116,7 @@ impl Error {
             name_to_string.as_str()
         };
 
-        let message = this.get_field("message");
+        let message = this.get_field("message", context)?;
         let message_to_string;
         let message = if message.is_undefined() {
             ""
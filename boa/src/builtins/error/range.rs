@@ -55,8 +55,14 @@ impl RangeError {
 
     /// Create a new error object.
     pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        // `RangeError(...)` is the same as `new RangeError(...)`.
+        if !this.is_object() {
+            let constructor = ctx.standard_objects().range_error_object().constructor();
+            return constructor.construct(args, &constructor.clone().into(), ctx);
+        }
+
         if let Some(message) = args.get(0) {
-            this.set_field("message", message.to_string(ctx)?);
+            this.set_field("message", message.to_string(ctx)?, ctx)?;
         }
 
         // This value is used by console.log and other routines to match Object type
@@ -60,8 +60,14 @@ impl TypeError {
 
     /// Create a new error object.
     pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        // `TypeError(...)` is the same as `new TypeError(...)`.
+        if !this.is_object() {
+            let constructor = ctx.standard_objects().type_error_object().constructor();
+            return constructor.construct(args, &constructor.clone().into(), ctx);
+        }
+
         if let Some(message) = args.get(0) {
-            this.set_field("message", message.to_string(ctx)?);
+            this.set_field("message", message.to_string(ctx)?, ctx)?;
         }
 
         // This value is used by console.log and other routines to match Object type
@@ -0,0 +1,152 @@
+//! This module implements the global `eval` function.
+//!
+//! The `eval()` function evaluates JavaScript code represented as a string.
+//!
+//! A call of `eval` with the plain name `eval` is a direct eval, which evaluates the code in the
+//! scope of the caller. Every other call is an indirect eval, which evaluates the code in the
+//! global scope.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-eval-x
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/eval
+
+use crate::{
+    builtins::BuiltIn,
+    environment::{
+        function_environment_record::BindingStatus,
+        lexical_environment::{new_declarative_environment, new_function_environment},
+    },
+    object::FunctionBuilder,
+    property::Attribute,
+    syntax::Parser,
+    BoaProfiler, Context, Executable, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The JavaScript `eval` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Eval;
+
+impl BuiltIn for Eval {
+    const NAME: &'static str = "eval";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let eval = context.standard_objects().eval_function().clone();
+        FunctionBuilder::new(context, Self::eval)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .callable(true)
+            .constructable(false)
+            .build_standard_function(&eval);
+
+        (Self::NAME, eval.into(), Self::attribute())
+    }
+}
+
+impl Eval {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// `eval( x )`
+    ///
+    /// Calls that reach this function are indirect evals, direct evals are handled by the call
+    /// expression.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-eval-x
+    fn eval(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let x = args.first().cloned().unwrap_or_default();
+        Self::perform_eval(&x, false, context)
+    }
+
+    /// Evaluates `x` as a script, if it's a string.
+    ///
+    /// A direct eval runs in the scope of the caller, and it is strict mode code if the caller
+    /// is. An indirect eval runs in the global scope.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub(crate) fn perform_eval(x: &Value, direct: bool, context: &mut Context) -> Result<Value> {
+        let source = match x {
+            Value::String(ref source) => source.clone(),
+            _ => return Ok(x.clone()),
+        };
+
+        context.ensure_can_compile_strings(&source)?;
+
+        let strict = direct && context.strict();
+        let in_function = direct
+            && context
+                .realm()
+                .environment
+                .get_this_environment()
+                .borrow()
+                .as_function_environment_record()
+                .is_some();
+        let private_names = if direct {
+            context.realm().environment.private_names()
+        } else {
+            Vec::new()
+        };
+        let mut parser = Parser::new(source.as_bytes());
+        parser.set_annex_b(context.annex_b());
+        let body = match parser.parse_eval(strict, in_function, &private_names) {
+            Ok(body) => body,
+            Err(e) => return context.throw_syntax_error(e.to_string()),
+        };
+
+        let environment = &mut context.realm_mut().environment;
+        let outer = if direct {
+            environment.get_current_environment().clone()
+        } else {
+            environment.get_global_environment()
+        };
+
+        // The `let` and `const` declarations of the code are always local to the eval. The `var`
+        // declarations of strict code are too, so it gets a function environment without a
+        // `this` binding of its own, which is where they are created.
+        let eval_env = if body.strict() {
+            new_function_environment(
+                context.standard_objects().eval_function().clone(),
+                None,
+                Some(outer),
+                BindingStatus::Lexical,
+                Value::undefined(),
+                Value::undefined(),
+            )
+        } else {
+            new_declarative_environment(Some(outer))
+        };
+
+        // The code of an indirect eval only sees the global scope, not the code that called it.
+        let environment = &mut context.realm_mut().environment;
+        let caller_environments = if direct {
+            None
+        } else {
+            Some(environment.enter_global_scope())
+        };
+        environment.push(eval_env.clone());
+        let result = body.run(context);
+        let environment = &mut context.realm_mut().environment;
+        match caller_environments {
+            Some(environments) => environment.restore(environments),
+            None => environment.pop_to(&eval_env),
+        }
+        result
+    }
+}
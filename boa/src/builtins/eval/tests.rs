@@ -0,0 +1,166 @@
+use crate::{forward, forward_val, Context};
+
+#[test]
+fn eval_non_string() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "eval(42)"), "42");
+    assert_eq!(forward(&mut engine, "let o = {}; eval(o) === o"), "true");
+    assert_eq!(forward(&mut engine, "eval()"), "undefined");
+}
+
+#[test]
+fn eval_completion_value() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "eval('1 + 2')"), "3");
+    assert_eq!(
+        forward(&mut engine, "eval('if (true) { \"a\"; }')"),
+        "\"a\""
+    );
+    assert_eq!(forward(&mut engine, "eval('')"), "undefined");
+}
+
+#[test]
+fn direct_eval_uses_caller_scope() {
+    let mut engine = Context::new();
+    let init = r#"
+        var x = "global";
+        function f() {
+            let x = "local";
+            eval("var y = x + '!';");
+            return y;
+        }
+        function g() {
+            let x = "local";
+            return (0, eval)("x");
+        }
+        function h() {
+            var x = "local";
+            return [eval("x"), (0, eval)("x")].join();
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "f()"), "\"local!\"");
+    assert!(forward(&mut engine, "y").starts_with("Uncaught \"ReferenceError\""));
+    assert_eq!(forward(&mut engine, "g()"), "\"global\"");
+    assert_eq!(forward(&mut engine, "h()"), "\"local,global\"");
+    assert_eq!(forward(&mut engine, "x"), "\"global\"");
+}
+
+#[test]
+fn indirect_eval_declares_globals() {
+    let mut engine = Context::new();
+    let init = r#"
+        function f() {
+            var indirect = eval;
+            indirect("var declared = 1; let local = 2;");
+        }
+        f();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "declared"), "1");
+    assert!(forward(&mut engine, "local").starts_with("Uncaught \"ReferenceError\""));
+}
+
+#[test]
+fn strict_eval_has_own_variables() {
+    let mut engine = Context::new();
+    let init = r#"
+        function f() {
+            "use strict";
+            eval("var x = 1;");
+            try {
+                return x;
+            } catch (e) {
+                return e.name;
+            }
+        }
+        function g() {
+            eval("'use strict'; var x = 1;");
+            try {
+                return x;
+            } catch (e) {
+                return e.name;
+            }
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "f()"), "\"ReferenceError\"");
+    assert_eq!(forward(&mut engine, "g()"), "\"ReferenceError\"");
+}
+
+#[test]
+fn eval_this() {
+    let mut engine = Context::new();
+    let init = r#"
+        let o = {
+            direct() { return eval("this"); },
+            indirect() { return (0, eval)("this"); },
+        };
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "o.direct() === o"), "true");
+    assert_eq!(forward(&mut engine, "o.indirect() === globalThis"), "true");
+}
+
+#[test]
+fn eval_syntax_error() {
+    let mut engine = Context::new();
+    let error = forward_val(&mut engine, "eval('1 +')").unwrap_err();
+    let name = error.get_field("name", &mut engine).unwrap();
+    assert_eq!(name.display().to_string(), "\"SyntaxError\"");
+
+    let caught = r#"
+        try {
+            eval("let let = 1;");
+        } catch (e) {
+            e.name;
+        }
+        "#;
+    assert_eq!(forward(&mut engine, caught), "\"SyntaxError\"");
+}
+
+#[test]
+fn compile_strings_hook() {
+    let mut engine = Context::new();
+    engine.set_compile_strings_hook(|source, context| {
+        if source.contains("forbidden") {
+            Err(context.construct_eval_error("code generation is disallowed"))
+        } else {
+            Ok(())
+        }
+    });
+    assert_eq!(forward(&mut engine, "eval('1 + 1')"), "2");
+    assert_eq!(forward(&mut engine, "eval(42)"), "42");
+    let caught = r#"
+        try {
+            eval("'forbidden'");
+        } catch (e) {
+            e.name;
+        }
+        "#;
+    assert_eq!(forward(&mut engine, caught), "\"EvalError\"");
+    let caught = r#"
+        try {
+            (0, eval)("'forbidden'");
+        } catch (e) {
+            e.name;
+        }
+        "#;
+    assert_eq!(forward(&mut engine, caught), "\"EvalError\"");
+}
+
+#[test]
+fn compile_strings_hook_with_state() {
+    use std::{cell::Cell, rc::Rc};
+
+    let mut engine = Context::new();
+    let compiled = Rc::new(Cell::new(0));
+    let counter = compiled.clone();
+    engine.set_compile_strings_hook(move |_, _| {
+        counter.set(counter.get() + 1);
+        Ok(())
+    });
+    assert_eq!(forward(&mut engine, "eval('1 + 1')"), "2");
+    assert_eq!(forward(&mut engine, "new Function('return 1')()"), "1");
+    assert_eq!(compiled.get(), 2);
+}
@@ -0,0 +1,177 @@
+//! This module implements the `arguments` objects of function calls.
+//!
+//! The arguments object of a non-strict function with a simple parameter list is mapped: its
+//! elements are linked to the bindings of the parameters, so that assigning to one of them also
+//! changes the other.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/arguments
+
+use crate::{
+    environment::lexical_environment::Environment,
+    object::{GcObject, Object, ObjectData},
+    property::{AccessorDescriptor, Attribute, PropertyKey},
+    syntax::ast::node::{Binding, FormalParameter},
+    Context, Value,
+};
+use gc::{Finalize, Trace};
+
+/// The kind of an arguments object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum Arguments {
+    /// The arguments object of a strict mode function, or of a function whose parameters are not
+    /// all plain names.
+    Unmapped,
+    /// An arguments exotic object, whose elements are linked to the parameters of the function.
+    Mapped(ParameterMap),
+}
+
+/// The `[[ParameterMap]]` of a mapped arguments object.
+///
+/// It gives the name of the parameter binding each element is linked to. An element stops being
+/// linked when it is deleted, redefined as an accessor, or made read-only.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ParameterMap {
+    environment: Environment,
+    names: Vec<Option<String>>,
+}
+
+impl ParameterMap {
+    /// Gets the name of the parameter the element `key` is linked to.
+    fn name(&self, key: &PropertyKey) -> Option<&str> {
+        match key {
+            PropertyKey::Index(index) => self.names.get(*index as usize)?.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Checks if the element `key` is linked to a parameter.
+    pub(crate) fn is_mapped(&self, key: &PropertyKey) -> bool {
+        self.name(key).is_some()
+    }
+
+    /// Gets the value of the parameter the element `key` is linked to.
+    pub(crate) fn get(&self, key: &PropertyKey) -> Option<Value> {
+        let name = self.name(key)?;
+        self.environment
+            .borrow()
+            .get_binding_value(name, false)
+            .ok()
+    }
+
+    /// Sets the value of the parameter the element `key` is linked to.
+    pub(crate) fn set(&self, key: &PropertyKey, value: Value) {
+        if let Some(name) = self.name(key) {
+            let _ = self
+                .environment
+                .borrow_mut()
+                .set_mutable_binding(name, value, false);
+        }
+    }
+
+    /// Unlinks the element `key` from its parameter.
+    pub(crate) fn delete(&mut self, key: &PropertyKey) {
+        if let PropertyKey::Index(index) = key {
+            if let Some(name) = self.names.get_mut(*index as usize) {
+                *name = None;
+            }
+        }
+    }
+}
+
+/// Creates the arguments object of a call, which is mapped to the parameter bindings in
+/// `environment` if the function is not strict and its parameters are all plain names.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-functiondeclarationinstantiation
+pub(crate) fn create_arguments_object(
+    function: &GcObject,
+    params: &[FormalParameter],
+    arguments_list: &[Value],
+    environment: &Environment,
+    strict: bool,
+    context: &mut Context,
+) -> Value {
+    let simple = params
+        .iter()
+        .all(|param| matches!(param.binding(), Binding::Identifier(_)) && param.init().is_none());
+    if strict || params.iter().any(FormalParameter::is_rest_param) || !simple {
+        return create_unmapped_arguments_object(arguments_list, context);
+    }
+
+    // A repeated parameter name is mapped to the last argument with that name.
+    let mut names = vec![None; arguments_list.len().min(params.len())];
+    let mut mapped_names = Vec::new();
+    for (index, param) in params.iter().enumerate().rev() {
+        if let Binding::Identifier(ref name) = param.binding() {
+            let name: &str = name.as_ref();
+            if !mapped_names.contains(&name) {
+                mapped_names.push(name);
+                if let Some(slot) = names.get_mut(index) {
+                    *slot = Some(name.to_string());
+                }
+            }
+        }
+    }
+
+    let map = ParameterMap {
+        environment: environment.clone(),
+        names,
+    };
+    let mut obj = arguments_object(arguments_list, Arguments::Mapped(map), context);
+    obj.insert_property(
+        "callee",
+        function.clone(),
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+    );
+    obj.into()
+}
+
+/// Creates an unmapped arguments object, whose `callee` property throws a `TypeError` when it
+/// is accessed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createunmappedargumentsobject
+pub fn create_unmapped_arguments_object(arguments_list: &[Value], context: &mut Context) -> Value {
+    let mut obj = arguments_object(arguments_list, Arguments::Unmapped, context);
+    let thrower = context
+        .standard_objects()
+        .throw_type_error_function()
+        .clone();
+    obj.insert(
+        "callee",
+        AccessorDescriptor::new(
+            Some(thrower.clone()),
+            Some(thrower),
+            Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        ),
+    );
+    obj.into()
+}
+
+/// Creates the properties that both kinds of arguments objects have: the elements, the `length`
+/// and the `@@iterator` method.
+fn arguments_object(arguments_list: &[Value], kind: Arguments, context: &mut Context) -> Object {
+    let prototype = context.standard_objects().object_object().prototype();
+    let mut obj = Object::create(prototype.into());
+    obj.data = ObjectData::Arguments(kind);
+
+    let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+    obj.insert_property("length", arguments_list.len(), attribute);
+    for (index, value) in arguments_list.iter().enumerate() {
+        obj.insert_property(index, value.clone(), Attribute::all());
+    }
+
+    let iterator = context.well_known_symbols().iterator_symbol();
+    let values = context.standard_objects().array_values_function().clone();
+    obj.insert_property(iterator, values, attribute);
+    obj
+}
@@ -13,16 +13,26 @@
 
 use crate::{
     builtins::{Array, BuiltIn},
-    environment::lexical_environment::Environment,
-    object::{ConstructorBuilder, FunctionBuilder, Object, ObjectData, PROTOTYPE},
-    property::{Attribute, DataDescriptor},
-    syntax::ast::node::{FormalParameter, RcStatementList},
+    environment::{lexical_environment::Environment, private_environment_record::PrivateName},
+    object::{
+        ConstructorBuilder, FunctionBuilder, GcObject, NativeObject, Object, ObjectData,
+        PrivateElement, PROTOTYPE,
+    },
+    property::{Attribute, DataDescriptor, PropertyKey},
+    syntax::{
+        ast::node::{FormalParameter, RcStatementList, StatementList},
+        parser::parse_function,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use bitflags::bitflags;
 use gc::{unsafe_empty_trace, Finalize, Trace};
 use std::fmt::{self, Debug};
 
+pub mod arguments;
+
+pub use arguments::create_unmapped_arguments_object;
+
 #[cfg(test)]
 mod tests;
 
@@ -48,12 +58,59 @@ impl Debug for BuiltInFunction {
     }
 }
 
+/// _fn(this, arguments, captures, ctx) -> ResultValue_ - The signature of a built-in closure
+///
+/// `captures` is the value the closure was created with, it's how the closure keeps state
+/// between calls.
+pub type NativeClosure = fn(&Value, &[Value], &Value, &mut Context) -> Result<Value>;
+
+#[derive(Clone, Copy, Finalize)]
+pub struct BuiltInClosure(pub(crate) NativeClosure);
+
+unsafe impl Trace for BuiltInClosure {
+    unsafe_empty_trace!();
+}
+
+impl From<NativeClosure> for BuiltInClosure {
+    fn from(function: NativeClosure) -> Self {
+        Self(function)
+    }
+}
+
+impl Debug for BuiltInClosure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[native closure]")
+    }
+}
+
+/// Gets a copy of the state a native closure was created with.
+pub(crate) fn captured<T: NativeObject + Clone>(captures: &Value) -> T {
+    captures
+        .as_object()
+        .and_then(|object| object.downcast_ref::<T>().cloned())
+        .expect("closure captures have the expected type")
+}
+
+/// Updates the state a native closure was created with.
+pub(crate) fn captured_mut<T: NativeObject, R>(captures: &Value, f: impl FnOnce(&mut T) -> R) -> R {
+    let mut object = captures
+        .as_object_mut()
+        .expect("closure captures are an object");
+    f(object
+        .downcast_mut::<T>()
+        .expect("closure captures have the expected type"))
+}
+
 bitflags! {
     #[derive(Finalize, Default)]
     pub struct FunctionFlags: u8 {
         const CALLABLE = 0b0000_0001;
         const CONSTRUCTABLE = 0b0000_0010;
         const LEXICAL_THIS_MODE = 0b0000_0100;
+        const CLASS_CONSTRUCTOR = 0b0000_1000;
+        const DERIVED_CONSTRUCTOR = 0b0001_0000;
+        const GENERATOR = 0b0010_0000;
+        const ASYNC = 0b0100_0000;
     }
 }
 
@@ -85,6 +142,26 @@ impl FunctionFlags {
     pub(crate) fn is_lexical_this_mode(&self) -> bool {
         self.contains(Self::LEXICAL_THIS_MODE)
     }
+
+    #[inline]
+    pub(crate) fn is_class_constructor(&self) -> bool {
+        self.contains(Self::CLASS_CONSTRUCTOR)
+    }
+
+    #[inline]
+    pub(crate) fn is_derived_constructor(&self) -> bool {
+        self.contains(Self::DERIVED_CONSTRUCTOR)
+    }
+
+    #[inline]
+    pub(crate) fn is_generator(&self) -> bool {
+        self.contains(Self::GENERATOR)
+    }
+
+    #[inline]
+    pub(crate) fn is_async(&self) -> bool {
+        self.contains(Self::ASYNC)
+    }
 }
 
 unsafe impl Trace for FunctionFlags {
@@ -99,61 +176,32 @@ unsafe impl Trace for FunctionFlags {
 #[derive(Debug, Clone, Finalize, Trace)]
 pub enum Function {
     BuiltIn(BuiltInFunction, FunctionFlags),
+    /// A built-in function that carries its own state, like the resolving functions of a promise.
+    Closure {
+        function: BuiltInClosure,
+        captures: Value,
+        flags: FunctionFlags,
+    },
     Ordinary {
         flags: FunctionFlags,
         body: RcStatementList,
         params: Box<[FormalParameter]>,
         environment: Environment,
+        home_object: Option<GcObject>,
+        /// The fields that a class constructor defines on the objects it constructs.
+        fields: Box<[ClassFieldDefinition]>,
+        /// The private methods and accessors that a class constructor adds to the objects it
+        /// constructs.
+        private_methods: Box<[(PrivateName, PrivateElement)]>,
     },
 }
 
 impl Function {
-    // Adds the final rest parameters to the Environment as an array
-    pub(crate) fn add_rest_param(
-        &self,
-        param: &FormalParameter,
-        index: usize,
-        args_list: &[Value],
-        interpreter: &mut Context,
-        local_env: &Environment,
-    ) {
-        // Create array of values
-        let array = Array::new_array(interpreter).unwrap();
-        Array::add_to_array_object(&array, &args_list[index..]).unwrap();
-
-        // Create binding
-        local_env
-            .borrow_mut()
-            .create_mutable_binding(param.name().to_owned(), false);
-
-        // Set Binding to value
-        local_env
-            .borrow_mut()
-            .initialize_binding(param.name(), array);
-    }
-
-    // Adds an argument to the environment
-    pub(crate) fn add_arguments_to_environment(
-        &self,
-        param: &FormalParameter,
-        value: Value,
-        local_env: &Environment,
-    ) {
-        // Create binding
-        local_env
-            .borrow_mut()
-            .create_mutable_binding(param.name().to_owned(), false);
-
-        // Set Binding to value
-        local_env
-            .borrow_mut()
-            .initialize_binding(param.name(), value);
-    }
-
     /// Returns true if the function object is callable.
     pub fn is_callable(&self) -> bool {
         match self {
             Self::BuiltIn(_, flags) => flags.is_callable(),
+            Self::Closure { flags, .. } => flags.is_callable(),
             Self::Ordinary { flags, .. } => flags.is_callable(),
         }
     }
@@ -162,37 +210,160 @@ impl Function {
     pub fn is_constructable(&self) -> bool {
         match self {
             Self::BuiltIn(_, flags) => flags.is_constructable(),
+            Self::Closure { flags, .. } => flags.is_constructable(),
             Self::Ordinary { flags, .. } => flags.is_constructable(),
         }
     }
+
+    /// Returns the `[[HomeObject]]` of the function, which is the object `super` property
+    /// lookups start from, if the function is a method.
+    pub fn home_object(&self) -> Option<&GcObject> {
+        match self {
+            Self::BuiltIn(_, _) | Self::Closure { .. } => None,
+            Self::Ordinary { home_object, .. } => home_object.as_ref(),
+        }
+    }
+
+    /// Makes the function a method of `home` by setting its `[[HomeObject]]`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-makemethod
+    pub(crate) fn set_home_object(&mut self, home: GcObject) {
+        if let Self::Ordinary { home_object, .. } = self {
+            *home_object = Some(home);
+        }
+    }
+
+    /// Returns the `[[Fields]]` of the function, which a class constructor defines on the
+    /// objects it constructs.
+    pub fn fields(&self) -> &[ClassFieldDefinition] {
+        match self {
+            Self::BuiltIn(_, _) | Self::Closure { .. } => &[],
+            Self::Ordinary { fields, .. } => fields,
+        }
+    }
+
+    /// Returns the `[[PrivateMethods]]` of the function, which a class constructor adds to the
+    /// objects it constructs.
+    pub fn private_methods(&self) -> &[(PrivateName, PrivateElement)] {
+        match self {
+            Self::BuiltIn(_, _) | Self::Closure { .. } => &[],
+            Self::Ordinary {
+                private_methods, ..
+            } => private_methods,
+        }
+    }
+
+    /// Sets the fields and the private methods of the instances of a class, whose constructor is
+    /// the function.
+    pub(crate) fn set_class_elements(
+        &mut self,
+        instance_fields: Vec<ClassFieldDefinition>,
+        instance_private_methods: Vec<(PrivateName, PrivateElement)>,
+    ) {
+        if let Self::Ordinary {
+            fields,
+            private_methods,
+            ..
+        } = self
+        {
+            *fields = instance_fields.into();
+            *private_methods = instance_private_methods.into();
+        }
+    }
+}
+
+/// The name of a field of a class, which is a property key for public fields.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum ClassFieldName {
+    Public(PropertyKey),
+    Private(PrivateName),
 }
 
-/// Arguments.
+/// A field of a class, with the function that computes its initial value.
 ///
-/// <https://tc39.es/ecma262/#sec-createunmappedargumentsobject>
-pub fn create_unmapped_arguments_object(arguments_list: &[Value]) -> Value {
-    let len = arguments_list.len();
-    let mut obj = Object::default();
-    // Set length
-    let length = DataDescriptor::new(
-        len,
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
-    );
-    // Define length as a property
-    obj.define_own_property("length", length.into());
-    let mut index: usize = 0;
-    while index < len {
-        let val = arguments_list.get(index).expect("Could not get argument");
-        let prop = DataDescriptor::new(
-            val.clone(),
-            Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
-        );
+/// The initializer is called with the object the field is defined on as `this`, and the field is
+/// `undefined` when it has no initializer.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-classfielddefinition-record-specification-type
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ClassFieldDefinition {
+    name: ClassFieldName,
+    initializer: Option<GcObject>,
+}
+
+impl ClassFieldDefinition {
+    /// Creates a new field definition.
+    pub(crate) fn new(name: ClassFieldName, initializer: Option<GcObject>) -> Self {
+        Self { name, initializer }
+    }
+
+    /// Gets the name of the field.
+    pub fn name(&self) -> &ClassFieldName {
+        &self.name
+    }
+
+    /// Gets the function that computes the initial value of the field, if it has one.
+    pub fn initializer(&self) -> Option<&GcObject> {
+        self.initializer.as_ref()
+    }
+}
 
-        obj.insert(index, prop);
-        index += 1;
+/// Binds the parameters of a function call in its function environment, along with the `var`
+/// declarations of its body.
+///
+/// The environment is pushed while the parameters are bound, since their default values and
+/// destructuring patterns can run code that refers to the previous parameters. The `var`
+/// declarations start as `undefined`, unless they have the name of a parameter.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-functiondeclarationinstantiation
+pub(crate) fn bind_parameters(
+    params: &[FormalParameter],
+    body: &StatementList,
+    args: &[Value],
+    local_env: &Environment,
+    ctx: &mut Context,
+) -> Result<()> {
+    ctx.realm_mut().environment.push(local_env.clone());
+    let mut result = Ok(());
+    for (i, param) in params.iter().enumerate() {
+        // Rest Parameters
+        if param.is_rest_param() {
+            let array = Array::new_array(ctx)?;
+            Array::add_to_array_object(&array, args.get(i..).unwrap_or_default(), ctx)?;
+            result = param.bind(array, ctx);
+            break;
+        }
+
+        let value = args.get(i).cloned().unwrap_or_else(Value::undefined);
+        result = param.bind(value, ctx);
+        if result.is_err() {
+            break;
+        }
     }
+    ctx.realm_mut().environment.pop_to(local_env);
+    result?;
 
-    Value::from(obj)
+    let mut var_names = Vec::new();
+    for node in body.statements() {
+        node.var_declared_names(&mut var_names);
+    }
+    let mut env = local_env.borrow_mut();
+    for name in var_names {
+        if !env.has_binding(name) {
+            env.create_mutable_binding(name.to_owned(), false);
+            env.initialize_binding(name, Value::undefined());
+        }
+    }
+    Ok(())
 }
 
 /// Creates a new constructor function
@@ -219,8 +390,10 @@ pub fn make_constructor_fn(
 
     // Get reference to Function.prototype
     // Create the function object and point its instance prototype to Function.prototype
-    let mut constructor =
-        Object::function(function, global.get_field("Function").get_field(PROTOTYPE));
+    let mut constructor = Object::function(
+        function,
+        global.get_data_field("Function").get_data_field(PROTOTYPE),
+    );
 
     let length = DataDescriptor::new(
         length,
@@ -284,8 +457,8 @@ pub fn make_builtin_fn<N>(
         Function::BuiltIn(function.into(), FunctionFlags::CALLABLE),
         interpreter
             .global_object()
-            .get_field("Function")
-            .get_field("prototype"),
+            .get_data_field("Function")
+            .get_data_field("prototype"),
     );
     function.insert_property("length", length, Attribute::all());
 
@@ -301,11 +474,73 @@ pub struct BuiltInFunctionObject;
 impl BuiltInFunctionObject {
     pub const LENGTH: usize = 1;
 
-    fn constructor(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        this.set_data(ObjectData::Function(Function::BuiltIn(
-            BuiltInFunction(|_, _, _| Ok(Value::undefined())),
-            FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
-        )));
+    /// `Function( p1, p2, … , pn, body )`
+    ///
+    /// Creates a function from the source text of its parameters and its body. The function
+    /// is created in the global scope, and it is named `anonymous`.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createdynamicfunction
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/Function
+    fn constructor(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // `Function(...)` is the same as `new Function(...)`.
+        if !this.is_object() {
+            let constructor = context.standard_objects().function_object().constructor();
+            return constructor.construct(args, &constructor.clone().into(), context);
+        }
+
+        let (body, params) = match args.split_last() {
+            Some((body, params)) => (body.to_string(context)?, params),
+            None => (Default::default(), args),
+        };
+        let mut params_source = String::new();
+        for (i, param) in params.iter().enumerate() {
+            if i > 0 {
+                params_source.push(',');
+            }
+            params_source.push_str(&param.to_string(context)?);
+        }
+
+        // The host sees the function the same way as it would be written in the source code.
+        context.ensure_can_compile_strings(&format!(
+            "function anonymous({}\n) {{\n{}\n}}",
+            params_source, body
+        ))?;
+
+        let (params, body) = match parse_function(&params_source, &body, context.annex_b()) {
+            Ok(function) => function,
+            Err(e) => return context.throw_syntax_error(e.to_string()),
+        };
+
+        let params_len = params.len();
+        let environment = context.realm().environment.get_global_environment();
+        this.set_data(ObjectData::Function(Function::Ordinary {
+            flags: FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
+            body: RcStatementList::from(body),
+            params,
+            environment,
+            home_object: None,
+            fields: Box::default(),
+            private_methods: Box::default(),
+        }));
+
+        let prototype = Value::new_object(Some(context.global_object()));
+        prototype.set_property(
+            "constructor",
+            DataDescriptor::new(this.clone(), Attribute::all()),
+        );
+        this.set_property(PROTOTYPE, DataDescriptor::new(prototype, Attribute::all()));
+        this.set_property("length", DataDescriptor::new(params_len, Attribute::all()));
+        this.set_property(
+            "name",
+            DataDescriptor::new(
+                "anonymous",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
         Ok(this.clone())
     }
 
@@ -332,6 +567,41 @@ impl BuiltInFunctionObject {
         let start = if !args.is_empty() { 1 } else { 0 };
         context.call(this, &this_arg, &args[start..])
     }
+
+    /// `Function.prototype [ @@hasInstance ] ( V )`
+    ///
+    /// Checks if `V` inherits from the `prototype` property of the function. This is the default
+    /// behaviour of the `instanceof` operator.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype-@@hasinstance
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/@@hasInstance
+    fn has_instance(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = args.first().cloned().unwrap_or_default();
+        match this {
+            Value::Object(ref function) => {
+                Ok(function.ordinary_has_instance(&object, context)?.into())
+            }
+            _ => Ok(false.into()),
+        }
+    }
+
+    /// `%ThrowTypeError%`
+    ///
+    /// Throws a `TypeError`, it is the `callee` accessor of unmapped arguments objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%throwtypeerror%
+    fn throw_type_error(_: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        context.throw_type_error(
+            "'callee' may not be accessed on the arguments objects of strict mode functions",
+        )
+    }
 }
 
 impl BuiltIn for BuiltInFunctionObject {
@@ -352,6 +622,26 @@ impl BuiltIn for BuiltInFunctionObject {
             .constructable(false)
             .build_function_prototype(&function_prototype);
 
+        let throw_type_error = context
+            .standard_objects()
+            .throw_type_error_function()
+            .clone();
+        FunctionBuilder::new(context, Self::throw_type_error)
+            .name("")
+            .length(0)
+            .callable(true)
+            .constructable(false)
+            .build_standard_function(&throw_type_error);
+        throw_type_error.borrow_mut().prevent_extensions();
+
+        let symbol_has_instance = context.well_known_symbols().has_instance_symbol();
+        let has_instance = FunctionBuilder::new(context, Self::has_instance)
+            .name("[Symbol.hasInstance]")
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+
         let function_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -360,6 +650,11 @@ impl BuiltIn for BuiltInFunctionObject {
         .name(Self::NAME)
         .length(Self::LENGTH)
         .method(Self::call, "call", 1)
+        .property(
+            symbol_has_instance,
+            has_instance,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        )
         .build();
 
         (Self::NAME, function_object.into(), Self::attribute())
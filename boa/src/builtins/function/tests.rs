@@ -163,3 +163,221 @@ fn function_prototype_call_multiple_args() {
         .unwrap();
     assert!(boolean);
 }
+
+#[test]
+fn function_constructor() {
+    let mut engine = Context::new();
+    let init = r#"
+        let add = new Function("a", "b", "return a + b;");
+        let noParams = Function("return 42");
+        let empty = new Function();
+        let joined = new Function("a, b", "...c", "return a + b + c.length;");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "add(1, 2)"), "3");
+    assert_eq!(forward(&mut engine, "noParams()"), "42");
+    assert_eq!(forward(&mut engine, "empty()"), "undefined");
+    assert_eq!(forward(&mut engine, "joined(1, 2, 3, 4)"), "5");
+    assert_eq!(forward(&mut engine, "add.length"), "2");
+    assert_eq!(forward(&mut engine, "add.name"), "\"anonymous\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(add) === Function.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(new add(1, 2)) === add.prototype"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn function_constructor_global_scope() {
+    let mut engine = Context::new();
+    let init = r#"
+        var x = "global";
+        function f() {
+            let x = "local";
+            return new Function("return x;")();
+        }
+        function g() {
+            var x = "local";
+            return new Function("return x;")();
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "f()"), "\"global\"");
+    assert_eq!(forward(&mut engine, "g()"), "\"global\"");
+    assert_eq!(forward(&mut engine, "x"), "\"global\"");
+}
+
+#[test]
+fn function_constructor_syntax_error() {
+    let mut engine = Context::new();
+    let check = |engine: &mut Context, code: &str| {
+        let error = forward_val(engine, code).unwrap_err();
+        let name = error.get_field("name", engine).unwrap();
+        assert_eq!(name.display().to_string(), "\"SyntaxError\"", "{}", code);
+    };
+    check(&mut engine, r#"new Function("return 1 +")"#);
+    check(&mut engine, r#"new Function("a) {", "}")"#);
+    check(&mut engine, r#"new Function("", "}); (function () {")"#);
+    check(&mut engine, r#"new Function("a, a", "'use strict';")"#);
+    check(&mut engine, r#"new Function("a /*", "*/ ) {")"#);
+    assert_eq!(
+        forward(
+            &mut engine,
+            r#"new Function("a // comment", "return a;")(7)"#
+        ),
+        "7"
+    );
+}
+
+#[test]
+fn function_constructor_compile_strings_hook() {
+    let mut engine = Context::new();
+    engine.set_compile_strings_hook(|source, context| {
+        if source == "function anonymous(a,b\n) {\nreturn a + b;\n}" {
+            Ok(())
+        } else {
+            Err(context.construct_eval_error("code generation is disallowed"))
+        }
+    });
+    assert_eq!(
+        forward(&mut engine, "new Function('a', 'b', 'return a + b;')(1, 2)"),
+        "3"
+    );
+    let error = forward_val(&mut engine, "new Function('return 1;')").unwrap_err();
+    let name = error.get_field("name", &mut engine).unwrap();
+    assert_eq!(name.display().to_string(), "\"EvalError\"");
+}
+
+#[test]
+fn function_prototype_has_instance() {
+    let mut engine = Context::new();
+    let init = r#"
+        function Foo() {}
+        var foo = new Foo();
+        var hasInstance = Function.prototype[Symbol.hasInstance];
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "hasInstance.call(Foo, foo)"), "true");
+    assert_eq!(forward(&mut engine, "hasInstance.call(Foo, {})"), "false");
+    assert_eq!(forward(&mut engine, "hasInstance.call(Foo, 1)"), "false");
+    assert_eq!(forward(&mut engine, "hasInstance.call({}, foo)"), "false");
+    assert_eq!(
+        forward(&mut engine, "hasInstance.name"),
+        "\"[Symbol.hasInstance]\""
+    );
+    assert_eq!(forward(&mut engine, "hasInstance.length"), "1");
+    assert_eq!(
+        forward(&mut engine, "Symbol.hasInstance === Symbol.asyncIterator"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut engine, "Function.prototype[Symbol.asyncIterator]"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut engine, "Symbol.hasInstance.toString()"),
+        "\"Symbol(Symbol.hasInstance)\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            r#"
+            Function.prototype[Symbol.hasInstance] = null;
+            Function.prototype[Symbol.hasInstance] === hasInstance;
+            "#
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn mapped_arguments_object() {
+    let mut engine = Context::new();
+    let init = r#"
+        function assign(a, b) {
+            arguments[0] = 10;
+            b = 20;
+            return [a, arguments[1], arguments.length].join();
+        }
+        function deleted(a) {
+            delete arguments[0];
+            arguments[0] = 5;
+            return a;
+        }
+        function readonly(a) {
+            Object.defineProperty(arguments, "0", { value: 7, writable: false });
+            a = 9;
+            return arguments[0];
+        }
+        function duplicate(a, a) {
+            return [a, arguments[0], arguments[1]].join();
+        }
+        function missing(a) {
+            a = 3;
+            return arguments[0];
+        }
+        function callee() {
+            return arguments.callee === callee;
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "assign(1, 2)"), "\"10,20,2\"");
+    assert_eq!(forward(&mut engine, "deleted(1)"), "1");
+    assert_eq!(forward(&mut engine, "readonly(1)"), "7");
+    assert_eq!(forward(&mut engine, "duplicate(1, 2)"), "\"2,1,2\"");
+    assert_eq!(forward(&mut engine, "missing()"), "undefined");
+    assert_eq!(forward(&mut engine, "callee()"), "true");
+}
+
+#[test]
+fn unmapped_arguments_object() {
+    let mut engine = Context::new();
+    let init = r#"
+        function strict(a) {
+            "use strict";
+            arguments[0] = 10;
+            return a;
+        }
+        function defaults(a = 0) {
+            arguments[0] = 10;
+            return a;
+        }
+        function strictCallee() {
+            "use strict";
+            return arguments.callee;
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "strict(1)"), "1");
+    assert_eq!(forward(&mut engine, "defaults(1)"), "1");
+    assert!(forward(&mut engine, "strictCallee()").starts_with("Uncaught \"TypeError\": "));
+}
+
+#[test]
+fn arguments_object_properties() {
+    let mut engine = Context::new();
+    let init = r#"
+        function tag() {
+            return Object.prototype.toString.call(arguments);
+        }
+        function iterate() {
+            var values = [];
+            for (var value of arguments) {
+                values.push(value);
+            }
+            return values.join();
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "tag()"), "\"[object Arguments]\"");
+    assert_eq!(forward(&mut engine, "iterate(1, 2, 3)"), "\"1,2,3\"");
+}
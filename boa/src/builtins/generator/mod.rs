@@ -0,0 +1,328 @@
+//! This module implements the global `Generator` object.
+//!
+//! Generator objects are returned by generator functions, and follow both the iterable and the
+//! iterator protocol.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-generator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator
+
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object},
+    environment::lexical_environment::Environment,
+    exec::{Executable, InterpreterState, Interruption, ResumeAction, ResumePoint},
+    object::{GcObject, Object, ObjectData, PROTOTYPE},
+    property::{Attribute, DataDescriptor},
+    syntax::ast::node::RcStatementList,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a generator.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-internal-slots-of-generator-instances
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+pub enum GeneratorState {
+    /// The body has not started executing yet.
+    SuspendedStart,
+    /// The body is suspended at a `yield`.
+    SuspendedYield,
+    /// The body is currently executing.
+    Executing,
+    /// The body has returned or thrown, the generator can't be resumed anymore.
+    Completed,
+}
+
+unsafe impl Trace for GeneratorState {
+    unsafe_empty_trace!();
+}
+
+/// A function body that can be suspended at a `yield` or an `await`, and resumed later.
+///
+/// The environments of the body and the progress of the nodes that were interrupted are kept
+/// here while it is suspended.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct SuspendedBody {
+    body: RcStatementList,
+    environments: Vec<Environment>,
+    resume_points: Vec<ResumePoint>,
+}
+
+/// How a suspended body stopped executing.
+#[derive(Debug)]
+pub(crate) enum Completion {
+    /// The body is suspended by `yield`, with the iterator result object for the caller.
+    Yield(Value),
+    /// The body is suspended by `await`, with the value that is awaited.
+    Await(Value),
+    /// The body has returned, or was closed by the `return` method of its generator.
+    Return(Value),
+    /// The body has thrown.
+    Throw(Value),
+}
+
+impl SuspendedBody {
+    /// Creates a body that hasn't started executing yet.
+    ///
+    /// `environment` is the function environment of the call, with the arguments already bound.
+    pub(crate) fn new(body: RcStatementList, environment: Environment) -> Self {
+        Self {
+            body,
+            environments: vec![environment],
+            resume_points: Vec::new(),
+        }
+    }
+
+    /// Takes the body out, leaving it without its environments and progress.
+    pub(crate) fn take(&mut self) -> Self {
+        Self {
+            body: self.body.clone(),
+            environments: std::mem::take(&mut self.environments),
+            resume_points: std::mem::take(&mut self.resume_points),
+        }
+    }
+
+    /// Runs the body until it is suspended or completes.
+    ///
+    /// `action` is what the `yield` or `await` the body was suspended at evaluates to, it is
+    /// `None` when the body starts. The body is given back to be resumed later.
+    pub(crate) fn run(
+        mut self,
+        action: Option<ResumeAction>,
+        ctx: &mut Context,
+    ) -> (Self, Completion) {
+        let function_environment = self
+            .environments
+            .first()
+            .cloned()
+            .expect("a suspended body always has its function environment");
+        for environment in std::mem::take(&mut self.environments) {
+            ctx.realm_mut().environment.push(environment);
+        }
+        let resume_points = std::mem::take(&mut self.resume_points);
+        let (outer_points, outer_action) = ctx.executor().swap_resume_state(resume_points, action);
+
+        let result = self.body.run(ctx);
+
+        let (resume_points, _) = ctx.executor().swap_resume_state(outer_points, outer_action);
+        self.resume_points = resume_points;
+        let interruption = ctx.executor().take_interruption();
+        let returned = ctx.executor().get_current_state() == &InterpreterState::Return;
+        ctx.executor()
+            .set_current_state(InterpreterState::Executing);
+        self.environments = ctx.realm_mut().environment.split_off(&function_environment);
+
+        let completion = match (interruption, result) {
+            (Some(Interruption::Yield(iter_result)), _) => Completion::Yield(iter_result),
+            (Some(Interruption::Await(value)), _) => Completion::Await(value),
+            (Some(Interruption::Return(value)), _) => Completion::Return(value),
+            (None, Ok(value)) if returned => Completion::Return(value),
+            (None, Ok(_)) => Completion::Return(Value::undefined()),
+            (None, Err(exception)) => Completion::Throw(exception),
+        };
+        (self, completion)
+    }
+}
+
+/// A generator object, the result of calling a generator function.
+///
+/// The body of the generator function runs each time the generator is resumed, until it
+/// reaches a `yield`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-generator-instances
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Generator {
+    state: GeneratorState,
+    body: SuspendedBody,
+}
+
+impl Generator {
+    pub(crate) const NAME: &'static str = "Generator";
+
+    /// Gets the state of the generator.
+    #[inline]
+    pub fn state(&self) -> GeneratorState {
+        self.state
+    }
+
+    /// Creates the generator object for a call to a generator function.
+    ///
+    /// `environment` is the function environment of the call, with the arguments already bound.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator-definitions-evaluatebody
+    pub(crate) fn create(
+        function: &GcObject,
+        body: RcStatementList,
+        environment: Environment,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let prototype = match function.get(&PROTOTYPE.into(), function.clone().into(), ctx)? {
+            prototype @ Value::Object(_) => prototype,
+            _ => ctx.iterator_prototypes().generator().into(),
+        };
+
+        let mut generator = Object::create(prototype);
+        generator.data = ObjectData::Generator(Self {
+            state: GeneratorState::SuspendedStart,
+            body: SuspendedBody::new(body, environment),
+        });
+        Ok(generator.into())
+    }
+
+    /// `Generator.prototype.next( value )`
+    ///
+    /// Resumes the generator, the `yield` it was suspended at evaluates to `value`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.next
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/next
+    pub(crate) fn next(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::resume(this, ResumeAction::Next(value), ctx)
+    }
+
+    /// `Generator.prototype.return( value )`
+    ///
+    /// Resumes the generator as if the `yield` it was suspended at was a `return` statement.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.return
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/return
+    pub(crate) fn r#return(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::resume(this, ResumeAction::Return(value), ctx)
+    }
+
+    /// `Generator.prototype.throw( exception )`
+    ///
+    /// Resumes the generator as if the `yield` it was suspended at threw `exception`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.throw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/throw
+    pub(crate) fn throw(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let exception = args.get(0).cloned().unwrap_or_default();
+        Self::resume(this, ResumeAction::Throw(exception), ctx)
+    }
+
+    /// Runs the body of the generator until it yields or completes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generatorresume
+    fn resume(this: &Value, action: ResumeAction, ctx: &mut Context) -> Result<Value> {
+        let object = match this {
+            Value::Object(ref object) if object.borrow().is_generator() => object.clone(),
+            _ => return ctx.throw_type_error("`this` is not a generator"),
+        };
+
+        let (body, action) = {
+            let mut object = object.borrow_mut();
+            let generator = object.as_generator_mut().expect("checked above");
+            match (generator.state, action) {
+                (GeneratorState::Executing, _) => {
+                    drop(object);
+                    return ctx.throw_type_error("Generator is already running");
+                }
+                (GeneratorState::Completed, ResumeAction::Next(_)) => {
+                    drop(object);
+                    return Ok(create_iter_result_object(ctx, Value::undefined(), true));
+                }
+                // A generator that hasn't started yet completes without running its body.
+                (GeneratorState::SuspendedStart, ResumeAction::Return(value))
+                | (GeneratorState::Completed, ResumeAction::Return(value)) => {
+                    generator.state = GeneratorState::Completed;
+                    drop(object);
+                    return Ok(create_iter_result_object(ctx, value, true));
+                }
+                (GeneratorState::SuspendedStart, ResumeAction::Throw(exception))
+                | (GeneratorState::Completed, ResumeAction::Throw(exception)) => {
+                    generator.state = GeneratorState::Completed;
+                    return Err(exception);
+                }
+                // The value given to the first `next` call is ignored, there is no `yield` for it.
+                (GeneratorState::SuspendedStart, ResumeAction::Next(_)) => {
+                    generator.state = GeneratorState::Executing;
+                    (generator.body.take(), None)
+                }
+                (GeneratorState::SuspendedYield, action) => {
+                    generator.state = GeneratorState::Executing;
+                    (generator.body.take(), Some(action))
+                }
+            }
+        };
+
+        let (body, completion) = body.run(action, ctx);
+
+        let mut object = object.borrow_mut();
+        let generator = object.as_generator_mut().expect("checked above");
+        match completion {
+            Completion::Yield(iter_result) => {
+                generator.state = GeneratorState::SuspendedYield;
+                generator.body = body;
+                Ok(iter_result)
+            }
+            Completion::Return(value) => {
+                generator.state = GeneratorState::Completed;
+                drop(object);
+                Ok(create_iter_result_object(ctx, value, true))
+            }
+            Completion::Throw(exception) => {
+                generator.state = GeneratorState::Completed;
+                Err(exception)
+            }
+            Completion::Await(_) => unreachable!("`await` is not allowed in generators"),
+        }
+    }
+
+    /// Create the %GeneratorPrototype% object
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-properties-of-generator-prototype
+    pub(crate) fn create_prototype(ctx: &mut Context, iterator_prototype: Value) -> Value {
+        let global = ctx.global_object();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let generator = Value::new_object(Some(global));
+        make_builtin_fn(Self::next, "next", &generator, 1, ctx);
+        make_builtin_fn(Self::r#return, "return", &generator, 1, ctx);
+        make_builtin_fn(Self::throw, "throw", &generator, 1, ctx);
+        generator
+            .as_object_mut()
+            .expect("generator prototype object")
+            .set_prototype_instance(iterator_prototype);
+
+        let to_string_tag = ctx.well_known_symbols().to_string_tag_symbol();
+        let to_string_tag_property = DataDescriptor::new(Self::NAME, Attribute::CONFIGURABLE);
+        generator.set_property(to_string_tag, to_string_tag_property);
+        generator
+    }
+}
@@ -0,0 +1,391 @@
+use crate::{forward, Context};
+
+#[test]
+fn next() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() {
+            const x = yield 1;
+            yield x * 2;
+            return "done";
+        }
+        var it = gen();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "it.next().value"), "1");
+    assert_eq!(forward(&mut engine, "it.next(21).value"), "42");
+    assert_eq!(forward(&mut engine, "it.next().value"), "\"done\"");
+    assert_eq!(forward(&mut engine, "it.next().done"), "true");
+    assert_eq!(forward(&mut engine, "it.next().value"), "undefined");
+}
+
+#[test]
+fn return_value() {
+    let mut engine = Context::new();
+    let init = r#"
+        var cleanup = false;
+        function* gen() {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                cleanup = true;
+            }
+        }
+        var it = gen();
+        it.next();
+        var result = it.return(5);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "result.value"), "5");
+    assert_eq!(forward(&mut engine, "result.done"), "true");
+    assert_eq!(forward(&mut engine, "cleanup"), "true");
+    assert_eq!(forward(&mut engine, "it.next().done"), "true");
+}
+
+#[test]
+fn return_before_start() {
+    let mut engine = Context::new();
+    let init = r#"
+        var started = false;
+        function* gen() {
+            started = true;
+            yield 1;
+        }
+        var it = gen();
+        var result = it.return(5);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "result.value"), "5");
+    assert_eq!(forward(&mut engine, "started"), "false");
+    assert_eq!(forward(&mut engine, "it.next().done"), "true");
+}
+
+#[test]
+fn throw() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() {
+            while (true) {
+                try {
+                    yield 1;
+                } catch (e) {
+                    yield "caught " + e;
+                }
+            }
+        }
+        var it = gen();
+        it.next();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "it.throw('x').value"), "\"caught x\"");
+    assert_eq!(forward(&mut engine, "it.next().value"), "1");
+}
+
+#[test]
+fn throw_uncaught() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() {
+            yield 1;
+        }
+        var it = gen();
+        it.next();
+        var message;
+        try {
+            it.throw(new Error("boom"));
+        } catch (e) {
+            message = e.message;
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "message"), "\"boom\"");
+    assert_eq!(forward(&mut engine, "it.next().done"), "true");
+}
+
+#[test]
+fn yield_in_loops() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() {
+            for (let i = 0; i < 2; i++) {
+                let j = 0;
+                do {
+                    yield i + "" + j;
+                } while (++j < 2);
+            }
+        }
+        var result = "";
+        for (const value of gen()) {
+            result += value + ",";
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "result"), "\"00,01,10,11,\"");
+}
+
+#[test]
+fn yield_in_expressions() {
+    let mut engine = Context::new();
+    let init = r#"
+        function add(a, b, c) {
+            return a + b + c;
+        }
+        function* gen() {
+            const o = { a: yield 1, b: [yield 2, 3] };
+            return add(o.a, yield 3, o.b[0]);
+        }
+        var it = gen();
+        it.next();
+        it.next("a");
+        it.next("b");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "it.next('c').value"), "\"acb\"");
+}
+
+#[test]
+fn yield_in_switch() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen(value) {
+            switch (value) {
+                case yield "case":
+                    yield "one";
+                case 2:
+                    yield "two";
+                    break;
+                default:
+                    yield "default";
+            }
+        }
+        var it = gen(1);
+        it.next();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "it.next(1).value"), "\"one\"");
+    assert_eq!(forward(&mut engine, "it.next().value"), "\"two\"");
+    assert_eq!(forward(&mut engine, "it.next().done"), "true");
+}
+
+#[test]
+fn yield_in_finally() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() {
+            try {
+                yield 1;
+            } finally {
+                yield 2;
+            }
+        }
+        var it = gen();
+        it.next();
+        var first = it.return(5);
+        var second = it.next();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "first.value"), "2");
+    assert_eq!(forward(&mut engine, "first.done"), "false");
+    assert_eq!(forward(&mut engine, "second.value"), "5");
+    assert_eq!(forward(&mut engine, "second.done"), "true");
+}
+
+#[test]
+fn yield_delegate() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* inner() {
+            const x = yield 1;
+            yield x;
+            return "inner";
+        }
+        function* outer() {
+            const result = yield* inner();
+            yield result;
+            yield* [4, 5];
+        }
+        var it = outer();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "it.next().value"), "1");
+    assert_eq!(forward(&mut engine, "it.next(2).value"), "2");
+    assert_eq!(forward(&mut engine, "it.next().value"), "\"inner\"");
+    assert_eq!(forward(&mut engine, "it.next().value"), "4");
+    assert_eq!(forward(&mut engine, "it.next().value"), "5");
+    assert_eq!(forward(&mut engine, "it.next().done"), "true");
+}
+
+#[test]
+fn already_running() {
+    let mut engine = Context::new();
+    let init = r#"
+        var it;
+        function* gen() {
+            it.next();
+        }
+        it = gen();
+        var message;
+        try {
+            it.next();
+        } catch (e) {
+            message = e.message;
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "message"),
+        "\"Generator is already running\""
+    );
+}
+
+#[test]
+fn generator_methods() {
+    let mut engine = Context::new();
+    let init = r#"
+        var obj = {
+            *values() {
+                yield 1;
+                yield 2;
+            }
+        };
+        class A {
+            *values() {
+                yield 3;
+            }
+        }
+        var result = 0;
+        for (const value of obj.values()) {
+            result += value;
+        }
+        for (const value of new A().values()) {
+            result += value;
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "result"), "6");
+}
+
+#[test]
+fn yield_in_delete_and_update() {
+    let mut engine = Context::new();
+    let init = r#"
+        var calls = 0;
+        var o = { a: 1, b: 1 };
+        function obj() { calls++; return o; }
+        function* gen() {
+            var deleted = delete obj()[yield];
+            obj()[yield]++;
+            return deleted;
+        }
+        var it = gen();
+        it.next();
+        it.next("a");
+        var result = it.next("b");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "result.value"), "true");
+    assert_eq!(forward(&mut engine, "result.done"), "true");
+    assert_eq!(forward(&mut engine, "'a' in o"), "false");
+    assert_eq!(forward(&mut engine, "o.b"), "2");
+    assert_eq!(forward(&mut engine, "calls"), "2");
+}
+
+#[test]
+fn yield_in_array_pattern() {
+    let mut engine = Context::new();
+    let init = r#"
+        var closes = 0;
+        var nexts = 0;
+        var iterable = {
+            [Symbol.iterator]() {
+                var i = 0;
+                return {
+                    next() {
+                        nexts++;
+                        i++;
+                        return { value: i === 1 ? undefined : i, done: i > 3 };
+                    },
+                    return() {
+                        closes++;
+                        return {};
+                    },
+                };
+            },
+        };
+        function* gen() {
+            var [a = yield "a", b] = iterable;
+            return [a, b].join();
+        }
+        var it = gen();
+        var first = it.next();
+        var result = it.next(1);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "first.value"), "\"a\"");
+    assert_eq!(forward(&mut engine, "result.value"), "\"1,2\"");
+    assert_eq!(forward(&mut engine, "closes"), "1");
+    assert_eq!(forward(&mut engine, "nexts"), "2");
+}
+
+#[test]
+fn yield_in_object_pattern() {
+    let mut engine = Context::new();
+    let init = r#"
+        var gets = 0;
+        var obj = { get a() { gets++; return undefined; }, b: 2 };
+        function* gen() {
+            let { a = yield "a", b, ...rest } = obj;
+            try {
+                throw {};
+            } catch ({ c = yield "c" }) {
+                return [a, b, c, gets].join();
+            }
+        }
+        var it = gen();
+        it.next();
+        it.next(1);
+        var result = it.next(3);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "result.value"), "\"1,2,3,1\"");
+    assert_eq!(forward(&mut engine, "result.done"), "true");
+}
+
+#[test]
+fn yield_in_for_of_pattern() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() {
+            var values = [];
+            for (var [a = yield "a"] of [[], [2]]) {
+                values.push(a);
+            }
+            return values.join();
+        }
+        var it = gen();
+        it.next();
+        var result = it.next(1);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "result.value"), "\"1,2\"");
+}
+
+#[test]
+fn prototype() {
+    let mut engine = Context::new();
+    let init = r#"
+        var gen = function* () {};
+        var it = gen();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Object.getPrototypeOf(it) === gen.prototype"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.prototype.toString.call(it)"),
+        "\"[object Generator]\""
+    );
+    assert_eq!(forward(&mut engine, "it[Symbol.iterator]() === it"), "true");
+}
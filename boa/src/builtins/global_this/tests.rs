@@ -8,3 +8,19 @@ fn global_this_exists_on_global_object_and_evaluates_to_an_object() {
 
     assert_eq!(&exec(scenario), "\"object\"");
 }
+
+#[test]
+fn global_accessor_bindings() {
+    let scenario = r#"
+        let set = [];
+        Object.defineProperty(globalThis, "x", {
+            get() { return 1; },
+            set(value) { set.push(value); },
+        });
+        x = 5;
+        x++;
+        [x, set.join()].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,5,2\"");
+}
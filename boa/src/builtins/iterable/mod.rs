@@ -1,6 +1,6 @@
 use crate::{
     builtins::string::string_iterator::StringIterator,
-    builtins::ArrayIterator,
+    builtins::{generator::Generator, object::for_in_iterator::ForInIterator, ArrayIterator},
     object::{GcObject, ObjectInitializer},
     property::{Attribute, DataDescriptor},
     BoaProfiler, Context, Result, Value,
@@ -11,6 +11,8 @@ pub struct IteratorPrototypes {
     iterator_prototype: GcObject,
     array_iterator: GcObject,
     string_iterator: GcObject,
+    for_in_iterator: GcObject,
+    generator: GcObject,
 }
 
 impl IteratorPrototypes {
@@ -23,9 +25,15 @@ impl IteratorPrototypes {
             array_iterator: ArrayIterator::create_prototype(ctx, iterator_prototype.clone())
                 .as_gc_object()
                 .expect("Array Iterator Prototype is not an object"),
-            string_iterator: StringIterator::create_prototype(ctx, iterator_prototype)
+            string_iterator: StringIterator::create_prototype(ctx, iterator_prototype.clone())
                 .as_gc_object()
                 .expect("String Iterator Prototype is not an object"),
+            for_in_iterator: ForInIterator::create_prototype(ctx, iterator_prototype.clone())
+                .as_gc_object()
+                .expect("For In Iterator Prototype is not an object"),
+            generator: Generator::create_prototype(ctx, iterator_prototype)
+                .as_gc_object()
+                .expect("Generator Prototype is not an object"),
         }
     }
 
@@ -40,6 +48,14 @@ impl IteratorPrototypes {
     pub fn string_iterator(&self) -> GcObject {
         self.string_iterator.clone()
     }
+
+    pub fn for_in_iterator(&self) -> GcObject {
+        self.for_in_iterator.clone()
+    }
+
+    pub fn generator(&self) -> GcObject {
+        self.generator.clone()
+    }
 }
 
 /// CreateIterResultObject( value, done )
@@ -98,13 +114,23 @@ pub struct IteratorRecord {
 }
 
 impl IteratorRecord {
-    fn new(iterator_object: Value, next_function: Value) -> Self {
+    pub(crate) fn new(iterator_object: Value, next_function: Value) -> Self {
         Self {
             iterator_object,
             next_function,
         }
     }
 
+    /// Gets the iterator object of the record.
+    pub(crate) fn iterator_object(&self) -> &Value {
+        &self.iterator_object
+    }
+
+    /// Gets the `next` method of the iterator object.
+    pub(crate) fn next_function(&self) -> &Value {
+        &self.next_function
+    }
+
     /// Get the next value in the iterator
     ///
     /// More information:
@@ -127,6 +153,29 @@ impl IteratorRecord {
             .unwrap_or_default();
         Ok(IteratorResult::new(next_result, done))
     }
+
+    /// Closes the iterator before it is exhausted, by calling its `return` method if it has one.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iteratorclose
+    pub(crate) fn close(&self, ctx: &mut Context) -> Result<()> {
+        let return_function = match self.iterator_object {
+            Value::Object(ref object) => {
+                object.get(&"return".into(), self.iterator_object.clone(), ctx)?
+            }
+            _ => return Ok(()),
+        };
+        if return_function.is_null_or_undefined() {
+            return Ok(());
+        }
+        let result = ctx.call(&return_function, &self.iterator_object, &[])?;
+        if !result.is_object() {
+            ctx.throw_type_error("the result of an iterator's return method must be an object")?;
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
@@ -73,7 +73,7 @@ impl Json {
                 match args.get(1) {
                     Some(reviver) if reviver.is_function() => {
                         let mut holder = Value::new_object(None);
-                        holder.set_field("", j);
+                        holder.set_field("", j, ctx)?;
                         Self::walk(reviver, ctx, &mut holder, &PropertyKey::from(""))
                     }
                     _ => Ok(j),
@@ -95,7 +95,7 @@ impl Json {
         holder: &mut Value,
         key: &PropertyKey,
     ) -> Result<Value> {
-        let value = holder.get_field(key.clone());
+        let value = holder.get_field(key.clone(), ctx)?;
 
         if let Value::Object(ref object) = value {
             let keys: Vec<_> = object.borrow().keys().collect();
@@ -104,7 +104,7 @@ impl Json {
                 let v = Self::walk(reviver, ctx, &mut value.clone(), &key);
                 match v {
                     Ok(v) if !v.is_undefined() => {
-                        value.set_field(key, v);
+                        value.set_field(key, v, ctx)?;
                     }
                     Ok(_) => {
                         value.remove_property(key);
@@ -176,13 +176,14 @@ impl Json {
                 .ok_or_else(Value::undefined)?
         } else if replacer_as_object.is_array() {
             let mut obj_to_return = serde_json::Map::new();
-            let fields = replacer_as_object.keys().filter_map(|key| {
-                if key == "length" {
-                    None
-                } else {
-                    Some(replacer.get_field(key))
-                }
-            });
+            let keys: Vec<PropertyKey> = replacer_as_object
+                .keys()
+                .filter(|key| *key != "length")
+                .collect();
+            let mut fields = Vec::with_capacity(keys.len());
+            for key in keys {
+                fields.push(replacer.get_field(key, ctx)?);
+            }
             for field in fields {
                 if let Some(value) = object
                     .get_property(field.to_string(ctx)?)
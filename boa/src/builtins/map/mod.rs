@@ -46,8 +46,14 @@ impl Map {
 
     /// Create a new map
     pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        if !this.is_object() {
+            return ctx
+                .throw_type_error("calling a builtin Map constructor without new is forbidden");
+        }
+
         // Set Prototype
-        let prototype = ctx.global_object().get_field("Map").get_field(PROTOTYPE);
+        let global = ctx.global_object().clone();
+        let prototype = global.get_field("Map", ctx)?.get_field(PROTOTYPE, ctx)?;
 
         this.as_object_mut()
             .expect("this is map object")
@@ -65,10 +71,10 @@ impl Map {
                         map
                     } else if object.is_array() {
                         let mut map = OrderedMap::new();
-                        let len = args[0].get_field("length").to_integer(ctx)? as i32;
+                        let len = args[0].get_field("length", ctx)?.to_integer(ctx)? as i32;
                         for i in 0..len {
-                            let val = &args[0].get_field(i.to_string());
-                            let (key, value) = Self::get_key_value(val).ok_or_else(|| {
+                            let val = &args[0].get_field(i.to_string(), ctx)?;
+                            let (key, value) = Self::get_key_value(val, ctx)?.ok_or_else(|| {
                                 ctx.construct_type_error(
                                     "iterable for Map should have array-like objects",
                                 )
@@ -286,17 +292,18 @@ impl Map {
     }
 
     /// Helper function to get a key-value pair from an array.
-    fn get_key_value(value: &Value) -> Option<(Value, Value)> {
+    fn get_key_value(value: &Value, ctx: &mut Context) -> Result<Option<(Value, Value)>> {
         if let Value::Object(object) = value {
             if object.borrow().is_array() {
-                let (key, value) = match value.get_field("length").as_number().unwrap() as i32 {
+                let (key, value) = match value.get_field("length", ctx)?.as_number().unwrap() as i32
+                {
                     0 => (Value::Undefined, Value::Undefined),
-                    1 => (value.get_field("0"), Value::Undefined),
-                    _ => (value.get_field("0"), value.get_field("1")),
+                    1 => (value.get_field("0", ctx)?, Value::Undefined),
+                    _ => (value.get_field("0", ctx)?, value.get_field("1", ctx)?),
                 };
-                return Some((key, value));
+                return Ok(Some((key, value)));
             }
         }
-        None
+        Ok(None)
     }
 }
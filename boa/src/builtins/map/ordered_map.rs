@@ -1,5 +1,5 @@
 use gc::{custom_trace, Finalize, Trace};
-use indexmap::{map::IntoIter, map::Iter, map::IterMut, IndexMap};
+use indexmap::{map::IntoIter, map::Iter, map::IterMut, map::Keys, map::Values, IndexMap};
 use std::collections::hash_map::RandomState;
 use std::fmt::Debug;
 use std::hash::{BuildHasher, Hash};
@@ -99,6 +99,16 @@ where
         self.0.iter()
     }
 
+    /// Return an iterator over the keys of the map, in their order
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        self.0.keys()
+    }
+
+    /// Return an iterator over the values of the map, in their order
+    pub fn values(&self) -> Values<'_, K, V> {
+        self.0.values()
+    }
+
     /// Return `true` if an equivalent to `key` exists in the map.
     ///
     /// Computes in **O(1)** time (average).
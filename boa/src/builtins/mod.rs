@@ -1,13 +1,17 @@
 //! Builtins live here, such as Object, String, Math, etc.
 
+pub mod annex_b;
 pub mod array;
+pub mod async_function;
 pub mod bigint;
 pub mod boolean;
 #[cfg(feature = "console")]
 pub mod console;
 pub mod date;
 pub mod error;
+pub mod eval;
 pub mod function;
+pub mod generator;
 pub mod global_this;
 pub mod infinity;
 pub mod iterable;
@@ -17,6 +21,7 @@ pub mod math;
 pub mod nan;
 pub mod number;
 pub mod object;
+pub mod promise;
 pub mod regexp;
 pub mod string;
 pub mod symbol;
@@ -27,7 +32,11 @@ pub(crate) use self::{
     bigint::BigInt,
     boolean::Boolean,
     date::Date,
-    error::{Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError, UriError},
+    error::{
+        AggregateError, Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError,
+        UriError,
+    },
+    eval::Eval,
     function::BuiltInFunctionObject,
     global_this::GlobalThis,
     infinity::Infinity,
@@ -37,6 +46,7 @@ pub(crate) use self::{
     nan::NaN,
     number::Number,
     object::Object as BuiltInObjectObject,
+    promise::Promise,
     regexp::RegExp,
     string::String,
     symbol::Symbol,
@@ -65,6 +75,7 @@ pub fn init(context: &mut Context) {
         NaN::init,
         GlobalThis::init,
         BuiltInFunctionObject::init,
+        Eval::init,
         BuiltInObjectObject::init,
         Math::init,
         Json::init,
@@ -74,6 +85,7 @@ pub fn init(context: &mut Context) {
         Date::init,
         Map::init,
         Number::init,
+        Promise::init,
         String::init,
         RegExp::init,
         Symbol::init,
@@ -84,6 +96,7 @@ pub fn init(context: &mut Context) {
         SyntaxError::init,
         EvalError::init,
         UriError::init,
+        AggregateError::init,
         #[cfg(feature = "console")]
         console::Console::init,
     ];
@@ -99,4 +112,8 @@ pub fn init(context: &mut Context) {
         let property = DataDescriptor::new(value, attribute);
         global_object.borrow_mut().insert(name, property);
     }
+
+    if context.annex_b() {
+        annex_b::init(context);
+    }
 }
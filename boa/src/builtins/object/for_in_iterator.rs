@@ -0,0 +1,142 @@
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object},
+    object::ObjectData,
+    property::PropertyKey,
+    value::RcString,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use rustc_hash::FxHashSet;
+use std::collections::VecDeque;
+
+/// The For-In Iterator object represents the enumeration of the keys of an object by a
+/// `for...in` statement.
+///
+/// It visits the enumerable string keys of the object and then of its prototypes, skipping the
+/// keys that are shadowed, and the keys that are deleted before they are visited.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-for-in-iterator-objects
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct ForInIterator {
+    object: Value,
+    visited_keys: FxHashSet<RcString>,
+    remaining_keys: VecDeque<RcString>,
+    object_was_visited: bool,
+}
+
+impl ForInIterator {
+    pub(crate) const NAME: &'static str = "ForInIterator";
+
+    fn new(object: Value) -> Self {
+        ForInIterator {
+            object,
+            visited_keys: FxHashSet::default(),
+            remaining_keys: VecDeque::default(),
+            object_was_visited: false,
+        }
+    }
+
+    /// CreateForInIterator( object )
+    ///
+    /// Creates a new iterator over the keys of the given object.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createforiniterator
+    pub(crate) fn create_for_in_iterator(ctx: &Context, object: Value) -> Value {
+        let for_in_iterator = Value::new_object(Some(ctx.global_object()));
+        for_in_iterator.set_data(ObjectData::ForInIterator(Self::new(object)));
+        for_in_iterator
+            .as_object_mut()
+            .expect("for in iterator object")
+            .set_prototype_instance(ctx.iterator_prototypes().for_in_iterator().into());
+        for_in_iterator
+    }
+
+    /// %ForInIteratorPrototype%.next( )
+    ///
+    /// Gets the next key of the enumerated object.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%foriniteratorprototype%.next
+    pub(crate) fn next(this: &Value, _args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let result = match this.as_object_mut() {
+            Some(mut object) => match object.as_for_in_iterator_mut() {
+                Some(iterator) => iterator.next_key(),
+                None => return ctx.throw_type_error("`this` is not a ForInIterator"),
+            },
+            None => return ctx.throw_type_error("`this` is not a ForInIterator"),
+        };
+
+        Ok(match result {
+            Some(key) => create_iter_result_object(ctx, key.into(), false),
+            None => create_iter_result_object(ctx, Value::undefined(), true),
+        })
+    }
+
+    /// Finds the next enumerable key that was not visited yet, walking up the prototype chain.
+    fn next_key(&mut self) -> Option<RcString> {
+        loop {
+            let object = match self.object {
+                Value::Object(ref object) => object.clone(),
+                _ => return None,
+            };
+
+            if !self.object_was_visited {
+                let keys = object.borrow().own_property_keys();
+                for key in keys {
+                    match key {
+                        PropertyKey::String(ref key) => self.remaining_keys.push_back(key.clone()),
+                        PropertyKey::Index(index) => {
+                            self.remaining_keys.push_back(index.to_string().into())
+                        }
+                        PropertyKey::Symbol(_) => {}
+                    }
+                }
+                self.object_was_visited = true;
+            }
+
+            while let Some(key) = self.remaining_keys.pop_front() {
+                if self.visited_keys.contains(&key) {
+                    continue;
+                }
+                // The property might have been deleted since the keys were collected.
+                if let Some(desc) = object.borrow().get_own_property(&key.clone().into()) {
+                    self.visited_keys.insert(key.clone());
+                    if desc.enumerable() {
+                        return Some(key);
+                    }
+                }
+            }
+
+            self.object = object.borrow().get_prototype_of();
+            self.object_was_visited = false;
+        }
+    }
+
+    /// Create the %ForInIteratorPrototype% object
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%foriniteratorprototype%-object
+    pub(crate) fn create_prototype(ctx: &mut Context, iterator_prototype: Value) -> Value {
+        let global = ctx.global_object();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let for_in_iterator = Value::new_object(Some(global));
+        make_builtin_fn(Self::next, "next", &for_in_iterator, 0, ctx);
+        for_in_iterator
+            .as_object_mut()
+            .expect("for in iterator prototype object")
+            .set_prototype_instance(iterator_prototype);
+        for_in_iterator
+    }
+}
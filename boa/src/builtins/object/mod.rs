@@ -13,10 +13,12 @@
 //! [spec]: https://tc39.es/ecma262/#sec-objects
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object
 
+pub mod for_in_iterator;
+
 use crate::{
     builtins::BuiltIn,
     object::{ConstructorBuilder, Object as BuiltinObject, ObjectData},
-    property::Attribute,
+    property::{AccessorDescriptor, Attribute, PropertyDescriptor},
     value::{same_value, Value},
     BoaProfiler, Context, Result,
 };
@@ -146,7 +148,11 @@ impl Object {
         } else {
             return context.throw_type_error("Property description must be an object");
         };
-        obj.set_property(prop, desc);
+        if let Value::Object(ref object) = obj {
+            if !object.borrow_mut().define_own_property(prop.clone(), desc) {
+                return context.throw_type_error(format!("Cannot redefine property: {}", prop));
+            }
+        }
         Ok(Value::undefined())
     }
 
@@ -192,7 +198,7 @@ impl Object {
             let o = gc_o.borrow();
             let builtin_tag = match &o.data {
                 ObjectData::Array => "Array",
-                // TODO: Arguments Exotic Objects are currently not supported
+                ObjectData::Arguments(_) => "Arguments",
                 ObjectData::Function(_) => "Function",
                 ObjectData::Error => "Error",
                 ObjectData::Boolean(_) => "Boolean",
@@ -203,7 +209,9 @@ impl Object {
                 _ => "Object",
             };
 
-            let tag = o.get(&ctx.well_known_symbols().to_string_tag_symbol().into());
+            drop(o);
+            let tag_key = ctx.well_known_symbols().to_string_tag_symbol();
+            let tag = gc_o.get(&tag_key.into(), this.clone(), ctx)?;
 
             let tag_str = tag.as_string().map(|s| s.as_str()).unwrap_or(builtin_tag);
 
@@ -257,4 +265,176 @@ impl Object {
             Value::from(own_prop.enumerable())
         }))
     }
+
+    /// `get Object.prototype.__proto__`
+    ///
+    /// Returns the prototype of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-object.prototype.__proto__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/proto
+    pub(crate) fn get_proto(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let prototype = object.borrow().get_prototype_of();
+        Ok(prototype)
+    }
+
+    /// `set Object.prototype.__proto__`
+    ///
+    /// Changes the prototype of the object, values that are neither objects nor `null` are
+    /// ignored.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-object.prototype.__proto__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/proto
+    pub(crate) fn set_proto(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let this = this.require_object_coercible(ctx)?;
+        let prototype = args.get(0).cloned().unwrap_or_default();
+        if !prototype.is_object() && !prototype.is_null() {
+            return Ok(Value::undefined());
+        }
+        if let Value::Object(ref object) = this {
+            if !object.set_prototype_of(prototype) {
+                return ctx.throw_type_error("cannot set the prototype of the object");
+            }
+        }
+        Ok(Value::undefined())
+    }
+
+    /// `Object.prototype.__defineGetter__( prop, func )`
+    ///
+    /// Defines `func` as the getter of the property `prop` of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__defineGetter__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__defineGetter__
+    pub(crate) fn define_getter(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::define_accessor(this, args, true, ctx)
+    }
+
+    /// `Object.prototype.__defineSetter__( prop, func )`
+    ///
+    /// Defines `func` as the setter of the property `prop` of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__defineSetter__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__defineSetter__
+    pub(crate) fn define_setter(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::define_accessor(this, args, false, ctx)
+    }
+
+    /// Defines the getter, or the setter, of a property for `__defineGetter__` and
+    /// `__defineSetter__`. The other function of an existing accessor property is kept.
+    fn define_accessor(
+        this: &Value,
+        args: &[Value],
+        getter: bool,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let function = match args.get(1) {
+            Some(Value::Object(ref function)) if function.borrow().is_callable() => {
+                function.clone()
+            }
+            _ => return ctx.throw_type_error("accessor must be a function"),
+        };
+        let key = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(ctx)?;
+
+        let (get, set) = match object.borrow().get_own_property(&key) {
+            Some(PropertyDescriptor::Accessor(ref existing)) if getter => {
+                (Some(function), existing.setter().cloned())
+            }
+            Some(PropertyDescriptor::Accessor(ref existing)) => {
+                (existing.getter().cloned(), Some(function))
+            }
+            _ if getter => (Some(function), None),
+            _ => (None, Some(function)),
+        };
+        let desc =
+            AccessorDescriptor::new(get, set, Attribute::ENUMERABLE | Attribute::CONFIGURABLE);
+        if !object
+            .borrow_mut()
+            .define_own_property(key.clone(), desc.into())
+        {
+            return ctx.throw_type_error(format!("Cannot redefine property: {}", key));
+        }
+        Ok(Value::undefined())
+    }
+
+    /// `Object.prototype.__lookupGetter__( prop )`
+    ///
+    /// Returns the getter of the property `prop` of the object or of its prototype chain.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__lookupGetter__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__lookupGetter__
+    pub(crate) fn lookup_getter(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::lookup_accessor(this, args, true, ctx)
+    }
+
+    /// `Object.prototype.__lookupSetter__( prop )`
+    ///
+    /// Returns the setter of the property `prop` of the object or of its prototype chain.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__lookupSetter__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__lookupSetter__
+    pub(crate) fn lookup_setter(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::lookup_accessor(this, args, false, ctx)
+    }
+
+    /// Finds the getter, or the setter, of a property for `__lookupGetter__` and
+    /// `__lookupSetter__`. The lookup stops at the first object that has the property.
+    fn lookup_accessor(
+        this: &Value,
+        args: &[Value],
+        getter: bool,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let object = this.to_object(ctx)?;
+        let key = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(ctx)?;
+
+        let mut current = Value::from(object);
+        while let Value::Object(ref object) = current {
+            let desc = object.borrow().get_own_property(&key);
+            match desc {
+                Some(PropertyDescriptor::Accessor(ref desc)) => {
+                    let function = if getter { desc.getter() } else { desc.setter() };
+                    return Ok(function.cloned().map(Value::from).unwrap_or_default());
+                }
+                Some(PropertyDescriptor::Data(_)) => return Ok(Value::undefined()),
+                None => {
+                    let next = object.borrow().get_prototype_of();
+                    current = next;
+                }
+            }
+        }
+        Ok(Value::undefined())
+    }
 }
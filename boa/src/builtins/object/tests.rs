@@ -92,7 +92,8 @@ fn object_is() {
     assert_eq!(forward(&mut engine, "Object.is()"), "true");
     assert_eq!(forward(&mut engine, "Object.is(undefined)"), "true");
     assert!(engine.global_object().is_global());
-    assert!(!engine.global_object().get_field("Object").is_global());
+    let object = engine.global_object().clone();
+    assert!(!object.get_field("Object", &mut engine).unwrap().is_global());
 }
 #[test]
 fn object_has_own_property() {
@@ -212,3 +213,96 @@ fn object_define_properties() {
 
     assert_eq!(forward(&mut ctx, "obj.p"), "42");
 }
+
+#[test]
+fn define_accessor_property() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const obj = { x: 1 };
+        Object.defineProperty(obj, "p", {
+            get: function () { return this.x * 2; },
+            set: function (value) { this.x = value; },
+            configurable: true
+        });
+    "#;
+    eprintln!("{}", forward(&mut ctx, init));
+
+    assert_eq!(forward(&mut ctx, "obj.p"), "2");
+    assert_eq!(forward(&mut ctx, "obj.p = 5"), "5");
+    assert_eq!(forward(&mut ctx, "obj.x"), "5");
+    assert_eq!(forward(&mut ctx, "obj.p"), "10");
+    assert_eq!(forward(&mut ctx, "obj['p']"), "10");
+}
+
+#[test]
+fn inherited_accessor_property() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const proto = {};
+        Object.defineProperty(proto, "p", {
+            get: function () { return this.name; },
+            set: function (value) { this.name = value + "!"; }
+        });
+        const obj = Object.create(proto);
+        obj.p = "obj";
+    "#;
+    eprintln!("{}", forward(&mut ctx, init));
+
+    assert_eq!(forward(&mut ctx, "obj.p"), "\"obj!\"");
+    assert_eq!(forward(&mut ctx, "obj.hasOwnProperty('p')"), "false");
+    assert_eq!(forward(&mut ctx, "obj.hasOwnProperty('name')"), "true");
+    assert_eq!(forward(&mut ctx, "proto.name"), "undefined");
+}
+
+#[test]
+fn accessor_property_without_setter() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const obj = {};
+        Object.defineProperty(obj, "p", { get: function () { return 1; } });
+        obj.p = 2;
+    "#;
+    eprintln!("{}", forward(&mut ctx, init));
+
+    assert_eq!(forward(&mut ctx, "obj.p"), "1");
+}
+
+#[test]
+fn inherited_readonly_property() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const proto = {};
+        Object.defineProperty(proto, "p", { value: 1 });
+        const obj = Object.create(proto);
+        obj.p = 2;
+    "#;
+    eprintln!("{}", forward(&mut ctx, init));
+
+    assert_eq!(forward(&mut ctx, "obj.p"), "1");
+    assert_eq!(forward(&mut ctx, "obj.hasOwnProperty('p')"), "false");
+}
+
+#[test]
+fn getter_errors_are_thrown() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const obj = {};
+        Object.defineProperty(obj, "p", {
+            get: function () { throw new Error("getter"); }
+        });
+        let message;
+        try {
+            obj.p;
+        } catch (e) {
+            message = e.message;
+        }
+    "#;
+    eprintln!("{}", forward(&mut ctx, init));
+
+    assert_eq!(forward(&mut ctx, "message"), "\"getter\"");
+}
@@ -0,0 +1,1272 @@
+//! This module implements the global `Promise` object.
+//!
+//! A `Promise` represents the eventual completion (or failure) of an asynchronous operation and
+//! its resulting value. The reactions to a promise run as jobs, once the host drains the job
+//! queue of the context with `Context::run_jobs`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-promise-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
+
+use crate::{
+    builtins::{
+        function::{captured, captured_mut, NativeClosure},
+        iterable::{get_iterator, IteratorRecord},
+        Array, BuiltIn,
+    },
+    job::Job,
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData},
+    property::{AccessorDescriptor, Attribute},
+    value::same_value,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a promise.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-internal-slots-of-promise-instances
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum PromiseState {
+    Pending,
+    Fulfilled(Value),
+    Rejected(Value),
+}
+
+/// The kind of a promise reaction, it decides what happens when the reaction has no handler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+pub(crate) enum ReactionType {
+    Fulfill,
+    Reject,
+}
+
+unsafe impl Trace for ReactionType {
+    unsafe_empty_trace!();
+}
+
+/// A promise capability, a promise along with the functions that resolve or reject it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisecapability-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct PromiseCapability {
+    promise: Value,
+    resolve: Value,
+    reject: Value,
+}
+
+impl PromiseCapability {
+    /// Creates a new promise capability from the constructor `c`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisecapability
+    pub(crate) fn new(c: &Value, ctx: &mut Context) -> Result<Self> {
+        let constructor = match c {
+            Value::Object(ref object) if c.is_constructor() => object.clone(),
+            _ => return Err(ctx.construct_type_error("Promise capability needs a constructor")),
+        };
+
+        // The executor stores the resolving functions of the promise being constructed.
+        let captures: Value = Object::native_object(Self {
+            promise: Value::undefined(),
+            resolve: Value::undefined(),
+            reject: Value::undefined(),
+        })
+        .into();
+        let executor = FunctionBuilder::closure(ctx, Self::executor, captures.clone())
+            .length(2)
+            .build();
+
+        let promise = constructor.construct(&[executor.into()], c, ctx)?;
+        let executor_state = captured::<Self>(&captures);
+        let (resolve, reject) = (
+            executor_state.resolve.clone(),
+            executor_state.reject.clone(),
+        );
+        if !resolve.is_callable() {
+            return Err(ctx.construct_type_error("Promise resolve function is not callable"));
+        }
+        if !reject.is_callable() {
+            return Err(ctx.construct_type_error("Promise reject function is not callable"));
+        }
+
+        Ok(Self {
+            promise,
+            resolve,
+            reject,
+        })
+    }
+
+    /// The executor given to the constructor of a new promise capability.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getcapabilitiesexecutor-functions
+    fn executor(_: &Value, args: &[Value], captures: &Value, ctx: &mut Context) -> Result<Value> {
+        let already_set = captured_mut(captures, |capability: &mut Self| {
+            if !capability.resolve.is_undefined() || !capability.reject.is_undefined() {
+                return true;
+            }
+            capability.resolve = args.get(0).cloned().unwrap_or_default();
+            capability.reject = args.get(1).cloned().unwrap_or_default();
+            false
+        });
+        if already_set {
+            return ctx.throw_type_error("Promise executor has already been called");
+        }
+        Ok(Value::undefined())
+    }
+
+    /// Gets the promise of the capability.
+    pub(crate) fn promise(&self) -> &Value {
+        &self.promise
+    }
+
+    /// Resolves the promise of the capability with `value`.
+    pub(crate) fn resolve(&self, value: Value, ctx: &mut Context) -> Result<Value> {
+        ctx.call(&self.resolve, &Value::undefined(), &[value])
+    }
+
+    /// Rejects the promise of the capability with `reason`.
+    pub(crate) fn reject(&self, reason: Value, ctx: &mut Context) -> Result<Value> {
+        ctx.call(&self.reject, &Value::undefined(), &[reason])
+    }
+
+    /// Rejects the promise of the capability with `reason` and returns the promise, this is how
+    /// most algorithms that return a promise report an error.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ifabruptrejectpromise
+    pub(crate) fn reject_with(&self, reason: Value, ctx: &mut Context) -> Result<Value> {
+        self.reject(reason, ctx)?;
+        Ok(self.promise.clone())
+    }
+}
+
+/// A reaction to the settlement of a promise.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisereaction-records
+#[derive(Debug, Clone, Trace, Finalize)]
+struct PromiseReaction {
+    capability: Option<PromiseCapability>,
+    kind: ReactionType,
+    handler: Option<Value>,
+}
+
+/// A promise object, with the reactions waiting for it to be settled.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-promise-instances
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Promise {
+    state: PromiseState,
+    fulfill_reactions: Vec<PromiseReaction>,
+    reject_reactions: Vec<PromiseReaction>,
+    is_handled: bool,
+}
+
+/// The state shared by the resolving functions of a promise.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ResolvingFunctions {
+    promise: GcObject,
+    already_resolved: bool,
+}
+
+/// The state of a job that resolves a promise with a thenable.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ThenableJob {
+    promise: GcObject,
+    thenable: Value,
+    then: Value,
+}
+
+/// The state of a job that runs a reaction.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ReactionJob {
+    reaction: PromiseReaction,
+    argument: Value,
+}
+
+/// The state of the functions created by `Promise.prototype.finally`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct Finally {
+    on_finally: Value,
+    constructor: Value,
+}
+
+/// The state shared by the element functions of a promise combinator, like `Promise.all`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct Combinator {
+    values: Vec<Value>,
+    remaining: usize,
+    capability: PromiseCapability,
+}
+
+/// The state of the element functions for one of the promises given to a combinator.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct Element {
+    index: usize,
+    already_called: bool,
+    combinator: Value,
+}
+
+impl BuiltIn for Promise {
+    const NAME: &'static str = "Promise";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+        let promise_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().promise_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::then, "then", 2)
+        .method(Self::catch, "catch", 1)
+        .method(Self::finally, "finally", 1)
+        .static_method(Self::all, "all", 1)
+        .static_method(Self::all_settled, "allSettled", 1)
+        .static_method(Self::any, "any", 1)
+        .static_method(Self::race, "race", 1)
+        .static_method(Self::resolve, "resolve", 1)
+        .static_method(Self::reject, "reject", 1)
+        .property(to_string_tag, Self::NAME, Attribute::CONFIGURABLE)
+        .callable(false)
+        .build();
+
+        let species = context.well_known_symbols().species_symbol();
+        let get_species = FunctionBuilder::new(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .build();
+        promise_object.borrow_mut().insert(
+            species,
+            AccessorDescriptor::new(Some(get_species), None, Attribute::CONFIGURABLE),
+        );
+
+        (Self::NAME, promise_object.into(), Self::attribute())
+    }
+}
+
+impl Promise {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Gets the state of the promise.
+    #[inline]
+    pub fn state(&self) -> &PromiseState {
+        &self.state
+    }
+
+    /// `Promise( executor )`
+    ///
+    /// Creates a new promise, `executor` is called with the functions that resolve or reject it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-executor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/Promise
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        if !this.is_object() {
+            return ctx.throw_type_error(
+                "calling a builtin Promise constructor without new is forbidden",
+            );
+        }
+
+        let executor = args.get(0).cloned().unwrap_or_default();
+        if !executor.is_callable() {
+            return ctx.throw_type_error("Promise executor is not a function");
+        }
+
+        this.set_data(ObjectData::Promise(Self {
+            state: PromiseState::Pending,
+            fulfill_reactions: Vec::new(),
+            reject_reactions: Vec::new(),
+            is_handled: false,
+        }));
+        let promise = match this {
+            Value::Object(ref object) => object.clone(),
+            _ => unreachable!("constructors are called with an object"),
+        };
+
+        let (resolve, reject) = Self::create_resolving_functions(&promise, ctx);
+        if let Err(error) = ctx.call(&executor, &Value::undefined(), &[resolve, reject.clone()]) {
+            ctx.call(&reject, &Value::undefined(), &[error])?;
+        }
+        Ok(this.clone())
+    }
+
+    /// `get Promise [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-promise-@@species
+    fn get_species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
+    /// Creates a new promise from the `Promise` constructor, with the functions that settle it.
+    pub(crate) fn new_capability(ctx: &mut Context) -> PromiseCapability {
+        let constructor = ctx.standard_objects().promise_object().constructor();
+        PromiseCapability::new(&constructor.into(), ctx)
+            .expect("the Promise constructor creates a valid capability")
+    }
+
+    /// Creates the functions that resolve or reject `promise`, only the first call to either of
+    /// them has an effect.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createresolvingfunctions
+    pub(crate) fn create_resolving_functions(
+        promise: &GcObject,
+        ctx: &mut Context,
+    ) -> (Value, Value) {
+        let captures: Value = Object::native_object(ResolvingFunctions {
+            promise: promise.clone(),
+            already_resolved: false,
+        })
+        .into();
+        let resolve = FunctionBuilder::closure(ctx, Self::resolve_function, captures.clone())
+            .length(1)
+            .build();
+        let reject = FunctionBuilder::closure(ctx, Self::reject_function, captures)
+            .length(1)
+            .build();
+        (resolve.into(), reject.into())
+    }
+
+    /// Marks the resolving functions as used, returns `false` if they already were.
+    fn try_resolve(captures: &Value) -> bool {
+        captured_mut(captures, |functions: &mut ResolvingFunctions| {
+            !std::mem::replace(&mut functions.already_resolved, true)
+        })
+    }
+
+    /// The function that resolves a promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve-functions
+    fn resolve_function(
+        _: &Value,
+        args: &[Value],
+        captures: &Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        if !Self::try_resolve(captures) {
+            return Ok(Value::undefined());
+        }
+        let promise = captured::<ResolvingFunctions>(captures).promise.clone();
+        let resolution = args.get(0).cloned().unwrap_or_default();
+
+        let thenable = match resolution {
+            Value::Object(ref object) if GcObject::equals(object, &promise) => {
+                let error = ctx.construct_type_error("Promise can't be resolved with itself");
+                Self::reject_promise(&promise, error, ctx);
+                return Ok(Value::undefined());
+            }
+            Value::Object(ref object) => object.clone(),
+            _ => {
+                Self::fulfill_promise(&promise, resolution, ctx);
+                return Ok(Value::undefined());
+            }
+        };
+
+        let then = match thenable.get(&"then".into(), resolution.clone(), ctx) {
+            Ok(then) => then,
+            Err(error) => {
+                Self::reject_promise(&promise, error, ctx);
+                return Ok(Value::undefined());
+            }
+        };
+        if !then.is_callable() {
+            Self::fulfill_promise(&promise, resolution, ctx);
+            return Ok(Value::undefined());
+        }
+
+        // The thenable is resolved in a job, so that its `then` method doesn't run in the middle
+        // of the current code.
+        let captures = Object::native_object(ThenableJob {
+            promise,
+            thenable: resolution,
+            then,
+        })
+        .into();
+        let job = FunctionBuilder::closure(ctx, Self::resolve_thenable_job, captures).build();
+        ctx.enqueue_job(Job::new(job.into(), Vec::new()));
+        Ok(Value::undefined())
+    }
+
+    /// The function that rejects a promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-reject-functions
+    fn reject_function(
+        _: &Value,
+        args: &[Value],
+        captures: &Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        if !Self::try_resolve(captures) {
+            return Ok(Value::undefined());
+        }
+        let promise = captured::<ResolvingFunctions>(captures).promise.clone();
+        let reason = args.get(0).cloned().unwrap_or_default();
+        Self::reject_promise(&promise, reason, ctx);
+        Ok(Value::undefined())
+    }
+
+    /// The job that resolves a promise with a thenable, by calling its `then` method.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromiseresolvethenablejob
+    fn resolve_thenable_job(
+        _: &Value,
+        _: &[Value],
+        captures: &Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let job = captured::<ThenableJob>(captures);
+        let (resolve, reject) = Self::create_resolving_functions(&job.promise, ctx);
+        match ctx.call(&job.then, &job.thenable, &[resolve, reject.clone()]) {
+            Ok(value) => Ok(value),
+            Err(error) => ctx.call(&reject, &Value::undefined(), &[error]),
+        }
+    }
+
+    /// Settles `promise` with `state`, and queues the reactions that were waiting for it.
+    fn settle_promise(promise: &GcObject, state: PromiseState, ctx: &mut Context) {
+        let (reactions, argument) = {
+            let mut object = promise.borrow_mut();
+            let promise = object.as_promise_mut().expect("promise object");
+            debug_assert!(matches!(promise.state, PromiseState::Pending));
+            let fulfill_reactions = std::mem::take(&mut promise.fulfill_reactions);
+            let reject_reactions = std::mem::take(&mut promise.reject_reactions);
+            promise.state = state.clone();
+            match state {
+                PromiseState::Fulfilled(ref value) => (fulfill_reactions, value.clone()),
+                PromiseState::Rejected(ref reason) => (reject_reactions, reason.clone()),
+                PromiseState::Pending => unreachable!("promises are settled with a value"),
+            }
+        };
+        Self::trigger_promise_reactions(reactions, argument, ctx);
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-fulfillpromise
+    fn fulfill_promise(promise: &GcObject, value: Value, ctx: &mut Context) {
+        Self::settle_promise(promise, PromiseState::Fulfilled(value), ctx)
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rejectpromise
+    fn reject_promise(promise: &GcObject, reason: Value, ctx: &mut Context) {
+        Self::settle_promise(promise, PromiseState::Rejected(reason), ctx)
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-triggerpromisereactions
+    fn trigger_promise_reactions(
+        reactions: Vec<PromiseReaction>,
+        argument: Value,
+        ctx: &mut Context,
+    ) {
+        for reaction in reactions {
+            Self::enqueue_reaction_job(reaction, argument.clone(), ctx);
+        }
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisereactionjob
+    fn enqueue_reaction_job(reaction: PromiseReaction, argument: Value, ctx: &mut Context) {
+        let captures = Object::native_object(ReactionJob { reaction, argument }).into();
+        let job = FunctionBuilder::closure(ctx, Self::reaction_job, captures).build();
+        ctx.enqueue_job(Job::new(job.into(), Vec::new()));
+    }
+
+    /// The job that runs the handler of a reaction, and settles the promise that was derived
+    /// from it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisereactionjob
+    fn reaction_job(_: &Value, _: &[Value], captures: &Value, ctx: &mut Context) -> Result<Value> {
+        let ReactionJob { reaction, argument } = &captured(captures);
+        let handler_result = match (&reaction.handler, reaction.kind) {
+            (Some(handler), _) => ctx.call(handler, &Value::undefined(), &[argument.clone()]),
+            (None, ReactionType::Fulfill) => Ok(argument.clone()),
+            (None, ReactionType::Reject) => Err(argument.clone()),
+        };
+
+        match (&reaction.capability, handler_result) {
+            (None, result) => result.map(|_| Value::undefined()),
+            (Some(capability), Ok(value)) => capability.resolve(value, ctx),
+            (Some(capability), Err(reason)) => capability.reject(reason, ctx),
+        }
+    }
+
+    /// Adds the handlers to `promise`, the promise of `capability` is settled with the result
+    /// of the handler that runs.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromisethen
+    pub(crate) fn perform_promise_then(
+        promise: &GcObject,
+        on_fulfilled: &Value,
+        on_rejected: &Value,
+        capability: Option<PromiseCapability>,
+        ctx: &mut Context,
+    ) -> Value {
+        let result = capability
+            .as_ref()
+            .map_or_else(Value::undefined, |capability| capability.promise.clone());
+        let handler = |handler: &Value| Some(handler.clone()).filter(Value::is_callable);
+        let fulfill_reaction = PromiseReaction {
+            capability: capability.clone(),
+            kind: ReactionType::Fulfill,
+            handler: handler(on_fulfilled),
+        };
+        let reject_reaction = PromiseReaction {
+            capability,
+            kind: ReactionType::Reject,
+            handler: handler(on_rejected),
+        };
+
+        let state = {
+            let mut object = promise.borrow_mut();
+            let promise = object.as_promise_mut().expect("promise object");
+            promise.is_handled = true;
+            if let PromiseState::Pending = promise.state {
+                promise.fulfill_reactions.push(fulfill_reaction.clone());
+                promise.reject_reactions.push(reject_reaction.clone());
+            }
+            promise.state.clone()
+        };
+        match state {
+            PromiseState::Pending => {}
+            PromiseState::Fulfilled(ref value) => {
+                Self::enqueue_reaction_job(fulfill_reaction, value.clone(), ctx)
+            }
+            PromiseState::Rejected(ref reason) => {
+                Self::enqueue_reaction_job(reject_reaction, reason.clone(), ctx)
+            }
+        }
+        result
+    }
+
+    /// Gets `this` as a promise object.
+    fn this_promise(this: &Value, ctx: &mut Context) -> Result<GcObject> {
+        match this {
+            Value::Object(ref object) if object.borrow().is_promise() => Ok(object.clone()),
+            _ => Err(ctx.construct_type_error("'this' is not a Promise")),
+        }
+    }
+
+    /// Gets the constructor that is used to create promises derived from `promise`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-speciesconstructor
+    fn species_constructor(promise: &GcObject, ctx: &mut Context) -> Result<Value> {
+        let default = ctx.standard_objects().promise_object().constructor();
+        let constructor = promise.get(&"constructor".into(), promise.clone().into(), ctx)?;
+        let constructor = match constructor {
+            Value::Undefined => return Ok(default.into()),
+            Value::Object(ref object) => object.clone(),
+            _ => return ctx.throw_type_error("Promise constructor is not an object"),
+        };
+        let species = ctx.well_known_symbols().species_symbol();
+        let species = constructor.get(&species.into(), constructor.clone().into(), ctx)?;
+        if species.is_null_or_undefined() {
+            return Ok(default.into());
+        }
+        if species.is_constructor() {
+            return Ok(species);
+        }
+        ctx.throw_type_error("Promise species is not a constructor")
+    }
+
+    /// `Promise.prototype.then( onFulfilled, onRejected )`
+    ///
+    /// Adds handlers to the promise, and returns a promise that is settled with the result of
+    /// the handler that runs.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.then
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/then
+    pub(crate) fn then(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let promise = Self::this_promise(this, ctx)?;
+        let constructor = Self::species_constructor(&promise, ctx)?;
+        let capability = PromiseCapability::new(&constructor, ctx)?;
+        let on_fulfilled = args.get(0).cloned().unwrap_or_default();
+        let on_rejected = args.get(1).cloned().unwrap_or_default();
+        Ok(Self::perform_promise_then(
+            &promise,
+            &on_fulfilled,
+            &on_rejected,
+            Some(capability),
+            ctx,
+        ))
+    }
+
+    /// Calls the `then` method of `promise`.
+    fn invoke_then(promise: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let then = promise.get_field("then", ctx)?;
+        ctx.call(&then, promise, args)
+    }
+
+    /// `Promise.prototype.catch( onRejected )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.catch
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/catch
+    pub(crate) fn catch(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let on_rejected = args.get(0).cloned().unwrap_or_default();
+        Self::invoke_then(this, &[Value::undefined(), on_rejected], ctx)
+    }
+
+    /// `Promise.prototype.finally( onFinally )`
+    ///
+    /// Adds a handler that runs when the promise is settled, whatever the outcome. The returned
+    /// promise is settled like this one, unless the handler throws.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.finally
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally
+    pub(crate) fn finally(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let promise = match this {
+            Value::Object(ref object) => object.clone(),
+            _ => return ctx.throw_type_error("'this' is not an object"),
+        };
+        let constructor = Self::species_constructor(&promise, ctx)?;
+        let on_finally = args.get(0).cloned().unwrap_or_default();
+        if !on_finally.is_callable() {
+            return Self::invoke_then(this, &[on_finally.clone(), on_finally], ctx);
+        }
+
+        let captures: Value = Object::native_object(Finally {
+            on_finally,
+            constructor,
+        })
+        .into();
+        let then_finally = FunctionBuilder::closure(ctx, Self::then_finally, captures.clone())
+            .length(1)
+            .build();
+        let catch_finally = FunctionBuilder::closure(ctx, Self::catch_finally, captures)
+            .length(1)
+            .build();
+        Self::invoke_then(this, &[then_finally.into(), catch_finally.into()], ctx)
+    }
+
+    /// Runs the handler of `finally`, and returns a promise that settles when the promise the
+    /// handler returned does.
+    fn run_finally(captures: &Value, ctx: &mut Context) -> Result<Value> {
+        let Finally {
+            on_finally,
+            constructor,
+        } = &captured(captures);
+        let result = ctx.call(on_finally, &Value::undefined(), &[])?;
+        Self::promise_resolve(constructor, result, ctx)
+    }
+
+    /// The handler `finally` adds for a fulfilled promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-thenfinallyfunctions
+    fn then_finally(
+        _: &Value,
+        args: &[Value],
+        captures: &Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        let promise = Self::run_finally(captures, ctx)?;
+        let value_thunk =
+            FunctionBuilder::closure(ctx, |_, _, value, _| Ok(value.clone()), value).build();
+        Self::invoke_then(&promise, &[value_thunk.into()], ctx)
+    }
+
+    /// The handler `finally` adds for a rejected promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-catchfinallyfunctions
+    fn catch_finally(
+        _: &Value,
+        args: &[Value],
+        captures: &Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let reason = args.get(0).cloned().unwrap_or_default();
+        let promise = Self::run_finally(captures, ctx)?;
+        let thrower =
+            FunctionBuilder::closure(ctx, |_, _, reason, _| Err(reason.clone()), reason).build();
+        Self::invoke_then(&promise, &[thrower.into()], ctx)
+    }
+
+    /// Returns `value` if it's a promise created by `constructor`, otherwise creates a new
+    /// promise from `constructor` and resolves it with `value`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve
+    pub(crate) fn promise_resolve(
+        constructor: &Value,
+        value: Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        if let Value::Object(ref object) = value {
+            if object.borrow().is_promise() {
+                let value_constructor = object.get(&"constructor".into(), value.clone(), ctx)?;
+                if same_value(&value_constructor, constructor) {
+                    return Ok(value);
+                }
+            }
+        }
+        let capability = PromiseCapability::new(constructor, ctx)?;
+        capability.resolve(value, ctx)?;
+        Ok(capability.promise.clone())
+    }
+
+    /// `Promise.resolve( x )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.resolve
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve
+    pub(crate) fn resolve(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        if !this.is_object() {
+            return ctx.throw_type_error("'this' is not an object");
+        }
+        Self::promise_resolve(this, args.get(0).cloned().unwrap_or_default(), ctx)
+    }
+
+    /// `Promise.reject( r )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.reject
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/reject
+    pub(crate) fn reject(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let capability = PromiseCapability::new(this, ctx)?;
+        capability.reject_with(args.get(0).cloned().unwrap_or_default(), ctx)
+    }
+
+    /// Runs a promise combinator like `Promise.all` over the iterable given in `args`.
+    ///
+    /// `perform` is called with the iterator, the constructor, the capability of the returned
+    /// promise and the `resolve` method of the constructor. It sets `done` once the iterator is
+    /// exhausted or broken, otherwise the iterator is closed if `perform` throws.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all
+    fn run_combinator(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Context,
+        perform: fn(
+            &IteratorRecord,
+            &Value,
+            &PromiseCapability,
+            &Value,
+            &mut bool,
+            &mut Context,
+        ) -> Result<Value>,
+    ) -> Result<Value> {
+        let capability = PromiseCapability::new(this, ctx)?;
+
+        // GetPromiseResolve ( promiseConstructor )
+        let promise_resolve = this.get_field("resolve", ctx)?;
+        if !promise_resolve.is_callable() {
+            let error = ctx.construct_type_error("Promise resolve is not a function");
+            return capability.reject_with(error, ctx);
+        }
+
+        let iterator = match get_iterator(ctx, args.get(0).cloned().unwrap_or_default()) {
+            Ok(iterator) => iterator,
+            Err(error) => return capability.reject_with(error, ctx),
+        };
+
+        let mut done = false;
+        match perform(
+            &iterator,
+            this,
+            &capability,
+            &promise_resolve,
+            &mut done,
+            ctx,
+        ) {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                if !done {
+                    // IteratorClose, the original error wins over any error it throws.
+                    let iterator_object = iterator.iterator_object();
+                    let r#return = iterator_object.get_field("return", ctx)?;
+                    if r#return.is_callable() {
+                        let _ = ctx.call(&r#return, iterator_object, &[]);
+                    }
+                }
+                capability.reject_with(error, ctx)
+            }
+        }
+    }
+
+    /// Gets the next value of a combinator iterator, `None` means that it is exhausted.
+    fn next_value(
+        iterator: &IteratorRecord,
+        done: &mut bool,
+        ctx: &mut Context,
+    ) -> Result<Option<Value>> {
+        // If the iterator itself throws, it must not be closed.
+        *done = true;
+        let next = iterator.next(ctx)?;
+        if next.is_done() {
+            return Ok(None);
+        }
+        *done = false;
+        Ok(Some(next.value()))
+    }
+
+    /// Creates the element functions for the promise at `index` of a combinator.
+    fn create_element_functions(
+        combinator: &Value,
+        index: usize,
+        functions: &[NativeClosure],
+        ctx: &mut Context,
+    ) -> Vec<Value> {
+        captured_mut(combinator, |combinator: &mut Combinator| {
+            combinator.values.push(Value::undefined());
+            combinator.remaining += 1;
+        });
+        let captures: Value = Object::native_object(Element {
+            index,
+            already_called: false,
+            combinator: combinator.clone(),
+        })
+        .into();
+        functions
+            .iter()
+            .map(|function| {
+                FunctionBuilder::closure(ctx, *function, captures.clone())
+                    .length(1)
+                    .build()
+                    .into()
+            })
+            .collect()
+    }
+
+    /// Creates the state shared by the element functions of a combinator.
+    fn create_combinator(capability: &PromiseCapability) -> Value {
+        Object::native_object(Combinator {
+            values: Vec::new(),
+            remaining: 1,
+            capability: capability.clone(),
+        })
+        .into()
+    }
+
+    /// Records the settlement of the element that `captures` is about, and returns the list of
+    /// values if it was the last one.
+    ///
+    /// Returns `None` if the element was already settled, or if there are elements remaining.
+    fn settle_element(captures: &Value, value: Value) -> Option<(Vec<Value>, PromiseCapability)> {
+        let already_called = captured_mut(captures, |element: &mut Element| {
+            std::mem::replace(&mut element.already_called, true)
+        });
+        if already_called {
+            return None;
+        }
+        let Element {
+            index, combinator, ..
+        } = &captured(captures);
+        Self::settle_combinator(combinator, Some((*index, value)))
+    }
+
+    /// Decrements the remaining elements of a combinator, storing the value of an element if
+    /// one is given. Returns the values and the capability once there are no elements left.
+    fn settle_combinator(
+        combinator: &Value,
+        element: Option<(usize, Value)>,
+    ) -> Option<(Vec<Value>, PromiseCapability)> {
+        captured_mut(combinator, |combinator: &mut Combinator| {
+            if let Some((index, value)) = element {
+                combinator.values[index] = value;
+            }
+            combinator.remaining -= 1;
+            if combinator.remaining == 0 {
+                Some((combinator.values.clone(), combinator.capability.clone()))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Creates an array from the values of a combinator.
+    fn values_array(values: &[Value], ctx: &mut Context) -> Result<Value> {
+        let array = Array::new_array(ctx)?;
+        Array::add_to_array_object(&array, values, ctx)
+    }
+
+    /// `Promise.all( iterable )`
+    ///
+    /// Returns a promise that is fulfilled with the values of every promise of `iterable`, or
+    /// rejected with the reason of the first one that is rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/all
+    pub(crate) fn all(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::run_combinator(this, args, ctx, Self::perform_promise_all)
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseall
+    fn perform_promise_all(
+        iterator: &IteratorRecord,
+        constructor: &Value,
+        capability: &PromiseCapability,
+        promise_resolve: &Value,
+        done: &mut bool,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let combinator = Self::create_combinator(capability);
+        let mut index = 0;
+        while let Some(value) = Self::next_value(iterator, done, ctx)? {
+            let next_promise = ctx.call(promise_resolve, constructor, &[value])?;
+            let mut functions = Self::create_element_functions(
+                &combinator,
+                index,
+                &[Self::all_resolve_element],
+                ctx,
+            );
+            let resolve_element = functions.remove(0);
+            Self::invoke_then(
+                &next_promise,
+                &[resolve_element, capability.reject.clone()],
+                ctx,
+            )?;
+            index += 1;
+        }
+
+        if let Some((values, _)) = Self::settle_combinator(&combinator, None) {
+            let array = Self::values_array(&values, ctx)?;
+            capability.resolve(array, ctx)?;
+        }
+        Ok(capability.promise.clone())
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all-resolve-element-functions
+    fn all_resolve_element(
+        _: &Value,
+        args: &[Value],
+        captures: &Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        match Self::settle_element(captures, value) {
+            Some((values, capability)) => {
+                let array = Self::values_array(&values, ctx)?;
+                capability.resolve(array, ctx)
+            }
+            None => Ok(Value::undefined()),
+        }
+    }
+
+    /// `Promise.allSettled( iterable )`
+    ///
+    /// Returns a promise that is fulfilled once every promise of `iterable` is settled, with
+    /// objects that describe the outcome of each of them.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/allSettled
+    pub(crate) fn all_settled(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::run_combinator(this, args, ctx, Self::perform_promise_all_settled)
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseallsettled
+    fn perform_promise_all_settled(
+        iterator: &IteratorRecord,
+        constructor: &Value,
+        capability: &PromiseCapability,
+        promise_resolve: &Value,
+        done: &mut bool,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let combinator = Self::create_combinator(capability);
+        let mut index = 0;
+        while let Some(value) = Self::next_value(iterator, done, ctx)? {
+            let next_promise = ctx.call(promise_resolve, constructor, &[value])?;
+            // Both functions share the same state, only the first one called has an effect.
+            let functions = Self::create_element_functions(
+                &combinator,
+                index,
+                &[
+                    Self::all_settled_resolve_element,
+                    Self::all_settled_reject_element,
+                ],
+                ctx,
+            );
+            Self::invoke_then(&next_promise, &functions, ctx)?;
+            index += 1;
+        }
+
+        if let Some((values, _)) = Self::settle_combinator(&combinator, None) {
+            let array = Self::values_array(&values, ctx)?;
+            capability.resolve(array, ctx)?;
+        }
+        Ok(capability.promise.clone())
+    }
+
+    /// Settles an element of `Promise.allSettled` with an object describing its outcome.
+    fn all_settled_element(
+        captures: &Value,
+        status: &str,
+        key: &str,
+        value: Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let object = Value::from(ctx.construct_object());
+        object.set_field("status", status, ctx)?;
+        object.set_field(key, value, ctx)?;
+        match Self::settle_element(captures, object) {
+            Some((values, capability)) => {
+                let array = Self::values_array(&values, ctx)?;
+                capability.resolve(array, ctx)
+            }
+            None => Ok(Value::undefined()),
+        }
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled-resolve-element-functions
+    fn all_settled_resolve_element(
+        _: &Value,
+        args: &[Value],
+        captures: &Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::all_settled_element(captures, "fulfilled", "value", value, ctx)
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled-reject-element-functions
+    fn all_settled_reject_element(
+        _: &Value,
+        args: &[Value],
+        captures: &Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let reason = args.get(0).cloned().unwrap_or_default();
+        Self::all_settled_element(captures, "rejected", "reason", reason, ctx)
+    }
+
+    /// `Promise.any( iterable )`
+    ///
+    /// Returns a promise that is fulfilled with the value of the first promise of `iterable`
+    /// that is fulfilled, or rejected with an `AggregateError` if all of them are rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.any
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/any
+    pub(crate) fn any(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::run_combinator(this, args, ctx, Self::perform_promise_any)
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseany
+    fn perform_promise_any(
+        iterator: &IteratorRecord,
+        constructor: &Value,
+        capability: &PromiseCapability,
+        promise_resolve: &Value,
+        done: &mut bool,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let combinator = Self::create_combinator(capability);
+        let mut index = 0;
+        while let Some(value) = Self::next_value(iterator, done, ctx)? {
+            let next_promise = ctx.call(promise_resolve, constructor, &[value])?;
+            let mut functions = Self::create_element_functions(
+                &combinator,
+                index,
+                &[Self::any_reject_element],
+                ctx,
+            );
+            let reject_element = functions.remove(0);
+            Self::invoke_then(
+                &next_promise,
+                &[capability.resolve.clone(), reject_element],
+                ctx,
+            )?;
+            index += 1;
+        }
+
+        match Self::settle_combinator(&combinator, None) {
+            Some((errors, _)) => Err(Self::aggregate_error(&errors, ctx)?),
+            None => Ok(capability.promise.clone()),
+        }
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.any-reject-element-functions
+    fn any_reject_element(
+        _: &Value,
+        args: &[Value],
+        captures: &Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let reason = args.get(0).cloned().unwrap_or_default();
+        match Self::settle_element(captures, reason) {
+            Some((errors, capability)) => {
+                let error = Self::aggregate_error(&errors, ctx)?;
+                capability.reject(error, ctx)
+            }
+            None => Ok(Value::undefined()),
+        }
+    }
+
+    /// Creates the `AggregateError` `Promise.any` is rejected with.
+    fn aggregate_error(errors: &[Value], ctx: &mut Context) -> Result<Value> {
+        let constructor = ctx
+            .standard_objects()
+            .aggregate_error_object()
+            .constructor();
+        let errors = Self::values_array(errors, ctx)?;
+        constructor.construct(
+            &[errors, "All promises were rejected".into()],
+            &constructor.clone().into(),
+            ctx,
+        )
+    }
+
+    /// `Promise.race( iterable )`
+    ///
+    /// Returns a promise that is settled like the first promise of `iterable` that is settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.race
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/race
+    pub(crate) fn race(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::run_combinator(this, args, ctx, Self::perform_promise_race)
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiserace
+    fn perform_promise_race(
+        iterator: &IteratorRecord,
+        constructor: &Value,
+        capability: &PromiseCapability,
+        promise_resolve: &Value,
+        done: &mut bool,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        while let Some(value) = Self::next_value(iterator, done, ctx)? {
+            let next_promise = ctx.call(promise_resolve, constructor, &[value])?;
+            Self::invoke_then(
+                &next_promise,
+                &[capability.resolve.clone(), capability.reject.clone()],
+                ctx,
+            )?;
+        }
+        Ok(capability.promise.clone())
+    }
+}
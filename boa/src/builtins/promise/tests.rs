@@ -0,0 +1,198 @@
+use crate::{forward, Context};
+
+#[test]
+fn reactions_run_as_jobs() {
+    let mut engine = Context::new();
+    let init = r#"
+        var log = [];
+        Promise.resolve(1).then(v => log.push("then " + v));
+        log.push("sync");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "log.join()"), "\"sync\"");
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "log.join()"), "\"sync,then 1\"");
+    assert!(!engine.has_pending_jobs());
+}
+
+#[test]
+fn executor() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        new Promise((resolve, reject) => {
+            resolve(1);
+            reject(2);
+            resolve(3);
+        }).then(v => { result = v; });
+        var error;
+        new Promise(() => { throw "thrown"; }).catch(e => { error = e; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "1");
+    assert_eq!(forward(&mut engine, "error"), "\"thrown\"");
+}
+
+#[test]
+fn executor_is_required() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "try { new Promise(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Promise(() => {}) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn then_chain() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        Promise.resolve(1)
+            .then(v => v + 1)
+            .then(v => { throw v * 10; })
+            .then(() => "skipped")
+            .catch(e => e + 1)
+            .then(v => { result = v; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "21");
+}
+
+#[test]
+fn resolve_with_thenable() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        var thenable = { then(resolve) { resolve("from thenable"); } };
+        new Promise(resolve => resolve(thenable)).then(v => { result = v; });
+        var self_error;
+        var p = new Promise(resolve => Promise.resolve().then(() => resolve(p)));
+        p.catch(e => { self_error = e.name; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "\"from thenable\"");
+    assert_eq!(forward(&mut engine, "self_error"), "\"TypeError\"");
+}
+
+#[test]
+fn resolve_returns_same_promise() {
+    let mut engine = Context::new();
+    let init = r#"
+        var p = new Promise(() => {});
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "Promise.resolve(p) === p"), "true");
+    assert_eq!(forward(&mut engine, "Promise.reject(p) === p"), "false");
+}
+
+#[test]
+fn finally() {
+    let mut engine = Context::new();
+    let init = r#"
+        var log = [];
+        Promise.resolve(1)
+            .finally(() => { log.push("finally"); return 2; })
+            .then(v => log.push("value " + v));
+        Promise.reject(3)
+            .finally(() => log.push("finally"))
+            .catch(e => log.push("reason " + e));
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut engine, "log.join()"),
+        "\"finally,finally,value 1,reason 3\""
+    );
+}
+
+#[test]
+fn all() {
+    let mut engine = Context::new();
+    let init = r#"
+        var values;
+        Promise.all([1, Promise.resolve(2), new Promise(r => r(3))]).then(v => { values = v; });
+        var reason;
+        Promise.all([Promise.resolve(1), Promise.reject("no")]).catch(e => { reason = e; });
+        var empty;
+        Promise.all([]).then(v => { empty = v; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "values.join()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut engine, "reason"), "\"no\"");
+    assert_eq!(forward(&mut engine, "empty.length"), "0");
+}
+
+#[test]
+fn all_settled() {
+    let mut engine = Context::new();
+    let init = r#"
+        var results;
+        Promise.allSettled([Promise.resolve(1), Promise.reject(2)]).then(v => { results = v; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "results[0].status"), "\"fulfilled\"");
+    assert_eq!(forward(&mut engine, "results[0].value"), "1");
+    assert_eq!(forward(&mut engine, "results[1].status"), "\"rejected\"");
+    assert_eq!(forward(&mut engine, "results[1].reason"), "2");
+}
+
+#[test]
+fn any() {
+    let mut engine = Context::new();
+    let init = r#"
+        var value;
+        Promise.any([Promise.reject(1), Promise.resolve(2)]).then(v => { value = v; });
+        var error;
+        Promise.any([Promise.reject(1), Promise.reject(2)]).catch(e => { error = e; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "value"), "2");
+    assert_eq!(forward(&mut engine, "error.name"), "\"AggregateError\"");
+    assert_eq!(forward(&mut engine, "error.errors.join()"), "\"1,2\"");
+}
+
+#[test]
+fn race() {
+    let mut engine = Context::new();
+    let init = r#"
+        var value;
+        Promise.race([new Promise(() => {}), Promise.resolve("fast")]).then(v => { value = v; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "value"), "\"fast\"");
+}
+
+#[test]
+fn combinator_rejects_non_iterable() {
+    let mut engine = Context::new();
+    let init = r#"
+        var reason;
+        Promise.all(1).catch(e => { reason = e.name; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "reason"), "\"TypeError\"");
+}
+
+#[test]
+fn run_jobs_reports_errors() {
+    let mut engine = Context::new();
+    forward(&mut engine, "Promise.resolve().then(() => {}); var x = 0;");
+    assert!(engine.has_pending_jobs());
+    engine.run_jobs().unwrap();
+    assert!(!engine.has_pending_jobs());
+}
@@ -97,7 +97,13 @@ impl RegExp {
     pub(crate) const LENGTH: usize = 2;
 
     /// Create a new `RegExp`
-    pub(crate) fn constructor(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        // `RegExp(...)` is the same as `new RegExp(...)`.
+        if !this.is_object() {
+            let constructor = ctx.standard_objects().regexp_object().constructor();
+            return constructor.construct(args, &constructor.clone().into(), ctx);
+        }
+
         let arg = args.get(0).ok_or_else(Value::undefined)?;
         let mut regex_body = String::new();
         let mut regex_flags = String::new();
@@ -154,8 +160,12 @@ impl RegExp {
             sorted_flags.push('y');
         }
 
-        let matcher = Regex::newf(regex_body.as_str(), Flags::from(sorted_flags.as_str()))
-            .expect("failed to create matcher");
+        let matcher = match Regex::newf(regex_body.as_str(), Flags::from(sorted_flags.as_str())) {
+            Ok(matcher) => matcher,
+            Err(error) => {
+                return ctx.throw_syntax_error(format!("invalid regular expression: {}", error))
+            }
+        };
         let regexp = RegExp {
             matcher,
             use_last_index: global || sticky,
@@ -307,7 +317,7 @@ impl RegExp {
             .get(0)
             .expect("could not get argument")
             .to_string(ctx)?;
-        let mut last_index = this.get_field("lastIndex").to_index(ctx)?;
+        let mut last_index = this.get_field("lastIndex", ctx)?.to_index(ctx)?;
         let result = if let Some(object) = this.as_object() {
             let regex = object.as_regexp().unwrap();
             let result =
@@ -326,7 +336,7 @@ impl RegExp {
         } else {
             panic!("object is not a regexp")
         };
-        this.set_field("lastIndex", Value::from(last_index));
+        this.set_field("lastIndex", Value::from(last_index), ctx)?;
         result
     }
 
@@ -347,7 +357,7 @@ impl RegExp {
             .get(0)
             .expect("could not get argument")
             .to_string(ctx)?;
-        let mut last_index = this.get_field("lastIndex").to_index(ctx)?;
+        let mut last_index = this.get_field("lastIndex", ctx)?.to_index(ctx)?;
         let result = if let Some(object) = this.as_object() {
             let regex = object.as_regexp().unwrap();
             let result = {
@@ -385,7 +395,7 @@ impl RegExp {
         } else {
             panic!("object is not a regexp")
         };
-        this.set_field("lastIndex", Value::from(last_index));
+        this.set_field("lastIndex", Value::from(last_index), ctx)?;
         result
     }
 
@@ -455,7 +465,7 @@ impl RegExp {
     /// [spec]: https://tc39.es/ecma262/#sec-regexp-prototype-matchall
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@matchAll
     // TODO: it's returning an array, it should return an iterator
-    pub(crate) fn match_all(this: &Value, arg_str: String) -> Result<Value> {
+    pub(crate) fn match_all(this: &Value, arg_str: String, ctx: &mut Context) -> Result<Value> {
         let matches = if let Some(object) = this.as_object() {
             let regex = object.as_regexp().unwrap();
             let mut matches = Vec::new();
@@ -493,7 +503,7 @@ impl RegExp {
 
         let length = matches.len();
         let result = Value::from(matches);
-        result.set_field("length", Value::from(length));
+        result.set_field("length", Value::from(length), ctx)?;
         result.set_data(ObjectData::Array);
 
         Ok(result)
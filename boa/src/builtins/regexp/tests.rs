@@ -98,3 +98,18 @@ fn to_string() {
     );
     assert_eq!(forward(&mut engine, "/\\n/g.toString()"), "\"/\\n/g\"");
 }
+
+#[test]
+fn invalid_pattern() {
+    let mut engine = Context::new();
+
+    assert!(forward(&mut engine, "new RegExp('(')").starts_with("Uncaught \"SyntaxError\""));
+    assert!(forward(&mut engine, "/(/").starts_with("Uncaught \"SyntaxError\""));
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { eval('/a[/'); 'valid' } catch (e) { e instanceof SyntaxError }"
+        ),
+        "true"
+    );
+}
@@ -16,7 +16,7 @@ mod tests;
 use crate::{
     builtins::{string::string_iterator::StringIterator, BuiltIn, RegExp},
     object::{ConstructorBuilder, Object, ObjectData},
-    property::Attribute,
+    property::{Attribute, DataDescriptor},
     value::{RcString, Value},
     BoaProfiler, Context, Result,
 };
@@ -102,11 +102,11 @@ impl BuiltIn for String {
         .method(Self::to_lowercase, "toLowerCase", 0)
         .method(Self::to_uppercase, "toUpperCase", 0)
         .method(Self::substring, "substring", 2)
-        .method(Self::substr, "substr", 2)
         .method(Self::value_of, "valueOf", 0)
         .method(Self::match_all, "matchAll", 1)
         .method(Self::replace, "replace", 2)
         .method(Self::iterator, (symbol_iterator, "[Symbol.iterator]"), 0)
+        .static_method(Self::raw, "raw", 1)
         .build();
 
         (Self::NAME, string_object.into(), Self::attribute())
@@ -137,13 +137,51 @@ impl String {
 
         let length = string.encode_utf16().count();
 
-        this.set_field("length", Value::from(length as i32));
+        this.set_property(
+            "length",
+            DataDescriptor::new(
+                length,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            ),
+        );
 
         this.set_data(ObjectData::String(string.clone()));
 
         Ok(Value::from(string))
     }
 
+    /// `String.raw( template, ...substitutions )`
+    ///
+    /// The static `String.raw()` method is a tag function of template literals, that returns the
+    /// raw strings of the template interleaved with the substitutions.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.raw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw
+    pub(crate) fn raw(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let cooked = args.get(0).cloned().unwrap_or_default().to_object(ctx)?;
+        let raw = Value::from(cooked).get_field("raw", ctx)?.to_object(ctx)?;
+        let raw = Value::from(raw);
+        let length = raw.get_field("length", ctx)?.to_length(ctx)?;
+
+        let substitutions = args.get(1..).unwrap_or_default();
+        let mut result = StdString::new();
+        for i in 0..length {
+            result.push_str(&raw.get_field(i, ctx)?.to_string(ctx)?);
+            if i + 1 == length {
+                break;
+            }
+            if let Some(substitution) = substitutions.get(i) {
+                result.push_str(&substitution.to_string(ctx)?);
+            }
+        }
+
+        Ok(Value::from(result))
+    }
+
     fn this_string_value(this: &Value, ctx: &mut Context) -> Result<RcString> {
         match this {
             Value::String(ref string) => return Ok(string.clone()),
@@ -1104,6 +1142,220 @@ impl String {
         }
     }
 
+    /// `CreateHTML( string, tag, attribute, value )`
+    ///
+    /// Wraps the string value of `this` in the HTML element `tag`, which gets the attribute
+    /// `attribute` if the method takes one. Double quotes in the value of the attribute are
+    /// escaped.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createhtml
+    fn create_html(
+        this: &Value,
+        tag: &str,
+        attribute: Option<&str>,
+        args: &[Value],
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let string = this.require_object_coercible(ctx)?.to_string(ctx)?;
+        let mut html = format!("<{}", tag);
+        if let Some(attribute) = attribute {
+            let value = args
+                .get(0)
+                .cloned()
+                .unwrap_or_default()
+                .to_string(ctx)?
+                .replace('"', "&quot;");
+            html.push_str(&format!(" {}=\"{}\"", attribute, value));
+        }
+        html.push_str(&format!(">{}</{}>", string, tag));
+        Ok(html.into())
+    }
+
+    /// `String.prototype.anchor( name )`
+    ///
+    /// Returns the string wrapped in an `<a>` element with a `name` attribute.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.anchor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/anchor
+    pub(crate) fn anchor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::create_html(this, "a", Some("name"), args, ctx)
+    }
+
+    /// `String.prototype.big()`
+    ///
+    /// Returns the string wrapped in a `<big>` element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.big
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/big
+    pub(crate) fn big(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::create_html(this, "big", None, args, ctx)
+    }
+
+    /// `String.prototype.blink()`
+    ///
+    /// Returns the string wrapped in a `<blink>` element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.blink
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/blink
+    pub(crate) fn blink(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::create_html(this, "blink", None, args, ctx)
+    }
+
+    /// `String.prototype.bold()`
+    ///
+    /// Returns the string wrapped in a `<b>` element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.bold
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/bold
+    pub(crate) fn bold(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::create_html(this, "b", None, args, ctx)
+    }
+
+    /// `String.prototype.fixed()`
+    ///
+    /// Returns the string wrapped in a `<tt>` element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.fixed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fixed
+    pub(crate) fn fixed(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::create_html(this, "tt", None, args, ctx)
+    }
+
+    /// `String.prototype.fontcolor( color )`
+    ///
+    /// Returns the string wrapped in a `<font>` element with a `color` attribute.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.fontcolor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fontcolor
+    pub(crate) fn fontcolor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::create_html(this, "font", Some("color"), args, ctx)
+    }
+
+    /// `String.prototype.fontsize( size )`
+    ///
+    /// Returns the string wrapped in a `<font>` element with a `size` attribute.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.fontsize
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fontsize
+    pub(crate) fn fontsize(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::create_html(this, "font", Some("size"), args, ctx)
+    }
+
+    /// `String.prototype.italics()`
+    ///
+    /// Returns the string wrapped in an `<i>` element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.italics
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/italics
+    pub(crate) fn italics(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::create_html(this, "i", None, args, ctx)
+    }
+
+    /// `String.prototype.link( url )`
+    ///
+    /// Returns the string wrapped in an `<a>` element with an `href` attribute.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.link
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/link
+    pub(crate) fn link(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::create_html(this, "a", Some("href"), args, ctx)
+    }
+
+    /// `String.prototype.small()`
+    ///
+    /// Returns the string wrapped in a `<small>` element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.small
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/small
+    pub(crate) fn small(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::create_html(this, "small", None, args, ctx)
+    }
+
+    /// `String.prototype.strike()`
+    ///
+    /// Returns the string wrapped in a `<strike>` element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.strike
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/strike
+    pub(crate) fn strike(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::create_html(this, "strike", None, args, ctx)
+    }
+
+    /// `String.prototype.sub()`
+    ///
+    /// Returns the string wrapped in a `<sub>` element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.sub
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/sub
+    pub(crate) fn sub(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::create_html(this, "sub", None, args, ctx)
+    }
+
+    /// `String.prototype.sup()`
+    ///
+    /// Returns the string wrapped in a `<sup>` element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.sup
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/sup
+    pub(crate) fn sup(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::create_html(this, "sup", None, args, ctx)
+    }
+
     /// String.prototype.valueOf()
     ///
     /// The `valueOf()` method returns the primitive value of a `String` object.
@@ -1158,7 +1410,7 @@ impl String {
             ),
         }?;
 
-        RegExp::match_all(&re, this.to_string(ctx)?.to_string())
+        RegExp::match_all(&re, this.to_string(ctx)?.to_string(), ctx)
     }
 
     pub(crate) fn iterator(this: &Value, _args: &[Value], ctx: &mut Context) -> Result<Value> {
@@ -520,8 +520,8 @@ fn test_match() {
 fn trim() {
     let mut engine = Context::new();
     assert_eq!(forward(&mut engine, "'Hello'.trim()"), "\"Hello\"");
-    assert_eq!(forward(&mut engine, "' \nHello'.trim()"), "\"Hello\"");
-    assert_eq!(forward(&mut engine, "'Hello \n\r'.trim()"), "\"Hello\"");
+    assert_eq!(forward(&mut engine, "' \\nHello'.trim()"), "\"Hello\"");
+    assert_eq!(forward(&mut engine, "'Hello \\n\\r'.trim()"), "\"Hello\"");
     assert_eq!(forward(&mut engine, "' Hello '.trim()"), "\"Hello\"");
 }
 
@@ -529,9 +529,9 @@ fn trim() {
 fn trim_start() {
     let mut engine = Context::new();
     assert_eq!(forward(&mut engine, "'Hello'.trimStart()"), "\"Hello\"");
-    assert_eq!(forward(&mut engine, "' \nHello'.trimStart()"), "\"Hello\"");
+    assert_eq!(forward(&mut engine, "' \\nHello'.trimStart()"), "\"Hello\"");
     assert_eq!(
-        forward(&mut engine, "'Hello \n'.trimStart()"),
+        forward(&mut engine, "'Hello \\n'.trimStart()"),
         "\"Hello \n\""
     );
     assert_eq!(forward(&mut engine, "' Hello '.trimStart()"), "\"Hello \"");
@@ -541,8 +541,11 @@ fn trim_start() {
 fn trim_end() {
     let mut engine = Context::new();
     assert_eq!(forward(&mut engine, "'Hello'.trimEnd()"), "\"Hello\"");
-    assert_eq!(forward(&mut engine, "' \nHello'.trimEnd()"), "\" \nHello\"");
-    assert_eq!(forward(&mut engine, "'Hello \n'.trimEnd()"), "\"Hello\"");
+    assert_eq!(
+        forward(&mut engine, "' \\nHello'.trimEnd()"),
+        "\" \nHello\""
+    );
+    assert_eq!(forward(&mut engine, "'Hello \\n'.trimEnd()"), "\"Hello\"");
     assert_eq!(forward(&mut engine, "' Hello '.trimEnd()"), "\" Hello\"");
 }
 
@@ -864,3 +867,21 @@ fn unicode_iter() {
     assert_eq!(forward(&mut engine, "next.value"), "undefined");
     assert_eq!(forward(&mut engine, "next.done"), "true");
 }
+
+#[test]
+fn raw() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "String.raw`a\\n${1}b${2}`"),
+        "\"a\\n1b2\""
+    );
+    assert_eq!(
+        forward(&mut engine, "String.raw({ raw: ['x', 'y', 'z'] }, 1, 2, 3)"),
+        "\"x1y2z\""
+    );
+    assert_eq!(
+        forward(&mut engine, "String.raw({ raw: 'abc' }, '-', '+')"),
+        "\"a-b+c\""
+    );
+    assert_eq!(forward(&mut engine, "String.raw({ raw: [] }, 1)"), "\"\"");
+}
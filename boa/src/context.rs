@@ -8,15 +8,19 @@ use crate::{
         symbol::{Symbol, WellKnownSymbols},
     },
     class::{Class, ClassBuilder},
+    environment::lexical_environment::{Environment, EnvironmentError, EnvironmentErrorKind},
     exec::Interpreter,
+    job::Job,
+    module::{IdleModuleLoader, ModuleLoader},
     object::{GcObject, Object, ObjectData, PROTOTYPE},
-    property::{DataDescriptor, PropertyKey},
+    property::{Attribute, DataDescriptor, PropertyKey},
     realm::Realm,
     syntax::{
         ast::{
             node::{
-                statement_list::RcStatementList, Call, FormalParameter, Identifier, New,
-                StatementList,
+                field::{resolve_private_name, super_reference_base},
+                statement_list::RcStatementList,
+                Call, FormalParameter, Identifier, New, StatementList,
             },
             Const, Node,
         },
@@ -25,11 +29,33 @@ use crate::{
     value::{RcString, RcSymbol, Value},
     BoaProfiler, Executable, Result,
 };
-use std::result::Result as StdResult;
+use std::{collections::VecDeque, fmt, rc::Rc, result::Result as StdResult};
 
 #[cfg(feature = "console")]
 use crate::builtins::console::Console;
 
+/// The host hook that is consulted before code is compiled from a string at runtime, like the
+/// code of `eval` and of the `Function` constructor.
+///
+/// It receives the source text of the code, and it can forbid the compilation by returning an
+/// error, which is usually an `EvalError`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-hostensurecancompilestrings
+pub type CompileStringsHook = Rc<dyn Fn(&str, &mut Context) -> Result<()>>;
+
+/// The compile strings hook of a context, which can't be formatted.
+#[derive(Clone)]
+struct CompileStrings(CompileStringsHook);
+
+impl fmt::Debug for CompileStrings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CompileStringsHook")
+    }
+}
+
 /// Store a builtin constructor (such as `Object`) and its corresponding prototype.
 #[derive(Debug, Clone)]
 pub struct StandardConstructor {
@@ -83,6 +109,11 @@ pub struct StandardObjects {
     syntax_error: StandardConstructor,
     eval_error: StandardConstructor,
     uri_error: StandardConstructor,
+    aggregate_error: StandardConstructor,
+    promise: StandardConstructor,
+    eval: GcObject,
+    throw_type_error: GcObject,
+    array_values: GcObject,
 }
 
 impl StandardObjects {
@@ -164,6 +195,36 @@ impl StandardObjects {
     pub fn uri_error_object(&self) -> &StandardConstructor {
         &self.uri_error
     }
+
+    #[inline]
+    pub fn aggregate_error_object(&self) -> &StandardConstructor {
+        &self.aggregate_error
+    }
+
+    #[inline]
+    pub fn promise_object(&self) -> &StandardConstructor {
+        &self.promise
+    }
+
+    /// Return the `eval` function, the only function whose calls can be direct evals.
+    #[inline]
+    pub fn eval_function(&self) -> &GcObject {
+        &self.eval
+    }
+
+    /// Return the `%ThrowTypeError%` function, the `callee` accessor of unmapped arguments
+    /// objects.
+    #[inline]
+    pub fn throw_type_error_function(&self) -> &GcObject {
+        &self.throw_type_error
+    }
+
+    /// Return the `Array.prototype.values` function, which is also the `@@iterator` method of
+    /// arguments objects.
+    #[inline]
+    pub fn array_values_function(&self) -> &GcObject {
+        &self.array_values
+    }
 }
 
 /// Javascript context. It is the primary way to interact with the runtime.
@@ -179,6 +240,9 @@ pub struct Context {
     /// The current executor.
     executor: Interpreter,
 
+    /// Whether the running code is strict mode code.
+    strict: bool,
+
     /// Symbol hash.
     ///
     /// For now this is an incremented u32 number.
@@ -196,6 +260,18 @@ pub struct Context {
 
     /// Cached standard objects and their prototypes
     standard_objects: StandardObjects,
+
+    /// Jobs waiting to run once the current code has completed.
+    job_queue: VecDeque<Job>,
+
+    /// The host hook that loads the modules imported by other modules.
+    module_loader: Rc<dyn ModuleLoader>,
+
+    /// The host hook that can forbid compiling code from strings.
+    compile_strings_hook: CompileStrings,
+
+    /// Whether the web compatibility features of Annex B of the specification are enabled.
+    annex_b: bool,
 }
 
 impl Default for Context {
@@ -206,12 +282,17 @@ impl Default for Context {
         let mut context = Self {
             realm,
             executor,
+            strict: false,
             symbol_count,
             #[cfg(feature = "console")]
             console: Console::default(),
             well_known_symbols,
             iterator_prototypes: IteratorPrototypes::default(),
             standard_objects: Default::default(),
+            job_queue: VecDeque::new(),
+            module_loader: Rc::new(IdleModuleLoader),
+            compile_strings_hook: CompileStrings(Rc::new(|_, _| Ok(()))),
+            annex_b: true,
         };
 
         // Add new builtIns to Context Realm
@@ -241,6 +322,18 @@ impl Context {
         &mut self.executor
     }
 
+    /// Checks if the running code is strict mode code.
+    #[inline]
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets whether the running code is strict mode code.
+    #[inline]
+    pub(crate) fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
     /// A helper function for getting a immutable reference to the `console` object.
     #[cfg(feature = "console")]
     pub(crate) fn console(&self) -> &Console {
@@ -281,8 +374,8 @@ impl Context {
     pub fn construct_object(&self) -> GcObject {
         let object_prototype = self
             .global_object()
-            .get_field("Object")
-            .get_field(PROTOTYPE);
+            .get_data_field("Object")
+            .get_data_field(PROTOTYPE);
         GcObject::new(Object::create(object_prototype))
     }
 
@@ -350,7 +443,7 @@ impl Context {
     {
         New::from(Call::new(
             Identifier::from("ReferenceError"),
-            vec![Const::from(message.into() + " is not defined").into()],
+            vec![Const::from(message.into()).into()],
         ))
         .run(self)
         .expect("Into<String> used as message")
@@ -427,6 +520,15 @@ impl Context {
         Err(self.construct_uri_error(message))
     }
 
+    /// Constructs the exception of a failed environment operation, like the use of a binding
+    /// before its initialization.
+    pub(crate) fn construct_environment_error(&mut self, error: EnvironmentError) -> Value {
+        match error.kind() {
+            EnvironmentErrorKind::Reference => self.construct_reference_error(error.to_string()),
+            EnvironmentErrorKind::Type => self.construct_type_error(error.to_string()),
+        }
+    }
+
     /// Utility to create a function Value for Function Declarations, Arrow Functions or Function Expressions
     pub(crate) fn create_function<P, B>(
         &mut self,
@@ -440,11 +542,16 @@ impl Context {
     {
         let function_prototype = self
             .global_object()
-            .get_field("Function")
-            .get_field(PROTOTYPE);
+            .get_data_field("Function")
+            .get_data_field(PROTOTYPE);
 
-        // Every new function has a prototype property pre-made
-        let proto = Value::new_object(Some(self.global_object()));
+        // Every new function has a prototype property pre-made, the one of a generator function
+        // is the prototype of the generator objects it creates.
+        let proto = if flags.is_generator() {
+            Object::create(self.iterator_prototypes().generator().into()).into()
+        } else {
+            Value::new_object(Some(self.global_object()))
+        };
 
         let params = params.into();
         let params_len = params.len();
@@ -453,6 +560,9 @@ impl Context {
             body: RcStatementList::from(body.into()),
             params,
             environment: self.realm.environment.get_current_environment().clone(),
+            home_object: None,
+            fields: Box::default(),
+            private_methods: Box::default(),
         };
 
         let new_func = Object::function(func, function_prototype);
@@ -460,10 +570,18 @@ impl Context {
         let val = Value::from(new_func);
 
         // Set constructor field to the newly created Value (function object)
-        proto.set_field("constructor", val.clone());
+        if !flags.is_generator() {
+            proto.set_property(
+                "constructor",
+                DataDescriptor::new(val.clone(), Attribute::all()),
+            );
+        }
 
-        val.set_field(PROTOTYPE, proto);
-        val.set_field("length", Value::from(params_len));
+        // Async functions aren't constructors and don't create objects, so they have no prototype.
+        if !flags.is_async() {
+            val.set_property(PROTOTYPE, DataDescriptor::new(proto, Attribute::all()));
+        }
+        val.set_property("length", DataDescriptor::new(params_len, Attribute::all()));
 
         val
     }
@@ -477,8 +595,8 @@ impl Context {
     ) -> Result<GcObject> {
         let function_prototype = self
             .global_object()
-            .get_field("Function")
-            .get_field(PROTOTYPE);
+            .get_data_field("Function")
+            .get_data_field(PROTOTYPE);
 
         // Every new function has a prototype property pre-made
         let proto = Value::new_object(Some(self.global_object()));
@@ -486,9 +604,9 @@ impl Context {
             Function::BuiltIn(body.into(), FunctionFlags::CALLABLE),
             function_prototype,
         );
-        function.set(PROTOTYPE.into(), proto);
-        function.set("length".into(), length.into());
-        function.set("name".into(), name.into());
+        function.insert_property(PROTOTYPE, proto, Attribute::all());
+        function.insert_property("length", length, Attribute::all());
+        function.insert_property("name", name, Attribute::all());
 
         Ok(GcObject::new(function))
     }
@@ -501,7 +619,8 @@ impl Context {
         body: NativeFunction,
     ) -> Result<()> {
         let function = self.create_builtin_function(name, length, body)?;
-        self.global_object().set_field(name, function);
+        self.global_object()
+            .set_property(name, DataDescriptor::new(function, Attribute::all()));
         Ok(())
     }
 
@@ -512,9 +631,9 @@ impl Context {
         if let Value::Object(ref x) = value {
             // Check if object is array
             if let ObjectData::Array = x.borrow().data {
-                let length = value.get_field("length").as_number().unwrap() as i32;
+                let length = value.get_data_field("length").as_number().unwrap() as i32;
                 let values = (0..length)
-                    .map(|idx| value.get_field(idx.to_string()))
+                    .map(|idx| value.get_data_field(idx.to_string()))
                     .collect();
                 return Ok(values);
             }
@@ -540,11 +659,11 @@ impl Context {
                                     .environment
                                     .get_binding_value("Array")
                                     .expect("Array was not initialized")
-                                    .get_field(PROTOTYPE),
+                                    .get_data_field(PROTOTYPE),
                             );
-                        array.set_field("0", key);
-                        array.set_field("1", value);
-                        array.set_field("length", Value::from(2));
+                        array.set_property("0", DataDescriptor::new(key, Attribute::all()));
+                        array.set_property("1", DataDescriptor::new(value, Attribute::all()));
+                        array.set_property("length", DataDescriptor::new(2, Attribute::all()));
                         array
                     })
                     .collect();
@@ -566,27 +685,162 @@ impl Context {
         }
     }
 
+    /// Finds the environment that has the binding `name`, the first one in the scope chain.
+    ///
+    /// The properties of the object of a `with` statement that its `Symbol.unscopables` property
+    /// lists are not bindings, getting them can run getters.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object-environment-records-hasbinding-n
+    pub(crate) fn resolve_binding(&mut self, name: &str) -> Result<Option<Environment>> {
+        let environments: Vec<_> = self.realm.environment.environments().collect();
+        for env in environments {
+            if !env.borrow().has_binding(name) {
+                continue;
+            }
+            let object = env.borrow().binding_object(name);
+            let unscopables = env.borrow().unscopables();
+            if let (Some(object), Some(unscopables)) = (object, unscopables) {
+                let unscopables = object.get_field(unscopables, self)?;
+                if unscopables.is_object() && unscopables.get_field(name, self)?.to_boolean() {
+                    continue;
+                }
+            }
+            return Ok(Some(env));
+        }
+        Ok(None)
+    }
+
+    /// Gets the value of the binding `name`, throwing a `ReferenceError` if there is none.
+    ///
+    /// The bindings of the object of a `with` statement and of the global object are their
+    /// properties, getting them can run getters.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getvalue
+    pub(crate) fn get_binding_value(&mut self, name: &str) -> Result<Value> {
+        let env = match self.resolve_binding(name)? {
+            Some(env) => env,
+            None => return self.throw_reference_error(format!("{} is not defined", name)),
+        };
+        let object = env.borrow().binding_object(name);
+        if let Some(Value::Object(ref object)) = object {
+            return object.get(&name.into(), object.clone().into(), self);
+        }
+        let value = env.borrow().get_binding_value(name, false);
+        value.map_err(|e| self.construct_environment_error(e))
+    }
+
+    /// Sets the value of the binding `name`, which has to exist.
+    ///
+    /// The bindings of the object of a `with` statement and of the global object are their
+    /// properties, setting them can run setters.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-putvalue
+    pub(crate) fn set_mutable_binding(&mut self, name: &str, value: Value) -> Result<()> {
+        match self.resolve_binding(name)? {
+            Some(env) => self.set_resolved_binding(&env, name, value),
+            None => Err(self.construct_environment_error(EnvironmentError::not_defined(name))),
+        }
+    }
+
+    /// Sets the value of the binding `name` of the environment `env`, that `resolve_binding()`
+    /// found.
+    pub(crate) fn set_resolved_binding(
+        &mut self,
+        env: &Environment,
+        name: &str,
+        value: Value,
+    ) -> Result<()> {
+        let object = env.borrow().binding_object(name);
+        if let Some(Value::Object(ref object)) = object {
+            let key = PropertyKey::from(name);
+            let succeeded = object.set(key.clone(), value, object.clone().into(), self)?;
+            return self.check_assignment(succeeded, &key);
+        }
+        let strict = self.strict();
+        let result = env.borrow_mut().set_mutable_binding(name, value, strict);
+        result.map_err(|e| self.construct_environment_error(e))
+    }
+
     pub(crate) fn set_value(&mut self, node: &Node, value: Value) -> Result<Value> {
         match node {
             Node::Identifier(ref name) => {
-                self.realm
-                    .environment
-                    .set_mutable_binding(name.as_ref(), value.clone(), true);
+                self.set_mutable_binding(name.as_ref(), value.clone())?;
+                Ok(value)
+            }
+            Node::GetConstField(ref get_const_field_node) => {
+                let obj = get_const_field_node.obj().run(self)?;
+                let key: PropertyKey = get_const_field_node.field().into();
+                let succeeded = obj
+                    .to_object(self)?
+                    .set(key.clone(), value.clone(), obj, self)?;
+                self.check_assignment(succeeded, &key)?;
                 Ok(value)
             }
-            Node::GetConstField(ref get_const_field_node) => Ok(get_const_field_node
-                .obj()
-                .run(self)?
-                .set_field(get_const_field_node.field(), value)),
             Node::GetField(ref get_field) => {
                 let field = get_field.field().run(self)?;
                 let key = field.to_property_key(self)?;
-                Ok(get_field.obj().run(self)?.set_field(key, value))
+                let obj = get_field.obj().run(self)?;
+                let succeeded = obj
+                    .to_object(self)?
+                    .set(key.clone(), value.clone(), obj, self)?;
+                self.check_assignment(succeeded, &key)?;
+                Ok(value)
+            }
+            Node::GetPrivateField(ref get_private_field) => {
+                let obj = get_private_field.obj().run(self)?;
+                let name = resolve_private_name(get_private_field.field(), self)?;
+                obj.to_object(self)?
+                    .private_set(&name, value.clone(), self)?;
+                Ok(value)
+            }
+            Node::GetSuperConstField(ref get_super_field) => {
+                let (base, this) = super_reference_base(self)?;
+                let key: PropertyKey = get_super_field.field().into();
+                let succeeded = base.set(key.clone(), value.clone(), this, self)?;
+                self.check_assignment(succeeded, &key)?;
+                Ok(value)
+            }
+            Node::GetSuperField(ref get_super_field) => {
+                let field = get_super_field.field().run(self)?;
+                let key = field.to_property_key(self)?;
+                let (base, this) = super_reference_base(self)?;
+                let succeeded = base.set(key.clone(), value.clone(), this, self)?;
+                self.check_assignment(succeeded, &key)?;
+                Ok(value)
             }
             _ => panic!("TypeError: invalid assignment to {}", node),
         }
     }
 
+    /// Checks the result of the `[[Set]]` of an assignment to the property `key`, an assignment
+    /// that was rejected throws a `TypeError` in strict mode code.
+    pub(crate) fn check_assignment(&mut self, succeeded: bool, key: &PropertyKey) -> Result<()> {
+        if !succeeded && self.strict {
+            return Err(
+                self.construct_type_error(format!("cannot assign to read only property '{}'", key))
+            );
+        }
+        Ok(())
+    }
+
+    /// Checks the result of the `[[Delete]]` of a `delete` of the property `key`, a property that
+    /// can't be deleted throws a `TypeError` in strict mode code.
+    pub(crate) fn check_deletion(&mut self, succeeded: bool, key: &PropertyKey) -> Result<()> {
+        if !succeeded && self.strict {
+            return Err(self.construct_type_error(format!("cannot delete property '{}'", key)));
+        }
+        Ok(())
+    }
+
     /// Register a global class of type `T`, where `T` implements `Class`.
     ///
     /// # Example
@@ -616,10 +870,10 @@ impl Context {
         Ok(())
     }
 
-    fn parser_expr(src: &str) -> StdResult<StatementList, String> {
-        Parser::new(src.as_bytes())
-            .parse_all()
-            .map_err(|e| e.to_string())
+    fn parser_expr(&self, src: &str) -> StdResult<StatementList, String> {
+        let mut parser = Parser::new(src.as_bytes());
+        parser.set_annex_b(self.annex_b);
+        parser.parse_all().map_err(|e| e.to_string())
     }
 
     /// Evaluates the given code.
@@ -638,7 +892,7 @@ impl Context {
     pub fn eval(&mut self, src: &str) -> Result<Value> {
         let main_timer = BoaProfiler::global().start_event("Main", "Main");
 
-        let result = match Self::parser_expr(src) {
+        let result = match self.parser_expr(src) {
             Ok(expr) => expr.run(self),
             Err(e) => self.throw_syntax_error(e),
         };
@@ -650,6 +904,133 @@ impl Context {
         result
     }
 
+    /// Adds a job at the end of the job queue.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostenqueuepromisejob
+    #[inline]
+    pub fn enqueue_job(&mut self, job: Job) {
+        self.job_queue.push_back(job);
+    }
+
+    /// Returns `true` if there are jobs waiting in the job queue.
+    #[inline]
+    pub fn has_pending_jobs(&self) -> bool {
+        !self.job_queue.is_empty()
+    }
+
+    /// Runs the jobs in the job queue until it is empty, including the jobs queued while running.
+    ///
+    /// If a job throws, the error is returned and the remaining jobs are kept in the queue.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// context.eval("var x = 0; Promise.resolve(1).then(v => { x = v; });").unwrap();
+    /// assert_eq!(context.eval("x").unwrap().as_number(), Some(0.0));
+    ///
+    /// context.run_jobs().unwrap();
+    /// assert_eq!(context.eval("x").unwrap().as_number(), Some(1.0));
+    /// ```
+    pub fn run_jobs(&mut self) -> Result<()> {
+        let _timer = BoaProfiler::global().start_event("run_jobs", "jobs");
+        while let Some(job) = self.job_queue.pop_front() {
+            job.run(self)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the module loader, which loads the modules imported by other modules.
+    ///
+    /// A new context can't load any module.
+    #[inline]
+    pub fn set_module_loader(&mut self, loader: Rc<dyn ModuleLoader>) {
+        self.module_loader = loader;
+    }
+
+    /// Gets the module loader of the context.
+    #[inline]
+    pub fn module_loader(&self) -> Rc<dyn ModuleLoader> {
+        self.module_loader.clone()
+    }
+
+    /// Sets the hook that is consulted before code is compiled from a string at runtime.
+    ///
+    /// A new context allows compiling any string. The hook can capture the state it needs, like
+    /// the policy of the embedder.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    /// context.set_compile_strings_hook(|_, context| {
+    ///     Err(context.construct_eval_error("code generation from strings is disallowed"))
+    /// });
+    ///
+    /// assert!(context.eval("eval('1 + 1')").is_err());
+    /// assert!(context.eval("new Function('return 1')").is_err());
+    /// ```
+    #[inline]
+    pub fn set_compile_strings_hook<F>(&mut self, hook: F)
+    where
+        F: Fn(&str, &mut Context) -> Result<()> + 'static,
+    {
+        self.compile_strings_hook = CompileStrings(Rc::new(hook));
+    }
+
+    /// Checks if the web compatibility features of Annex B of the specification are enabled.
+    #[inline]
+    pub fn annex_b(&self) -> bool {
+        self.annex_b
+    }
+
+    /// Enables or disables the web compatibility features of Annex B of the specification.
+    ///
+    /// A new context enables them. They are the HTML-like comments, the legacy octal literals and
+    /// escape sequences, the hoisting of function declarations in blocks to the function scope,
+    /// the global `escape` and `unescape` functions, the `substr` and HTML methods of
+    /// `String.prototype`, and the `__proto__` accessor and the legacy accessor methods of
+    /// `Object.prototype`. Disabling them removes those built-ins from the context.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    /// assert!(context.eval("<!-- a comment\n escape('a b')").is_ok());
+    ///
+    /// context.set_annex_b(false);
+    /// assert!(context.eval("<!-- a comment").is_err());
+    /// assert!(context.eval("escape('a b')").is_err());
+    /// ```
+    pub fn set_annex_b(&mut self, annex_b: bool) {
+        if annex_b == self.annex_b {
+            return;
+        }
+        self.annex_b = annex_b;
+        if annex_b {
+            builtins::annex_b::init(self);
+        } else {
+            builtins::annex_b::remove(self);
+        }
+    }
+
+    /// Checks with the host that `source` can be compiled, before code is compiled from a string
+    /// at runtime.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostensurecancompilestrings
+    #[inline]
+    pub(crate) fn ensure_can_compile_strings(&mut self, source: &str) -> Result<()> {
+        let hook = self.compile_strings_hook.0.clone();
+        hook(source, self)
+    }
+
     /// Returns a structure that contains the JavaScript well known symbols.
     ///
     /// # Examples
@@ -8,7 +8,7 @@
 use crate::{
     environment::{
         environment_record_trait::EnvironmentRecordTrait,
-        lexical_environment::{Environment, EnvironmentType},
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
     },
     Value,
 };
@@ -88,46 +88,44 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
         }
     }
 
-    #[allow(clippy::else_if_without_else)]
-    fn set_mutable_binding(&mut self, name: &str, value: Value, mut strict: bool) {
-        if self.env_rec.get(name).is_none() {
-            if strict {
-                // TODO: change this when error handling comes into play
-                panic!("Reference Error: Cannot set mutable binding for {}", name);
+    fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        strict: bool,
+    ) -> Result<(), EnvironmentError> {
+        let record = match self.env_rec.get_mut(name) {
+            Some(record) => record,
+            None if strict => return Err(EnvironmentError::not_defined(name)),
+            None => {
+                self.create_mutable_binding(name.to_owned(), true);
+                self.initialize_binding(name, value);
+                return Ok(());
             }
+        };
 
-            self.create_mutable_binding(name.to_owned(), true);
-            self.initialize_binding(name, value);
-            return;
-        }
-
-        let record: &mut DeclarativeEnvironmentRecordBinding = self.env_rec.get_mut(name).unwrap();
-        if record.strict {
-            strict = true
-        }
         if record.value.is_none() {
-            // TODO: change this when error handling comes into play
-            panic!("Reference Error: Cannot set mutable binding for {}", name);
+            return Err(EnvironmentError::uninitialized(name));
         }
 
         if record.mutable {
             record.value = Some(value);
-        } else if strict {
-            // TODO: change this when error handling comes into play
-            panic!("TypeError: Cannot mutate an immutable binding {}", name);
+        } else if strict || record.strict {
+            return Err(EnvironmentError::type_error(&format!(
+                "Assignment to constant variable '{}'",
+                name
+            )));
         }
+        Ok(())
     }
 
-    fn get_binding_value(&self, name: &str, _strict: bool) -> Value {
-        if let Some(binding) = self.env_rec.get(name) {
-            binding
+    fn get_binding_value(&self, name: &str, _strict: bool) -> Result<Value, EnvironmentError> {
+        match self.env_rec.get(name) {
+            Some(binding) => binding
                 .value
-                .as_ref()
-                .expect("Could not get record as reference")
                 .clone()
-        } else {
-            // TODO: change this when error handling comes into play
-            panic!("ReferenceError: Cannot get binding value for {}", name);
+                .ok_or_else(|| EnvironmentError::uninitialized(name)),
+            None => Err(EnvironmentError::not_defined(name)),
         }
     }
 
@@ -149,8 +147,8 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
         false
     }
 
-    fn get_this_binding(&self) -> Value {
-        Value::undefined()
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
+        Ok(Value::undefined())
     }
 
     fn has_super_binding(&self) -> bool {
@@ -9,7 +9,14 @@
 //! There are 5 Environment record kinds. They all have methods in common, these are implemented as a the `EnvironmentRecordTrait`
 //!
 use crate::{
-    environment::lexical_environment::{Environment, EnvironmentType},
+    environment::{
+        function_environment_record::FunctionEnvironmentRecord,
+        global_environment_record::GlobalEnvironmentRecord,
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
+        module_environment_record::ModuleEnvironmentRecord,
+        private_environment_record::PrivateEnvironmentRecord,
+    },
+    value::RcSymbol,
     Value,
 };
 use gc::{Finalize, Trace};
@@ -42,13 +49,22 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// The String value `name` is the text of the bound name.
     /// value is the `value` for the binding and may be a value of any ECMAScript language type. S is a Boolean flag.
     /// If `strict` is true and the binding cannot be set throw a TypeError exception.
-    fn set_mutable_binding(&mut self, name: &str, value: Value, strict: bool);
+    ///
+    /// Fails if the binding is not initialized yet, or if it is immutable and `strict` is true.
+    fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        strict: bool,
+    ) -> Result<(), EnvironmentError>;
 
     /// Returns the value of an already existing binding from an Environment Record.
     /// The String value N is the text of the bound name.
     /// S is used to identify references originating in strict mode code or that
     /// otherwise require strict mode reference semantics.
-    fn get_binding_value(&self, name: &str, strict: bool) -> Value;
+    ///
+    /// Fails if the binding is not initialized yet, like a `let` declaration before it runs.
+    fn get_binding_value(&self, name: &str, strict: bool) -> Result<Value, EnvironmentError>;
 
     /// Delete a binding from an Environment Record.
     /// The String value name is the text of the bound name.
@@ -61,7 +77,10 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     fn has_this_binding(&self) -> bool;
 
     /// Return the `this` binding from the environment
-    fn get_this_binding(&self) -> Value;
+    ///
+    /// Fails if the binding has not been initialized yet, like in a derived class constructor
+    /// before `super()` has been called.
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError>;
 
     /// Determine if an Environment Record establishes a super method binding.
     /// Return true if it does and false if it does not.
@@ -71,6 +90,22 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// Otherwise, return undefined.
     fn with_base_object(&self) -> Value;
 
+    /// Returns the binding object of the record that holds the binding `name` as a property, if
+    /// it's an object Environment Record, or the object record part of the global one.
+    ///
+    /// Its bindings are got and set with the `[[Get]]` and `[[Set]]` internal methods of the
+    /// binding object, which can run accessors, so that is done with a `Context`.
+    fn binding_object(&self, _name: &str) -> Option<Value> {
+        None
+    }
+
+    /// Returns the `Symbol.unscopables` symbol if this is the object Environment Record of a
+    /// `with` statement, whose bindings exclude the properties that the `Symbol.unscopables`
+    /// property of its binding object lists.
+    fn unscopables(&self) -> Option<RcSymbol> {
+        None
+    }
+
     /// Get the next environment up
     fn get_outer_environment(&self) -> Option<Environment>;
 
@@ -82,4 +117,41 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
 
     /// Fetch global variable
     fn get_global_object(&self) -> Option<Value>;
+
+    /// Return this record as a function Environment Record, if it is one.
+    ///
+    /// Function Environment Records have extra state (`[[FunctionObject]]`, `[[HomeObject]]`,
+    /// `[[NewTarget]]`) that `super` and `new.target` need access to.
+    fn as_function_environment_record(&self) -> Option<&FunctionEnvironmentRecord> {
+        None
+    }
+
+    /// Mutable version of [`as_function_environment_record`](#method.as_function_environment_record).
+    fn as_function_environment_record_mut(&mut self) -> Option<&mut FunctionEnvironmentRecord> {
+        None
+    }
+
+    /// Return this record as a module Environment Record, if it is one.
+    ///
+    /// The module Environment Record gives access to the module whose code is running, which
+    /// `import.meta` needs.
+    fn as_module_environment_record(&self) -> Option<&ModuleEnvironmentRecord> {
+        None
+    }
+
+    /// Return this record as a global Environment Record, if it is one.
+    ///
+    /// The global Environment Record keeps apart the lexical declarations of scripts and the
+    /// properties of the global object, which can be shadowed by them.
+    fn as_global_environment_record(&self) -> Option<&GlobalEnvironmentRecord> {
+        None
+    }
+
+    /// Return this record as a private Environment Record, if it is one.
+    ///
+    /// Private Environment Records hold the Private Names of the private members of a class, which
+    /// the references to them like `this.#x` are resolved to.
+    fn as_private_environment_record(&self) -> Option<&PrivateEnvironmentRecord> {
+        None
+    }
 }
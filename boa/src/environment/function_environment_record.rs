@@ -12,7 +12,7 @@ use crate::{
     environment::{
         declarative_environment_record::DeclarativeEnvironmentRecordBinding,
         environment_record_trait::EnvironmentRecordTrait,
-        lexical_environment::{Environment, EnvironmentType},
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
     },
     object::GcObject,
     Value,
@@ -60,7 +60,13 @@ pub struct FunctionEnvironmentRecord {
 }
 
 impl FunctionEnvironmentRecord {
-    pub fn bind_this_value(&mut self, value: Value) {
+    /// Binds the `this` value of the environment.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-bindthisvalue
+    pub fn bind_this_value(&mut self, value: Value) -> Result<Value, EnvironmentError> {
         match self.this_binding_status {
             // You can not bind an arrow function, their `this` value comes from the lexical scope above
             BindingStatus::Lexical => {
@@ -68,22 +74,33 @@ impl FunctionEnvironmentRecord {
                 panic!("Cannot bind to an arrow function!");
             }
             // You can not bind a function twice
-            BindingStatus::Initialized => {
-                // TODO: change this when error handling comes into play
-                panic!("Reference Error: Cannot bind to an initialised function!");
-            }
-
+            BindingStatus::Initialized => Err(EnvironmentError::new(
+                "Super constructor may only be called once",
+            )),
             BindingStatus::Uninitialized => {
-                self.this_value = value;
+                self.this_value = value.clone();
                 self.this_binding_status = BindingStatus::Initialized;
+                Ok(value)
             }
         }
     }
+
+    /// Returns the object on which `super` property lookups are done, this is the prototype of
+    /// the `[[HomeObject]]` of the function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getsuperbase
+    pub fn get_super_base(&self) -> Value {
+        match self.home_object {
+            Value::Object(ref home) => home.borrow().get_prototype_of(),
+            _ => Value::undefined(),
+        }
+    }
 }
 
 impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
-    // TODO: get_super_base can't implement until GetPrototypeof is implemented on object
-
     fn has_binding(&self, name: &str) -> bool {
         self.env_rec.contains_key(name)
     }
@@ -105,18 +122,16 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         );
     }
 
-    fn get_this_binding(&self) -> Value {
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
         match self.this_binding_status {
             BindingStatus::Lexical => {
                 // TODO: change this when error handling comes into play
                 panic!("There is no this for a lexical function record");
             }
-            BindingStatus::Uninitialized => {
-                // TODO: change this when error handling comes into play
-                panic!("Reference Error: Uninitialised binding for this function");
-            }
-
-            BindingStatus::Initialized => self.this_value.clone(),
+            BindingStatus::Uninitialized => Err(EnvironmentError::new(
+                "Must call super constructor in derived class before accessing 'this' or returning from derived constructor",
+            )),
+            BindingStatus::Initialized => Ok(self.this_value.clone()),
         }
     }
 
@@ -140,58 +155,51 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
     }
 
     fn initialize_binding(&mut self, name: &str, value: Value) {
+        // A non-strict function can have several parameters with the same name, the last one
+        // wins. A parameter named `arguments` also replaces the arguments object.
         if let Some(ref mut record) = self.env_rec.get_mut(name) {
-            match record.value {
-                Some(_) => {
-                    // TODO: change this when error handling comes into play
-                    panic!("Identifier {} has already been defined", name);
-                }
-                None => record.value = Some(value),
-            }
+            record.value = Some(value);
         }
     }
 
-    #[allow(clippy::else_if_without_else)]
-    fn set_mutable_binding(&mut self, name: &str, value: Value, mut strict: bool) {
-        if self.env_rec.get(name).is_none() {
-            if strict {
-                // TODO: change this when error handling comes into play
-                panic!("Reference Error: Cannot set mutable binding for {}", name);
+    fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        strict: bool,
+    ) -> Result<(), EnvironmentError> {
+        let record = match self.env_rec.get_mut(name) {
+            Some(record) => record,
+            None if strict => return Err(EnvironmentError::not_defined(name)),
+            None => {
+                self.create_mutable_binding(name.to_owned(), true);
+                self.initialize_binding(name, value);
+                return Ok(());
             }
-
-            self.create_mutable_binding(name.to_owned(), true);
-            self.initialize_binding(name, value);
-            return;
-        }
-
-        let record: &mut DeclarativeEnvironmentRecordBinding = self.env_rec.get_mut(name).unwrap();
-        if record.strict {
-            strict = true
-        }
+        };
 
         if record.value.is_none() {
-            // TODO: change this when error handling comes into play
-            panic!("Reference Error: Cannot set mutable binding for {}", name);
+            return Err(EnvironmentError::uninitialized(name));
         }
 
         if record.mutable {
             record.value = Some(value);
-        } else if strict {
-            // TODO: change this when error handling comes into play
-            panic!("TypeError: Cannot mutate an immutable binding {}", name);
+        } else if strict || record.strict {
+            return Err(EnvironmentError::type_error(&format!(
+                "Assignment to constant variable '{}'",
+                name
+            )));
         }
+        Ok(())
     }
 
-    fn get_binding_value(&self, name: &str, _strict: bool) -> Value {
-        if let Some(binding) = self.env_rec.get(name) {
-            binding
+    fn get_binding_value(&self, name: &str, _strict: bool) -> Result<Value, EnvironmentError> {
+        match self.env_rec.get(name) {
+            Some(binding) => binding
                 .value
-                .as_ref()
-                .expect("Could not get record as reference")
                 .clone()
-        } else {
-            // TODO: change this when error handling comes into play
-            panic!("ReferenceError: Cannot get binding value for {}", name);
+                .ok_or_else(|| EnvironmentError::uninitialized(name)),
+            None => Err(EnvironmentError::not_defined(name)),
         }
     }
 
@@ -246,4 +254,12 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
             None => None,
         }
     }
+
+    fn as_function_environment_record(&self) -> Option<&FunctionEnvironmentRecord> {
+        Some(self)
+    }
+
+    fn as_function_environment_record_mut(&mut self) -> Option<&mut FunctionEnvironmentRecord> {
+        Some(self)
+    }
 }
@@ -11,7 +11,7 @@ use crate::{
     environment::{
         declarative_environment_record::DeclarativeEnvironmentRecord,
         environment_record_trait::EnvironmentRecordTrait,
-        lexical_environment::{Environment, EnvironmentType},
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
         object_environment_record::ObjectEnvironmentRecord,
     },
     property::{Attribute, DataDescriptor},
@@ -90,8 +90,8 @@ impl GlobalEnvironmentRecord {
 }
 
 impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
-    fn get_this_binding(&self) -> Value {
-        self.global_this_binding.clone()
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
+        Ok(self.global_this_binding.clone())
     }
 
     fn has_binding(&self, name: &str) -> bool {
@@ -130,7 +130,12 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         panic!("Should not initialized binding without creating first.");
     }
 
-    fn set_mutable_binding(&mut self, name: &str, value: Value, strict: bool) {
+    fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        strict: bool,
+    ) -> Result<(), EnvironmentError> {
         if self.declarative_record.has_binding(&name) {
             return self
                 .declarative_record
@@ -139,7 +144,7 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         self.object_record.set_mutable_binding(name, value, strict)
     }
 
-    fn get_binding_value(&self, name: &str, strict: bool) -> Value {
+    fn get_binding_value(&self, name: &str, strict: bool) -> Result<Value, EnvironmentError> {
         if self.declarative_record.has_binding(&name) {
             return self.declarative_record.get_binding_value(name, strict);
         }
@@ -177,6 +182,13 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         Value::undefined()
     }
 
+    fn binding_object(&self, name: &str) -> Option<Value> {
+        if self.declarative_record.has_binding(name) {
+            return None;
+        }
+        self.object_record.binding_object(name)
+    }
+
     fn get_outer_environment(&self) -> Option<Environment> {
         None
     }
@@ -193,4 +205,8 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
     fn get_global_object(&self) -> Option<Value> {
         Some(self.global_this_binding.clone())
     }
+
+    fn as_global_environment_record(&self) -> Option<&GlobalEnvironmentRecord> {
+        Some(self)
+    }
 }
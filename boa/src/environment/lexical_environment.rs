@@ -12,8 +12,10 @@ use crate::{
         function_environment_record::{BindingStatus, FunctionEnvironmentRecord},
         global_environment_record::GlobalEnvironmentRecord,
         object_environment_record::ObjectEnvironmentRecord,
+        private_environment_record::{PrivateEnvironmentRecord, PrivateName},
     },
     object::GcObject,
+    value::RcSymbol,
     BoaProfiler, Value,
 };
 use gc::{Gc, GcCell};
@@ -30,6 +32,7 @@ pub enum EnvironmentType {
     Declarative,
     Function,
     Global,
+    Module,
     Object,
 }
 
@@ -47,18 +50,53 @@ pub struct LexicalEnvironment {
     environment_stack: VecDeque<Environment>,
 }
 
+/// The kind of exception an `EnvironmentError` is thrown as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EnvironmentErrorKind {
+    /// A `ReferenceError`, like for the use of a binding before its initialization.
+    Reference,
+    /// A `TypeError`, like for the assignment of a constant.
+    Type,
+}
+
 /// An error that occurred during lexing or compiling of the source input.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct EnvironmentError {
+    kind: EnvironmentErrorKind,
     details: String,
 }
 
 impl EnvironmentError {
+    /// Creates an error that is thrown as a `ReferenceError`.
     pub fn new(msg: &str) -> Self {
         Self {
+            kind: EnvironmentErrorKind::Reference,
+            details: msg.to_string(),
+        }
+    }
+
+    /// Creates an error that is thrown as a `TypeError`.
+    pub fn type_error(msg: &str) -> Self {
+        Self {
+            kind: EnvironmentErrorKind::Type,
             details: msg.to_string(),
         }
     }
+
+    /// Gets the kind of exception the error is thrown as.
+    pub fn kind(&self) -> EnvironmentErrorKind {
+        self.kind
+    }
+
+    /// The error of the use of the binding `name` before its initialization.
+    pub(crate) fn uninitialized(name: &str) -> Self {
+        Self::new(&format!("Cannot access '{}' before initialization", name))
+    }
+
+    /// The error of the use of the binding `name`, which doesn't exist.
+    pub(crate) fn not_defined(name: &str) -> Self {
+        Self::new(&format!("{} is not defined", name))
+    }
 }
 
 impl fmt::Display for EnvironmentError {
@@ -91,9 +129,15 @@ impl LexicalEnvironment {
         lexical_env
     }
 
+    /// Pushes a new environment on top of the stack.
+    ///
+    /// The outer environment of `env` is kept if it was given on creation, this is what allows
+    /// functions to close over the environment they were defined in.
     pub fn push(&mut self, env: Environment) {
-        let current_env: Environment = self.get_current_environment().clone();
-        env.borrow_mut().set_outer_environment(current_env);
+        if env.borrow().get_outer_environment().is_none() {
+            let current_env: Environment = self.get_current_environment().clone();
+            env.borrow_mut().set_outer_environment(current_env);
+        }
         self.environment_stack.push_back(env);
     }
 
@@ -101,8 +145,67 @@ impl LexicalEnvironment {
         self.environment_stack.pop_back()
     }
 
-    pub fn environments(&self) -> impl Iterator<Item = &Environment> {
-        self.environment_stack.iter().rev()
+    /// Pops `env` and every environment that was pushed after it.
+    ///
+    /// Abrupt completions (like a thrown error) can leave block environments on the stack, this
+    /// makes sure they are dropped along with the function environment that contains them.
+    pub fn pop_to(&mut self, env: &Environment) {
+        if let Some(index) = self
+            .environment_stack
+            .iter()
+            .rposition(|e| Gc::ptr_eq(e, env))
+        {
+            self.environment_stack.truncate(index);
+        }
+    }
+
+    /// Removes `env` and every environment that was pushed after it, returning them in the
+    /// order they were pushed.
+    ///
+    /// This is how a suspended generator keeps the environments of its body until it is resumed.
+    pub fn split_off(&mut self, env: &Environment) -> Vec<Environment> {
+        match self
+            .environment_stack
+            .iter()
+            .rposition(|e| Gc::ptr_eq(e, env))
+        {
+            Some(index) => self
+                .environment_stack
+                .split_off(index)
+                .into_iter()
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Replaces the stack with one that only has the global environment, for code that runs in
+    /// the global scope whatever the code that runs it, like the code of an indirect `eval`.
+    ///
+    /// The environments that were on the stack are returned, to be put back by `restore()`.
+    pub fn enter_global_scope(&mut self) -> VecDeque<Environment> {
+        let global = self.get_global_environment();
+        std::mem::replace(&mut self.environment_stack, VecDeque::from(vec![global]))
+    }
+
+    /// Puts back the environments that `enter_global_scope()` took off the stack.
+    pub fn restore(&mut self, environments: VecDeque<Environment>) {
+        self.environment_stack = environments;
+    }
+
+    /// Iterates over the scope chain, starting from the current environment and following the
+    /// outer environment of each record.
+    pub fn environments(&self) -> impl Iterator<Item = Environment> {
+        std::iter::successors(Some(self.get_current_environment_ref().clone()), |env| {
+            env.borrow().get_outer_environment()
+        })
+    }
+
+    /// Gets the global environment, which is at the end of every scope chain.
+    pub fn get_global_environment(&self) -> Environment {
+        self.environment_stack
+            .front()
+            .expect("the environment stack always contains the global environment")
+            .clone()
     }
 
     pub fn get_global_object(&self) -> Option<Value> {
@@ -113,11 +216,20 @@ impl LexicalEnvironment {
             .get_global_object()
     }
 
-    pub fn get_this_binding(&self) -> Value {
+    /// Finds the environment that currently supplies the binding of the keyword `this`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getthisenvironment
+    pub fn get_this_environment(&self) -> Environment {
         self.environments()
             .find(|env| env.borrow().has_this_binding())
-            .map(|env| env.borrow().get_this_binding())
-            .unwrap_or_else(Value::undefined)
+            .expect("the global environment always has a this binding")
+    }
+
+    pub fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
+        self.get_this_environment().borrow().get_this_binding()
     }
 
     pub fn create_mutable_binding(&mut self, name: String, deletion: bool, scope: VariableScope) {
@@ -133,7 +245,9 @@ impl LexicalEnvironment {
                     .find(|env| {
                         matches!(
                             env.borrow().get_environment_type(),
-                            EnvironmentType::Function | EnvironmentType::Global
+                            EnvironmentType::Function
+                                | EnvironmentType::Global
+                                | EnvironmentType::Module
                         )
                     })
                     .expect("No function or global environment");
@@ -161,24 +275,32 @@ impl LexicalEnvironment {
                     .find(|env| {
                         matches!(
                             env.borrow().get_environment_type(),
-                            EnvironmentType::Function | EnvironmentType::Global
+                            EnvironmentType::Function
+                                | EnvironmentType::Global
+                                | EnvironmentType::Module
                         )
                     })
                     .expect("No function or global environment");
 
-                env.borrow_mut().create_immutable_binding(name, deletion)
+                let created = env.borrow_mut().create_immutable_binding(name, deletion);
+                created
             }
         }
     }
 
-    pub fn set_mutable_binding(&mut self, name: &str, value: Value, strict: bool) {
+    pub fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        strict: bool,
+    ) -> Result<(), EnvironmentError> {
         // Find the first environment which has the given binding
         let env = self
-            .environments()
-            .find(|env| env.borrow().has_binding(name))
-            .expect("Binding does not exists"); // TODO graceful error handling
+            .resolve_binding(name)
+            .ok_or_else(|| EnvironmentError::not_defined(name))?;
 
-        env.borrow_mut().set_mutable_binding(name, value, strict);
+        let result = env.borrow_mut().set_mutable_binding(name, value, strict);
+        result
     }
 
     pub fn initialize_binding(&mut self, name: &str, value: Value) {
@@ -212,10 +334,51 @@ impl LexicalEnvironment {
             .any(|env| env.borrow().has_binding(name))
     }
 
-    pub fn get_binding_value(&self, name: &str) -> Option<Value> {
+    /// Finds the environment that has the binding `name`, the first one in the scope chain.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getidentifierreference
+    pub fn resolve_binding(&self, name: &str) -> Option<Environment> {
         self.environments()
             .find(|env| env.borrow().has_binding(name))
-            .map(|env| env.borrow().get_binding_value(name, false))
+    }
+
+    /// Finds the Private Name of the private member `name`, declared by the innermost class that
+    /// declares it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-resolve-private-identifier
+    pub fn resolve_private_identifier(&self, name: &str) -> Option<PrivateName> {
+        self.environments().find_map(|env| {
+            env.borrow()
+                .as_private_environment_record()
+                .and_then(|record| record.get_private_name(name).cloned())
+        })
+    }
+
+    /// Gets the names of all the private members that are visible from the current environment,
+    /// which code parsed by a direct `eval` can refer to.
+    pub fn private_names(&self) -> Vec<Box<str>> {
+        let mut names = Vec::new();
+        for env in self.environments() {
+            if let Some(record) = env.borrow().as_private_environment_record() {
+                names.extend(record.names.keys().map(|name| name.as_str().into()));
+            }
+        }
+        names
+    }
+
+    pub fn get_binding_value(&self, name: &str) -> Result<Value, EnvironmentError> {
+        let env = self
+            .resolve_binding(name)
+            .ok_or_else(|| EnvironmentError::not_defined(name))?;
+
+        let value = env.borrow().get_binding_value(name, false);
+        value
     }
 }
 
@@ -229,24 +392,48 @@ pub fn new_declarative_environment(env: Option<Environment>) -> Environment {
     Gc::new(GcCell::new(boxed_env))
 }
 
+/// Creates the scope of a class, with new Private Names for the private members `names`.
+pub fn new_private_environment<'a, I>(env: Option<Environment>, names: I) -> Environment
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let _timer = BoaProfiler::global().start_event("new_private_environment", "env");
+    let mut record = PrivateEnvironmentRecord {
+        declarative_record: DeclarativeEnvironmentRecord {
+            env_rec: FxHashMap::default(),
+            outer_env: env,
+        },
+        names: FxHashMap::default(),
+    };
+    for name in names {
+        record.declare_private_name(name);
+    }
+
+    Gc::new(GcCell::new(Box::new(record)))
+}
+
 pub fn new_function_environment(
     f: GcObject,
     this: Option<Value>,
     outer: Option<Environment>,
     binding_status: BindingStatus,
+    home_object: Value,
+    new_target: Value,
 ) -> Environment {
     let mut func_env = FunctionEnvironmentRecord {
         env_rec: FxHashMap::default(),
         function: f,
         this_binding_status: binding_status,
-        home_object: Value::undefined(),
-        new_target: Value::undefined(),
+        home_object,
+        new_target,
         outer_env: outer, // this will come from Environment set as a private property of F - https://tc39.es/ecma262/#sec-ecmascript-function-objects
         this_value: Value::undefined(),
     };
     // If a `this` value has been passed, bind it to the environment
     if let Some(v) = this {
-        func_env.bind_this_value(v);
+        func_env
+            .bind_this_value(v)
+            .expect("this binding of a new function environment is uninitialized");
     }
     Gc::new(GcCell::new(Box::new(func_env)))
 }
@@ -261,6 +448,27 @@ pub fn new_object_environment(object: Value, environment: Option<Environment>) -
         /// with each object Environment Record. By default, the value of withEnvironment is false
         /// for any object Environment Record.
         with_environment: false,
+        unscopables: None,
+    })))
+}
+
+/// Creates the object environment of a `with` statement, whose bindings are the properties of
+/// `object`, except the ones listed by its `unscopables` property.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-with-statement-runtime-semantics-evaluation
+pub fn new_with_environment(
+    object: Value,
+    unscopables: RcSymbol,
+    environment: Option<Environment>,
+) -> Environment {
+    Gc::new(GcCell::new(Box::new(ObjectEnvironmentRecord {
+        bindings: object,
+        outer_env: environment,
+        with_environment: true,
+        unscopables: Some(unscopables),
     })))
 }
 
@@ -274,6 +482,7 @@ pub fn new_global_environment(global: Value, this_value: Value) -> Environment {
         /// with each object Environment Record. By default, the value of withEnvironment is false
         /// for any object Environment Record.
         with_environment: false,
+        unscopables: None,
     };
 
     let dcl_rec = DeclarativeEnvironmentRecord {
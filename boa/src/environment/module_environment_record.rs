@@ -0,0 +1,151 @@
+//! # Module Environment Records
+//!
+//! A module Environment Record is a declarative Environment Record that is used to represent the
+//! outer scope of an ECMAScript Module. In additional to normal mutable and immutable bindings,
+//! module Environment Records also provide immutable import bindings which are bindings that
+//! provide indirect access to a target binding that exists in another Environment Record.
+//! More info: [ECMA-262 sec-module-environment-records](https://tc39.es/ecma262/#sec-module-environment-records)
+
+use crate::{
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        environment_record_trait::EnvironmentRecordTrait,
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
+    },
+    module::Module,
+    Value,
+};
+use gc::{Finalize, Trace};
+use rustc_hash::FxHashMap;
+
+/// An import binding gives access to the binding `name` in the environment of `module`.
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct ImportBinding {
+    pub module: Module,
+    pub name: String,
+}
+
+impl ImportBinding {
+    /// Gets the environment that holds the target binding, if the module was already linked.
+    fn target_environment(&self) -> Option<Environment> {
+        self.module.environment()
+    }
+}
+
+/// The Environment Record of the top level code of a module.
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct ModuleEnvironmentRecord {
+    pub declarative_record: DeclarativeEnvironmentRecord,
+    pub import_bindings: FxHashMap<String, ImportBinding>,
+    pub module: Module,
+}
+
+impl ModuleEnvironmentRecord {
+    /// Creates an immutable indirect binding `name` for the binding `binding_name` of `module`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createimportbinding
+    pub fn create_import_binding(&mut self, name: String, module: Module, binding_name: String) {
+        self.import_bindings.insert(
+            name,
+            ImportBinding {
+                module,
+                name: binding_name,
+            },
+        );
+    }
+}
+
+impl EnvironmentRecordTrait for ModuleEnvironmentRecord {
+    fn has_binding(&self, name: &str) -> bool {
+        if let Some(binding) = self.import_bindings.get(name) {
+            return binding
+                .target_environment()
+                .is_some_and(|env| env.borrow().has_binding(&binding.name));
+        }
+        self.declarative_record.has_binding(name)
+    }
+
+    fn create_mutable_binding(&mut self, name: String, deletion: bool) {
+        self.declarative_record
+            .create_mutable_binding(name, deletion)
+    }
+
+    fn create_immutable_binding(&mut self, name: String, strict: bool) -> bool {
+        self.declarative_record
+            .create_immutable_binding(name, strict)
+    }
+
+    fn initialize_binding(&mut self, name: &str, value: Value) {
+        self.declarative_record.initialize_binding(name, value)
+    }
+
+    fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        strict: bool,
+    ) -> Result<(), EnvironmentError> {
+        if self.import_bindings.contains_key(name) {
+            return Err(EnvironmentError::type_error(&format!(
+                "Assignment to constant variable '{}'",
+                name
+            )));
+        }
+        self.declarative_record
+            .set_mutable_binding(name, value, strict)
+    }
+
+    fn get_binding_value(&self, name: &str, strict: bool) -> Result<Value, EnvironmentError> {
+        if let Some(binding) = self.import_bindings.get(name) {
+            let env = binding
+                .target_environment()
+                .expect("the module of an import binding must be linked");
+            let value = env.borrow().get_binding_value(&binding.name, strict);
+            return value;
+        }
+        self.declarative_record.get_binding_value(name, strict)
+    }
+
+    fn delete_binding(&mut self, name: &str) -> bool {
+        self.declarative_record.delete_binding(name)
+    }
+
+    fn has_this_binding(&self) -> bool {
+        true
+    }
+
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
+        Ok(Value::undefined())
+    }
+
+    fn has_super_binding(&self) -> bool {
+        false
+    }
+
+    fn with_base_object(&self) -> Value {
+        Value::undefined()
+    }
+
+    fn get_outer_environment(&self) -> Option<Environment> {
+        self.declarative_record.get_outer_environment()
+    }
+
+    fn set_outer_environment(&mut self, env: Environment) {
+        self.declarative_record.set_outer_environment(env)
+    }
+
+    fn get_environment_type(&self) -> EnvironmentType {
+        EnvironmentType::Module
+    }
+
+    fn get_global_object(&self) -> Option<Value> {
+        self.declarative_record.get_global_object()
+    }
+
+    fn as_module_environment_record(&self) -> Option<&ModuleEnvironmentRecord> {
+        Some(self)
+    }
+}
@@ -9,9 +9,10 @@
 use crate::{
     environment::{
         environment_record_trait::EnvironmentRecordTrait,
-        lexical_environment::{Environment, EnvironmentType},
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
     },
-    property::{Attribute, DataDescriptor},
+    property::{Attribute, DataDescriptor, PropertyDescriptor},
+    value::RcSymbol,
     Value,
 };
 use gc::{Finalize, Trace};
@@ -20,19 +21,19 @@ use gc::{Finalize, Trace};
 pub struct ObjectEnvironmentRecord {
     pub bindings: Value,
     pub with_environment: bool,
+    /// The `Symbol.unscopables` symbol, the key of the property of the binding object of a `with`
+    /// statement that lists the properties which are not bindings.
+    pub unscopables: Option<RcSymbol>,
     pub outer_env: Option<Environment>,
 }
 
 impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
+    /// Checks if the binding object has the property `name`.
+    ///
+    /// The properties that the `Symbol.unscopables` property of the object of a `with` statement
+    /// lists are not bindings, reading them can run getters, so they are checked by the `Context`.
     fn has_binding(&self, name: &str) -> bool {
-        if self.bindings.has_field(name) {
-            if self.with_environment {
-                // TODO: implement unscopables
-            }
-            true
-        } else {
-            false
-        }
+        self.bindings.has_field(name)
     }
 
     fn create_mutable_binding(&mut self, name: String, deletion: bool) {
@@ -58,28 +59,42 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         // The below is just a check.
         debug_assert!(self.has_binding(&name));
         self.set_mutable_binding(name, value, false)
+            .expect("setting the binding of an object environment cannot fail")
     }
 
-    fn set_mutable_binding(&mut self, name: &str, value: Value, strict: bool) {
-        debug_assert!(value.is_object() || value.is_function());
-
-        let mut property = DataDescriptor::new(value, Attribute::ENUMERABLE);
-        property.set_configurable(strict);
+    fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        strict: bool,
+    ) -> Result<(), EnvironmentError> {
+        // Accessor properties are set by the `Context`, which can call their setter.
+        let attributes = match self.bindings.get_property(name) {
+            Some(PropertyDescriptor::Data(ref data)) if data.writable() => data.attributes(),
+            Some(_) if strict => {
+                return Err(EnvironmentError::type_error(&format!(
+                    "cannot assign to read only property '{}'",
+                    name
+                )))
+            }
+            Some(_) => return Ok(()),
+            None => Attribute::all(),
+        };
         self.bindings
             .as_object_mut()
             .expect("binding object")
-            .insert(name, property);
+            .insert(name, DataDescriptor::new(value, attributes));
+        Ok(())
     }
 
-    fn get_binding_value(&self, name: &str, strict: bool) -> Value {
+    fn get_binding_value(&self, name: &str, strict: bool) -> Result<Value, EnvironmentError> {
+        // Accessor properties are got by the `Context`, which can call their getter.
         if self.bindings.has_field(name) {
-            self.bindings.get_field(name)
+            Ok(self.bindings.get_data_field(name))
+        } else if strict {
+            Err(EnvironmentError::not_defined(name))
         } else {
-            if strict {
-                // TODO: throw error here
-                // Error handling not implemented yet
-            }
-            Value::undefined()
+            Ok(Value::undefined())
         }
     }
 
@@ -92,8 +107,8 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         false
     }
 
-    fn get_this_binding(&self) -> Value {
-        Value::undefined()
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
+        Ok(Value::undefined())
     }
 
     fn has_super_binding(&self) -> bool {
@@ -110,6 +125,14 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         Value::undefined()
     }
 
+    fn binding_object(&self, _name: &str) -> Option<Value> {
+        Some(self.bindings.clone())
+    }
+
+    fn unscopables(&self) -> Option<RcSymbol> {
+        self.unscopables.clone()
+    }
+
     fn get_outer_environment(&self) -> Option<Environment> {
         match &self.outer_env {
             Some(outer) => Some(outer.clone()),
@@ -122,7 +145,7 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
     }
 
     fn get_environment_type(&self) -> EnvironmentType {
-        EnvironmentType::Function
+        EnvironmentType::Object
     }
 
     fn get_global_object(&self) -> Option<Value> {
@@ -0,0 +1,155 @@
+//! # Private Environment Records
+//!
+//! A private Environment Record holds the Private Names of the private members declared in the
+//! body of a class. Each evaluation of a class creates new Private Names, so the instances of two
+//! evaluations of the same class don't share their private members.
+//!
+//! The record is also the scope of the class, which holds the binding of the class name that is
+//! visible from inside the class. The functions defined in the class body close over it, so they
+//! can resolve the private names of the class wherever they are called from.
+//! More info: <https://tc39.es/ecma262/#sec-privateenvironment-records>
+
+use crate::{
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        environment_record_trait::EnvironmentRecordTrait,
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
+    },
+    Value,
+};
+use gc::{Finalize, Gc, Trace};
+use rustc_hash::FxHashMap;
+use std::fmt;
+
+/// A Private Name, the key of a private member of a class.
+///
+/// Private Names are compared by identity: two Private Names with the same description are
+/// different unless they were created by the same evaluation of a class.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-private-names
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct PrivateName(Gc<Box<str>>);
+
+impl PrivateName {
+    /// Creates a new Private Name, the description is the name without the `#`.
+    pub fn new(description: &str) -> Self {
+        Self(Gc::new(description.into()))
+    }
+
+    /// Gets the description of the Private Name, which is its name without the `#`.
+    pub fn description(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq for PrivateName {
+    fn eq(&self, other: &Self) -> bool {
+        // `Gc::ptr_eq` also compares whether the pointers are rooted, so it can't be used here.
+        std::ptr::eq::<Box<str>>(&*self.0, &*other.0)
+    }
+}
+
+impl fmt::Display for PrivateName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.description())
+    }
+}
+
+/// The Environment Record of the scope of a class, with the Private Names of its private members.
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct PrivateEnvironmentRecord {
+    pub declarative_record: DeclarativeEnvironmentRecord,
+    pub names: FxHashMap<String, PrivateName>,
+}
+
+impl PrivateEnvironmentRecord {
+    /// Adds a new Private Name for the private member `name` of the class, if the class doesn't
+    /// already have one, like the getter and the setter of the same private accessor.
+    pub fn declare_private_name(&mut self, name: &str) {
+        self.names
+            .entry(name.to_owned())
+            .or_insert_with(|| PrivateName::new(name));
+    }
+
+    /// Gets the Private Name of the private member `name` of the class, if the class declares it.
+    pub fn get_private_name(&self, name: &str) -> Option<&PrivateName> {
+        self.names.get(name)
+    }
+}
+
+impl EnvironmentRecordTrait for PrivateEnvironmentRecord {
+    fn has_binding(&self, name: &str) -> bool {
+        self.declarative_record.has_binding(name)
+    }
+
+    fn create_mutable_binding(&mut self, name: String, deletion: bool) {
+        self.declarative_record
+            .create_mutable_binding(name, deletion)
+    }
+
+    fn create_immutable_binding(&mut self, name: String, strict: bool) -> bool {
+        self.declarative_record
+            .create_immutable_binding(name, strict)
+    }
+
+    fn initialize_binding(&mut self, name: &str, value: Value) {
+        self.declarative_record.initialize_binding(name, value)
+    }
+
+    fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        strict: bool,
+    ) -> Result<(), EnvironmentError> {
+        self.declarative_record
+            .set_mutable_binding(name, value, strict)
+    }
+
+    fn get_binding_value(&self, name: &str, strict: bool) -> Result<Value, EnvironmentError> {
+        self.declarative_record.get_binding_value(name, strict)
+    }
+
+    fn delete_binding(&mut self, name: &str) -> bool {
+        self.declarative_record.delete_binding(name)
+    }
+
+    fn has_this_binding(&self) -> bool {
+        false
+    }
+
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
+        Ok(Value::undefined())
+    }
+
+    fn has_super_binding(&self) -> bool {
+        false
+    }
+
+    fn with_base_object(&self) -> Value {
+        Value::undefined()
+    }
+
+    fn get_outer_environment(&self) -> Option<Environment> {
+        self.declarative_record.get_outer_environment()
+    }
+
+    fn set_outer_environment(&mut self, env: Environment) {
+        self.declarative_record.set_outer_environment(env)
+    }
+
+    fn get_environment_type(&self) -> EnvironmentType {
+        EnvironmentType::Declarative
+    }
+
+    fn get_global_object(&self) -> Option<Value> {
+        self.declarative_record.get_global_object()
+    }
+
+    fn as_private_environment_record(&self) -> Option<&PrivateEnvironmentRecord> {
+        Some(self)
+    }
+}
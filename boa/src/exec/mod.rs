@@ -4,13 +4,15 @@
 mod tests;
 
 use crate::{Context, Result, Value};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+use std::any::TypeId;
 
 pub trait Executable {
     /// Runs this executable in the given context.
     fn run(&self, interpreter: &mut Context) -> Result<Value>;
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum InterpreterState {
     Executing,
     Return,
@@ -18,11 +20,90 @@ pub(crate) enum InterpreterState {
     Continue(Option<Box<str>>),
 }
 
+/// Something other than an exception that makes a generator or async function body exit
+/// through the `Err` path.
+///
+/// `try` statements must not catch these.
+#[derive(Debug)]
+pub(crate) enum Interruption {
+    /// The body is suspended by `yield`, with the iterator result object for the caller.
+    Yield(Value),
+    /// The body is suspended by `await`, with the value that is awaited.
+    Await(Value),
+    /// The generator was closed with its `return` method while it was suspended.
+    Return(Value),
+}
+
+/// How a suspended generator is resumed, this is the result of the `yield` expression it was
+/// suspended at.
+#[derive(Debug, Clone)]
+pub(crate) enum ResumeAction {
+    /// `next(value)`, the `yield` evaluates to the value.
+    Next(Value),
+    /// `throw(value)`, the `yield` throws the value.
+    Throw(Value),
+    /// `return(value)`, the generator returns the value from the `yield`.
+    Return(Value),
+}
+
+/// Identifies an AST node by its address and its type.
+///
+/// The body of a generator function is never moved while the generator is alive, so this stays
+/// valid between a suspension and the next resumption.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct NodeKey(usize, TypeId);
+
+impl NodeKey {
+    fn of<N: 'static>(node: &N) -> Self {
+        Self(node as *const N as usize, TypeId::of::<N>())
+    }
+}
+
+impl Finalize for NodeKey {}
+unsafe impl Trace for NodeKey {
+    unsafe_empty_trace!();
+}
+
+/// The progress of a node that was interrupted by a `yield` in one of its children.
+///
+/// When a generator suspends, every node between the `yield` and the generator body that has
+/// already done some work saves where it was, so that resuming the generator skips straight to
+/// the child that suspended.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct ResumePoint {
+    node: NodeKey,
+    step: usize,
+    values: Vec<Value>,
+}
+
+impl ResumePoint {
+    /// Gets the step of the node that was interrupted, the meaning depends on the node.
+    #[inline]
+    pub(crate) fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Gets the values the node had already computed when it was interrupted.
+    #[inline]
+    pub(crate) fn values(&self) -> &[Value] {
+        &self.values
+    }
+}
+
 /// A Javascript intepreter
 #[derive(Debug)]
 pub struct Interpreter {
     /// the current state of the interpreter.
     state: InterpreterState,
+
+    /// Set while a generator body is being exited by a `yield` or a `return` call.
+    interruption: Option<Interruption>,
+
+    /// The saved progress of the generator body that is being suspended or resumed.
+    resume_points: Vec<ResumePoint>,
+
+    /// What the generator that is being resumed should do at the `yield` it was suspended at.
+    resume_action: Option<ResumeAction>,
 }
 
 impl Default for Interpreter {
@@ -36,6 +117,9 @@ impl Interpreter {
     pub fn new() -> Self {
         Self {
             state: InterpreterState::Executing,
+            interruption: None,
+            resume_points: Vec::new(),
+            resume_action: None,
         }
     }
 
@@ -48,4 +132,85 @@ impl Interpreter {
     pub(crate) fn get_current_state(&self) -> &InterpreterState {
         &self.state
     }
+
+    /// Checks if a generator body is being exited by something that isn't an exception.
+    #[inline]
+    pub(crate) fn is_interrupted(&self) -> bool {
+        self.interruption.is_some()
+    }
+
+    /// Checks if a generator body is being suspended by a `yield`, or an async function body by
+    /// an `await`.
+    #[inline]
+    pub(crate) fn is_suspending(&self) -> bool {
+        matches!(
+            self.interruption,
+            Some(Interruption::Yield(_)) | Some(Interruption::Await(_))
+        )
+    }
+
+    #[inline]
+    pub(crate) fn set_interruption(&mut self, interruption: Interruption) {
+        self.interruption = Some(interruption);
+    }
+
+    #[inline]
+    pub(crate) fn take_interruption(&mut self) -> Option<Interruption> {
+        self.interruption.take()
+    }
+
+    /// Takes the progress `node` saved when its generator was suspended, if the generator is
+    /// being resumed and `node` is the next node on the way to the `yield`.
+    #[inline]
+    pub(crate) fn take_resume_point<N: 'static>(&mut self, node: &N) -> Option<ResumePoint> {
+        match self.resume_points.last() {
+            Some(point) if point.node == NodeKey::of(node) => self.resume_points.pop(),
+            _ => None,
+        }
+    }
+
+    /// Saves the progress of `node` if `result` comes from a child that suspended its generator.
+    ///
+    /// `step` and `values` are given back by [`take_resume_point`](#method.take_resume_point)
+    /// when the generator is resumed.
+    pub(crate) fn save_resume_point<N, T, F>(
+        &mut self,
+        result: Result<T>,
+        node: &N,
+        step: usize,
+        values: F,
+    ) -> Result<T>
+    where
+        N: 'static,
+        F: FnOnce() -> Vec<Value>,
+    {
+        if result.is_err() && self.is_suspending() {
+            self.resume_points.push(ResumePoint {
+                node: NodeKey::of(node),
+                step,
+                values: values(),
+            });
+        }
+        result
+    }
+
+    /// Replaces the saved progress and the resume action, returning the previous ones.
+    ///
+    /// Generators swap these in when they are resumed and out when they stop executing, so
+    /// that generators resumed from within other generators don't mix up their progress.
+    pub(crate) fn swap_resume_state(
+        &mut self,
+        resume_points: Vec<ResumePoint>,
+        resume_action: Option<ResumeAction>,
+    ) -> (Vec<ResumePoint>, Option<ResumeAction>) {
+        (
+            std::mem::replace(&mut self.resume_points, resume_points),
+            std::mem::replace(&mut self.resume_action, resume_action),
+        )
+    }
+
+    #[inline]
+    pub(crate) fn take_resume_action(&mut self) -> Option<ResumeAction> {
+        self.resume_action.take()
+    }
 }
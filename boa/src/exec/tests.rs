@@ -594,6 +594,23 @@ fn unary_post() {
     assert_eq!(&exec(execs_after_dec), "true");
 }
 
+#[test]
+fn unary_update_evaluates_target_once() {
+    let scenario = r#"
+        var log = [];
+        var o = { a: 1, b: 2 };
+        function obj() { log.push("obj"); return o; }
+        function key(k) { log.push("key"); return k; }
+        var results = [obj()[key("a")]++, --obj()[key("b")]];
+        delete obj()[key("a")];
+        [results.join(), o.a, o.b, log.join()].join(";");
+    "#;
+    assert_eq!(
+        &exec(scenario),
+        "\"1,1;undefined;1;obj,key,obj,key,obj,key\""
+    );
+}
+
 #[test]
 fn unary_void() {
     let void_should_return_undefined = r#"
@@ -661,6 +678,13 @@ fn unary_delete() {
         delete delete delete 1;
     "#;
     assert_eq!(&exec(delete_recursive), "true");
+
+    let delete_call = r#"
+        let called = false;
+        const b = delete (() => { called = true; })();
+        called + ',' + b
+    "#;
+    assert_eq!(&exec(delete_call), "\"true,true\"");
 }
 
 #[cfg(test)]
@@ -769,7 +793,7 @@ mod in_operator {
         let foo_val = forward_val(&mut engine, "Foo").unwrap();
         assert!(bar_obj
             .prototype_instance()
-            .strict_equals(&foo_val.get_field("prototype")));
+            .strict_equals(&foo_val.get_field("prototype", &mut engine).unwrap()));
     }
 }
 
@@ -1440,6 +1464,26 @@ fn test_strict_mode_delete() {
     assert!(string.starts_with("Uncaught \"SyntaxError\": "));
 }
 
+#[test]
+fn test_strict_mode_delete_non_configurable() {
+    // Checks that deleting a property that can't be deleted throws in strict mode code, and
+    // returns `false` in sloppy mode code.
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "delete Object.prototype"), "false");
+    assert_eq!(
+        forward(&mut engine, "'use strict'; delete Object.prototype"),
+        "Uncaught \"TypeError\": \"cannot delete property 'prototype'\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'use strict'; delete Math?.PI"),
+        "Uncaught \"TypeError\": \"cannot delete property 'PI'\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'use strict'; var o = { a: 1 }; delete o.a"),
+        "true"
+    );
+}
+
 #[test]
 fn test_strict_mode_reserved_name() {
     // Checks that usage of a reserved keyword for an identifier name is
@@ -1471,21 +1515,94 @@ fn test_strict_mode_reserved_name() {
 
 #[test]
 fn test_strict_mode_func_decl_in_block() {
-    // Checks that a function declaration in a block is an error in
-    // strict mode code as per https://tc39.es/ecma262/#early-error.
+    // Checks that a function declaration in a block is scoped to the block in
+    // strict mode code as per https://tc39.es/ecma262/#sec-blockdeclarationinstantiation.
 
     let scenario = r#"
     'use strict';
     let a = 4;
     let b = 5;
     if (a < b) { function f() {} }
+    f;
     "#;
 
     let mut engine = Context::new();
 
     let string = dbg!(forward(&mut engine, scenario));
 
-    assert!(string.starts_with("Uncaught \"SyntaxError\": "));
+    assert!(string.starts_with("Uncaught \"ReferenceError\": "));
+}
+
+#[test]
+fn test_web_compat_func_decl_in_block() {
+    // Checks that a function declaration in a block is also bound in the enclosing function in
+    // non-strict code as per https://tc39.es/ecma262/#sec-block-level-function-declarations-web-legacy-compatibility-semantics.
+
+    let scenario = r#"
+    function outer() {
+        { function f() { return 1; } }
+        { function f() { return 2; } }
+        return f();
+    }
+    function parameter(f) {
+        { function f() {} }
+        return f;
+    }
+    function lexical() {
+        let f = 3;
+        { function f() {} }
+        return f;
+    }
+    function before() {
+        var result = f;
+        { function f() {} }
+        return result;
+    }
+    function inSwitch() {
+        var result = f;
+        switch (1) { case 1: function f() {} }
+        return result === undefined && typeof f;
+    }
+    var beforeScript = f2;
+    { function f2() {} }
+    "#;
+
+    let mut engine = Context::new();
+    forward(&mut engine, scenario);
+
+    assert_eq!(forward(&mut engine, "outer()"), "2");
+    assert_eq!(forward(&mut engine, "parameter(1)"), "1");
+    assert_eq!(forward(&mut engine, "lexical()"), "3");
+    assert_eq!(forward(&mut engine, "before()"), "undefined");
+    assert_eq!(forward(&mut engine, "inSwitch()"), "\"function\"");
+    assert_eq!(forward(&mut engine, "beforeScript"), "undefined");
+    assert_eq!(forward(&mut engine, "typeof f2"), "\"function\"");
+
+    let scenario = r#"
+    function shadowed() {
+        { let f = 1; { function f() {} } }
+        return f;
+    }
+    shadowed();
+    "#;
+    assert!(forward(&mut engine, scenario).starts_with("Uncaught \"ReferenceError\": "));
+}
+
+#[test]
+fn test_func_decl_in_block_without_annex_b() {
+    let scenario = r#"
+    function outer() {
+        { function f() {} }
+        return f;
+    }
+    outer();
+    "#;
+
+    let mut engine = Context::new();
+    engine.set_annex_b(false);
+
+    assert!(forward(&mut engine, scenario).starts_with("Uncaught \"ReferenceError\": "));
+    assert!(engine.eval("{ function g() {} function g() {} }").is_err());
 }
 
 #[test]
@@ -1504,3 +1621,664 @@ fn test_strict_mode_dup_func_parameters() {
 
     assert!(string.starts_with("Uncaught \"SyntaxError\": "));
 }
+
+#[test]
+fn class_methods_and_accessors() {
+    let scenario = r#"
+        class Point {
+            constructor(x, y) {
+                this.x = x;
+                this.y = y;
+            }
+            sum() { return this.x + this.y; }
+            get double() { return this.sum() * 2; }
+            set both(v) { this.x = v; this.y = v; }
+            static origin() { return new Point(0, 0); }
+        }
+        let p = new Point(1, 2);
+        p.both = 5;
+        [p.sum(), p.double, Point.origin().sum(), typeof Point.prototype.sum].join(",");
+        "#;
+
+    assert_eq!(&exec(scenario), "\"10,20,0,function\"");
+}
+
+#[test]
+fn class_extends_and_super() {
+    let scenario = r#"
+        class Animal {
+            constructor(name) { this.name = name; }
+            speak() { return this.name + " speaks"; }
+        }
+        class Dog extends Animal {
+            speak() { return super.speak() + " loudly"; }
+        }
+        let d = new Dog("Rex");
+        [d.speak(), Object.getPrototypeOf(Dog) === Animal, d.constructor === Dog].join(",");
+        "#;
+
+    assert_eq!(&exec(scenario), "\"Rex speaks loudly,true,true\"");
+}
+
+#[test]
+fn class_expression_name_binding() {
+    let scenario = r#"
+        const A = class B {
+            name() { return B.name; }
+        };
+        [new A().name(), A.name].join(",");
+        "#;
+
+    assert_eq!(&exec(scenario), "\"B,B\"");
+}
+
+#[test]
+fn class_constructor_requires_new() {
+    let scenario = r#"
+        class A {}
+        A();
+        "#;
+
+    let mut engine = Context::new();
+
+    let string = forward(&mut engine, scenario);
+
+    assert!(string.starts_with("Uncaught \"TypeError\": "));
+}
+
+#[test]
+fn class_derived_constructor_this_before_super() {
+    let scenario = r#"
+        class A {}
+        class B extends A {
+            constructor() {
+                this.x = 1;
+                super();
+            }
+        }
+        new B();
+        "#;
+
+    let mut engine = Context::new();
+
+    let string = forward(&mut engine, scenario);
+
+    assert!(string.starts_with("Uncaught \"ReferenceError\": "));
+}
+
+#[test]
+fn class_private_members() {
+    let scenario = r#"
+        class Counter {
+            #count = 0;
+            static #instances = 0;
+            constructor() { Counter.#instances++; }
+            #step() { return 2; }
+            get #doubled() { return this.#count * 2; }
+            set #doubled(v) { this.#count = v / 2; }
+            increment() { this.#count += this.#step(); return this; }
+            reset() { this.#doubled = 10; return this.#doubled; }
+            value() { return this.#count; }
+            static has(o) { return #count in o; }
+            static instances() { return Counter.#instances; }
+        }
+        let c = new Counter().increment().increment();
+        new Counter();
+        [c.value(), c.reset(), c.value(), Counter.has(c), Counter.has({}),
+         Counter.instances(), c.hasOwnProperty("count")].join(",");
+        "#;
+
+    assert_eq!(&exec(scenario), "\"4,10,5,true,false,2,false\"");
+}
+
+#[test]
+fn class_private_member_of_other_object() {
+    let scenario = r#"
+        class A {
+            #x = 1;
+            static get(o) { return o.#x; }
+        }
+        A.get({});
+        "#;
+
+    let mut engine = Context::new();
+
+    let string = forward(&mut engine, scenario);
+
+    assert!(string.starts_with("Uncaught \"TypeError\": "));
+}
+
+#[test]
+fn class_private_method_not_writable() {
+    let scenario = r#"
+        class A {
+            #m() {}
+            constructor() { this.#m = 1; }
+        }
+        new A();
+        "#;
+
+    let mut engine = Context::new();
+
+    let string = forward(&mut engine, scenario);
+
+    assert!(string.starts_with("Uncaught \"TypeError\": "));
+}
+
+#[test]
+fn class_fields_initialization_order() {
+    let scenario = r#"
+        let log = [];
+        class A {
+            a = log.push("a") && "a";
+            constructor() { log.push("A"); }
+        }
+        class B extends A {
+            b = log.push("b") && this.a;
+            constructor() { log.push("before super"); super(); log.push("B"); }
+        }
+        let b = new B();
+        [log.join(" "), b.a, b.b, b.hasOwnProperty("b")].join(",");
+        "#;
+
+    assert_eq!(&exec(scenario), "\"before super a A b B,a,a,true\"");
+}
+
+#[test]
+fn class_computed_element_names() {
+    let scenario = r#"
+        let log = [];
+        function key(name) { log.push(name); return name; }
+        class A {
+            *[Symbol.iterator]() { yield 1; }
+            static ['x']() { return 'x'; }
+            get [key('g')]() { return 'g'; }
+            [key('f')] = 'f';
+            static [key('s')] = 's';
+            ['constructor']() { return 'method'; }
+        }
+        let a = new A();
+        let values = [];
+        for (let value of a) values.push(value);
+        [values, A.x(), a.g, a.f, A.s, a.constructor(), log.join(" ")].join(",");
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,x,g,f,s,method,g f s\"");
+
+    let scenario = r#"
+        try {
+            class B { static ['proto' + 'type']() {} }
+        } catch (e) {
+            e.name;
+        }
+        "#;
+
+    assert_eq!(&exec(scenario), "\"TypeError\"");
+}
+
+#[test]
+fn class_static_fields_and_blocks() {
+    let scenario = r#"
+        let log = [];
+        class A {
+            static x = 1;
+            static #y = this.x + 1;
+            static {
+                log.push(this === A, A.#y);
+                this.z = A.#y + 1;
+            }
+            static y() { return A.#y; }
+        }
+        [log.join(" "), A.x, A.y(), A.z].join(",");
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true 2,1,2,3\"");
+}
+
+#[test]
+fn class_private_names_are_per_evaluation() {
+    let scenario = r#"
+        function make() {
+            return class {
+                #x = 1;
+                static read(o) { return #x in o; }
+            };
+        }
+        let A = make();
+        let B = make();
+        [A.read(new A()), A.read(new B())].join(",");
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,false\"");
+}
+
+#[test]
+fn class_private_name_in_eval() {
+    let scenario = r#"
+        class A {
+            #x = 42;
+            read() { return eval("this.#x"); }
+        }
+        new A().read();
+        "#;
+
+    assert_eq!(&exec(scenario), "42");
+}
+
+#[test]
+fn strict_mode_this_in_plain_calls() {
+    let scenario = r#"
+        function sloppy() { return this; }
+        function strict() { "use strict"; return this; }
+        [sloppy() === this, strict() === undefined, typeof strict.call(1)].join(",");
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,true,number\"");
+}
+
+#[test]
+fn strict_mode_assignment_to_undeclared() {
+    let sloppy = r#"
+        undeclared = 1;
+        undeclared;
+        "#;
+
+    assert_eq!(&exec(sloppy), "1");
+
+    let scenario = r#"
+        "use strict";
+        undeclared = 1;
+        "#;
+
+    let mut engine = Context::new();
+
+    let string = forward(&mut engine, scenario);
+
+    assert!(string.starts_with("Uncaught \"ReferenceError\": "));
+}
+
+#[test]
+fn strict_mode_read_only_property() {
+    let sloppy = r#"
+        var obj = {};
+        Object.defineProperty(obj, "x", { value: 1, writable: false });
+        obj.x = 2;
+        obj.x;
+        "#;
+
+    assert_eq!(&exec(sloppy), "1");
+
+    let scenario = r#"
+        var obj = {};
+        Object.defineProperty(obj, "x", { value: 1, writable: false });
+        (function () {
+            "use strict";
+            obj.x = 2;
+        })();
+        "#;
+
+    let mut engine = Context::new();
+
+    let string = forward(&mut engine, scenario);
+
+    assert!(string.starts_with("Uncaught \"TypeError\": "));
+}
+
+#[test]
+fn strict_mode_function_directive_is_local() {
+    let scenario = r#"
+        function f() { "use strict"; }
+        f();
+        undeclared = 1;
+        undeclared;
+        "#;
+
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn strict_mode_not_a_directive() {
+    let scenario = r#"
+        "use strict".length;
+        undeclared = 1;
+        undeclared;
+        "#;
+
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn with_statement_property_lookup() {
+    let scenario = r#"
+        var a = "outer";
+        var obj = { a: "inner" };
+        var result;
+        with (obj) {
+            result = a;
+        }
+        result + "," + a;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"inner,outer\"");
+}
+
+#[test]
+fn with_statement_assignment() {
+    let scenario = r#"
+        var obj = { a: 1 };
+        with (obj) {
+            a = 2;
+            a += 3;
+            var b = 4;
+        }
+        obj.a + "," + b + "," + obj.hasOwnProperty("b");
+        "#;
+
+    assert_eq!(&exec(scenario), "\"5,4,false\"");
+}
+
+#[test]
+fn with_statement_method_this() {
+    let scenario = r#"
+        var obj = {
+            value: 42,
+            get() {
+                return this.value;
+            }
+        };
+        with (obj) {
+            get();
+        }
+        "#;
+
+    assert_eq!(&exec(scenario), "42");
+}
+
+#[test]
+fn with_statement_unscopables() {
+    let scenario = r#"
+        var a = "outer";
+        var obj = { a: "inner", b: "inner" };
+        obj[Symbol.unscopables] = { a: true, b: false };
+        with (obj) {
+            a + "," + b;
+        }
+        "#;
+
+    assert_eq!(&exec(scenario), "\"outer,inner\"");
+}
+
+#[test]
+fn with_statement_unscopables_getters() {
+    let scenario = r#"
+        var log = [];
+        var a = "outer";
+        var obj = {
+            a: "inner",
+            get [Symbol.unscopables]() {
+                log.push("unscopables");
+                return { get a() { log.push("a"); return 1; } };
+            },
+        };
+        with (obj) {
+            a = a + "!";
+        }
+        [a, obj.a, log.join()].join();
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"outer!,inner,unscopables,a,unscopables,a\""
+    );
+}
+
+#[test]
+fn with_statement_array_unscopables() {
+    let scenario = r#"
+        var values = "outer";
+        var keys = "outer";
+        with ([]) {
+            [values, keys, typeof push].join();
+        }
+        "#;
+
+    assert_eq!(&exec(scenario), "\"outer,outer,function\"");
+}
+
+#[test]
+fn with_statement_primitive_and_nullish() {
+    let scenario = r#"
+        with ("abc") {
+            length;
+        }
+        "#;
+
+    assert_eq!(&exec(scenario), "3");
+
+    let mut engine = Context::new();
+    let string = forward(&mut engine, "with (null) {}");
+    assert!(string.starts_with("Uncaught \"TypeError\": "));
+}
+
+#[test]
+fn with_statement_strict_mode() {
+    let mut engine = Context::new();
+    let string = forward(&mut engine, "'use strict'; with ({}) {}");
+    assert!(string.starts_with("Uncaught \"SyntaxError\": "));
+}
+
+#[test]
+fn instanceof_operator() {
+    let scenario = r#"
+        function Foo() {}
+        class Bar extends Foo {}
+        var bar = new Bar();
+        [
+            bar instanceof Bar,
+            bar instanceof Foo,
+            bar instanceof Object,
+            [] instanceof Array,
+            ({}) instanceof Foo,
+            1 instanceof Number,
+        ].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,true,true,true,false,false\"");
+}
+
+#[test]
+fn instanceof_symbol_has_instance() {
+    let scenario = r#"
+        var Even = {
+            [Symbol.hasInstance](n) {
+                return n % 2 === 0;
+            }
+        };
+        (2 instanceof Even) + "," + (3 instanceof Even);
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,false\"");
+}
+
+#[test]
+fn instanceof_type_errors() {
+    let mut engine = Context::new();
+    for scenario in &[
+        "1 instanceof 1",
+        "({}) instanceof {}",
+        "({}) instanceof { [Symbol.hasInstance]: 1 }",
+        "function F() {} F.prototype = 1; new Object() instanceof F",
+    ] {
+        let string = forward(&mut engine, scenario);
+        assert!(
+            string.starts_with("Uncaught \"TypeError\": "),
+            "{}: {}",
+            scenario,
+            string
+        );
+    }
+}
+
+#[test]
+fn temporal_dead_zone() {
+    let mut engine = Context::new();
+    for scenario in &[
+        "{ a; let a = 1; }",
+        "{ a = 2; let a = 1; }",
+        "{ typeof a; const a = 1; }",
+        "{ new A(); class A {} }",
+        "{ function f() { return a; } f(); let a = 1; }",
+        "for (let i = i; i < 1; i++) {}",
+        "switch (1) { case 0: let b; case 1: b; }",
+        "switch (1) { case 1: c; default: const c = 1; }",
+    ] {
+        let string = forward(&mut engine, scenario);
+        assert!(
+            string.starts_with("Uncaught \"ReferenceError\": ")
+                && string.contains("before initialization"),
+            "{}: {}",
+            scenario,
+            string
+        );
+    }
+
+    let scenario = r#"
+        function f() { return a; }
+        let a = 1;
+        f();
+        "#;
+    assert_eq!(&exec(scenario), "1");
+
+    let scenario = r#"
+        switch (0) {
+            case 0: let d = 1;
+            case 1: d += 1;
+        }
+        d;
+        "#;
+    let string = forward(&mut engine, scenario);
+    assert!(
+        string.starts_with("Uncaught \"ReferenceError\": "),
+        "{}",
+        string
+    );
+}
+
+#[test]
+fn assignment_to_constant() {
+    let mut engine = Context::new();
+    let string = forward(&mut engine, "const a = 1; a = 2;");
+    assert!(string.starts_with("Uncaught \"TypeError\": "), "{}", string);
+    assert_eq!(&forward(&mut engine, "a"), "1");
+}
+
+#[test]
+fn new_target() {
+    let scenario = r#"
+        function F() {
+            var arrow = () => new.target;
+            return [new.target === F, arrow() === F].join();
+        }
+        class A {
+            constructor() {
+                this.target = new.target.name;
+            }
+        }
+        class B extends A {}
+        [F(), new F() instanceof F, new A().target, new B().target].join(";");
+        "#;
+    assert_eq!(&exec(scenario), "\"false,false;true;A;B\"");
+
+    let scenario = r#"
+        function F() {
+            return eval("new.target");
+        }
+        new F() === F;
+        "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn thrown_error_pops_block_environments() {
+    let mut engine = Context::new();
+    let scenario = r#"
+        try { throw 1 } catch (e) {}
+        let y = 1;
+        function g() { return y }
+        g();
+        "#;
+    assert_eq!(&forward(&mut engine, scenario), "1");
+    // The environments of the first script are not kept for the next one.
+    assert_eq!(&forward(&mut engine, "const z = 2; (() => z)()"), "2");
+
+    let scenario = r#"
+        function f() {
+            try {
+                for (let i = 0; i < 1; i++) {
+                    switch (i) { case 0: { with ({}) { for (let k in { a: 1 }) throw 1; } } }
+                }
+            } catch (e) {}
+            const y = 3;
+            return (() => y)();
+        }
+        f();
+        "#;
+    assert_eq!(&forward(&mut engine, scenario), "3");
+}
+
+#[test]
+fn for_of_closes_iterator() {
+    let scenario = r#"
+        var log = [];
+        function* gen() {
+            try { yield 1; yield 2; } finally { log.push("finally"); }
+        }
+        for (var x of gen()) break;
+        function iter(name) {
+            return {
+                [Symbol.iterator]() { return this; },
+                next() { return { value: 1, done: false }; },
+                return() { log.push(name); return {}; },
+            };
+        }
+        function f() {
+            for (var y of iter("return")) return y;
+            return 2;
+        }
+        var result = f();
+        outer: for (var i of [1]) {
+            for (var z of iter("continue")) continue outer;
+        }
+        try {
+            for (var w of iter("throw")) throw "error";
+        } catch (e) {
+            log.push(e);
+        }
+        let after = 3;
+        [result, (() => after)(), log.join()].join(";");
+        "#;
+    assert_eq!(
+        &exec(scenario),
+        "\"1;3;finally,return,continue,throw,error\""
+    );
+
+    let scenario = r#"
+        var closed = false;
+        var done = {
+            [Symbol.iterator]() { return this; },
+            next() { return { done: true }; },
+            return() { closed = true; return {}; },
+        };
+        for (var x of done) {}
+        var invalid = {
+            [Symbol.iterator]() { return this; },
+            next() { return { value: 1, done: false }; },
+            return() { return 1; },
+        };
+        var errors = [];
+        try { for (var x of invalid) break; } catch (e) { errors.push(e.name); }
+        try { for (var x of invalid) throw "body"; } catch (e) { errors.push(e); }
+        [closed, errors.join()].join(";");
+        "#;
+    assert_eq!(&exec(scenario), "\"false;TypeError,body\"");
+}
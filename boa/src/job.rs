@@ -0,0 +1,39 @@
+//! Jobs are the units of work that run once the currently running code has completed, like the
+//! reactions of a promise.
+//!
+//! The engine only queues them, it's up to the host to decide when to run them with
+//! [`Context::run_jobs`](crate::Context::run_jobs), usually as part of its own event loop.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-jobs
+
+use crate::{Context, Result, Value};
+
+/// A job waiting in the job queue of a `Context`.
+///
+/// A job is a function object that is called with no `this` value and the given arguments.
+#[derive(Debug, Clone)]
+pub struct Job {
+    function: Value,
+    arguments: Box<[Value]>,
+}
+
+impl Job {
+    /// Creates a job that calls `function` with `arguments`.
+    pub fn new<A>(function: Value, arguments: A) -> Self
+    where
+        A: Into<Box<[Value]>>,
+    {
+        Self {
+            function,
+            arguments: arguments.into(),
+        }
+    }
+
+    /// Runs the job.
+    pub fn run(&self, ctx: &mut Context) -> Result<Value> {
+        ctx.call(&self.function, &Value::undefined(), &self.arguments)
+    }
+}
@@ -0,0 +1,140 @@
+//! Module loaders, the host hooks that load the modules imported by other modules.
+
+use super::Module;
+use crate::{object::GcObject, Context, Result};
+use rustc_hash::FxHashMap;
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A module loader is how the host resolves the module specifiers of imports and loads the
+/// imported modules.
+///
+/// The loader of a `Context` is set with [`Context::set_module_loader`].
+pub trait ModuleLoader: Debug {
+    /// Loads the module that `referrer` imports with `specifier`.
+    ///
+    /// Loading the same specifier from the same referrer must give the same module every time.
+    /// Usually, the modules are cached by the resolved specifier, so that every module is only
+    /// evaluated once.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
+    fn load_imported_module(
+        &self,
+        referrer: &Module,
+        specifier: &str,
+        context: &mut Context,
+    ) -> Result<Module>;
+
+    /// Adds the host defined properties of the `import.meta` object of `module`, when it's first
+    /// accessed.
+    ///
+    /// The default implementation doesn't add any property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostgetimportmetaproperties
+    fn init_import_meta(
+        &self,
+        _import_meta: &GcObject,
+        _module: &Module,
+        _context: &mut Context,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// The module loader of a new `Context`, which can't load any module.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdleModuleLoader;
+
+impl ModuleLoader for IdleModuleLoader {
+    fn load_imported_module(
+        &self,
+        _referrer: &Module,
+        specifier: &str,
+        context: &mut Context,
+    ) -> Result<Module> {
+        Err(context.construct_type_error(format!(
+            "can't load module '{}': no module loader was set",
+            specifier
+        )))
+    }
+}
+
+/// A module loader that loads modules from the file system.
+///
+/// Specifiers starting with `./` or `../` are relative to the directory of the importing module,
+/// other specifiers are relative to the root directory of the loader. Modules are named after
+/// their canonical path, and each file is only loaded once.
+#[derive(Debug)]
+pub struct SimpleModuleLoader {
+    root: PathBuf,
+    modules: RefCell<FxHashMap<PathBuf, Module>>,
+}
+
+impl SimpleModuleLoader {
+    /// Creates a new loader that loads the modules from the `root` directory.
+    pub fn new<P>(root: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            root: root.into(),
+            modules: RefCell::default(),
+        }
+    }
+
+    /// Gets the root directory of the loader.
+    #[inline]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Loads the module in the file at `path`, relative to the root directory.
+    pub fn load_path<P>(&self, path: P, context: &mut Context) -> Result<Module>
+    where
+        P: AsRef<Path>,
+    {
+        let path = self.root.join(path);
+        let path = path.canonicalize().map_err(|e| {
+            context.construct_type_error(format!("can't load module '{}': {}", path.display(), e))
+        })?;
+        if let Some(module) = self.modules.borrow().get(&path) {
+            return Ok(module.clone());
+        }
+
+        let source = fs::read_to_string(&path).map_err(|e| {
+            context.construct_type_error(format!("can't load module '{}': {}", path.display(), e))
+        })?;
+        let module = Module::parse(&source, path.to_string_lossy(), context)?;
+        self.modules.borrow_mut().insert(path, module.clone());
+        Ok(module)
+    }
+}
+
+impl ModuleLoader for SimpleModuleLoader {
+    fn load_imported_module(
+        &self,
+        referrer: &Module,
+        specifier: &str,
+        context: &mut Context,
+    ) -> Result<Module> {
+        let path = if specifier.starts_with("./") || specifier.starts_with("../") {
+            match Path::new(referrer.name()).parent() {
+                Some(directory) => directory.join(specifier),
+                None => PathBuf::from(specifier),
+            }
+        } else {
+            PathBuf::from(specifier)
+        };
+        self.load_path(path, context)
+    }
+}
@@ -0,0 +1,889 @@
+//! This module implements ECMAScript modules.
+//!
+//! A module is parsed into a [`Module`] record. Linking the module loads the modules that it
+//! imports, with the [`ModuleLoader`] of the [`Context`], and creates the environments of the
+//! module graph, with the imported bindings. Evaluating it then runs the code of every module of
+//! the graph, once.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Modules
+
+mod loader;
+mod namespace;
+#[cfg(test)]
+mod tests;
+
+pub use loader::{IdleModuleLoader, ModuleLoader, SimpleModuleLoader};
+pub use namespace::ModuleNamespace;
+
+use crate::{
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        environment_record_trait::EnvironmentRecordTrait, lexical_environment::Environment,
+        module_environment_record::ModuleEnvironmentRecord,
+    },
+    gc::{Finalize, Trace},
+    object::{GcObject, Object, ObjectData},
+    property::{Attribute, PropertyDescriptor},
+    syntax::{
+        ast::node::{
+            module::declaration_names, ConstDecl, ConstDeclList, ExportDecl, ExportSpecifier,
+            ImportName, ModuleItem, ModuleItemList, Node, StatementList, DEFAULT_EXPORT_BINDING,
+        },
+        Parser,
+    },
+    BoaProfiler, Context, Executable, Result, Value,
+};
+use gc::{unsafe_empty_trace, Gc, GcCell};
+use namespace::NamespaceExport;
+use rustc_hash::FxHashMap;
+use std::{collections::BTreeMap, fmt};
+
+/// The progress of a module in the linking and evaluation of its module graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+enum ModuleStatus {
+    Unlinked,
+    Linking,
+    Linked,
+    Evaluating,
+    Evaluated,
+}
+
+unsafe impl Trace for ModuleStatus {
+    unsafe_empty_trace!();
+}
+
+/// A binding that a module imports from another module.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ImportEntry {
+    module_request: Box<str>,
+    import_name: ImportName,
+    local_name: Box<str>,
+}
+
+/// A binding of the module itself that it exports.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct LocalExport {
+    export_name: Box<str>,
+    local_name: Box<str>,
+}
+
+/// A binding, or the namespace object, of another module that a module exports.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct IndirectExport {
+    export_name: Box<str>,
+    module_request: Box<str>,
+    import_name: ImportName,
+}
+
+/// The binding that an export name of a module resolves to.
+#[derive(Debug, Clone, PartialEq)]
+struct ResolvedBinding {
+    module: Module,
+    binding_name: ImportName,
+}
+
+/// The result of resolving an export name of a module.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-resolveexport
+#[derive(Debug, Clone)]
+enum ExportResolution {
+    Resolved(ResolvedBinding),
+    NotFound,
+    Ambiguous,
+}
+
+/// The state of a module that changes when it's linked and evaluated.
+#[derive(Debug, Trace, Finalize)]
+struct ModuleState {
+    status: ModuleStatus,
+    loaded_modules: FxHashMap<Box<str>, Module>,
+    environment: Option<Environment>,
+    namespace: Option<GcObject>,
+    import_meta: Option<GcObject>,
+    dfs_index: usize,
+    dfs_ancestor_index: usize,
+    evaluation_error: Option<Value>,
+}
+
+#[derive(Debug, Trace, Finalize)]
+struct ModuleRecord {
+    name: Box<str>,
+    requested_modules: Box<[Box<str>]>,
+    import_entries: Box<[ImportEntry]>,
+    local_exports: Box<[LocalExport]>,
+    indirect_exports: Box<[IndirectExport]>,
+    star_exports: Box<[Box<str>]>,
+    /// The function declarations, which are instantiated when the module is linked.
+    functions: Box<[Node]>,
+    /// The names of the `var` declarations, which are initialized when the module is linked.
+    var_names: Box<[Box<str>]>,
+    body: StatementList,
+    state: GcCell<ModuleState>,
+}
+
+/// A Source Text Module Record, the representation of a module.
+///
+/// Modules are compared by identity, every import of the same module by a host must give the
+/// same `Module`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-source-text-module-records
+#[derive(Clone, Trace, Finalize)]
+pub struct Module {
+    inner: Gc<ModuleRecord>,
+}
+
+impl Module {
+    /// Creates a new module record from the items of a parsed module.
+    ///
+    /// The `name` identifies the module for the [`ModuleLoader`], relative specifiers are usually
+    /// resolved against it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-parsemodule
+    pub fn new<N>(items: &ModuleItemList, name: N) -> Self
+    where
+        N: Into<Box<str>>,
+    {
+        let _timer = BoaProfiler::global().start_event("Module::new", "module");
+
+        let mut requested_modules: Vec<Box<str>> = Vec::new();
+        let mut request = |specifier: &str| {
+            if !requested_modules.iter().any(|s| **s == *specifier) {
+                requested_modules.push(specifier.into());
+            }
+        };
+        let mut import_entries = Vec::new();
+        let mut local_exports = Vec::new();
+        let mut indirect_exports = Vec::new();
+        let mut star_exports = Vec::new();
+        let mut named_exports: Vec<&ExportSpecifier> = Vec::new();
+        let mut functions = Vec::new();
+        let mut statements = Vec::new();
+        let mut declare = |node: &Node| match node {
+            Node::FunctionDecl(_) | Node::GeneratorDecl(_) | Node::AsyncFunctionDecl(_) => {
+                functions.push(node.clone())
+            }
+            _ => statements.push(node.clone()),
+        };
+
+        for item in items.items() {
+            match item {
+                ModuleItem::ImportDeclaration(decl) => {
+                    request(decl.module());
+                    import_entries.extend(decl.specifiers().iter().map(|spec| ImportEntry {
+                        module_request: decl.module().into(),
+                        import_name: spec.import_name().clone(),
+                        local_name: spec.local_name().into(),
+                    }));
+                }
+                ModuleItem::ExportDeclaration(decl) => match decl {
+                    ExportDecl::ReExportAll {
+                        export_name: None,
+                        module,
+                    } => {
+                        request(module);
+                        star_exports.push(module.clone());
+                    }
+                    ExportDecl::ReExportAll {
+                        export_name: Some(export_name),
+                        module,
+                    } => {
+                        request(module);
+                        indirect_exports.push(IndirectExport {
+                            export_name: export_name.clone(),
+                            module_request: module.clone(),
+                            import_name: ImportName::Namespace,
+                        });
+                    }
+                    ExportDecl::Named {
+                        specifiers,
+                        module: Some(module),
+                    } => {
+                        request(module);
+                        indirect_exports.extend(specifiers.iter().map(|spec| IndirectExport {
+                            export_name: spec.export_name().into(),
+                            module_request: module.clone(),
+                            import_name: ImportName::Name(spec.local_name().into()),
+                        }));
+                    }
+                    ExportDecl::Named {
+                        specifiers,
+                        module: None,
+                    } => named_exports.extend(specifiers.iter()),
+                    ExportDecl::Declaration(node) => {
+                        local_exports.extend(declaration_names(node).into_iter().map(|name| {
+                            LocalExport {
+                                export_name: name.into(),
+                                local_name: name.into(),
+                            }
+                        }));
+                        declare(node);
+                    }
+                    ExportDecl::DefaultDeclaration(node) => {
+                        local_exports.extend(declaration_names(node).into_iter().map(|name| {
+                            LocalExport {
+                                export_name: "default".into(),
+                                local_name: name.into(),
+                            }
+                        }));
+                        declare(node);
+                    }
+                    ExportDecl::DefaultExpression(expr) => {
+                        local_exports.push(LocalExport {
+                            export_name: "default".into(),
+                            local_name: DEFAULT_EXPORT_BINDING.into(),
+                        });
+                        let decl = ConstDecl::new(DEFAULT_EXPORT_BINDING, Some(expr.clone()));
+                        declare(&ConstDeclList::from(vec![decl]).into());
+                    }
+                },
+                ModuleItem::StatementListItem(node) => declare(node),
+            }
+        }
+
+        // Exporting an imported binding re-exports the binding of the other module, only the
+        // namespace objects of the imported modules are exported as local bindings.
+        for spec in named_exports {
+            let import = import_entries
+                .iter()
+                .find(|entry| *entry.local_name == *spec.local_name());
+            match import {
+                Some(ImportEntry {
+                    module_request,
+                    import_name: import_name @ ImportName::Name(_),
+                    ..
+                }) => indirect_exports.push(IndirectExport {
+                    export_name: spec.export_name().into(),
+                    module_request: module_request.clone(),
+                    import_name: import_name.clone(),
+                }),
+                _ => local_exports.push(LocalExport {
+                    export_name: spec.export_name().into(),
+                    local_name: spec.local_name().into(),
+                }),
+            }
+        }
+
+        let mut var_names = Vec::new();
+        for node in &statements {
+            if let Node::VarDeclList(list) = node {
+                for decl in list.as_ref() {
+                    decl.binding().bound_names(&mut var_names);
+                }
+            }
+        }
+        let var_names = var_names.into_iter().map(Box::from).collect();
+
+        let mut body = StatementList::from(statements);
+        body.set_strict(true);
+
+        Self {
+            inner: Gc::new(ModuleRecord {
+                name: name.into(),
+                requested_modules: requested_modules.into(),
+                import_entries: import_entries.into(),
+                local_exports: local_exports.into(),
+                indirect_exports: indirect_exports.into(),
+                star_exports: star_exports.into(),
+                functions: functions.into(),
+                var_names,
+                body,
+                state: GcCell::new(ModuleState {
+                    status: ModuleStatus::Unlinked,
+                    loaded_modules: FxHashMap::default(),
+                    environment: None,
+                    namespace: None,
+                    import_meta: None,
+                    dfs_index: 0,
+                    dfs_ancestor_index: 0,
+                    evaluation_error: None,
+                }),
+            }),
+        }
+    }
+
+    /// Parses the source code of a module, throwing a `SyntaxError` if it isn't valid.
+    pub fn parse<N>(src: &str, name: N, context: &mut Context) -> Result<Self>
+    where
+        N: Into<Box<str>>,
+    {
+        match Parser::new(src.as_bytes()).parse_module() {
+            Ok(items) => Ok(Self::new(&items, name)),
+            Err(e) => Err(context.construct_syntax_error(e.to_string())),
+        }
+    }
+
+    /// Gets the name of the module.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    /// Gets the specifiers of the modules that the module imports, in the order of the code.
+    #[inline]
+    pub fn requested_modules(&self) -> &[Box<str>] {
+        &self.inner.requested_modules
+    }
+
+    /// Gets the environment of the module, if it was linked.
+    pub(crate) fn environment(&self) -> Option<Environment> {
+        self.inner.state.borrow().environment.clone()
+    }
+
+    fn status(&self) -> ModuleStatus {
+        self.inner.state.borrow().status
+    }
+
+    /// Gets the module that was loaded for one of the requested modules.
+    fn imported_module(&self, specifier: &str) -> Module {
+        self.inner
+            .state
+            .borrow()
+            .loaded_modules
+            .get(specifier)
+            .cloned()
+            .expect("the requested modules of a module must be loaded before it's linked")
+    }
+
+    /// Gets the module whose code is running, if any.
+    pub(crate) fn running(context: &Context) -> Option<Self> {
+        context.realm().environment.environments().find_map(|env| {
+            env.borrow()
+                .as_module_environment_record()
+                .map(|record| record.module.clone())
+        })
+    }
+
+    /// Loads the modules of the module graph, with the module loader of the context.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-LoadRequestedModules
+    fn load_requested_modules(
+        &self,
+        context: &mut Context,
+        visited: &mut Vec<Module>,
+    ) -> Result<()> {
+        if visited.contains(self) {
+            return Ok(());
+        }
+        visited.push(self.clone());
+
+        for specifier in self.inner.requested_modules.iter() {
+            let loaded = self
+                .inner
+                .state
+                .borrow()
+                .loaded_modules
+                .get(specifier)
+                .cloned();
+            let module = if let Some(module) = loaded {
+                module
+            } else {
+                let loader = context.module_loader();
+                let module = loader.load_imported_module(self, specifier, context)?;
+                self.inner
+                    .state
+                    .borrow_mut()
+                    .loaded_modules
+                    .insert(specifier.clone(), module.clone());
+                module
+            };
+            module.load_requested_modules(context, visited)?;
+        }
+        Ok(())
+    }
+
+    /// Loads the module graph of the module and links it, creating the environments of the
+    /// modules.
+    ///
+    /// If a module can't be loaded, or an import can't be resolved, the error is returned and the
+    /// modules are left unlinked.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduledeclarationlinking
+    pub fn link(&self, context: &mut Context) -> Result<()> {
+        let _timer = BoaProfiler::global().start_event("Module::link", "module");
+        self.load_requested_modules(context, &mut Vec::new())?;
+
+        let mut stack = Vec::new();
+        if let Err(e) = self.inner_link(&mut stack, 0, context) {
+            for module in stack {
+                let mut state = module.inner.state.borrow_mut();
+                state.status = ModuleStatus::Unlinked;
+                state.environment = None;
+            }
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-InnerModuleLinking
+    fn inner_link(
+        &self,
+        stack: &mut Vec<Module>,
+        mut index: usize,
+        context: &mut Context,
+    ) -> Result<usize> {
+        if self.status() != ModuleStatus::Unlinked {
+            return Ok(index);
+        }
+        {
+            let mut state = self.inner.state.borrow_mut();
+            state.status = ModuleStatus::Linking;
+            state.dfs_index = index;
+            state.dfs_ancestor_index = index;
+        }
+        index += 1;
+        stack.push(self.clone());
+
+        for specifier in self.inner.requested_modules.iter() {
+            let required = self.imported_module(specifier);
+            index = required.inner_link(stack, index, context)?;
+            let (status, ancestor_index) = {
+                let required = required.inner.state.borrow();
+                (required.status, required.dfs_ancestor_index)
+            };
+            if status == ModuleStatus::Linking {
+                let mut state = self.inner.state.borrow_mut();
+                state.dfs_ancestor_index = state.dfs_ancestor_index.min(ancestor_index);
+            }
+        }
+        self.initialize_environment(context)?;
+
+        let state = self.inner.state.borrow();
+        if state.dfs_ancestor_index == state.dfs_index {
+            drop(state);
+            while let Some(module) = stack.pop() {
+                module.inner.state.borrow_mut().status = ModuleStatus::Linked;
+                if module == *self {
+                    break;
+                }
+            }
+        }
+        Ok(index)
+    }
+
+    /// Creates the environment of the module, with its imported bindings and its function
+    /// declarations.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-source-text-module-record-initialize-environment
+    fn initialize_environment(&self, context: &mut Context) -> Result<()> {
+        for export in self.inner.indirect_exports.iter() {
+            if let ExportResolution::NotFound | ExportResolution::Ambiguous =
+                self.resolve_export(&export.export_name, &mut Vec::new())
+            {
+                return Err(context.construct_syntax_error(format!(
+                    "the export '{}' of module '{}' can't be resolved",
+                    export.export_name, self.inner.name
+                )));
+            }
+        }
+
+        let mut record = ModuleEnvironmentRecord {
+            declarative_record: DeclarativeEnvironmentRecord {
+                env_rec: FxHashMap::default(),
+                outer_env: Some(context.realm().environment.get_global_environment()),
+            },
+            import_bindings: FxHashMap::default(),
+            module: self.clone(),
+        };
+        for import in self.inner.import_entries.iter() {
+            let imported = self.imported_module(&import.module_request);
+            let resolution = match import.import_name {
+                ImportName::Namespace => ExportResolution::Resolved(ResolvedBinding {
+                    module: imported,
+                    binding_name: ImportName::Namespace,
+                }),
+                ImportName::Name(ref name) => imported.resolve_export(name, &mut Vec::new()),
+            };
+            match resolution {
+                ExportResolution::Resolved(ResolvedBinding {
+                    module,
+                    binding_name: ImportName::Namespace,
+                }) => {
+                    let namespace = module.namespace(context);
+                    record.create_immutable_binding(import.local_name.to_string(), true);
+                    record.initialize_binding(&import.local_name, namespace.into());
+                }
+                ExportResolution::Resolved(ResolvedBinding {
+                    module,
+                    binding_name: ImportName::Name(ref name),
+                }) => record.create_import_binding(
+                    import.local_name.to_string(),
+                    module,
+                    name.to_string(),
+                ),
+                ExportResolution::NotFound | ExportResolution::Ambiguous => {
+                    return Err(context.construct_syntax_error(format!(
+                        "the import '{}' of module '{}' can't be resolved",
+                        import.local_name, self.inner.name
+                    )));
+                }
+            }
+        }
+        for name in self.inner.var_names.iter() {
+            if !record.has_binding(name) {
+                record.create_mutable_binding(name.to_string(), false);
+                record.initialize_binding(name, Value::undefined());
+            }
+        }
+
+        let env: Environment = Gc::new(GcCell::new(Box::new(record)));
+        self.inner.state.borrow_mut().environment = Some(env.clone());
+
+        context.realm_mut().environment.push(env.clone());
+        let result = self
+            .inner
+            .functions
+            .iter()
+            .try_for_each(|function| function.run(context).map(|_| ()));
+        context.realm_mut().environment.pop_to(&env);
+        result?;
+
+        set_default_export_name(&env);
+        Ok(())
+    }
+
+    /// Finds the binding that an export name of the module refers to.
+    ///
+    /// The `resolve_set` holds the exports that are being resolved, so that circular re-exports
+    /// are not found instead of looping forever.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-resolveexport
+    fn resolve_export(
+        &self,
+        export_name: &str,
+        resolve_set: &mut Vec<(Module, Box<str>)>,
+    ) -> ExportResolution {
+        if resolve_set
+            .iter()
+            .any(|(module, name)| module == self && **name == *export_name)
+        {
+            return ExportResolution::NotFound;
+        }
+        resolve_set.push((self.clone(), export_name.into()));
+
+        if let Some(export) = self
+            .inner
+            .local_exports
+            .iter()
+            .find(|export| *export.export_name == *export_name)
+        {
+            return ExportResolution::Resolved(ResolvedBinding {
+                module: self.clone(),
+                binding_name: ImportName::Name(export.local_name.clone()),
+            });
+        }
+
+        if let Some(export) = self
+            .inner
+            .indirect_exports
+            .iter()
+            .find(|export| *export.export_name == *export_name)
+        {
+            let imported = self.imported_module(&export.module_request);
+            return match export.import_name {
+                ImportName::Namespace => ExportResolution::Resolved(ResolvedBinding {
+                    module: imported,
+                    binding_name: ImportName::Namespace,
+                }),
+                ImportName::Name(ref name) => imported.resolve_export(name, resolve_set),
+            };
+        }
+
+        // A default export is never exported by `export *`.
+        if export_name == "default" {
+            return ExportResolution::NotFound;
+        }
+
+        let mut star_resolution: Option<ResolvedBinding> = None;
+        for specifier in self.inner.star_exports.iter() {
+            let imported = self.imported_module(specifier);
+            match imported.resolve_export(export_name, resolve_set) {
+                ExportResolution::Ambiguous => return ExportResolution::Ambiguous,
+                ExportResolution::NotFound => {}
+                ExportResolution::Resolved(resolution) => match star_resolution {
+                    None => star_resolution = Some(resolution),
+                    Some(ref star) if *star != resolution => return ExportResolution::Ambiguous,
+                    Some(_) => {}
+                },
+            }
+        }
+        star_resolution.map_or(ExportResolution::NotFound, ExportResolution::Resolved)
+    }
+
+    /// Gets the names of all the exports of the module, including the ones that may be
+    /// ambiguous.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getexportednames
+    fn exported_names(&self, export_star_set: &mut Vec<Module>) -> Vec<Box<str>> {
+        if export_star_set.contains(self) {
+            return Vec::new();
+        }
+        export_star_set.push(self.clone());
+
+        let mut names: Vec<Box<str>> = self
+            .inner
+            .local_exports
+            .iter()
+            .map(|export| export.export_name.clone())
+            .chain(
+                self.inner
+                    .indirect_exports
+                    .iter()
+                    .map(|export| export.export_name.clone()),
+            )
+            .collect();
+        for specifier in self.inner.star_exports.iter() {
+            for name in self
+                .imported_module(specifier)
+                .exported_names(export_star_set)
+            {
+                if &*name != "default" && !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+
+    /// Gets the namespace object of the module, creating it the first time.
+    ///
+    /// The namespace object has the exports of the module that can be resolved as properties,
+    /// which give the current values of the exported bindings.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmodulenamespace
+    pub fn namespace(&self, context: &mut Context) -> GcObject {
+        if let Some(ref namespace) = self.inner.state.borrow().namespace {
+            return namespace.clone();
+        }
+
+        let namespace = GcObject::new(Object::default());
+        {
+            let mut object = namespace.borrow_mut();
+            let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+            object.insert_property(to_string_tag, "Module", Attribute::empty());
+            object.prevent_extensions();
+        }
+        // The namespace is stored before the exports are resolved, since a module can re-export
+        // its own namespace object.
+        self.inner.state.borrow_mut().namespace = Some(namespace.clone());
+
+        let mut exports = BTreeMap::new();
+        for name in self.exported_names(&mut Vec::new()) {
+            if let ExportResolution::Resolved(resolution) =
+                self.resolve_export(&name, &mut Vec::new())
+            {
+                let export = match resolution.binding_name {
+                    ImportName::Name(ref binding) => NamespaceExport::Binding {
+                        module: resolution.module,
+                        name: binding.clone(),
+                    },
+                    ImportName::Namespace => {
+                        NamespaceExport::Namespace(resolution.module.namespace(context))
+                    }
+                };
+                exports.insert(name, export);
+            }
+        }
+        namespace.borrow_mut().data =
+            ObjectData::ModuleNamespace(ModuleNamespace::new(self.clone(), exports));
+
+        namespace
+    }
+
+    /// Links the module if needed, and evaluates the modules of its module graph that weren't
+    /// evaluated yet.
+    ///
+    /// If the evaluation of a module throws, the error is returned, and every later evaluation
+    /// of the module returns the same error.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduleevaluation
+    pub fn evaluate(&self, context: &mut Context) -> Result<()> {
+        let _timer = BoaProfiler::global().start_event("Module::evaluate", "module");
+        self.link(context)?;
+
+        let mut stack = Vec::new();
+        if let Err(e) = self.inner_evaluate(&mut stack, 0, context) {
+            for module in stack {
+                let mut state = module.inner.state.borrow_mut();
+                state.status = ModuleStatus::Evaluated;
+                state.evaluation_error = Some(e.clone());
+            }
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-innermoduleevaluation
+    fn inner_evaluate(
+        &self,
+        stack: &mut Vec<Module>,
+        mut index: usize,
+        context: &mut Context,
+    ) -> Result<usize> {
+        {
+            let mut state = self.inner.state.borrow_mut();
+            match state.status {
+                ModuleStatus::Evaluated => {
+                    return state.evaluation_error.clone().map_or(Ok(index), Err)
+                }
+                ModuleStatus::Evaluating => return Ok(index),
+                _ => {}
+            }
+            state.status = ModuleStatus::Evaluating;
+            state.dfs_index = index;
+            state.dfs_ancestor_index = index;
+        }
+        index += 1;
+        stack.push(self.clone());
+
+        for specifier in self.inner.requested_modules.iter() {
+            let required = self.imported_module(specifier);
+            index = required.inner_evaluate(stack, index, context)?;
+            let (status, ancestor_index) = {
+                let required = required.inner.state.borrow();
+                (required.status, required.dfs_ancestor_index)
+            };
+            if status == ModuleStatus::Evaluating {
+                let mut state = self.inner.state.borrow_mut();
+                state.dfs_ancestor_index = state.dfs_ancestor_index.min(ancestor_index);
+            }
+        }
+        self.execute(context)?;
+
+        let state = self.inner.state.borrow();
+        if state.dfs_ancestor_index == state.dfs_index {
+            drop(state);
+            while let Some(module) = stack.pop() {
+                module.inner.state.borrow_mut().status = ModuleStatus::Evaluated;
+                if module == *self {
+                    break;
+                }
+            }
+        }
+        Ok(index)
+    }
+
+    /// Runs the code of the module in its environment.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-source-text-module-record-execute-module
+    fn execute(&self, context: &mut Context) -> Result<()> {
+        let env = self
+            .environment()
+            .expect("a module must be linked before it's evaluated");
+
+        context.realm_mut().environment.push(env.clone());
+        let result = self.inner.body.run(context);
+        context.realm_mut().environment.pop_to(&env);
+
+        set_default_export_name(&env);
+        result.map(|_| ())
+    }
+
+    /// Gets the `import.meta` object of the module, creating it the first time.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-meta-properties-runtime-semantics-evaluation
+    pub(crate) fn import_meta(&self, context: &mut Context) -> Result<GcObject> {
+        if let Some(ref import_meta) = self.inner.state.borrow().import_meta {
+            return Ok(import_meta.clone());
+        }
+
+        let import_meta = GcObject::new(Object::default());
+        let loader = context.module_loader();
+        loader.init_import_meta(&import_meta, self, context)?;
+        self.inner.state.borrow_mut().import_meta = Some(import_meta.clone());
+        Ok(import_meta)
+    }
+}
+
+/// Names an anonymous function or class that is the default export of a module `default`.
+fn set_default_export_name(env: &Environment) {
+    let env = env.borrow();
+    if !env.has_binding(DEFAULT_EXPORT_BINDING) {
+        return;
+    }
+    let value = env.get_binding_value(DEFAULT_EXPORT_BINDING, true);
+    let object = match value {
+        Ok(Value::Object(ref object)) if object.borrow().is_callable() => object,
+        _ => return,
+    };
+
+    let name = object.borrow().get_own_property(&"name".into());
+    let anonymous = match name {
+        None => true,
+        Some(PropertyDescriptor::Data(ref name)) => name
+            .value()
+            .as_string()
+            .is_some_and(|name| name.is_empty() || **name == *DEFAULT_EXPORT_BINDING),
+        Some(PropertyDescriptor::Accessor(_)) => false,
+    };
+    if anonymous {
+        object.borrow_mut().insert_property(
+            "name",
+            "default",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+    }
+}
+
+impl PartialEq for Module {
+    fn eq(&self, other: &Self) -> bool {
+        Gc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Module {}
+
+impl fmt::Debug for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The module graph can have cycles, so only the name is shown.
+        f.debug_struct("Module")
+            .field("name", &self.inner.name)
+            .finish()
+    }
+}
@@ -0,0 +1,71 @@
+//! Module namespace objects.
+
+use super::Module;
+use crate::{
+    gc::{Finalize, Trace},
+    object::GcObject,
+    Value,
+};
+use std::collections::BTreeMap;
+
+/// An export of a module namespace object.
+#[derive(Debug, Trace, Finalize)]
+pub(super) enum NamespaceExport {
+    /// A binding of the environment of `module`.
+    Binding { module: Module, name: Box<str> },
+    /// The namespace object of another module, re-exported with `export * as name`.
+    Namespace(GcObject),
+}
+
+/// The data of a module namespace object, the object that has the exports of a module as its
+/// properties.
+///
+/// The properties are not stored in the object, they always have the current value of the
+/// exported bindings. They can't be changed, and no property can be added.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects
+#[derive(Debug, Trace, Finalize)]
+pub struct ModuleNamespace {
+    module: Module,
+    exports: BTreeMap<Box<str>, NamespaceExport>,
+}
+
+impl ModuleNamespace {
+    /// Creates the namespace data of `module`, with the resolved exports.
+    pub(super) fn new(module: Module, exports: BTreeMap<Box<str>, NamespaceExport>) -> Self {
+        Self { module, exports }
+    }
+
+    /// Gets the module of the namespace.
+    #[inline]
+    pub fn module(&self) -> &Module {
+        &self.module
+    }
+
+    /// Gets the export names of the module, sorted.
+    pub fn export_names(&self) -> impl Iterator<Item = &str> {
+        self.exports.keys().map(AsRef::as_ref)
+    }
+
+    /// Gets the current value of the export `name`, or `None` if the module doesn't export it.
+    ///
+    /// The value of an exported binding that is not initialized yet is `undefined`.
+    pub fn get(&self, name: &str) -> Option<Value> {
+        match self.exports.get(name)? {
+            NamespaceExport::Binding { module, name } => Some(
+                module
+                    .environment()
+                    .filter(|env| env.borrow().has_binding(name))
+                    .map_or_else(Value::undefined, |env| {
+                        env.borrow()
+                            .get_binding_value(name, true)
+                            .unwrap_or_default()
+                    }),
+            ),
+            NamespaceExport::Namespace(namespace) => Some(namespace.clone().into()),
+        }
+    }
+}
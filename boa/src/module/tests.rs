@@ -0,0 +1,369 @@
+use super::{Module, ModuleLoader};
+use crate::{object::GcObject, property::Attribute, Context, Result, Value};
+use std::{cell::RefCell, rc::Rc};
+
+/// Loads modules from their sources, with their names as specifiers.
+#[derive(Debug)]
+struct TestLoader {
+    sources: Vec<(&'static str, &'static str)>,
+    modules: RefCell<Vec<Module>>,
+}
+
+impl TestLoader {
+    fn load(&self, name: &str, context: &mut Context) -> Result<Module> {
+        if let Some(module) = self.modules.borrow().iter().find(|m| m.name() == name) {
+            return Ok(module.clone());
+        }
+        let source = match self.sources.iter().find(|(n, _)| *n == name) {
+            Some((_, source)) => *source,
+            None => return Err(context.construct_type_error("module not found")),
+        };
+        let module = Module::parse(source, name, context)?;
+        self.modules.borrow_mut().push(module.clone());
+        Ok(module)
+    }
+}
+
+impl ModuleLoader for TestLoader {
+    fn load_imported_module(
+        &self,
+        _referrer: &Module,
+        specifier: &str,
+        context: &mut Context,
+    ) -> Result<Module> {
+        self.load(specifier, context)
+    }
+
+    fn init_import_meta(
+        &self,
+        import_meta: &GcObject,
+        module: &Module,
+        _context: &mut Context,
+    ) -> Result<()> {
+        import_meta
+            .borrow_mut()
+            .insert_property("url", module.name(), Attribute::all());
+        Ok(())
+    }
+}
+
+/// Evaluates the first of the given modules, and returns its namespace object.
+fn evaluate(sources: &[(&'static str, &'static str)]) -> (Context, Result<GcObject>) {
+    let mut context = Context::new();
+    let loader = Rc::new(TestLoader {
+        sources: sources.to_vec(),
+        modules: RefCell::default(),
+    });
+    context.set_module_loader(loader.clone());
+
+    let result = loader.load(sources[0].0, &mut context).and_then(|module| {
+        module.evaluate(&mut context)?;
+        Ok(module.namespace(&mut context))
+    });
+    (context, result)
+}
+
+/// Gets the value of an export of the module of a namespace object.
+fn export(context: &mut Context, namespace: &GcObject, name: &str) -> Value {
+    namespace
+        .get(&name.into(), namespace.clone().into(), context)
+        .expect("could not get export")
+}
+
+#[test]
+fn live_bindings() {
+    let (mut context, namespace) = evaluate(&[
+        (
+            "main",
+            r#"
+            import { count, increment } from "counter";
+            export const before = count;
+            increment();
+            increment();
+            export const after = count;
+            "#,
+        ),
+        (
+            "counter",
+            r#"
+            export let count = 0;
+            export function increment() {
+                count++;
+            }
+            "#,
+        ),
+    ]);
+    let namespace = namespace.expect("evaluation failed");
+
+    assert_eq!(export(&mut context, &namespace, "before"), Value::from(0));
+    assert_eq!(export(&mut context, &namespace, "after"), Value::from(2));
+}
+
+#[test]
+fn imported_bindings_are_immutable() {
+    let (mut context, namespace) = evaluate(&[
+        (
+            "main",
+            r#"
+            import * as lib from "lib";
+            export let error = "";
+            try {
+                lib.a = 2;
+            } catch (e) {
+                error = e.name;
+            }
+            "#,
+        ),
+        ("lib", "export const a = 1;"),
+    ]);
+    let namespace = namespace.expect("evaluation failed");
+
+    assert_eq!(
+        export(&mut context, &namespace, "error"),
+        Value::from("TypeError")
+    );
+}
+
+#[test]
+fn namespace_object() {
+    let (mut context, namespace) = evaluate(&[
+        (
+            "main",
+            r#"
+            import * as lib from "lib";
+            export let keys = "";
+            for (let key in lib) {
+                keys += key + ",";
+            }
+            export const tag = lib[Symbol.toStringTag];
+            export const value = lib.b;
+            "#,
+        ),
+        (
+            "lib",
+            r#"
+            export const b = 2;
+            export const a = 1;
+            export default 3;
+            "#,
+        ),
+    ]);
+    let namespace = namespace.expect("evaluation failed");
+
+    assert_eq!(
+        export(&mut context, &namespace, "keys"),
+        Value::from("a,b,default,")
+    );
+    assert_eq!(
+        export(&mut context, &namespace, "tag"),
+        Value::from("Module")
+    );
+    assert_eq!(export(&mut context, &namespace, "value"), Value::from(2));
+    assert!(!namespace.borrow().is_extensible());
+}
+
+#[test]
+fn re_exports() {
+    let (mut context, namespace) = evaluate(&[
+        (
+            "main",
+            r#"
+            export * from "lib";
+            export { a as renamed } from "lib";
+            export * as lib from "lib";
+            "#,
+        ),
+        (
+            "lib",
+            r#"
+            export const a = 1;
+            export default 2;
+            "#,
+        ),
+    ]);
+    let namespace = namespace.expect("evaluation failed");
+
+    assert_eq!(export(&mut context, &namespace, "a"), Value::from(1));
+    assert_eq!(export(&mut context, &namespace, "renamed"), Value::from(1));
+    assert_eq!(
+        export(&mut context, &namespace, "default"),
+        Value::undefined()
+    );
+    let lib = export(&mut context, &namespace, "lib");
+    assert_eq!(
+        lib.get_field("default", &mut context)
+            .expect("could not get default export"),
+        Value::from(2)
+    );
+}
+
+#[test]
+fn cyclic_imports() {
+    let (mut context, namespace) = evaluate(&[
+        (
+            "a",
+            r#"
+            import { b } from "b";
+            export function a() {
+                return "a";
+            }
+            export const result = b();
+            "#,
+        ),
+        (
+            "b",
+            r#"
+            import { a } from "a";
+            export function b() {
+                return a() + "b";
+            }
+            "#,
+        ),
+    ]);
+    let namespace = namespace.expect("evaluation failed");
+
+    assert_eq!(
+        export(&mut context, &namespace, "result"),
+        Value::from("ab")
+    );
+}
+
+#[test]
+fn modules_are_evaluated_once() {
+    let (mut context, namespace) = evaluate(&[
+        (
+            "main",
+            r#"
+            import { count } from "counter";
+            import "a";
+            import "b";
+            export { count };
+            "#,
+        ),
+        ("a", r#"import { increment } from "counter"; increment();"#),
+        ("b", r#"import { increment } from "counter"; increment();"#),
+        (
+            "counter",
+            r#"
+            export let count = 0;
+            export function increment() {
+                count++;
+            }
+            increment();
+            "#,
+        ),
+    ]);
+    let namespace = namespace.expect("evaluation failed");
+
+    assert_eq!(export(&mut context, &namespace, "count"), Value::from(3));
+}
+
+#[test]
+fn import_meta() {
+    let (mut context, namespace) = evaluate(&[(
+        "main",
+        r#"
+        export const url = import.meta.url;
+        export const same = import.meta === import.meta;
+        export const prototype = Object.getPrototypeOf(import.meta);
+        "#,
+    )]);
+    let namespace = namespace.expect("evaluation failed");
+
+    assert_eq!(export(&mut context, &namespace, "url"), Value::from("main"));
+    assert_eq!(export(&mut context, &namespace, "same"), Value::from(true));
+    assert_eq!(export(&mut context, &namespace, "prototype"), Value::null());
+}
+
+#[test]
+fn default_export_names() {
+    let (mut context, namespace) = evaluate(&[
+        (
+            "main",
+            r#"
+            import f from "function";
+            import C from "class";
+            export const names = f.name + "," + C.name;
+            "#,
+        ),
+        ("function", "export default function () {}"),
+        ("class", "export default class {}"),
+    ]);
+    let namespace = namespace.expect("evaluation failed");
+
+    assert_eq!(
+        export(&mut context, &namespace, "names"),
+        Value::from("default,default")
+    );
+}
+
+#[test]
+fn unresolved_imports() {
+    let (mut context, result) = evaluate(&[
+        ("main", r#"import { missing } from "lib";"#),
+        ("lib", "export const a = 1;"),
+    ]);
+    let error = result.expect_err("the import should not resolve");
+    assert_eq!(
+        error.get_field("name", &mut context).expect("no name"),
+        Value::from("SyntaxError")
+    );
+
+    let (mut context, result) = evaluate(&[
+        ("main", r#"import { a } from "ambiguous";"#),
+        ("ambiguous", r#"export * from "b"; export * from "c";"#),
+        ("b", "export const a = 1;"),
+        ("c", "export const a = 2;"),
+    ]);
+    let error = result.expect_err("the import should be ambiguous");
+    assert_eq!(
+        error.get_field("name", &mut context).expect("no name"),
+        Value::from("SyntaxError")
+    );
+
+    let (_, result) = evaluate(&[("main", r#"import "missing";"#)]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn evaluation_errors_are_cached() {
+    let mut context = Context::new();
+    let loader = Rc::new(TestLoader {
+        sources: vec![("main", "export let count = 0; count++; throw count;")],
+        modules: RefCell::default(),
+    });
+    context.set_module_loader(loader.clone());
+
+    let module = loader.load("main", &mut context).expect("parsing failed");
+    let first = module
+        .evaluate(&mut context)
+        .expect_err("evaluation should throw");
+    let second = module
+        .evaluate(&mut context)
+        .expect_err("evaluation should throw");
+    assert_eq!(first, Value::from(1));
+    assert_eq!(second, Value::from(1));
+}
+
+#[test]
+fn module_code_is_strict() {
+    let (mut context, namespace) = evaluate(&[(
+        "main",
+        r#"
+        export let error = "";
+        try {
+            undeclared = 1;
+        } catch (e) {
+            error = e.name;
+        }
+        export const self = this;
+        "#,
+    )]);
+    let namespace = namespace.expect("evaluation failed");
+
+    assert_eq!(
+        export(&mut context, &namespace, "error"),
+        Value::from("ReferenceError")
+    );
+    assert_eq!(export(&mut context, &namespace, "self"), Value::undefined());
+}
@@ -2,17 +2,25 @@
 //!
 //! The `GcObject` is a garbage collected Object.
 
-use super::{Object, PROTOTYPE};
+use super::{Object, PrivateElement, PROTOTYPE};
 use crate::{
-    builtins::function::{
-        create_unmapped_arguments_object, BuiltInFunction, Function, NativeFunction,
+    builtins::{
+        async_function::AsyncFunction,
+        function::{
+            arguments::create_arguments_object, bind_parameters, BuiltInClosure, BuiltInFunction,
+            ClassFieldDefinition, ClassFieldName, Function, NativeClosure, NativeFunction,
+        },
+        generator::Generator,
     },
     environment::{
-        function_environment_record::BindingStatus, lexical_environment::new_function_environment,
+        function_environment_record::BindingStatus,
+        lexical_environment::{new_function_environment, Environment},
+        private_environment_record::PrivateName,
     },
+    exec::InterpreterState,
     property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
-    syntax::ast::node::RcStatementList,
-    value::PreferredType,
+    syntax::ast::node::{FormalParameter, RcStatementList},
+    value::{same_value, PreferredType},
     Context, Executable, Result, Value,
 };
 use gc::{Finalize, Gc, GcCell, GcCellRef, GcCellRefMut, Trace};
@@ -39,7 +47,10 @@ pub struct GcObject(Gc<GcCell<Object>>);
 // already borrow it so we get the function body clone it then drop the borrow and run the body
 enum FunctionBody {
     BuiltIn(NativeFunction),
-    Ordinary(RcStatementList),
+    Closure(NativeClosure, Value),
+    Ordinary(RcStatementList, Box<[FormalParameter]>, Environment),
+    Generator(RcStatementList, Box<[FormalParameter]>, Environment),
+    Async(RcStatementList, Box<[FormalParameter]>, Environment),
 }
 
 impl GcObject {
@@ -118,21 +129,48 @@ impl GcObject {
                     Function::BuiltIn(BuiltInFunction(function), _) => {
                         FunctionBody::BuiltIn(*function)
                     }
+                    Function::Closure {
+                        function: BuiltInClosure(function),
+                        captures,
+                        ..
+                    } => FunctionBody::Closure(*function, captures.clone()),
                     Function::Ordinary {
                         body,
                         params,
                         environment,
                         flags,
+                        home_object,
+                        ..
                     } => {
+                        // Class constructors can only be called with `new`.
+                        if flags.is_class_constructor() {
+                            let name = self.get(&"name".into(), self.clone().into(), ctx)?;
+                            let name = name.to_string(ctx)?;
+                            return ctx.throw_type_error(format!(
+                                "Class constructor {} cannot be invoked without 'new'",
+                                name
+                            ));
+                        }
+
+                        // Strict mode functions get the `this` value as it is, other functions get
+                        // the global object instead of `undefined` or `null`, and primitives
+                        // converted to objects.
+                        // <https://tc39.es/ecma262/#sec-ordinarycallbindthis>
+                        let this = if flags.is_lexical_this_mode() {
+                            None
+                        } else if body.strict() {
+                            Some(this.clone())
+                        } else if this.is_null_or_undefined() {
+                            Some(ctx.global_object().clone())
+                        } else {
+                            Some(this.to_object(ctx)?.into())
+                        };
+
                         // Create a new Function environment who's parent is set to the scope of the function declaration (self.environment)
                         // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
                         let local_env = new_function_environment(
                             this_function_object,
-                            if flags.is_lexical_this_mode() {
-                                None
-                            } else {
-                                Some(this.clone())
-                            },
+                            this,
                             Some(environment.clone()),
                             // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
                             if flags.is_lexical_this_mode() {
@@ -140,32 +178,40 @@ impl GcObject {
                             } else {
                                 BindingStatus::Uninitialized
                             },
+                            home_object
+                                .clone()
+                                .map(Value::from)
+                                .unwrap_or_else(Value::undefined),
+                            Value::undefined(),
                         );
 
-                        // Add argument bindings to the function environment
-                        for (i, param) in params.iter().enumerate() {
-                            // Rest Parameters
-                            if param.is_rest_param() {
-                                function.add_rest_param(param, i, args, ctx, &local_env);
-                                break;
-                            }
-
-                            let value = args.get(i).cloned().unwrap_or_else(Value::undefined);
-                            function.add_arguments_to_environment(param, value, &local_env);
+                        // Arrow functions use the arguments object of the enclosing function.
+                        if !flags.is_lexical_this_mode() {
+                            let arguments_obj = create_arguments_object(
+                                self,
+                                params,
+                                args,
+                                &local_env,
+                                body.strict(),
+                                ctx,
+                            );
+                            local_env
+                                .borrow_mut()
+                                .create_mutable_binding("arguments".to_string(), false);
+                            local_env
+                                .borrow_mut()
+                                .initialize_binding("arguments", arguments_obj);
                         }
 
-                        // Add arguments object
-                        let arguments_obj = create_unmapped_arguments_object(args);
-                        local_env
-                            .borrow_mut()
-                            .create_mutable_binding("arguments".to_string(), false);
-                        local_env
-                            .borrow_mut()
-                            .initialize_binding("arguments", arguments_obj);
-
-                        ctx.realm_mut().environment.push(local_env);
-
-                        FunctionBody::Ordinary(body.clone())
+                        // The parameters are bound once the function is no longer borrowed, since
+                        // their initializers can run arbitrary code.
+                        if flags.is_generator() {
+                            FunctionBody::Generator(body.clone(), params.clone(), local_env)
+                        } else if flags.is_async() {
+                            FunctionBody::Async(body.clone(), params.clone(), local_env)
+                        } else {
+                            FunctionBody::Ordinary(body.clone(), params.clone(), local_env)
+                        }
                     }
                 }
             } else {
@@ -177,42 +223,80 @@ impl GcObject {
 
         match f_body {
             FunctionBody::BuiltIn(func) => func(this, args, ctx),
-            FunctionBody::Ordinary(body) => {
+            FunctionBody::Closure(func, captures) => func(this, args, &captures, ctx),
+            FunctionBody::Ordinary(body, params, local_env) => {
+                bind_parameters(&params, &body, args, &local_env, ctx)?;
+                ctx.realm_mut().environment.push(local_env.clone());
                 let result = body.run(ctx);
-                ctx.realm_mut().environment.pop();
+                ctx.realm_mut().environment.pop_to(&local_env);
+
+                // The `return` of the body must not leak into native code calling the function.
+                if ctx.executor().get_current_state() == &InterpreterState::Return {
+                    ctx.executor()
+                        .set_current_state(InterpreterState::Executing);
+                }
 
                 result
             }
+            FunctionBody::Generator(body, params, local_env) => {
+                bind_parameters(&params, &body, args, &local_env, ctx)?;
+                Generator::create(self, body, local_env, ctx)
+            }
+            FunctionBody::Async(body, params, local_env) => {
+                AsyncFunction::start(body, &params, args, local_env, ctx)
+            }
         }
     }
 
     /// Construct an instance of this object with the specified arguments.
     ///
+    /// `new_target` is the constructor `new` was initially applied to, it is different from
+    /// `self` when a derived class constructor calls `super(...)`.
+    ///
     ///# Panics
     /// Panics if the object is currently mutably borrowed.
     // <https://tc39.es/ecma262/#sec-ecmascript-function-objects-construct-argumentslist-newtarget>
     #[track_caller]
-    pub fn construct(&self, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let this: Value = Object::create(self.borrow().get(&PROTOTYPE.into())).into();
-
+    pub fn construct(
+        &self,
+        args: &[Value],
+        new_target: &Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
         let this_function_object = self.clone();
+        let mut derived = false;
         let body = if let Some(function) = self.borrow().as_function() {
             if function.is_constructable() {
                 match function {
                     Function::BuiltIn(BuiltInFunction(function), _) => {
                         FunctionBody::BuiltIn(*function)
                     }
+                    Function::Closure {
+                        function: BuiltInClosure(function),
+                        captures,
+                        ..
+                    } => FunctionBody::Closure(*function, captures.clone()),
                     Function::Ordinary {
                         body,
                         params,
                         environment,
                         flags,
+                        home_object,
+                        ..
                     } => {
+                        // Derived constructors get their `this` value from the `super(...)` call.
+                        derived = flags.is_derived_constructor();
+                        let this = if derived {
+                            None
+                        } else {
+                            Some(Self::ordinary_create_from_constructor(new_target, ctx)?)
+                        };
+
                         // Create a new Function environment who's parent is set to the scope of the function declaration (self.environment)
                         // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
                         let local_env = new_function_environment(
                             this_function_object,
-                            Some(this.clone()),
+                            this,
                             Some(environment.clone()),
                             // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
                             if flags.is_lexical_this_mode() {
@@ -220,36 +304,37 @@ impl GcObject {
                             } else {
                                 BindingStatus::Uninitialized
                             },
+                            home_object
+                                .clone()
+                                .map(Value::from)
+                                .unwrap_or_else(Value::undefined),
+                            new_target.clone(),
                         );
 
-                        // Add argument bindings to the function environment
-                        for (i, param) in params.iter().enumerate() {
-                            // Rest Parameters
-                            if param.is_rest_param() {
-                                function.add_rest_param(param, i, args, ctx, &local_env);
-                                break;
-                            }
-
-                            let value = args.get(i).cloned().unwrap_or_else(Value::undefined);
-                            function.add_arguments_to_environment(param, value, &local_env);
+                        // Arrow functions use the arguments object of the enclosing function.
+                        if !flags.is_lexical_this_mode() {
+                            let arguments_obj = create_arguments_object(
+                                self,
+                                params,
+                                args,
+                                &local_env,
+                                body.strict(),
+                                ctx,
+                            );
+                            local_env
+                                .borrow_mut()
+                                .create_mutable_binding("arguments".to_string(), false);
+                            local_env
+                                .borrow_mut()
+                                .initialize_binding("arguments", arguments_obj);
                         }
 
-                        // Add arguments object
-                        let arguments_obj = create_unmapped_arguments_object(args);
-                        local_env
-                            .borrow_mut()
-                            .create_mutable_binding("arguments".to_string(), false);
-                        local_env
-                            .borrow_mut()
-                            .initialize_binding("arguments", arguments_obj);
-
-                        ctx.realm_mut().environment.push(local_env);
-
-                        FunctionBody::Ordinary(body.clone())
+                        FunctionBody::Ordinary(body.clone(), params.clone(), local_env)
                     }
                 }
             } else {
-                let name = this.get_field("name").display().to_string();
+                let name = self.get(&"name".into(), self.clone().into(), ctx)?;
+                let name = name.display().to_string();
                 return ctx.throw_type_error(format!("{} is not a constructor", name));
             }
         } else {
@@ -258,19 +343,393 @@ impl GcObject {
 
         match body {
             FunctionBody::BuiltIn(function) => {
+                let this = Self::ordinary_create_from_constructor(new_target, ctx)?;
                 function(&this, args, ctx)?;
                 Ok(this)
             }
-            FunctionBody::Ordinary(body) => {
-                let _ = body.run(ctx);
+            FunctionBody::Closure(function, captures) => {
+                let this = Self::ordinary_create_from_constructor(new_target, ctx)?;
+                function(&this, args, &captures, ctx)?;
+                Ok(this)
+            }
+            FunctionBody::Ordinary(body, params, local_env) => {
+                // The instance of a base class gets the fields of the class before the body of
+                // the constructor runs, derived classes get them from the `super(...)` call.
+                if !derived {
+                    let this = local_env.borrow().get_this_binding();
+                    if let Ok(Value::Object(ref this)) = this {
+                        this.initialize_instance_elements(self, ctx)?;
+                    }
+                }
+                bind_parameters(&params, &body, args, &local_env, ctx)?;
+                ctx.realm_mut().environment.push(local_env.clone());
+                let result = body.run(ctx);
+                ctx.realm_mut().environment.pop_to(&local_env);
+                let result = result?;
+
+                // An explicit `return` of an object replaces the constructed object.
+                if ctx.executor().get_current_state() == &InterpreterState::Return {
+                    ctx.executor()
+                        .set_current_state(InterpreterState::Executing);
+                    if result.is_object() {
+                        return Ok(result);
+                    }
+                    if derived && !result.is_undefined() {
+                        return ctx.throw_type_error(
+                            "Derived constructors may only return object or undefined",
+                        );
+                    }
+                }
 
-                // local_env gets dropped here, its no longer needed
-                let binding = ctx.realm_mut().environment.get_this_binding();
-                Ok(binding)
+                let binding = local_env.borrow().get_this_binding();
+                binding.or_else(|e| ctx.throw_reference_error(e.to_string()))
+            }
+            FunctionBody::Generator(..) | FunctionBody::Async(..) => {
+                unreachable!("generator and async functions are not constructable")
             }
         }
     }
 
+    /// Creates a new ordinary object whose prototype is the `prototype` property of `constructor`,
+    /// falling back to `Object.prototype` when that property is not an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinarycreatefromconstructor
+    fn ordinary_create_from_constructor(constructor: &Value, ctx: &mut Context) -> Result<Value> {
+        let proto = constructor.get_field(PROTOTYPE, ctx)?;
+        let proto = if proto.is_object() {
+            proto
+        } else {
+            ctx.standard_objects().object_object().prototype().into()
+        };
+        Ok(Object::create(proto).into())
+    }
+
+    /// `[[Get]]`, the property lookup that runs getters.
+    ///
+    /// `receiver` is the `this` value accessors are called with, it's the original object the
+    /// lookup started at, even when the property is found further up the prototype chain.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-get-p-receiver
+    pub fn get(&self, key: &PropertyKey, receiver: Value, ctx: &mut Context) -> Result<Value> {
+        let own_desc = self.borrow().get_own_property(key);
+        match &own_desc {
+            None => {
+                let parent = self.borrow().get_prototype_of();
+                match parent {
+                    Value::Object(ref parent) => parent.get(key, receiver, ctx),
+                    _ => Ok(Value::undefined()),
+                }
+            }
+            Some(PropertyDescriptor::Data(desc)) => Ok(desc.value()),
+            Some(PropertyDescriptor::Accessor(desc)) => match desc.getter() {
+                Some(getter) => getter.call(&receiver, &[], ctx),
+                None => Ok(Value::undefined()),
+            },
+        }
+    }
+
+    /// `[[SetPrototypeOf]]`, changes the prototype of the object to `prototype`, which is an
+    /// object or `null`.
+    ///
+    /// Returns `false` if the prototype can't be changed, because the object is not extensible or
+    /// because the object would end up in its own prototype chain.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-setprototypeof-v
+    pub fn set_prototype_of(&self, prototype: Value) -> bool {
+        debug_assert!(prototype.is_object() || prototype.is_null());
+        if same_value(&self.borrow().get_prototype_of(), &prototype) {
+            return true;
+        }
+        if !self.borrow().is_extensible() {
+            return false;
+        }
+
+        let mut current = prototype.clone();
+        while let Value::Object(ref object) = current {
+            if GcObject::equals(self, object) {
+                return false;
+            }
+            let next = object.borrow().get_prototype_of();
+            current = next;
+        }
+
+        self.borrow_mut().set_prototype_instance(prototype);
+        true
+    }
+
+    /// `[[Set]]`, the property assignment that runs setters.
+    ///
+    /// Returns `false` if the assignment was rejected, for example because the property is not
+    /// writable or only has a getter.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-set-p-v-receiver
+    pub fn set(
+        &self,
+        key: PropertyKey,
+        value: Value,
+        receiver: Value,
+        ctx: &mut Context,
+    ) -> Result<bool> {
+        // The properties of a module namespace object can't be assigned to.
+        if self.borrow().as_module_namespace().is_some() {
+            return Ok(false);
+        }
+
+        let own_desc = self.borrow().get_own_property(&key);
+        let own_desc = if let Some(desc) = own_desc {
+            desc
+        } else {
+            let parent = self.borrow().get_prototype_of();
+            if let Value::Object(ref parent) = parent {
+                return parent.set(key, value, receiver, ctx);
+            }
+            DataDescriptor::new(
+                Value::undefined(),
+                Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .into()
+        };
+
+        match &own_desc {
+            PropertyDescriptor::Data(desc) => {
+                if !desc.writable() {
+                    return Ok(false);
+                }
+                if let Value::Object(ref object) = receiver {
+                    // The property is created on, or updated in, the receiver.
+                    let existing = object.borrow().get_own_property(&key);
+                    let desc = match existing {
+                        Some(PropertyDescriptor::Accessor(_)) => return Ok(false),
+                        Some(PropertyDescriptor::Data(ref existing)) if !existing.writable() => {
+                            return Ok(false)
+                        }
+                        Some(PropertyDescriptor::Data(ref existing)) => {
+                            DataDescriptor::new(value, existing.attributes())
+                        }
+                        None => DataDescriptor::new(
+                            value,
+                            Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+                        ),
+                    };
+                    Ok(object.borrow_mut().define_own_property(key, desc.into()))
+                } else {
+                    Ok(false)
+                }
+            }
+            PropertyDescriptor::Accessor(desc) => match desc.setter() {
+                Some(setter) => {
+                    setter.call(&receiver, &[value], ctx)?;
+                    Ok(true)
+                }
+                None => Ok(false),
+            },
+        }
+    }
+
+    /// Adds the private member `name` to the object.
+    ///
+    /// Fails if the object already has the member, which happens when a class constructor
+    /// initializes an object that another call of the constructor already initialized, like an
+    /// object returned by the constructor of the parent class.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privatefieldadd
+    pub(crate) fn add_private_element(
+        &self,
+        name: &PrivateName,
+        element: PrivateElement,
+        ctx: &mut Context,
+    ) -> Result<()> {
+        if self.borrow().private_element_find(name).is_some() {
+            return Err(ctx.construct_type_error(format!(
+                "Cannot initialize {} twice on the same object",
+                name
+            )));
+        }
+        self.borrow_mut().private_element_add(name.clone(), element);
+        Ok(())
+    }
+
+    /// Gets the value of the private member `name` of the object, a private accessor runs its
+    /// getter.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privateget
+    pub(crate) fn private_get(&self, name: &PrivateName, ctx: &mut Context) -> Result<Value> {
+        let element = self.borrow().private_element_find(name).cloned();
+        match &element {
+            Some(PrivateElement::Field(value)) => Ok(value.clone()),
+            Some(PrivateElement::Method(method)) => Ok(method.clone().into()),
+            Some(PrivateElement::Accessor {
+                getter: Some(getter),
+                ..
+            }) => getter.call(&self.clone().into(), &[], ctx),
+            Some(PrivateElement::Accessor { getter: None, .. }) => {
+                ctx.throw_type_error(format!("'{}' was defined without a getter", name))
+            }
+            None => ctx.throw_type_error(format!(
+                "Cannot read private member {} from an object whose class did not declare it",
+                name
+            )),
+        }
+    }
+
+    /// Sets the value of the private member `name` of the object, a private accessor runs its
+    /// setter.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privateset
+    pub(crate) fn private_set(
+        &self,
+        name: &PrivateName,
+        value: Value,
+        ctx: &mut Context,
+    ) -> Result<()> {
+        let element = self.borrow().private_element_find(name).cloned();
+        match &element {
+            Some(PrivateElement::Field(_)) => {
+                if let Some(PrivateElement::Field(field)) =
+                    self.borrow_mut().private_element_find_mut(name)
+                {
+                    *field = value;
+                }
+                Ok(())
+            }
+            Some(PrivateElement::Method(_)) => {
+                Err(ctx.construct_type_error(format!("Private method {} is not writable", name)))
+            }
+            Some(PrivateElement::Accessor {
+                setter: Some(setter),
+                ..
+            }) => {
+                setter.call(&self.clone().into(), &[value], ctx)?;
+                Ok(())
+            }
+            Some(PrivateElement::Accessor { setter: None, .. }) => {
+                Err(ctx.construct_type_error(format!("'{}' was defined without a setter", name)))
+            }
+            None => Err(ctx.construct_type_error(format!(
+                "Cannot write private member {} to an object whose class did not declare it",
+                name
+            ))),
+        }
+    }
+
+    /// Defines the field `field` of a class on the object, with the value computed by its
+    /// initializer.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-definefield
+    pub(crate) fn define_field(
+        &self,
+        field: &ClassFieldDefinition,
+        ctx: &mut Context,
+    ) -> Result<()> {
+        let value = match field.initializer() {
+            Some(initializer) => initializer.call(&self.clone().into(), &[], ctx)?,
+            None => Value::undefined(),
+        };
+        match field.name() {
+            ClassFieldName::Private(name) => {
+                self.add_private_element(name, PrivateElement::Field(value), ctx)
+            }
+            ClassFieldName::Public(key) => {
+                let desc = DataDescriptor::new(value, Attribute::all());
+                if self
+                    .borrow_mut()
+                    .define_own_property(key.clone(), desc.into())
+                {
+                    Ok(())
+                } else {
+                    Err(ctx.construct_type_error(format!("Cannot redefine property: {}", key)))
+                }
+            }
+        }
+    }
+
+    /// Adds the private methods and defines the fields of the class whose constructor is
+    /// `constructor` on the object, once it has been created by the constructor of the class or
+    /// by the `super(...)` call of a derived class.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializeinstanceelements
+    pub(crate) fn initialize_instance_elements(
+        &self,
+        constructor: &GcObject,
+        ctx: &mut Context,
+    ) -> Result<()> {
+        let (fields, private_methods) = match constructor.borrow().as_function() {
+            Some(function) => (
+                function.fields().to_vec(),
+                function.private_methods().to_vec(),
+            ),
+            None => return Ok(()),
+        };
+        for (name, method) in private_methods {
+            self.add_private_element(&name, method, ctx)?;
+        }
+        for field in &fields {
+            self.define_field(field, ctx)?;
+        }
+        Ok(())
+    }
+
+    /// Copies the own enumerable properties of `source` to this object, except the ones with a
+    /// key in `excluded`.
+    ///
+    /// Nothing is copied if `source` is `null` or `undefined`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-copydataproperties
+    pub(crate) fn copy_data_properties(
+        &self,
+        source: &Value,
+        excluded: &[PropertyKey],
+        ctx: &mut Context,
+    ) -> Result<()> {
+        if source.is_null_or_undefined() {
+            return Ok(());
+        }
+        let from = source.to_object(ctx)?;
+        let keys = from.borrow().own_property_keys();
+        for key in keys.into_iter().filter(|key| !excluded.contains(key)) {
+            let enumerable = from
+                .borrow()
+                .get_own_property(&key)
+                .map_or(false, |desc| desc.enumerable());
+            if enumerable {
+                let value = from.get(&key, from.clone().into(), ctx)?;
+                let desc = DataDescriptor::new(value, Attribute::all());
+                self.borrow_mut().insert(key, desc);
+            }
+        }
+        Ok(())
+    }
+
     /// Converts an object to a primitive.
     ///
     /// Diverges from the spec to prevent a stack overflow when the object is recursive.
@@ -327,7 +786,7 @@ impl GcObject {
         let this = Value::from(self.clone());
         for name in &method_names {
             // a. Let method be ? Get(O, name).
-            let method: Value = this.get_field(*name);
+            let method: Value = this.get_field(*name, interpreter)?;
             // b. If IsCallable(method) is true, then
             if method.is_function() {
                 // i. Let result be ? Call(method, O).
@@ -343,6 +802,60 @@ impl GcObject {
         interpreter.throw_type_error("cannot convert object to primitive value")
     }
 
+    /// Checks if `object` inherits from the `prototype` property of this object, when this object
+    /// is callable.
+    ///
+    /// This is the default behaviour of the `instanceof` operator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinaryhasinstance
+    pub(crate) fn ordinary_has_instance(
+        &self,
+        object: &Value,
+        context: &mut Context,
+    ) -> Result<bool> {
+        // 1. If IsCallable(C) is false, return false.
+        if !self.borrow().is_callable() {
+            return Ok(false);
+        }
+
+        // 3. If Type(O) is not Object, return false.
+        let mut object = match object {
+            Value::Object(ref object) => object.clone(),
+            _ => return Ok(false),
+        };
+
+        // 4. Let P be ? Get(C, "prototype").
+        let prototype = self.get(&PROTOTYPE.into(), self.clone().into(), context)?;
+
+        // 5. If Type(P) is not Object, throw a TypeError exception.
+        let prototype = match prototype {
+            Value::Object(ref prototype) => prototype.clone(),
+            _ => {
+                return Err(context
+                    .construct_type_error("function has non-object prototype in instanceof check"))
+            }
+        };
+
+        // 6. Repeat,
+        loop {
+            // a. Set O to ? O.[[GetPrototypeOf]]().
+            // b. If O is null, return false.
+            let prototype_of = object.borrow().get_prototype_of();
+            object = match prototype_of {
+                Value::Object(ref object) => object.clone(),
+                _ => return Ok(false),
+            };
+
+            // c. If SameValue(P, O) is true, return true.
+            if GcObject::equals(&prototype, &object) {
+                return Ok(true);
+            }
+        }
+    }
+
     /// Converts an object to JSON, checking for reference cycles and throwing a TypeError if one is found
     pub(crate) fn to_json(&self, interpreter: &mut Context) -> Result<JSONValue> {
         let rec_limiter = RecursionLimiter::new(self);
@@ -354,7 +867,7 @@ impl GcObject {
             let mut arr: Vec<JSONValue> = Vec::with_capacity(keys.len());
             let this = Value::from(self.clone());
             for key in keys {
-                let value = this.get_field(key);
+                let value = this.get_field(key, interpreter)?;
                 if value.is_undefined() || value.is_function() || value.is_symbol() {
                     arr.push(JSONValue::Null);
                 } else {
@@ -367,7 +880,7 @@ impl GcObject {
             let this = Value::from(self.clone());
             for k in self.borrow().keys() {
                 let key = k.clone();
-                let value = this.get_field(k.to_string());
+                let value = this.get_field(k.to_string(), interpreter)?;
                 if !value.is_undefined() && !value.is_function() && !value.is_symbol() {
                     new_obj.insert(key.to_string(), value.to_json(interpreter)?);
                 }
@@ -377,18 +890,23 @@ impl GcObject {
     }
 
     pub fn to_property_descriptor(&self, context: &mut Context) -> Result<PropertyDescriptor> {
+        let this = Value::from(self.clone());
         let mut attribute = Attribute::empty();
 
         let enumerable_key = PropertyKey::from("enumerable");
         if self.borrow().has_property(&enumerable_key)
-            && self.borrow().get(&enumerable_key).to_boolean()
+            && self
+                .get(&enumerable_key, this.clone(), context)?
+                .to_boolean()
         {
             attribute |= Attribute::ENUMERABLE;
         }
 
         let configurable_key = PropertyKey::from("configurable");
         if self.borrow().has_property(&configurable_key)
-            && self.borrow().get(&configurable_key).to_boolean()
+            && self
+                .get(&configurable_key, this.clone(), context)?
+                .to_boolean()
         {
             attribute |= Attribute::CONFIGURABLE;
         }
@@ -396,14 +914,14 @@ impl GcObject {
         let mut value = None;
         let value_key = PropertyKey::from("value");
         if self.borrow().has_property(&value_key) {
-            value = Some(self.borrow().get(&value_key));
+            value = Some(self.get(&value_key, this.clone(), context)?);
         }
 
         let mut has_writable = false;
         let writable_key = PropertyKey::from("writable");
         if self.borrow().has_property(&writable_key) {
             has_writable = true;
-            if self.borrow().get(&writable_key).to_boolean() {
+            if self.get(&writable_key, this.clone(), context)?.to_boolean() {
                 attribute |= Attribute::WRITABLE;
             }
         }
@@ -411,7 +929,7 @@ impl GcObject {
         let mut get = None;
         let get_key = PropertyKey::from("get");
         if self.borrow().has_property(&get_key) {
-            let getter = self.borrow().get(&get_key);
+            let getter = self.get(&get_key, this.clone(), context)?;
             match getter {
                 Value::Object(ref object) if object.borrow().is_callable() => {
                     get = Some(object.clone());
@@ -427,7 +945,7 @@ impl GcObject {
         let mut set = None;
         let set_key = PropertyKey::from("set");
         if self.borrow().has_property(&set_key) {
-            let setter = self.borrow().get(&set_key);
+            let setter = self.get(&set_key, this.clone(), context)?;
             match setter {
                 Value::Object(ref object) if object.borrow().is_callable() => {
                     set = Some(object.clone());
@@ -6,8 +6,8 @@
 //! [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots
 
 use crate::{
-    object::{GcObject, Object},
-    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    object::{GcObject, Object, ObjectData},
+    property::{Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
     value::{same_value, Value},
     BoaProfiler, Context, Result,
 };
@@ -56,10 +56,15 @@ impl Object {
     }
 
     /// Delete property.
+    ///
+    /// Deleting an element of a mapped arguments object unlinks it from its parameter.
     pub fn delete(&mut self, key: &PropertyKey) -> bool {
         match self.get_own_property(key) {
             Some(desc) if desc.configurable() => {
                 self.remove_property(&key);
+                if let Some(map) = self.as_mapped_arguments_mut() {
+                    map.delete(key);
+                }
                 true
             }
             Some(_) => false,
@@ -67,59 +72,6 @@ impl Object {
         }
     }
 
-    /// [[Get]]
-    /// https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-get-p-receiver
-    pub fn get(&self, key: &PropertyKey) -> Value {
-        match self.get_own_property(key) {
-            None => {
-                // parent will either be null or an Object
-                let parent = self.get_prototype_of();
-                if parent.is_null() {
-                    return Value::undefined();
-                }
-
-                parent.get_field(key.clone())
-            }
-            Some(ref desc) => match desc {
-                PropertyDescriptor::Accessor(_) => todo!(),
-                PropertyDescriptor::Data(desc) => desc.value(),
-            },
-        }
-    }
-
-    /// [[Set]]
-    /// <https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-set-p-v-receiver>
-    pub fn set(&mut self, key: PropertyKey, val: Value) -> bool {
-        let _timer = BoaProfiler::global().start_event("Object::set", "object");
-
-        // Fetch property key
-        let own_desc = if let Some(desc) = self.get_own_property(&key) {
-            desc
-        } else {
-            let parent = self.get_prototype_of();
-            if !parent.is_null() {
-                // TODO: come back to this
-            }
-            DataDescriptor::new(
-                Value::undefined(),
-                Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
-            )
-            .into()
-        };
-
-        match &own_desc {
-            PropertyDescriptor::Data(desc) => {
-                if !desc.writable() {
-                    return false;
-                }
-
-                let desc = DataDescriptor::new(val, own_desc.attributes()).into();
-                self.define_own_property(key, desc)
-            }
-            PropertyDescriptor::Accessor(_) => todo!(),
-        }
-    }
-
     /// Define an own property.
     ///
     /// More information:
@@ -133,6 +85,25 @@ impl Object {
         let _timer = BoaProfiler::global().start_event("Object::define_own_property", "object");
 
         let key = key.into();
+        if self.as_module_namespace().is_some() && !matches!(key, PropertyKey::Symbol(_)) {
+            return self.module_namespace_define_own_property(&key, &desc);
+        }
+        if self
+            .as_mapped_arguments()
+            .is_some_and(|map| map.is_mapped(&key))
+        {
+            return self.mapped_arguments_define_own_property(key, desc);
+        }
+        self.ordinary_define_own_property(key, desc)
+    }
+
+    /// Defines an own property, without the behaviour of exotic objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinarydefineownproperty
+    fn ordinary_define_own_property(&mut self, key: PropertyKey, desc: PropertyDescriptor) -> bool {
         let extensible = self.is_extensible();
 
         let current = if let Some(desc) = self.get_own_property(&key) {
@@ -142,6 +113,9 @@ impl Object {
                 return false;
             }
 
+            if let PropertyKey::Index(index) = key {
+                self.extend_array_length(index);
+            }
             self.insert(key, desc);
             return true;
         };
@@ -169,23 +143,16 @@ impl Object {
                     }
                 }
             }
+            // A configurable property can change its kind, it's replaced by the new descriptor.
             (PropertyDescriptor::Data(current), PropertyDescriptor::Accessor(_)) => {
                 if !current.configurable() {
                     return false;
                 }
-
-                let current = AccessorDescriptor::new(None, None, current.attributes());
-                self.insert(key, current);
-                return true;
             }
             (PropertyDescriptor::Accessor(current), PropertyDescriptor::Data(_)) => {
                 if !current.configurable() {
                     return false;
                 }
-
-                let current = DataDescriptor::new(Value::undefined(), current.attributes());
-                self.insert(key, current);
-                return true;
             }
             (PropertyDescriptor::Accessor(current), PropertyDescriptor::Accessor(desc)) => {
                 if !current.configurable() {
@@ -208,6 +175,87 @@ impl Object {
         true
     }
 
+    /// Redefining an element of a mapped arguments object also sets its parameter, unless it
+    /// becomes an accessor. An element that becomes an accessor or read-only is unlinked from its
+    /// parameter.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-defineownproperty-p-desc
+    fn mapped_arguments_define_own_property(
+        &mut self,
+        key: PropertyKey,
+        desc: PropertyDescriptor,
+    ) -> bool {
+        if !self.ordinary_define_own_property(key.clone(), desc.clone()) {
+            return false;
+        }
+        let map = self
+            .as_mapped_arguments_mut()
+            .expect("the object must be a mapped arguments object");
+        match &desc {
+            PropertyDescriptor::Accessor(_) => map.delete(&key),
+            PropertyDescriptor::Data(desc) => {
+                map.set(&key, desc.value());
+                if !desc.writable() {
+                    map.delete(&key);
+                }
+            }
+        }
+        true
+    }
+
+    /// The exports of a module namespace object can't be redefined, except with a descriptor that
+    /// doesn't change them.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-defineownproperty-p-desc
+    fn module_namespace_define_own_property(
+        &self,
+        key: &PropertyKey,
+        desc: &PropertyDescriptor,
+    ) -> bool {
+        let current = match self.get_own_property(key) {
+            Some(PropertyDescriptor::Data(ref current)) => current.value(),
+            _ => return false,
+        };
+        match desc {
+            PropertyDescriptor::Data(desc) => {
+                !desc.configurable()
+                    && desc.enumerable()
+                    && desc.writable()
+                    && same_value(&desc.value(), &current)
+            }
+            PropertyDescriptor::Accessor(_) => false,
+        }
+    }
+
+    /// Makes the `length` of an array greater than `index`, when an element is added at that index.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array-exotic-objects-defineownproperty-p-desc
+    fn extend_array_length(&mut self, index: u32) {
+        if !self.is_array() {
+            return;
+        }
+        let length_key = PropertyKey::from("length");
+        if let Some(PropertyDescriptor::Data(ref length)) = self.get_own_property(&length_key) {
+            if length
+                .value()
+                .as_number()
+                .map_or(true, |len| len <= f64::from(index))
+            {
+                let length = DataDescriptor::new(index + 1, length.attributes());
+                self.insert(length_key, length);
+            }
+        }
+    }
+
     /// The specification returns a Property Descriptor or Undefined.
     ///
     /// These are 2 separate types and we can't do that here.
@@ -225,17 +273,113 @@ impl Object {
             PropertyKey::Symbol(ref symbol) => self.symbol_properties.get(symbol),
         };
 
-        property.cloned()
+        property
+            .cloned()
+            .map(|property| self.mapped_arguments_get_own_property(key, property))
+            .or_else(|| self.string_get_own_property(key))
+            .or_else(|| self.module_namespace_get_own_property(key))
+    }
+
+    /// The own elements of a mapped arguments object that are linked to a parameter have the
+    /// current value of the parameter.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-getownproperty-p
+    fn mapped_arguments_get_own_property(
+        &self,
+        key: &PropertyKey,
+        property: PropertyDescriptor,
+    ) -> PropertyDescriptor {
+        let value = match (self.as_mapped_arguments(), &property) {
+            (Some(map), PropertyDescriptor::Data(_)) => map.get(key),
+            _ => None,
+        };
+        match value {
+            Some(value) => DataDescriptor::new(value, property.attributes()).into(),
+            None => property,
+        }
+    }
+
+    /// The own property of a `String` object at one of the indices of its string, which is the
+    /// character at that index.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-stringgetownproperty
+    fn string_get_own_property(&self, key: &PropertyKey) -> Option<PropertyDescriptor> {
+        let (string, index) = match (&self.data, key) {
+            (ObjectData::String(string), PropertyKey::Index(index)) => (string, *index),
+            _ => return None,
+        };
+        let code_unit = string.encode_utf16().nth(index as usize)?;
+
+        Some(
+            DataDescriptor::new(
+                String::from_utf16_lossy(&[code_unit]),
+                Attribute::READONLY | Attribute::ENUMERABLE | Attribute::PERMANENT,
+            )
+            .into(),
+        )
+    }
+
+    /// The own property of a module namespace object for one of the exports of its module, which
+    /// has the current value of the exported binding.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-getownproperty-p
+    fn module_namespace_get_own_property(&self, key: &PropertyKey) -> Option<PropertyDescriptor> {
+        let namespace = self.as_module_namespace()?;
+        let value = match key {
+            PropertyKey::Index(index) => namespace.get(&index.to_string())?,
+            PropertyKey::String(ref name) => namespace.get(name)?,
+            PropertyKey::Symbol(_) => return None,
+        };
+
+        Some(
+            DataDescriptor::new(
+                value,
+                Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::PERMANENT,
+            )
+            .into(),
+        )
     }
 
     /// Essential internal method OwnPropertyKeys
     ///
+    /// The keys are in ascending order for array indices, including the indices of the string of
+    /// a `String` object, followed by the string keys and then the symbol keys, both in the order
+    /// they were created. The string keys of a module namespace object are its export names, in
+    /// code unit order.
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
-    /// [spec](https://tc39.es/ecma262/#table-essential-internal-methods)
+    /// [spec](https://tc39.es/ecma262/#sec-ordinaryownpropertykeys)
     pub fn own_property_keys(&self) -> Vec<PropertyKey> {
-        self.keys().collect()
+        let mut index_keys: Vec<u32> = self.index_property_keys().copied().collect();
+        if let ObjectData::String(ref string) = self.data {
+            index_keys.extend(0..string.encode_utf16().count() as u32);
+        }
+        index_keys.sort_unstable();
+        index_keys.dedup();
+
+        index_keys
+            .into_iter()
+            .map(PropertyKey::from)
+            .chain(
+                self.as_module_namespace()
+                    .into_iter()
+                    .flat_map(|namespace| namespace.export_names())
+                    .map(PropertyKey::from),
+            )
+            .chain(self.string_property_keys().cloned().map(PropertyKey::from))
+            .chain(self.symbol_property_keys().cloned().map(PropertyKey::from))
+            .collect()
     }
 
     /// The abstract operation ObjectDefineProperties
@@ -252,7 +396,7 @@ impl Object {
         for next_key in keys {
             if let Some(prop_desc) = props.borrow().get_own_property(&next_key) {
                 if prop_desc.enumerable() {
-                    let desc_obj = props.borrow().get(&next_key);
+                    let desc_obj = props.get(&next_key, props.clone().into(), ctx)?;
                     let desc = desc_obj.to_property_descriptor(ctx)?;
                     descriptors.push((next_key, desc));
                 }
@@ -266,47 +410,6 @@ impl Object {
         Ok(())
     }
 
-    /// `Object.setPropertyOf(obj, prototype)`
-    ///
-    /// This method sets the prototype (i.e., the internal `[[Prototype]]` property)
-    /// of a specified object to another object or `null`.
-    ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///  - [MDN documentation][mdn]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-setprototypeof-v
-    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/setPrototypeOf
-    pub fn set_prototype_of(&mut self, _val: Value) -> bool {
-        // debug_assert!(val.is_object() || val.is_null());
-        // let current = self.prototype.clone();
-        // if same_value(&current, &val) {
-        //     return true;
-        // }
-        // if !self.is_extensible() {
-        //     return false;
-        // }
-        // let mut p = val.clone();
-        // let mut done = false;
-        // while !done {
-        //     if p.is_null() {
-        //         done = true
-        //     } else if same_value(&Value::from(self.clone()), &p) {
-        //         return false;
-        //     } else {
-        //         let prototype = p
-        //             .as_object()
-        //             .expect("prototype should be null or object")
-        //             .prototype
-        //             .clone();
-        //         p = prototype;
-        //     }
-        // }
-        // self.prototype = val;
-        // true
-        todo!("Object.setPropertyOf(obj, prototype)")
-    }
-
     /// Returns either the prototype or null
     ///
     /// More information:
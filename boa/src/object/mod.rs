@@ -3,13 +3,22 @@
 use crate::{
     builtins::{
         array::array_iterator::ArrayIterator,
-        function::{BuiltInFunction, Function, FunctionFlags, NativeFunction},
+        function::{
+            arguments::{Arguments, ParameterMap},
+            BuiltInClosure, BuiltInFunction, Function, FunctionFlags, NativeClosure,
+            NativeFunction,
+        },
+        generator::Generator,
         map::ordered_map::OrderedMap,
+        object::for_in_iterator::ForInIterator,
+        promise::Promise,
         string::string_iterator::StringIterator,
         BigInt, Date, RegExp,
     },
     context::StandardConstructor,
+    environment::private_environment_record::PrivateName,
     gc::{Finalize, Trace},
+    module::ModuleNamespace,
     property::{Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
     value::{RcBigInt, RcString, RcSymbol, Value},
     BoaProfiler, Context,
@@ -61,33 +70,60 @@ pub struct Object {
     pub data: ObjectData,
     indexed_properties: FxHashMap<u32, PropertyDescriptor>,
     /// Properties
-    string_properties: FxHashMap<RcString, PropertyDescriptor>,
+    string_properties: OrderedMap<RcString, PropertyDescriptor>,
     /// Symbol Properties
-    symbol_properties: FxHashMap<RcSymbol, PropertyDescriptor>,
+    symbol_properties: OrderedMap<RcSymbol, PropertyDescriptor>,
     /// Instance prototype `__proto__`.
     prototype: Value,
     /// Whether it can have new properties added to it.
     extensible: bool,
+    /// The private fields, methods and accessors of the classes the object is an instance of.
+    private_elements: Vec<(PrivateName, PrivateElement)>,
+}
+
+/// A private member of an object, the value of a private field or the function of a private
+/// method or accessor of a class.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-privateelement-specification-type
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum PrivateElement {
+    /// A private field, like `#x = 1`.
+    Field(Value),
+    /// A private method, like `#m() {}`, which can't be assigned.
+    Method(GcObject),
+    /// A private accessor, like `get #x() {}` and `set #x(value) {}`.
+    Accessor {
+        getter: Option<GcObject>,
+        setter: Option<GcObject>,
+    },
 }
 
 /// Defines the different types of objects.
 #[derive(Debug, Trace, Finalize)]
 pub enum ObjectData {
     Array,
+    Arguments(Arguments),
     ArrayIterator(ArrayIterator),
+    ForInIterator(ForInIterator),
     Map(OrderedMap<Value, Value>),
     RegExp(Box<RegExp>),
     BigInt(RcBigInt),
     Boolean(bool),
     Function(Function),
+    Generator(Generator),
     String(RcString),
     StringIterator(StringIterator),
     Number(f64),
+    Promise(Promise),
     Symbol(RcSymbol),
     Error,
     Ordinary,
     Date(Date),
     Global,
+    ModuleNamespace(ModuleNamespace),
     NativeObject(Box<dyn NativeObject>),
 }
 
@@ -98,12 +134,16 @@ impl Display for ObjectData {
             "{}",
             match self {
                 Self::Array => "Array",
+                Self::Arguments(_) => "Arguments",
                 Self::ArrayIterator(_) => "ArrayIterator",
+                Self::ForInIterator(_) => "ForInIterator",
                 Self::Function(_) => "Function",
+                Self::Generator(_) => "Generator",
                 Self::RegExp(_) => "RegExp",
                 Self::Map(_) => "Map",
                 Self::String(_) => "String",
                 Self::StringIterator(_) => "StringIterator",
+                Self::Promise(_) => "Promise",
                 Self::Symbol(_) => "Symbol",
                 Self::Error => "Error",
                 Self::Ordinary => "Ordinary",
@@ -112,6 +152,7 @@ impl Display for ObjectData {
                 Self::BigInt(_) => "BigInt",
                 Self::Date(_) => "Date",
                 Self::Global => "Global",
+                Self::ModuleNamespace(_) => "ModuleNamespace",
                 Self::NativeObject(_) => "NativeObject",
             }
         )
@@ -125,10 +166,11 @@ impl Default for Object {
         Self {
             data: ObjectData::Ordinary,
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: OrderedMap::new(),
+            symbol_properties: OrderedMap::new(),
             prototype: Value::null(),
             extensible: true,
+            private_elements: Vec::new(),
         }
     }
 }
@@ -147,10 +189,11 @@ impl Object {
         Self {
             data: ObjectData::Function(function),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: OrderedMap::new(),
+            symbol_properties: OrderedMap::new(),
             prototype,
             extensible: true,
+            private_elements: Vec::new(),
         }
     }
 
@@ -174,10 +217,11 @@ impl Object {
         Self {
             data: ObjectData::Boolean(value),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: OrderedMap::new(),
+            symbol_properties: OrderedMap::new(),
             prototype: Value::null(),
             extensible: true,
+            private_elements: Vec::new(),
         }
     }
 
@@ -187,10 +231,11 @@ impl Object {
         Self {
             data: ObjectData::Number(value),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: OrderedMap::new(),
+            symbol_properties: OrderedMap::new(),
             prototype: Value::null(),
             extensible: true,
+            private_elements: Vec::new(),
         }
     }
 
@@ -203,10 +248,11 @@ impl Object {
         Self {
             data: ObjectData::String(value.into()),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: OrderedMap::new(),
+            symbol_properties: OrderedMap::new(),
             prototype: Value::null(),
             extensible: true,
+            private_elements: Vec::new(),
         }
     }
 
@@ -216,10 +262,11 @@ impl Object {
         Self {
             data: ObjectData::BigInt(value),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: OrderedMap::new(),
+            symbol_properties: OrderedMap::new(),
             prototype: Value::null(),
             extensible: true,
+            private_elements: Vec::new(),
         }
     }
 
@@ -232,10 +279,11 @@ impl Object {
         Self {
             data: ObjectData::NativeObject(Box::new(value)),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: OrderedMap::new(),
+            symbol_properties: OrderedMap::new(),
             prototype: Value::null(),
             extensible: true,
+            private_elements: Vec::new(),
         }
     }
 
@@ -297,6 +345,58 @@ impl Object {
         }
     }
 
+    /// Checks if it is a `Generator` object.
+    #[inline]
+    pub fn is_generator(&self) -> bool {
+        matches!(self.data, ObjectData::Generator(_))
+    }
+
+    #[inline]
+    pub fn as_generator(&self) -> Option<&Generator> {
+        match self.data {
+            ObjectData::Generator(ref generator) => Some(generator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_generator_mut(&mut self) -> Option<&mut Generator> {
+        match &mut self.data {
+            ObjectData::Generator(generator) => Some(generator),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `Promise` object.
+    #[inline]
+    pub fn is_promise(&self) -> bool {
+        matches!(self.data, ObjectData::Promise(_))
+    }
+
+    #[inline]
+    pub fn as_promise(&self) -> Option<&Promise> {
+        match self.data {
+            ObjectData::Promise(ref promise) => Some(promise),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_promise_mut(&mut self) -> Option<&mut Promise> {
+        match &mut self.data {
+            ObjectData::Promise(promise) => Some(promise),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_for_in_iterator_mut(&mut self) -> Option<&mut ForInIterator> {
+        match &mut self.data {
+            ObjectData::ForInIterator(iter) => Some(iter),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn as_string_iterator_mut(&mut self) -> Option<&mut StringIterator> {
         match &mut self.data {
@@ -305,7 +405,33 @@ impl Object {
         }
     }
 
+    /// Gets the parameter map of a mapped arguments object.
+    #[inline]
+    pub fn as_mapped_arguments(&self) -> Option<&ParameterMap> {
+        match self.data {
+            ObjectData::Arguments(Arguments::Mapped(ref map)) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Gets the parameter map of a mapped arguments object, mutably.
+    #[inline]
+    pub fn as_mapped_arguments_mut(&mut self) -> Option<&mut ParameterMap> {
+        match self.data {
+            ObjectData::Arguments(Arguments::Mapped(ref mut map)) => Some(map),
+            _ => None,
+        }
+    }
+
     /// Checks if it is a `Map` object.pub
+    #[inline]
+    pub fn as_module_namespace(&self) -> Option<&ModuleNamespace> {
+        match self.data {
+            ObjectData::ModuleNamespace(ref namespace) => Some(namespace),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn is_map(&self) -> bool {
         matches!(self.data, ObjectData::Map(_))
@@ -355,6 +481,14 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_function_mut(&mut self) -> Option<&mut Function> {
+        match self.data {
+            ObjectData::Function(ref mut function) => Some(function),
+            _ => None,
+        }
+    }
+
     /// Checks if it a Symbol object.
     #[inline]
     pub fn is_symbol(&self) -> bool {
@@ -466,6 +600,39 @@ impl Object {
         object
     }
 
+    /// Gets the private member of the object with the Private Name `name`, if it has one.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privateelementfind
+    #[inline]
+    pub fn private_element_find(&self, name: &PrivateName) -> Option<&PrivateElement> {
+        self.private_elements
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, element)| element)
+    }
+
+    /// Mutable version of [`private_element_find`](#method.private_element_find).
+    #[inline]
+    pub(crate) fn private_element_find_mut(
+        &mut self,
+        name: &PrivateName,
+    ) -> Option<&mut PrivateElement> {
+        self.private_elements
+            .iter_mut()
+            .find(|(key, _)| key == name)
+            .map(|(_, element)| element)
+    }
+
+    /// Adds the private member `element` to the object, which must not already have a private
+    /// member with the same Private Name.
+    #[inline]
+    pub(crate) fn private_element_add(&mut self, name: PrivateName, element: PrivateElement) {
+        self.private_elements.push((name, element));
+    }
+
     /// Returns `true` if it holds an Rust type that implements `NativeObject`.
     #[inline]
     pub fn is_native_object(&self) -> bool {
@@ -580,11 +747,18 @@ where
     }
 }
 
+/// The native code of a function built by a `FunctionBuilder`.
+#[derive(Debug)]
+enum NativeBody {
+    Function(BuiltInFunction),
+    Closure(BuiltInClosure, Value),
+}
+
 /// Builder for creating native function objects
 #[derive(Debug)]
 pub struct FunctionBuilder<'context> {
     context: &'context mut Context,
-    function: BuiltInFunction,
+    function: NativeBody,
     name: Option<String>,
     length: usize,
     callable: bool,
@@ -597,7 +771,25 @@ impl<'context> FunctionBuilder<'context> {
     pub fn new(context: &'context mut Context, function: NativeFunction) -> Self {
         Self {
             context,
-            function: function.into(),
+            function: NativeBody::Function(function.into()),
+            name: None,
+            length: 0,
+            callable: true,
+            constructable: false,
+        }
+    }
+
+    /// Create a new `FunctionBuilder` for a closure, `captures` is given to every call of
+    /// `function`.
+    #[inline]
+    pub fn closure(
+        context: &'context mut Context,
+        function: NativeClosure,
+        captures: Value,
+    ) -> Self {
+        Self {
+            context,
+            function: NativeBody::Closure(function.into(), captures),
             name: None,
             length: 0,
             callable: true,
@@ -646,14 +838,34 @@ impl<'context> FunctionBuilder<'context> {
         self
     }
 
+    /// Creates the `Function` for the native code of the builder.
+    fn native_function(&self) -> Function {
+        let flags = FunctionFlags::from_parameters(self.callable, self.constructable);
+        match self.function {
+            NativeBody::Function(function) => Function::BuiltIn(function, flags),
+            NativeBody::Closure(function, ref captures) => Function::Closure {
+                function,
+                captures: captures.clone(),
+                flags,
+            },
+        }
+    }
+
     /// Build the function object.
     #[inline]
     pub fn build(&mut self) -> GcObject {
+        GcObject::new(self.build_object())
+    }
+
+    /// Initializes a function object of the standard objects, like the `eval` function.
+    pub(crate) fn build_standard_function(&mut self, object: &GcObject) {
+        *object.borrow_mut() = self.build_object();
+    }
+
+    /// Creates the object of the function.
+    fn build_object(&mut self) -> Object {
         let mut function = Object::function(
-            Function::BuiltIn(
-                self.function,
-                FunctionFlags::from_parameters(self.callable, self.constructable),
-            ),
+            self.native_function(),
             self.context
                 .standard_objects()
                 .function_object()
@@ -667,17 +879,13 @@ impl<'context> FunctionBuilder<'context> {
             function.insert_property("name", "", attribute);
         }
         function.insert_property("length", self.length, attribute);
-
-        GcObject::new(function)
+        function
     }
 
     /// Initializes the `Function.prototype` function object.
     pub(crate) fn build_function_prototype(&mut self, object: &GcObject) {
         let mut object = object.borrow_mut();
-        object.data = ObjectData::Function(Function::BuiltIn(
-            self.function,
-            FunctionFlags::from_parameters(self.callable, self.constructable),
-        ));
+        object.data = ObjectData::Function(self.native_function());
         object.set_prototype_instance(
             self.context
                 .standard_objects()
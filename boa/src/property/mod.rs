@@ -303,7 +303,7 @@ impl PropertyDescriptor {
 /// - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-ispropertykey
-#[derive(Trace, Finalize, Debug, Clone)]
+#[derive(Trace, Finalize, Debug, Clone, PartialEq, Eq)]
 pub enum PropertyKey {
     String(RcString),
     Symbol(RcSymbol),
@@ -24,6 +24,8 @@ pub struct Realm {
     pub global_obj: Value,
     pub global_env: Gc<GcCell<GlobalEnvironmentRecord>>,
     pub environment: LexicalEnvironment,
+    /// The template objects of the tagged templates that were evaluated, by template site.
+    pub template_map: FxHashMap<usize, Value>,
 }
 
 impl Realm {
@@ -43,6 +45,7 @@ impl Realm {
             global_obj: global.clone(),
             global_env,
             environment: LexicalEnvironment::new(global),
+            template_map: FxHashMap::default(),
         }
     }
 }
@@ -58,6 +61,7 @@ fn new_global_environment(global: Value, this_value: Value) -> Gc<GcCell<GlobalE
         /// with each object Environment Record. By default, the value of withEnvironment is false
         /// for any object Environment Record.
         with_environment: false,
+        unscopables: None,
     };
 
     let dcl_rec = DeclarativeEnvironmentRecord {
@@ -34,18 +34,37 @@ pub struct ArrayDecl {
 impl Executable for ArrayDecl {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("ArrayDecl", "exec");
-        let array = Array::new_array(interpreter)?;
-        let mut elements = Vec::new();
-        for elem in self.as_ref() {
+        // The resume step is the index of the element that was interrupted, with the array and
+        // the elements that were already evaluated.
+        let (array, mut elements, start) = match interpreter.executor().take_resume_point(self) {
+            Some(point) => {
+                let values = point.values();
+                (values[0].clone(), values[1..].to_vec(), point.step())
+            }
+            None => (Array::new_array(interpreter)?, Vec::new(), 0),
+        };
+        let frame = |elements: &[Value]| {
+            let mut values = vec![array.clone()];
+            values.extend_from_slice(elements);
+            values
+        };
+        for (i, elem) in self.as_ref().iter().enumerate().skip(start) {
             if let Node::Spread(ref x) = elem {
-                let val = x.run(interpreter)?;
+                let val = x.run(interpreter);
+                let val = interpreter
+                    .executor()
+                    .save_resume_point(val, self, i, || frame(&elements))?;
                 let mut vals = interpreter.extract_array_properties(&val).unwrap();
                 elements.append(&mut vals);
                 continue; // Don't push array after spread
             }
-            elements.push(elem.run(interpreter)?);
+            let val = elem.run(interpreter);
+            let val = interpreter
+                .executor()
+                .save_resume_point(val, self, i, || frame(&elements))?;
+            elements.push(val);
         }
-        Array::add_to_array_object(&array, &elements)?;
+        Array::add_to_array_object(&array, &elements, interpreter)?;
 
         Ok(array)
     }
@@ -0,0 +1,79 @@
+use crate::{
+    exec::{Executable, Interruption, ResumeAction},
+    syntax::ast::node::Node,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `await` operator is used to wait for a promise inside an async function.
+///
+/// The async function is suspended until the awaited value is settled, the `await` expression
+/// then evaluates to the fulfillment value, or throws the rejection reason.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AwaitExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/await
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Await {
+    expr: Box<Node>,
+}
+
+impl Await {
+    /// Creates an `Await` AST node.
+    pub fn new<E>(expr: E) -> Self
+    where
+        E: Into<Node>,
+    {
+        Self {
+            expr: Box::new(expr.into()),
+        }
+    }
+
+    /// Gets the expression that is awaited.
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+}
+
+impl Executable for Await {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Await", "exec");
+
+        // The async function is being resumed at this `await`.
+        if interpreter.executor().take_resume_point(self).is_some() {
+            return match interpreter.executor().take_resume_action() {
+                Some(ResumeAction::Next(value)) => Ok(value),
+                Some(ResumeAction::Throw(exception)) => Err(exception),
+                _ => unreachable!("async functions are resumed with a value or an exception"),
+            };
+        }
+
+        let value = self.expr().run(interpreter)?;
+        interpreter
+            .executor()
+            .set_interruption(Interruption::Await(value));
+        interpreter
+            .executor()
+            .save_resume_point(Err(Value::undefined()), self, 0, Vec::new)
+    }
+}
+
+impl fmt::Display for Await {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "await {}", self.expr)
+    }
+}
+
+impl From<Await> for Node {
+    fn from(r#await: Await) -> Node {
+        Node::Await(r#await)
+    }
+}
@@ -3,7 +3,8 @@
 use super::{Node, StatementList};
 use crate::{
     environment::lexical_environment::new_declarative_environment, exec::Executable,
-    exec::InterpreterState, BoaProfiler, Context, Result, Value,
+    exec::InterpreterState, syntax::ast::node::statement_list::instantiate_lexical_declarations,
+    BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
 use std::fmt;
@@ -27,11 +28,11 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#prod-BlockStatement
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/block
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Block {
     #[cfg_attr(feature = "serde", serde(flatten))]
     statements: StatementList,
+    label: Option<Box<str>>,
 }
 
 impl Block {
@@ -40,8 +41,19 @@ impl Block {
         self.statements.statements()
     }
 
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_ref().map(Box::as_ref)
+    }
+
+    pub fn set_label(&mut self, label: Box<str>) {
+        self.label = Some(label);
+    }
+
     /// Implements the display formatting with indentation.
     pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        if let Some(label) = self.label() {
+            write!(f, "{}: ", label)?;
+        }
         writeln!(f, "{{")?;
         self.statements.display(f, indentation + 1)?;
         write!(f, "{}}}", "    ".repeat(indentation))
@@ -51,32 +63,53 @@ impl Block {
 impl Executable for Block {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Block", "exec");
-        {
+        // A resumed generator has already restored the environment of the block.
+        let start = if let Some(point) = interpreter.executor().take_resume_point(self) {
+            point.step()
+        } else {
             let env = &mut interpreter.realm_mut().environment;
             env.push(new_declarative_environment(Some(
                 env.get_current_environment_ref().clone(),
             )));
-        }
+            if let Err(e) = instantiate_lexical_declarations(self.statements(), true, interpreter) {
+                let _ = interpreter.realm_mut().environment.pop();
+                return Err(e);
+            }
+            0
+        };
 
         // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
         // The return value is uninitialized, which means it defaults to Value::Undefined
         let mut obj = Value::default();
-        for statement in self.statements() {
-            obj = statement.run(interpreter)?;
+        for (i, statement) in self.statements().iter().enumerate().skip(start) {
+            let result = statement.run(interpreter);
+            let result = interpreter
+                .executor()
+                .save_resume_point(result, self, i, Vec::new);
+            // A thrown error pops the block env too, unless it has to be kept for a suspended
+            // generator.
+            if result.is_err() && !interpreter.executor().is_suspending() {
+                let _ = interpreter.realm_mut().environment.pop();
+            }
+            obj = result?;
 
             match interpreter.executor().get_current_state() {
                 InterpreterState::Return => {
                     // Early return.
                     break;
                 }
-                InterpreterState::Break(_label) => {
-                    // TODO, break to a label.
-
-                    // Early break.
+                InterpreterState::Break(label) => {
+                    // A break to the label of the block ends the block, and any other break
+                    // ends the enclosing statement.
+                    if label.is_some() && label.as_deref() == self.label() {
+                        interpreter
+                            .executor()
+                            .set_current_state(InterpreterState::Executing);
+                    }
                     break;
                 }
                 InterpreterState::Continue(_label) => {
-                    // TODO, continue to a label
+                    // A continue always targets an enclosing loop.
                     break;
                 }
                 InterpreterState::Executing => {
@@ -99,6 +132,7 @@ where
     fn from(list: T) -> Self {
         Self {
             statements: list.into(),
+            label: None,
         }
     }
 }
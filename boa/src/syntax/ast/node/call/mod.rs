@@ -1,8 +1,13 @@
 use crate::{
+    builtins::Eval,
     exec::Executable,
     exec::InterpreterState,
-    syntax::ast::node::{join_nodes, Node},
-    value::{Type, Value},
+    object::GcObject,
+    syntax::ast::node::{
+        field::{resolve_private_name, super_reference_base},
+        join_nodes, Node,
+    },
+    value::Value,
     BoaProfiler, Context, Result,
 };
 use gc::{Finalize, Trace};
@@ -54,45 +59,131 @@ impl Call {
     pub fn args(&self) -> &[Node] {
         &self.args
     }
+
+    /// Checks if the call is a direct eval, a call of the `eval` function with the name `eval`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function-calls-runtime-semantics-evaluation
+    fn is_direct_eval(&self, func: &Value, interpreter: &Context) -> bool {
+        match (self.expr(), func) {
+            (Node::Identifier(name), Value::Object(func)) => {
+                name.as_ref() == "eval"
+                    && GcObject::equals(func, interpreter.standard_objects().eval_function())
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Evaluates the callee of a call, returning the `this` value of the call and the function.
+///
+/// This is also used for the tag of a tagged template, which is called like a function.
+pub(in crate::syntax::ast::node) fn run_callee(
+    expr: &Node,
+    interpreter: &mut Context,
+) -> Result<(Value, Value)> {
+    Ok(match expr {
+        Node::GetConstField(ref get_const_field) => {
+            let obj = get_const_field.obj().run(interpreter)?;
+            let func = obj.to_object(interpreter)?.get(
+                &get_const_field.field().into(),
+                obj.clone(),
+                interpreter,
+            )?;
+            (obj, func)
+        }
+        Node::GetField(ref get_field) => {
+            let obj = get_field.obj().run(interpreter)?;
+            let field = get_field.field().run(interpreter)?;
+            let key = field.to_property_key(interpreter)?;
+            let func = obj
+                .to_object(interpreter)?
+                .get(&key, obj.clone(), interpreter)?;
+            (obj, func)
+        }
+        Node::GetPrivateField(ref get_private_field) => {
+            let obj = get_private_field.obj().run(interpreter)?;
+            let name = resolve_private_name(get_private_field.field(), interpreter)?;
+            let func = obj
+                .to_object(interpreter)?
+                .private_get(&name, interpreter)?;
+            (obj, func)
+        }
+        Node::GetSuperConstField(ref get_super_field) => {
+            let (base, this) = super_reference_base(interpreter)?;
+            let func = base.get(&get_super_field.field().into(), this.clone(), interpreter)?;
+            (this, func)
+        }
+        Node::GetSuperField(ref get_super_field) => {
+            let field = get_super_field.field().run(interpreter)?;
+            let key = field.to_property_key(interpreter)?;
+            let (base, this) = super_reference_base(interpreter)?;
+            let func = base.get(&key, this.clone(), interpreter)?;
+            (this, func)
+        }
+        Node::Optional(ref optional) => optional
+            .run_reference(optional.chain().len(), interpreter)?
+            .unwrap_or_default(),
+        // A function found in the object of a `with` statement gets the object as `this`.
+        Node::Identifier(ref name) => {
+            let func = expr.run(interpreter)?;
+            let this = interpreter
+                .resolve_binding(name.as_ref())?
+                .map_or_else(Value::undefined, |env| env.borrow().with_base_object());
+            (this, func)
+        }
+        // Plain calls pass `undefined` as the `this` value, non-strict functions replace it with
+        // the global object.
+        _ => (Value::undefined(), expr.run(interpreter)?),
+    })
 }
 
 impl Executable for Call {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Call", "exec");
-        let (this, func) = match self.expr() {
-            Node::GetConstField(ref get_const_field) => {
-                let mut obj = get_const_field.obj().run(interpreter)?;
-                if obj.get_type() != Type::Object {
-                    obj = Value::Object(obj.to_object(interpreter)?);
-                }
-                (obj.clone(), obj.get_field(get_const_field.field()))
-            }
-            Node::GetField(ref get_field) => {
-                let obj = get_field.obj().run(interpreter)?;
-                let field = get_field.field().run(interpreter)?;
-                (
-                    obj.clone(),
-                    obj.get_field(field.to_property_key(interpreter)?),
-                )
-            }
-            _ => (
-                interpreter.realm().global_obj.clone(),
-                self.expr().run(interpreter)?,
-            ), // 'this' binding should come from the function's self-contained environment
+        // The resume step is the index of the argument that was interrupted, with the `this`
+        // value, the function and the arguments that were already evaluated.
+        let (this, func, mut v_args, start) =
+            if let Some(point) = interpreter.executor().take_resume_point(self) {
+                let values = point.values();
+                let v_args = values[2..].to_vec();
+                (values[0].clone(), values[1].clone(), v_args, point.step())
+            } else {
+                let (this, func) = run_callee(self.expr(), interpreter)?;
+                (this, func, Vec::with_capacity(self.args().len()), 0)
+            };
+        let frame = |v_args: &[Value]| {
+            let mut values = vec![this.clone(), func.clone()];
+            values.extend_from_slice(v_args);
+            values
         };
-        let mut v_args = Vec::with_capacity(self.args().len());
-        for arg in self.args() {
+        for (i, arg) in self.args().iter().enumerate().skip(start) {
             if let Node::Spread(ref x) = arg {
-                let val = x.run(interpreter)?;
+                let val = x.run(interpreter);
+                let val = interpreter
+                    .executor()
+                    .save_resume_point(val, self, i, || frame(&v_args))?;
                 let mut vals = interpreter.extract_array_properties(&val).unwrap();
                 v_args.append(&mut vals);
                 break; // after spread we don't accept any new arguments
             }
-            v_args.push(arg.run(interpreter)?);
+            let val = arg.run(interpreter);
+            let val = interpreter
+                .executor()
+                .save_resume_point(val, self, i, || frame(&v_args))?;
+            v_args.push(val);
         }
 
-        // execute the function call itself
-        let fnct_result = interpreter.call(&func, &this, &v_args);
+        // execute the function call itself, a call of the `eval` function by its plain name is a
+        // direct eval, which evaluates the code in the current scope.
+        let fnct_result = if self.is_direct_eval(&func, interpreter) {
+            let x = v_args.first().cloned().unwrap_or_default();
+            Eval::perform_eval(&x, true, interpreter)
+        } else {
+            interpreter.call(&func, &this, &v_args)
+        };
 
         // unset the early return flag
         interpreter
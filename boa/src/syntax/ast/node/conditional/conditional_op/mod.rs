@@ -57,11 +57,19 @@ impl ConditionalOp {
 
 impl Executable for ConditionalOp {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        Ok(if self.cond().run(interpreter)?.to_boolean() {
-            self.if_true().run(interpreter)?
+        // The resume step is the branch that was taken, `1` if true and `0` if false.
+        let cond = match interpreter.executor().take_resume_point(self) {
+            Some(point) => point.step() == 1,
+            None => self.cond().run(interpreter)?.to_boolean(),
+        };
+        let result = if cond {
+            self.if_true().run(interpreter)
         } else {
-            self.if_false().run(interpreter)?
-        })
+            self.if_false().run(interpreter)
+        };
+        interpreter
+            .executor()
+            .save_resume_point(result, self, cond as usize, Vec::new)
     }
 }
 
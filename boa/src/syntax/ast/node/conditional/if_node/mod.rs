@@ -76,10 +76,21 @@ impl If {
 
 impl Executable for If {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        Ok(if self.cond().run(interpreter)?.to_boolean() {
-            self.body().run(interpreter)?
+        // The resume step is the branch that was taken, `1` for the body and `0` for the else.
+        let cond = match interpreter.executor().take_resume_point(self) {
+            Some(point) => point.step() == 1,
+            None => self.cond().run(interpreter)?.to_boolean(),
+        };
+        Ok(if cond {
+            let result = self.body().run(interpreter);
+            interpreter
+                .executor()
+                .save_resume_point(result, self, 1, Vec::new)?
         } else if let Some(ref else_e) = self.else_node() {
-            else_e.run(interpreter)?
+            let result = else_e.run(interpreter);
+            interpreter
+                .executor()
+                .save_resume_point(result, self, 0, Vec::new)?
         } else {
             Value::undefined()
         })
@@ -49,8 +49,8 @@ impl ArrowFunctionDecl {
     }
 
     /// Gets the body of the arrow function.
-    pub(crate) fn body(&self) -> &[Node] {
-        &self.body.statements()
+    pub(crate) fn body(&self) -> &StatementList {
+        &self.body
     }
 
     /// Implements the display formatting with indentation.
@@ -70,7 +70,7 @@ impl Executable for ArrowFunctionDecl {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         Ok(interpreter.create_function(
             self.params().to_vec(),
-            self.body().to_vec(),
+            self.body().clone(),
             FunctionFlags::CALLABLE
                 | FunctionFlags::CONSTRUCTABLE
                 | FunctionFlags::LEXICAL_THIS_MODE,
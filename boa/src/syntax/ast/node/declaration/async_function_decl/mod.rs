@@ -0,0 +1,119 @@
+use crate::{
+    builtins::function::FunctionFlags,
+    exec::Executable,
+    property::{Attribute, DataDescriptor},
+    syntax::ast::node::{
+        declaration::function_decl::bind_function_declaration, join_nodes, FormalParameter, Node,
+        StatementList,
+    },
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `async function` declaration defines an async function with the specified parameters.
+///
+/// Calling an async function returns a `Promise`, which is settled once the body of the function
+/// completes. The body can use `await` to wait for other promises.
+///
+/// An async function can also be created using an expression (see
+/// [async function expression][async_expr]).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncFunctionDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+/// [async_expr]: ../enum.Node.html#variant.AsyncFunctionExpr
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct AsyncFunctionDecl {
+    name: Box<str>,
+    parameters: Box<[FormalParameter]>,
+    body: StatementList,
+}
+
+impl AsyncFunctionDecl {
+    /// Creates a new async function declaration.
+    pub(in crate::syntax) fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    where
+        N: Into<Box<str>>,
+        P: Into<Box<[FormalParameter]>>,
+        B: Into<StatementList>,
+    {
+        Self {
+            name: name.into(),
+            parameters: parameters.into(),
+            body: body.into(),
+        }
+    }
+
+    /// Gets the name of the async function declaration.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the list of parameters of the async function declaration.
+    pub fn parameters(&self) -> &[FormalParameter] {
+        &self.parameters
+    }
+
+    /// Gets the body of the async function declaration.
+    pub fn body(&self) -> &[Node] {
+        self.body.statements()
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        write!(f, "async function {}(", self.name)?;
+        join_nodes(f, &self.parameters)?;
+        f.write_str(") {{")?;
+
+        self.body.display(f, indentation + 1)?;
+
+        writeln!(f, "}}")
+    }
+}
+
+impl Executable for AsyncFunctionDecl {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("AsyncFunctionDecl", "exec");
+        let val = interpreter.create_function(
+            self.parameters().to_vec(),
+            self.body.clone(),
+            FunctionFlags::CALLABLE | FunctionFlags::ASYNC,
+        );
+
+        // Set the name and assign it in the current environment
+        val.set_property(
+            "name",
+            DataDescriptor::new(
+                self.name(),
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
+        bind_function_declaration(self.name(), val, false, interpreter);
+
+        Ok(Value::undefined())
+    }
+}
+
+impl From<AsyncFunctionDecl> for Node {
+    fn from(decl: AsyncFunctionDecl) -> Self {
+        Self::AsyncFunctionDecl(decl)
+    }
+}
+
+impl fmt::Display for AsyncFunctionDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
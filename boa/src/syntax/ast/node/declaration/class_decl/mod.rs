@@ -0,0 +1,678 @@
+use crate::{
+    builtins::function::{ClassFieldDefinition, ClassFieldName, FunctionFlags},
+    environment::{
+        lexical_environment::{new_private_environment, VariableScope},
+        private_environment_record::PrivateName,
+    },
+    exec::Executable,
+    object::{GcObject, Object, PrivateElement, PROTOTYPE},
+    property::{Attribute, PropertyKey},
+    syntax::ast::node::{
+        field::resolve_private_name, FormalParameter, FunctionExpr, Identifier,
+        MethodDefinitionKind, Node, PropertyName, Return, Spread, StatementList, SuperCall,
+    },
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `class` declaration creates a new class with a given name using prototype-based
+/// inheritance.
+///
+/// Unlike function declarations, class declarations are not hoisted: the class can only be
+/// used after the declaration has been evaluated.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ClassDecl {
+    class: Class,
+}
+
+impl ClassDecl {
+    /// Creates a new class declaration.
+    pub(in crate::syntax) fn new<N, S, C, E>(
+        name: N,
+        super_ref: S,
+        constructor: C,
+        elements: E,
+    ) -> Self
+    where
+        N: Into<Box<str>>,
+        S: Into<Option<Node>>,
+        C: Into<Option<FunctionExpr>>,
+        E: Into<Box<[ClassElement]>>,
+    {
+        Self {
+            class: Class::new(Some(name.into()), super_ref, constructor, elements),
+        }
+    }
+
+    /// Gets the name of the class.
+    pub fn name(&self) -> &str {
+        self.class
+            .name()
+            .expect("class declarations always have a name")
+    }
+
+    /// Gets the class definition.
+    pub fn class(&self) -> &Class {
+        &self.class
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        self.class.display(f, indentation)
+    }
+}
+
+impl Executable for ClassDecl {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("ClassDecl", "exec");
+        let class = self.class.evaluate(interpreter)?;
+
+        // The binding was created when the scope of the declaration was entered.
+        let environment = &mut interpreter.realm_mut().environment;
+        if !environment
+            .get_current_environment_ref()
+            .borrow()
+            .has_binding(self.name())
+        {
+            environment.create_mutable_binding(self.name().to_owned(), false, VariableScope::Block);
+        }
+        environment.initialize_binding(self.name(), class);
+
+        Ok(Value::undefined())
+    }
+}
+
+impl From<ClassDecl> for Node {
+    fn from(decl: ClassDecl) -> Self {
+        Self::ClassDecl(decl)
+    }
+}
+
+impl fmt::Display for ClassDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+/// The definition of a class, shared by class declarations and class expressions.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassTail
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Class {
+    name: Option<Box<str>>,
+    super_ref: Option<Box<Node>>,
+    constructor: Option<FunctionExpr>,
+    elements: Box<[ClassElement]>,
+}
+
+impl Class {
+    /// Creates a new class definition.
+    pub(in crate::syntax) fn new<S, C, E>(
+        name: Option<Box<str>>,
+        super_ref: S,
+        constructor: C,
+        elements: E,
+    ) -> Self
+    where
+        S: Into<Option<Node>>,
+        C: Into<Option<FunctionExpr>>,
+        E: Into<Box<[ClassElement]>>,
+    {
+        Self {
+            name,
+            super_ref: super_ref.into().map(Box::new),
+            constructor: constructor.into(),
+            elements: elements.into(),
+        }
+    }
+
+    /// Gets the name of the class, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(Box::as_ref)
+    }
+
+    /// Gets the expression after `extends`, if any.
+    pub fn super_ref(&self) -> Option<&Node> {
+        self.super_ref.as_ref().map(Box::as_ref)
+    }
+
+    /// Gets the explicit `constructor` method of the class, if any.
+    pub fn constructor(&self) -> Option<&FunctionExpr> {
+        self.constructor.as_ref()
+    }
+
+    /// Gets the methods, getters, setters, fields and static initialization blocks of the class.
+    pub fn elements(&self) -> &[ClassElement] {
+        &self.elements
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("class")?;
+        if let Some(ref name) = self.name {
+            write!(f, " {}", name)?;
+        }
+        if let Some(ref super_ref) = self.super_ref {
+            write!(f, " extends {}", super_ref)?;
+        }
+        writeln!(f, " {{")?;
+
+        let indent = "    ".repeat(indentation + 1);
+        if let Some(ref constructor) = self.constructor {
+            write!(f, "{}constructor", indent)?;
+            constructor.display_method(f, indentation + 1)?;
+            writeln!(f)?;
+        }
+        for element in self.elements.iter() {
+            f.write_str(&indent)?;
+            element.display(f, indentation + 1)?;
+            writeln!(f)?;
+        }
+
+        write!(f, "{}}}", "    ".repeat(indentation))
+    }
+
+    /// Creates the constructor function of the class, along with its prototype and methods.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classdefinitionevaluation
+    pub(in crate::syntax::ast::node) fn evaluate(
+        &self,
+        interpreter: &mut Context,
+    ) -> Result<Value> {
+        // The class scope holds the binding of the class name visible from inside the class,
+        // and the Private Names of the private members of the class.
+        {
+            let env = &mut interpreter.realm_mut().environment;
+            env.push(new_private_environment(
+                Some(env.get_current_environment_ref().clone()),
+                self.elements()
+                    .iter()
+                    .filter_map(|element| element.name()?.private_name()),
+            ));
+            if let Some(name) = self.name() {
+                env.create_immutable_binding(name.to_owned(), true, VariableScope::Block);
+            }
+        }
+
+        let result = self.evaluate_in_class_scope(interpreter);
+
+        let _ = interpreter.realm_mut().environment.pop();
+        result
+    }
+
+    fn evaluate_in_class_scope(&self, interpreter: &mut Context) -> Result<Value> {
+        let function_prototype: Value = interpreter
+            .standard_objects()
+            .function_object()
+            .prototype()
+            .into();
+
+        let (proto_parent, constructor_parent) = match self.super_ref() {
+            None => (
+                interpreter
+                    .standard_objects()
+                    .object_object()
+                    .prototype()
+                    .into(),
+                function_prototype,
+            ),
+            Some(super_ref) => {
+                let superclass = super_ref.run(interpreter)?;
+                match superclass {
+                    Value::Null => (Value::null(), function_prototype),
+                    Value::Object(ref object) if object.borrow().is_constructable() => {
+                        let proto_parent =
+                            object.get(&PROTOTYPE.into(), superclass.clone(), interpreter)?;
+                        if !proto_parent.is_object() && !proto_parent.is_null() {
+                            return interpreter.throw_type_error(format!(
+                                "Class extends value does not have valid prototype property {}",
+                                proto_parent.display()
+                            ));
+                        }
+                        (proto_parent, superclass.clone())
+                    }
+                    _ => {
+                        return interpreter.throw_type_error(format!(
+                            "Class extends value {} is not a constructor or null",
+                            superclass.display()
+                        ))
+                    }
+                }
+            }
+        };
+
+        let proto = GcObject::new(Object::create(proto_parent));
+
+        // Classes without a constructor get a default one, which forwards its arguments to
+        // the parent constructor for derived classes.
+        let (params, body) = match self.constructor() {
+            Some(constructor) => (
+                constructor.parameters().to_vec(),
+                constructor.body().to_vec(),
+            ),
+            None if self.super_ref().is_some() => (
+                vec![FormalParameter::new("args", None, true)],
+                vec![SuperCall::new(vec![Spread::new(Identifier::from("args")).into()]).into()],
+            ),
+            None => (Vec::new(), Vec::new()),
+        };
+        // All parts of a class are strict mode code.
+        let mut body = StatementList::from(body);
+        body.set_strict(true);
+        let mut flags = FunctionFlags::CALLABLE
+            | FunctionFlags::CONSTRUCTABLE
+            | FunctionFlags::CLASS_CONSTRUCTOR;
+        if self.super_ref().is_some() {
+            flags |= FunctionFlags::DERIVED_CONSTRUCTOR;
+        }
+        let constructor = interpreter.create_function(params, body, flags);
+        let constructor_object = constructor
+            .as_gc_object()
+            .expect("functions are always objects");
+        {
+            let mut constructor_object = constructor_object.borrow_mut();
+            constructor_object.set_prototype_instance(constructor_parent);
+            constructor_object
+                .as_function_mut()
+                .expect("class constructor is a function")
+                .set_home_object(proto.clone());
+            constructor_object.insert_property(
+                PROTOTYPE,
+                proto.clone(),
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            );
+            constructor_object.insert_property(
+                "name",
+                self.name().unwrap_or_default(),
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            );
+        }
+        proto.borrow_mut().insert_property(
+            "constructor",
+            constructor.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+
+        let mut instance_fields = Vec::new();
+        let mut instance_private_methods = Vec::new();
+        let mut static_private_methods = Vec::new();
+        let mut static_elements = Vec::new();
+        for element in self.elements().iter() {
+            let home = if element.is_static() {
+                &constructor_object
+            } else {
+                &proto
+            };
+            match element {
+                ClassElement::MethodDefinition {
+                    kind,
+                    name: ClassElementName::PropertyName(name),
+                    is_static,
+                    method,
+                } => {
+                    let key = property_key(name, *is_static, interpreter)?;
+                    method.define_method(*kind, home, key, false, interpreter)
+                }
+                ClassElement::MethodDefinition {
+                    kind,
+                    name: ClassElementName::PrivateName(name),
+                    is_static,
+                    method,
+                } => {
+                    let private_name = resolve_private_name(name, interpreter)?;
+                    let function =
+                        method.create_method(*kind, home, &private_name.to_string(), interpreter);
+                    let methods = if *is_static {
+                        &mut static_private_methods
+                    } else {
+                        &mut instance_private_methods
+                    };
+                    add_private_method(methods, private_name, *kind, function);
+                }
+                ClassElement::FieldDefinition {
+                    name,
+                    is_static,
+                    initializer,
+                } => {
+                    let name = match name {
+                        ClassElementName::PropertyName(name) => {
+                            ClassFieldName::Public(property_key(name, *is_static, interpreter)?)
+                        }
+                        ClassElementName::PrivateName(name) => {
+                            ClassFieldName::Private(resolve_private_name(name, interpreter)?)
+                        }
+                    };
+                    let initializer = initializer.as_ref().map(|initializer| {
+                        let body = vec![Return::new(initializer.clone(), None).into()];
+                        create_initializer(body, home, interpreter)
+                    });
+                    let field = ClassFieldDefinition::new(name, initializer);
+                    if *is_static {
+                        static_elements.push(StaticElement::Field(field));
+                    } else {
+                        instance_fields.push(field);
+                    }
+                }
+                ClassElement::StaticBlock(body) => {
+                    let block = create_initializer(body.statements().to_vec(), home, interpreter);
+                    static_elements.push(StaticElement::Block(block));
+                }
+            }
+        }
+        constructor_object
+            .borrow_mut()
+            .as_function_mut()
+            .expect("class constructor is a function")
+            .set_class_elements(instance_fields, instance_private_methods);
+
+        if let Some(name) = self.name() {
+            interpreter
+                .realm_mut()
+                .environment
+                .initialize_binding(name, constructor.clone());
+        }
+
+        for (name, method) in static_private_methods {
+            constructor_object.add_private_element(&name, method, interpreter)?;
+        }
+        for element in static_elements {
+            match element {
+                StaticElement::Field(field) => {
+                    constructor_object.define_field(&field, interpreter)?
+                }
+                StaticElement::Block(block) => {
+                    block.call(&constructor, &[], interpreter)?;
+                }
+            }
+        }
+
+        Ok(constructor)
+    }
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+/// The name of an element of a class, which is either a property name or the name of a private
+/// member.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ClassElementName {
+    /// A property name, like `x` in `x() {}`, or `[x]` if it is computed.
+    PropertyName(PropertyName),
+    /// The name of a private member, like `#x` in `#x() {}`, without the `#`.
+    PrivateName(Box<str>),
+}
+
+impl ClassElementName {
+    /// Gets the name of a private member, without the `#`.
+    pub fn private_name(&self) -> Option<&str> {
+        match self {
+            Self::PrivateName(name) => Some(name),
+            Self::PropertyName(_) => None,
+        }
+    }
+}
+
+impl From<&str> for ClassElementName {
+    fn from(name: &str) -> Self {
+        Self::PropertyName(name.into())
+    }
+}
+
+impl fmt::Display for ClassElementName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PropertyName(name) => write!(f, "{}", name),
+            Self::PrivateName(name) => write!(f, "#{}", name),
+        }
+    }
+}
+
+/// An element of the body of a class: a method, getter or setter, a field, or a static
+/// initialization block.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes#Class_body_and_method_definitions
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ClassElement {
+    /// A method, getter or setter.
+    MethodDefinition {
+        kind: MethodDefinitionKind,
+        name: ClassElementName,
+        is_static: bool,
+        method: FunctionExpr,
+    },
+    /// A field, like `x = 1;` or `static #y;`, which is defined on each instance of the class or
+    /// on the constructor when it is `static`.
+    FieldDefinition {
+        name: ClassElementName,
+        is_static: bool,
+        initializer: Option<Node>,
+    },
+    /// A static initialization block, like `static { ... }`, which runs once when the class is
+    /// defined, with the constructor as `this`.
+    StaticBlock(StatementList),
+}
+
+impl ClassElement {
+    /// Creates a new method, getter or setter.
+    pub(in crate::syntax) fn new<N>(
+        kind: MethodDefinitionKind,
+        name: N,
+        is_static: bool,
+        method: FunctionExpr,
+    ) -> Self
+    where
+        N: Into<ClassElementName>,
+    {
+        Self::MethodDefinition {
+            kind,
+            name: name.into(),
+            is_static,
+            method,
+        }
+    }
+
+    /// Creates a new field.
+    pub(in crate::syntax) fn field<N, I>(name: N, is_static: bool, initializer: I) -> Self
+    where
+        N: Into<ClassElementName>,
+        I: Into<Option<Node>>,
+    {
+        Self::FieldDefinition {
+            name: name.into(),
+            is_static,
+            initializer: initializer.into(),
+        }
+    }
+
+    /// Creates a new static initialization block.
+    pub(in crate::syntax) fn static_block<B>(body: B) -> Self
+    where
+        B: Into<StatementList>,
+    {
+        Self::StaticBlock(body.into())
+    }
+
+    /// Gets the name of the element, static initialization blocks don't have one.
+    pub fn name(&self) -> Option<&ClassElementName> {
+        match self {
+            Self::MethodDefinition { name, .. } | Self::FieldDefinition { name, .. } => Some(name),
+            Self::StaticBlock(_) => None,
+        }
+    }
+
+    /// Checks if the element belongs to the constructor (`static`) instead of the prototype or
+    /// the instances.
+    pub fn is_static(&self) -> bool {
+        match self {
+            Self::MethodDefinition { is_static, .. } | Self::FieldDefinition { is_static, .. } => {
+                *is_static
+            }
+            Self::StaticBlock(_) => true,
+        }
+    }
+
+    /// Implements the display formatting with indentation.
+    fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        if self.is_static() {
+            f.write_str("static ")?;
+        }
+        match self {
+            Self::MethodDefinition {
+                kind, name, method, ..
+            } => {
+                match kind {
+                    MethodDefinitionKind::Get => f.write_str("get ")?,
+                    MethodDefinitionKind::Set => f.write_str("set ")?,
+                    MethodDefinitionKind::Generator => f.write_str("*")?,
+                    MethodDefinitionKind::Async => f.write_str("async ")?,
+                    MethodDefinitionKind::Ordinary => {}
+                }
+                write!(f, "{}", name)?;
+                method.display_method(f, indentation)
+            }
+            Self::FieldDefinition {
+                name, initializer, ..
+            } => {
+                write!(f, "{}", name)?;
+                if let Some(initializer) = initializer {
+                    write!(f, " = {}", initializer)?;
+                }
+                f.write_str(";")
+            }
+            Self::StaticBlock(body) => {
+                writeln!(f, "{{")?;
+                body.display(f, indentation + 1)?;
+                write!(f, "{}}}", "    ".repeat(indentation))
+            }
+        }
+    }
+}
+
+impl fmt::Display for ClassElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+/// Evaluates the name of a public method or field of a class, when the class is defined.
+///
+/// A computed name can't be `prototype` for a static element, since the `prototype` property of
+/// the constructor can't be redefined.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classelementevaluation
+fn property_key(
+    name: &PropertyName,
+    is_static: bool,
+    interpreter: &mut Context,
+) -> Result<PropertyKey> {
+    match name {
+        PropertyName::Literal(name) => Ok(name.as_ref().into()),
+        PropertyName::Computed(node) => {
+            let key = node.run(interpreter)?.to_property_key(interpreter)?;
+            if is_static && key == PROTOTYPE {
+                return Err(interpreter.construct_type_error(
+                    "Classes may not have a static property named 'prototype'",
+                ));
+            }
+            Ok(key)
+        }
+    }
+}
+
+/// A static element of a class, which is evaluated once the class binding is initialized.
+enum StaticElement {
+    Field(ClassFieldDefinition),
+    Block(GcObject),
+}
+
+/// Adds the private method, getter or setter `function` to `methods`. The getter and the setter
+/// of the same private name are a single private accessor.
+fn add_private_method(
+    methods: &mut Vec<(PrivateName, PrivateElement)>,
+    name: PrivateName,
+    kind: MethodDefinitionKind,
+    function: GcObject,
+) {
+    if let Some((_, PrivateElement::Accessor { getter, setter })) =
+        methods.iter_mut().find(|(existing, _)| *existing == name)
+    {
+        match kind {
+            MethodDefinitionKind::Get => *getter = Some(function),
+            MethodDefinitionKind::Set => *setter = Some(function),
+            _ => {}
+        }
+        return;
+    }
+
+    let element = match kind {
+        MethodDefinitionKind::Get => PrivateElement::Accessor {
+            getter: Some(function),
+            setter: None,
+        },
+        MethodDefinitionKind::Set => PrivateElement::Accessor {
+            getter: None,
+            setter: Some(function),
+        },
+        _ => PrivateElement::Method(function),
+    };
+    methods.push((name, element));
+}
+
+/// Creates the function that computes the value of a field or runs a static initialization
+/// block, with `home` as its `[[HomeObject]]` so that it can use `super` property lookups.
+fn create_initializer(body: Vec<Node>, home: &GcObject, interpreter: &mut Context) -> GcObject {
+    // All parts of a class are strict mode code.
+    let mut body = StatementList::from(body);
+    body.set_strict(true);
+    let function = interpreter.create_function(Vec::new(), body, FunctionFlags::CALLABLE);
+    let function = function
+        .as_gc_object()
+        .expect("functions are always objects");
+    function
+        .borrow_mut()
+        .as_function_mut()
+        .expect("initializer is a function")
+        .set_home_object(home.clone());
+    function
+}
@@ -0,0 +1,83 @@
+use crate::{
+    exec::Executable,
+    syntax::ast::node::{declaration::class_decl::ClassElement, Class, FunctionExpr, Node},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `class` expression defines a class, optionally with a name that is only visible inside
+/// the class body.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/class
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ClassExpr {
+    class: Class,
+}
+
+impl ClassExpr {
+    /// Creates a new class expression.
+    pub(in crate::syntax) fn new<N, S, C, E>(
+        name: N,
+        super_ref: S,
+        constructor: C,
+        elements: E,
+    ) -> Self
+    where
+        N: Into<Option<Box<str>>>,
+        S: Into<Option<Node>>,
+        C: Into<Option<FunctionExpr>>,
+        E: Into<Box<[ClassElement]>>,
+    {
+        Self {
+            class: Class::new(name.into(), super_ref, constructor, elements),
+        }
+    }
+
+    /// Gets the name of the class expression, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.class.name()
+    }
+
+    /// Gets the class definition.
+    pub fn class(&self) -> &Class {
+        &self.class
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        self.class.display(f, indentation)
+    }
+}
+
+impl Executable for ClassExpr {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("ClassExpr", "exec");
+        self.class.evaluate(interpreter)
+    }
+}
+
+impl From<ClassExpr> for Node {
+    fn from(expr: ClassExpr) -> Self {
+        Self::ClassExpr(expr)
+    }
+}
+
+impl fmt::Display for ClassExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
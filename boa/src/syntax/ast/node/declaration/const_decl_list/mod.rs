@@ -1,7 +1,6 @@
 use crate::{
-    environment::lexical_environment::VariableScope,
     exec::Executable,
-    syntax::ast::node::{join_nodes, Identifier, Node},
+    syntax::ast::node::{join_nodes, pattern::BindingKind, Binding, Node},
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -37,22 +36,32 @@ pub struct ConstDeclList {
 
 impl Executable for ConstDeclList {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        for decl in self.as_ref() {
-            let val = if let Some(init) = decl.init() {
-                init.run(interpreter)?
-            } else {
-                return interpreter.throw_syntax_error("missing = in const declaration");
+        // The resume step is the index of the declaration that was interrupted, with the value
+        // being bound if its binding was interrupted.
+        let (start, mut resumed) = match interpreter.executor().take_resume_point(self) {
+            Some(point) => (point.step(), point.values().first().cloned()),
+            None => (0, None),
+        };
+        for (i, decl) in self.as_ref().iter().enumerate().skip(start) {
+            let val = match (resumed.take(), decl.init()) {
+                (Some(val), _) => val,
+                (None, Some(init)) => {
+                    let val = init.run(interpreter);
+                    interpreter
+                        .executor()
+                        .save_resume_point(val, self, i, Vec::new)?
+                }
+                (None, None) => {
+                    return interpreter.throw_syntax_error("missing = in const declaration")
+                }
             };
 
+            let result = decl
+                .binding()
+                .bind(val.clone(), BindingKind::Const, interpreter);
             interpreter
-                .realm_mut()
-                .environment
-                .create_immutable_binding(decl.name().to_owned(), false, VariableScope::Block);
-
-            interpreter
-                .realm_mut()
-                .environment
-                .initialize_binding(decl.name(), val);
+                .executor()
+                .save_resume_point(result, self, i, || vec![val])?;
         }
         Ok(Value::undefined())
     }
@@ -102,13 +111,13 @@ impl From<ConstDeclList> for Node {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct ConstDecl {
-    name: Identifier,
+    binding: Binding,
     init: Option<Node>,
 }
 
 impl fmt::Display for ConstDecl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.name, f)?;
+        fmt::Display::fmt(&self.binding, f)?;
         if let Some(ref init) = self.init {
             write!(f, " = {}", init)?;
         }
@@ -118,20 +127,20 @@ impl fmt::Display for ConstDecl {
 
 impl ConstDecl {
     /// Creates a new variable declaration.
-    pub(in crate::syntax) fn new<N, I>(name: N, init: Option<I>) -> Self
+    pub(crate) fn new<B, I>(binding: B, init: Option<I>) -> Self
     where
-        N: Into<Identifier>,
+        B: Into<Binding>,
         I: Into<Node>,
     {
         Self {
-            name: name.into(),
+            binding: binding.into(),
             init: init.map(|n| n.into()),
         }
     }
 
-    /// Gets the name of the variable.
-    pub fn name(&self) -> &str {
-        self.name.as_ref()
+    /// Gets the binding of the declaration, an identifier or a destructuring pattern.
+    pub fn binding(&self) -> &Binding {
+        &self.binding
     }
 
     /// Gets the initialization node for the variable, if any.
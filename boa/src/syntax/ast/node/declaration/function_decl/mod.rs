@@ -1,8 +1,14 @@
 use crate::{
-    builtins::function::FunctionFlags,
-    environment::lexical_environment::VariableScope,
+    builtins::function::{Function, FunctionFlags},
+    environment::{
+        function_environment_record::FunctionEnvironmentRecord,
+        lexical_environment::{Environment, EnvironmentType},
+    },
     exec::Executable,
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    property::{Attribute, DataDescriptor},
+    syntax::ast::node::{
+        join_nodes, Binding, FormalParameter, IterableLoopInitializer, Node, StatementList,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -88,27 +94,277 @@ impl Executable for FunctionDecl {
         let _timer = BoaProfiler::global().start_event("FunctionDecl", "exec");
         let val = interpreter.create_function(
             self.parameters().to_vec(),
-            self.body().to_vec(),
+            self.body.clone(),
             FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
         );
 
         // Set the name and assign it in the current environment
-        val.set_field("name", self.name());
-        interpreter.realm_mut().environment.create_mutable_binding(
-            self.name().to_owned(),
-            false,
-            VariableScope::Function,
+        val.set_property(
+            "name",
+            DataDescriptor::new(
+                self.name(),
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
         );
-
-        interpreter
-            .realm_mut()
-            .environment
-            .initialize_binding(self.name(), val);
+        let web_compat = interpreter.annex_b() && !interpreter.strict();
+        bind_function_declaration(self.name(), val, web_compat, interpreter);
 
         Ok(Value::undefined())
     }
 }
 
+/// Binds the function object `value` of a function declaration to its `name`.
+///
+/// The binding of a declaration in a block is created in the block when the block is entered.
+/// The other declarations are like `var` declarations, they are bound in the nearest function or
+/// global scope.
+///
+/// If `web_compat` is `true`, the function of a declaration in a block is also assigned to a
+/// `var` binding of the same name, unless that would conflict with a lexical declaration of an
+/// enclosing block or with a parameter of the function.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-block-level-function-declarations-web-legacy-compatibility-semantics
+pub(crate) fn bind_function_declaration(
+    name: &str,
+    value: Value,
+    web_compat: bool,
+    interpreter: &mut Context,
+) {
+    let environment = &interpreter.realm().environment;
+    let current = environment.get_current_environment_ref().clone();
+    let in_block = {
+        let env = current.borrow();
+        env.get_environment_type() == EnvironmentType::Declarative && env.has_binding(name)
+    };
+    if !in_block {
+        let var_env = var_environment(interpreter);
+        if !var_env.borrow().has_binding(name) {
+            var_env
+                .borrow_mut()
+                .create_mutable_binding(name.to_owned(), false);
+        }
+        set_or_initialize_binding(&var_env, name, value);
+        return;
+    }
+
+    set_or_initialize_binding(&current, name, value.clone());
+    if !web_compat {
+        return;
+    }
+
+    let mut envs = interpreter.realm().environment.environments().skip(1);
+    let var_env = loop {
+        let env = match envs.next() {
+            Some(env) => env,
+            None => return,
+        };
+        let env_ref = env.borrow();
+        match env_ref.get_environment_type() {
+            EnvironmentType::Declarative if env_ref.has_binding(name) => return,
+            EnvironmentType::Declarative | EnvironmentType::Object => {}
+            EnvironmentType::Function => {
+                let conflicts = env_ref
+                    .as_function_environment_record()
+                    .is_some_and(|record| is_parameter_or_lexical(record, name));
+                if conflicts {
+                    return;
+                }
+                drop(env_ref);
+                break env;
+            }
+            EnvironmentType::Global | EnvironmentType::Module => {
+                drop(env_ref);
+                break env;
+            }
+        }
+    };
+
+    if !var_env.borrow().has_binding(name) {
+        var_env
+            .borrow_mut()
+            .create_mutable_binding(name.to_owned(), false);
+    }
+    set_or_initialize_binding(&var_env, name, value);
+}
+
+/// Creates the `var` bindings of the function declarations in the blocks of `statements`, the body
+/// of a function or a script, that are assigned to a `var` binding when they run in web
+/// compatible code.
+///
+/// The bindings are initialized to `undefined`, so that the name can be used before the block of
+/// the declaration runs. Names that are already bound, like parameters or lexical declarations of
+/// the body, are skipped.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-web-compat-functiondeclarationinstantiation
+pub(crate) fn instantiate_web_compat_functions(statements: &[Node], interpreter: &mut Context) {
+    let mut names = Vec::new();
+    for node in statements {
+        web_compat_function_names(node, &mut Vec::new(), &mut names);
+    }
+
+    let var_env = var_environment(interpreter);
+    for name in names {
+        if !var_env.borrow().has_binding(name) {
+            let mut env = var_env.borrow_mut();
+            env.create_mutable_binding(name.to_owned(), false);
+            env.initialize_binding(name, Value::undefined());
+        }
+    }
+}
+
+/// Collects the names of the function declarations in the blocks nested in the statement `node`
+/// that are not declared lexically by an enclosing block, whose names are in `lexical`.
+///
+/// These are the declarations that a `var` declaration could replace without an early error.
+fn web_compat_function_names<'a>(
+    node: &'a Node,
+    lexical: &mut Vec<&'a str>,
+    names: &mut Vec<&'a str>,
+) {
+    match node {
+        Node::Block(block) => block_web_compat_function_names(block.statements(), lexical, names),
+        Node::If(if_smt) => {
+            web_compat_function_names(if_smt.body(), lexical, names);
+            if let Some(node) = if_smt.else_node() {
+                web_compat_function_names(node, lexical, names);
+            }
+        }
+        Node::WhileLoop(while_loop) => web_compat_function_names(while_loop.expr(), lexical, names),
+        Node::DoWhileLoop(do_while) => web_compat_function_names(do_while.body(), lexical, names),
+        Node::ForLoop(for_loop) => {
+            let len = lexical.len();
+            if let Some(init) = for_loop.init() {
+                init.lexically_declared_names(lexical, false);
+            }
+            web_compat_function_names(for_loop.body(), lexical, names);
+            lexical.truncate(len);
+        }
+        Node::ForInLoop(for_in) => {
+            let len = lexical.len();
+            if let IterableLoopInitializer::Let(binding) | IterableLoopInitializer::Const(binding) =
+                for_in.variable()
+            {
+                binding.bound_names(lexical);
+            }
+            web_compat_function_names(for_in.body(), lexical, names);
+            lexical.truncate(len);
+        }
+        Node::ForOfLoop(for_of) => {
+            let len = lexical.len();
+            if let IterableLoopInitializer::Let(binding) | IterableLoopInitializer::Const(binding) =
+                for_of.variable()
+            {
+                binding.bound_names(lexical);
+            }
+            web_compat_function_names(for_of.body(), lexical, names);
+            lexical.truncate(len);
+        }
+        Node::Try(try_node) => {
+            block_web_compat_function_names(try_node.block().statements(), lexical, names);
+            if let Some(catch) = try_node.catch() {
+                // A `var` declaration can redeclare a catch parameter that is not a pattern.
+                let len = lexical.len();
+                match catch.parameter() {
+                    Some(Binding::Identifier(_)) | None => {}
+                    Some(binding) => binding.bound_names(lexical),
+                }
+                block_web_compat_function_names(catch.block().statements(), lexical, names);
+                lexical.truncate(len);
+            }
+            if let Some(finally) = try_node.finally() {
+                block_web_compat_function_names(finally.statements(), lexical, names);
+            }
+        }
+        Node::Switch(switch) => {
+            let cases = switch.cases().iter().map(|case| case.body().statements());
+            let statements = cases.chain(switch.default()).flatten();
+            block_web_compat_function_names(statements, lexical, names);
+        }
+        Node::With(with) => web_compat_function_names(with.body(), lexical, names),
+        _ => {}
+    }
+}
+
+/// Collects the names like [`web_compat_function_names`], for the statements of a block.
+fn block_web_compat_function_names<'a, I>(
+    statements: I,
+    lexical: &mut Vec<&'a str>,
+    names: &mut Vec<&'a str>,
+) where
+    I: IntoIterator<Item = &'a Node> + Clone,
+{
+    let len = lexical.len();
+    for node in statements.clone() {
+        node.lexically_declared_names(lexical, false);
+    }
+    for node in statements.clone() {
+        if let Node::FunctionDecl(decl) = node {
+            if !lexical.contains(&decl.name()) && !names.contains(&decl.name()) {
+                names.push(decl.name());
+            }
+        }
+    }
+    for node in statements.clone() {
+        if let Node::FunctionDecl(_) | Node::GeneratorDecl(_) | Node::AsyncFunctionDecl(_) = node {
+            node.lexically_declared_names(lexical, true);
+        }
+    }
+    for node in statements {
+        web_compat_function_names(node, lexical, names);
+    }
+    lexical.truncate(len);
+}
+
+/// Checks if `name` is a parameter or a lexical declaration at the top level of the function of
+/// `record`, which the function declarations of its blocks are not hoisted over.
+fn is_parameter_or_lexical(record: &FunctionEnvironmentRecord, name: &str) -> bool {
+    match record.function.borrow().as_function() {
+        Some(Function::Ordinary { params, body, .. }) => {
+            let mut names = Vec::new();
+            for param in params.iter() {
+                param.binding().bound_names(&mut names);
+            }
+            for node in body.statements() {
+                node.lexically_declared_names(&mut names, false);
+            }
+            names.contains(&name)
+        }
+        _ => false,
+    }
+}
+
+/// Finds the nearest function, global or module environment, where `var` declarations are bound.
+fn var_environment(interpreter: &Context) -> Environment {
+    interpreter
+        .realm()
+        .environment
+        .environments()
+        .find(|env| {
+            matches!(
+                env.borrow().get_environment_type(),
+                EnvironmentType::Function | EnvironmentType::Global | EnvironmentType::Module
+            )
+        })
+        .expect("No function or global environment")
+}
+
+/// Initializes the binding `name` of `env`, or assigns to it if it was already initialized by
+/// another declaration of the same name.
+fn set_or_initialize_binding(env: &Environment, name: &str, value: Value) {
+    let initialized = env.borrow().get_binding_value(name, false).is_ok();
+    if initialized {
+        let _ = env.borrow_mut().set_mutable_binding(name, value, false);
+    } else {
+        env.borrow_mut().initialize_binding(name, value);
+    }
+}
+
 impl From<FunctionDecl> for Node {
     fn from(decl: FunctionDecl) -> Self {
         Self::FunctionDecl(decl)
@@ -1,7 +1,9 @@
 use crate::{
     builtins::function::FunctionFlags,
     exec::Executable,
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    object::{GcObject, PROTOTYPE},
+    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    syntax::ast::node::{join_nodes, FormalParameter, MethodDefinitionKind, Node, StatementList},
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -35,6 +37,59 @@ pub struct FunctionExpr {
 }
 
 impl FunctionExpr {
+    /// Creates the function of a method, getter or setter named `name`, whose `super` property
+    /// lookups start from `home`.
+    ///
+    /// The name of the function gets the `get` or `set` prefix of getters and setters.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-definemethod
+    pub(in crate::syntax::ast::node) fn create_method(
+        &self,
+        kind: MethodDefinitionKind,
+        home: &GcObject,
+        name: &str,
+        interpreter: &mut Context,
+    ) -> GcObject {
+        let flags = match kind {
+            MethodDefinitionKind::Generator => FunctionFlags::CALLABLE | FunctionFlags::GENERATOR,
+            MethodDefinitionKind::Async => FunctionFlags::CALLABLE | FunctionFlags::ASYNC,
+            _ => FunctionFlags::CALLABLE,
+        };
+        let function =
+            interpreter.create_function(self.parameters().to_vec(), self.body.clone(), flags);
+        let function_object = function
+            .as_gc_object()
+            .expect("functions are always objects");
+        {
+            let name = match kind {
+                MethodDefinitionKind::Get => format!("get {}", name),
+                MethodDefinitionKind::Set => format!("set {}", name),
+                MethodDefinitionKind::Ordinary
+                | MethodDefinitionKind::Generator
+                | MethodDefinitionKind::Async => name.to_owned(),
+            };
+            let mut function_object = function_object.borrow_mut();
+            function_object
+                .as_function_mut()
+                .expect("method is a function")
+                .set_home_object(home.clone());
+            // Methods are not constructors, so they don't have a prototype. Generator methods
+            // keep the prototype of the generator objects they create.
+            if kind != MethodDefinitionKind::Generator {
+                function_object.remove_property(&PROTOTYPE.into());
+            }
+            function_object.insert_property(
+                "name",
+                name,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            );
+        }
+        function_object
+    }
+
     /// Creates a new function expression
     pub(in crate::syntax) fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
     where
@@ -82,18 +137,109 @@ impl FunctionExpr {
 
         writeln!(f, "}}")
     }
+
+    /// Displays the function as a method, i.e. only its parameters and body, to follow the name
+    /// of a method definition.
+    pub(in crate::syntax::ast::node) fn display_method(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("(")?;
+        join_nodes(f, &self.parameters)?;
+        writeln!(f, ") {{")?;
+
+        self.body.display(f, indentation + 1)?;
+
+        write!(f, "{}}}", "    ".repeat(indentation))
+    }
+}
+
+impl FunctionExpr {
+    /// Creates the function of a method, getter or setter, and defines it as the property `key`
+    /// of `home`, which is also the object the function looks up `super` properties from.
+    ///
+    /// A getter or setter keeps the setter or getter already defined for the same property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-methoddefinitionevaluation
+    pub(in crate::syntax::ast::node) fn define_method(
+        &self,
+        kind: MethodDefinitionKind,
+        home: &GcObject,
+        key: PropertyKey,
+        enumerable: bool,
+        interpreter: &mut Context,
+    ) {
+        // Symbol keys give their description as the name of the function.
+        let name = match key {
+            PropertyKey::Symbol(ref symbol) => symbol
+                .description()
+                .map_or_else(String::new, |desc| format!("[{}]", desc)),
+            ref key => key.to_string(),
+        };
+        let function_object = self.create_method(kind, home, &name, interpreter);
+        let function = Value::from(function_object.clone());
+
+        let enumerable = if enumerable {
+            Attribute::ENUMERABLE
+        } else {
+            Attribute::NON_ENUMERABLE
+        };
+        let mut home = home.borrow_mut();
+        let property: PropertyDescriptor = match kind {
+            MethodDefinitionKind::Ordinary
+            | MethodDefinitionKind::Generator
+            | MethodDefinitionKind::Async => DataDescriptor::new(
+                function,
+                Attribute::WRITABLE | enumerable | Attribute::CONFIGURABLE,
+            )
+            .into(),
+            MethodDefinitionKind::Get => {
+                let setter = home
+                    .get_own_property(&key)
+                    .and_then(|p| p.as_accessor_descriptor().and_then(|a| a.setter().cloned()));
+                AccessorDescriptor::new(
+                    Some(function_object),
+                    setter,
+                    enumerable | Attribute::CONFIGURABLE,
+                )
+                .into()
+            }
+            MethodDefinitionKind::Set => {
+                let getter = home
+                    .get_own_property(&key)
+                    .and_then(|p| p.as_accessor_descriptor().and_then(|a| a.getter().cloned()));
+                AccessorDescriptor::new(
+                    getter,
+                    Some(function_object),
+                    enumerable | Attribute::CONFIGURABLE,
+                )
+                .into()
+            }
+        };
+        home.insert(key, property);
+    }
 }
 
 impl Executable for FunctionExpr {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let val = interpreter.create_function(
             self.parameters().to_vec(),
-            self.body().to_vec(),
+            self.body.clone(),
             FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
         );
 
         if let Some(name) = self.name() {
-            val.set_field("name", Value::from(name));
+            val.set_property(
+                "name",
+                DataDescriptor::new(
+                    Value::from(name),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
         }
 
         Ok(val)
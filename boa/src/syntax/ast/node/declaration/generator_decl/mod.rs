@@ -0,0 +1,119 @@
+use crate::{
+    builtins::function::FunctionFlags,
+    exec::Executable,
+    property::{Attribute, DataDescriptor},
+    syntax::ast::node::{
+        declaration::function_decl::bind_function_declaration, join_nodes, FormalParameter, Node,
+        StatementList,
+    },
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `function*` declaration defines a generator function with the specified parameters.
+///
+/// Calling a generator function doesn't run its body, it returns a `Generator` object instead.
+/// The body runs when the generator's `next` method is called, until it reaches a `yield`.
+///
+/// A generator function can also be created using an expression (see
+/// [generator expression][gen_expr]).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-GeneratorDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/function*
+/// [gen_expr]: ../enum.Node.html#variant.GeneratorExpr
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct GeneratorDecl {
+    name: Box<str>,
+    parameters: Box<[FormalParameter]>,
+    body: StatementList,
+}
+
+impl GeneratorDecl {
+    /// Creates a new generator function declaration.
+    pub(in crate::syntax) fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    where
+        N: Into<Box<str>>,
+        P: Into<Box<[FormalParameter]>>,
+        B: Into<StatementList>,
+    {
+        Self {
+            name: name.into(),
+            parameters: parameters.into(),
+            body: body.into(),
+        }
+    }
+
+    /// Gets the name of the generator function declaration.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the list of parameters of the generator function declaration.
+    pub fn parameters(&self) -> &[FormalParameter] {
+        &self.parameters
+    }
+
+    /// Gets the body of the generator function declaration.
+    pub fn body(&self) -> &[Node] {
+        self.body.statements()
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        write!(f, "function* {}(", self.name)?;
+        join_nodes(f, &self.parameters)?;
+        f.write_str(") {{")?;
+
+        self.body.display(f, indentation + 1)?;
+
+        writeln!(f, "}}")
+    }
+}
+
+impl Executable for GeneratorDecl {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("GeneratorDecl", "exec");
+        let val = interpreter.create_function(
+            self.parameters().to_vec(),
+            self.body.clone(),
+            FunctionFlags::CALLABLE | FunctionFlags::GENERATOR,
+        );
+
+        // Set the name and assign it in the current environment
+        val.set_property(
+            "name",
+            DataDescriptor::new(
+                self.name(),
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
+        bind_function_declaration(self.name(), val, false, interpreter);
+
+        Ok(Value::undefined())
+    }
+}
+
+impl From<GeneratorDecl> for Node {
+    fn from(decl: GeneratorDecl) -> Self {
+        Self::GeneratorDecl(decl)
+    }
+}
+
+impl fmt::Display for GeneratorDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    builtins::function::FunctionFlags,
+    exec::Executable,
+    property::{Attribute, DataDescriptor},
+    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `function*` expression defines a generator function with the specified parameters.
+///
+/// Calling a generator function doesn't run its body, it returns a `Generator` object instead.
+///
+/// A generator function can also be created using a declaration (see generator declaration).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-GeneratorExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/function*
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct GeneratorExpr {
+    name: Option<Box<str>>,
+    parameters: Box<[FormalParameter]>,
+    body: StatementList,
+}
+
+impl GeneratorExpr {
+    /// Creates a new generator function expression
+    pub(in crate::syntax) fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    where
+        N: Into<Option<Box<str>>>,
+        P: Into<Box<[FormalParameter]>>,
+        B: Into<StatementList>,
+    {
+        Self {
+            name: name.into(),
+            parameters: parameters.into(),
+            body: body.into(),
+        }
+    }
+
+    /// Gets the name of the generator function expression.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(Box::as_ref)
+    }
+
+    /// Gets the list of parameters of the generator function expression.
+    pub fn parameters(&self) -> &[FormalParameter] {
+        &self.parameters
+    }
+
+    /// Gets the body of the generator function expression.
+    pub fn body(&self) -> &[Node] {
+        self.body.statements()
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("function*")?;
+        if let Some(ref name) = self.name {
+            write!(f, " {}", name)?;
+        }
+        f.write_str("(")?;
+        join_nodes(f, &self.parameters)?;
+        f.write_str(") {{")?;
+
+        self.body.display(f, indentation + 1)?;
+
+        writeln!(f, "}}")
+    }
+}
+
+impl Executable for GeneratorExpr {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let val = interpreter.create_function(
+            self.parameters().to_vec(),
+            self.body.clone(),
+            FunctionFlags::CALLABLE | FunctionFlags::GENERATOR,
+        );
+
+        if let Some(name) = self.name() {
+            val.set_property(
+                "name",
+                DataDescriptor::new(
+                    Value::from(name),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+        }
+
+        Ok(val)
+    }
+}
+
+impl fmt::Display for GeneratorExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<GeneratorExpr> for Node {
+    fn from(expr: GeneratorExpr) -> Self {
+        Self::GeneratorExpr(expr)
+    }
+}
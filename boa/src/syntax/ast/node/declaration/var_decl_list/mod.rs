@@ -1,7 +1,6 @@
 use crate::{
-    environment::lexical_environment::VariableScope,
     exec::Executable,
-    syntax::ast::node::{join_nodes, Identifier, Node},
+    syntax::ast::node::{join_nodes, pattern::BindingKind, Binding, Node},
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -37,25 +36,40 @@ pub struct VarDeclList {
 
 impl Executable for VarDeclList {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        for var in self.as_ref() {
-            let val = match var.init() {
-                Some(v) => v.run(interpreter)?,
-                None => Value::undefined(),
+        // The resume step is the index of the declaration that was interrupted, with the value
+        // being bound if its binding was interrupted.
+        let (start, mut resumed) = match interpreter.executor().take_resume_point(self) {
+            Some(point) => (point.step(), point.values().first().cloned()),
+            None => (0, None),
+        };
+        for (i, var) in self.as_ref().iter().enumerate().skip(start) {
+            let val = match (resumed.take(), var.init()) {
+                (Some(val), _) => val,
+                (None, Some(v)) => {
+                    let val = v.run(interpreter);
+                    interpreter
+                        .executor()
+                        .save_resume_point(val, self, i, Vec::new)?
+                }
+                (None, None) => Value::undefined(),
             };
-            let environment = &mut interpreter.realm_mut().environment;
 
-            if environment.has_binding(var.name()) {
-                if var.init().is_some() {
-                    environment.set_mutable_binding(var.name(), val, true);
+            // Redeclaring a variable without an initializer keeps its value.
+            if let (Binding::Identifier(ident), None) = (var.binding(), var.init()) {
+                if interpreter
+                    .realm_mut()
+                    .environment
+                    .has_binding(ident.as_ref())
+                {
+                    continue;
                 }
-            } else {
-                environment.create_mutable_binding(
-                    var.name().to_owned(),
-                    false,
-                    VariableScope::Function,
-                );
-                environment.initialize_binding(var.name(), val);
             }
+            let result = var
+                .binding()
+                .bind(val.clone(), BindingKind::Var, interpreter);
+            interpreter
+                .executor()
+                .save_resume_point(result, self, i, || vec![val])?;
         }
         Ok(Value::undefined())
     }
@@ -105,13 +119,13 @@ impl From<VarDeclList> for Node {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct VarDecl {
-    name: Identifier,
+    binding: Binding,
     init: Option<Node>,
 }
 
 impl fmt::Display for VarDecl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.name, f)?;
+        fmt::Display::fmt(&self.binding, f)?;
         if let Some(ref init) = self.init {
             write!(f, " = {}", init)?;
         }
@@ -121,20 +135,20 @@ impl fmt::Display for VarDecl {
 
 impl VarDecl {
     /// Creates a new variable declaration.
-    pub(in crate::syntax) fn new<N, I>(name: N, init: I) -> Self
+    pub(in crate::syntax) fn new<B, I>(binding: B, init: I) -> Self
     where
-        N: Into<Identifier>,
+        B: Into<Binding>,
         I: Into<Option<Node>>,
     {
         Self {
-            name: name.into(),
+            binding: binding.into(),
             init: init.into(),
         }
     }
 
-    /// Gets the name of the variable.
-    pub fn name(&self) -> &str {
-        self.name.as_ref()
+    /// Gets the binding of the declaration, an identifier or a destructuring pattern.
+    pub fn binding(&self) -> &Binding {
+        &self.binding
     }
 
     /// Gets the initialization node for the variable, if any.
@@ -1,9 +1,4 @@
-use crate::{
-    exec::Executable,
-    syntax::ast::node::Node,
-    value::{Type, Value},
-    Context, Result,
-};
+use crate::{exec::Executable, syntax::ast::node::Node, value::Value, Context, Result};
 use gc::{Finalize, Trace};
 use std::fmt;
 
@@ -64,12 +59,9 @@ impl GetConstField {
 
 impl Executable for GetConstField {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let mut obj = self.obj().run(interpreter)?;
-        if obj.get_type() != Type::Object {
-            obj = Value::Object(obj.to_object(interpreter)?);
-        }
-
-        Ok(obj.get_field(self.field()))
+        let obj = self.obj().run(interpreter)?;
+        obj.to_object(interpreter)?
+            .get(&self.field().into(), obj, interpreter)
     }
 }
 
@@ -1,9 +1,4 @@
-use crate::{
-    exec::Executable,
-    syntax::ast::node::Node,
-    value::{Type, Value},
-    Context, Result,
-};
+use crate::{exec::Executable, syntax::ast::node::Node, value::Value, Context, Result};
 use gc::{Finalize, Trace};
 use std::fmt;
 
@@ -63,13 +58,10 @@ impl GetField {
 
 impl Executable for GetField {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let mut obj = self.obj().run(interpreter)?;
-        if obj.get_type() != Type::Object {
-            obj = Value::Object(obj.to_object(interpreter)?);
-        }
+        let obj = self.obj().run(interpreter)?;
         let field = self.field().run(interpreter)?;
-
-        Ok(obj.get_field(field.to_property_key(interpreter)?))
+        let key = field.to_property_key(interpreter)?;
+        obj.to_object(interpreter)?.get(&key, obj, interpreter)
     }
 }
 
@@ -0,0 +1,72 @@
+use crate::{
+    exec::Executable,
+    syntax::ast::node::{field::resolve_private_name, Node},
+    Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// This property accessor provides access to a private member of an object, like `this.#x`.
+///
+/// The name is resolved to the Private Name created by the innermost class that declares it, so
+/// the object must be an instance of that class, or the class itself for static members.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-MemberExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Private_class_fields
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct GetPrivateField {
+    obj: Box<Node>,
+    field: Box<str>,
+}
+
+impl GetPrivateField {
+    /// Creates a `GetPrivateField` AST node, the name of the field doesn't include the `#`.
+    pub fn new<V, L>(value: V, field: L) -> Self
+    where
+        V: Into<Node>,
+        L: Into<Box<str>>,
+    {
+        Self {
+            obj: Box::new(value.into()),
+            field: field.into(),
+        }
+    }
+
+    /// Gets the original object from where to get the field from.
+    pub fn obj(&self) -> &Node {
+        &self.obj
+    }
+
+    /// Gets the name of the private member to retrieve, without the `#`.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+}
+
+impl Executable for GetPrivateField {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let obj = self.obj().run(interpreter)?;
+        let name = resolve_private_name(self.field(), interpreter)?;
+        obj.to_object(interpreter)?.private_get(&name, interpreter)
+    }
+}
+
+impl fmt::Display for GetPrivateField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.#{}", self.obj(), self.field())
+    }
+}
+
+impl From<GetPrivateField> for Node {
+    fn from(get_private_field: GetPrivateField) -> Self {
+        Self::GetPrivateField(get_private_field)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{
+    exec::Executable,
+    syntax::ast::node::{field::super_reference_base, Node},
+    Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `super.property` syntax accesses a property of the parent of the object the current
+/// method is defined on, with `this` as the receiver.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-SuperProperty
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct GetSuperConstField {
+    field: Box<str>,
+}
+
+impl GetSuperConstField {
+    /// Creates a `GetSuperConstField` AST node.
+    pub fn new<L>(label: L) -> Self
+    where
+        L: Into<Box<str>>,
+    {
+        Self {
+            field: label.into(),
+        }
+    }
+
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+}
+
+impl Executable for GetSuperConstField {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let (base, this) = super_reference_base(interpreter)?;
+        base.get(&self.field().into(), this, interpreter)
+    }
+}
+
+impl fmt::Display for GetSuperConstField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "super.{}", self.field())
+    }
+}
+
+impl From<GetSuperConstField> for Node {
+    fn from(get_super_const_field: GetSuperConstField) -> Self {
+        Self::GetSuperConstField(get_super_const_field)
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{
+    exec::Executable,
+    syntax::ast::node::{field::super_reference_base, Node},
+    Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `super[expression]` syntax accesses a computed property of the parent of the object the
+/// current method is defined on, with `this` as the receiver.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-SuperProperty
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct GetSuperField {
+    field: Box<Node>,
+}
+
+impl GetSuperField {
+    /// Creates a `GetSuperField` AST node.
+    pub fn new<F>(field: F) -> Self
+    where
+        F: Into<Node>,
+    {
+        Self {
+            field: Box::new(field.into()),
+        }
+    }
+
+    pub fn field(&self) -> &Node {
+        &self.field
+    }
+}
+
+impl Executable for GetSuperField {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let field = self.field().run(interpreter)?;
+        let key = field.to_property_key(interpreter)?;
+        let (base, this) = super_reference_base(interpreter)?;
+        base.get(&key, this, interpreter)
+    }
+}
+
+impl fmt::Display for GetSuperField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "super[{}]", self.field())
+    }
+}
+
+impl From<GetSuperField> for Node {
+    fn from(get_super_field: GetSuperField) -> Self {
+        Self::GetSuperField(get_super_field)
+    }
+}
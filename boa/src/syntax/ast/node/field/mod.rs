@@ -2,5 +2,64 @@
 
 pub mod get_const_field;
 pub mod get_field;
+pub mod get_private_field;
+pub mod get_super_const_field;
+pub mod get_super_field;
 
-pub use self::{get_const_field::GetConstField, get_field::GetField};
+pub use self::{
+    get_const_field::GetConstField, get_field::GetField, get_private_field::GetPrivateField,
+    get_super_const_field::GetSuperConstField, get_super_field::GetSuperField,
+};
+use crate::{
+    environment::{
+        environment_record_trait::EnvironmentRecordTrait, private_environment_record::PrivateName,
+    },
+    object::GcObject,
+    Context, Result, Value,
+};
+
+/// Resolves the object a `super` property reference looks up properties on, together with the
+/// `this` value used as the receiver.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makesuperpropertyreference
+pub(crate) fn super_reference_base(interpreter: &mut Context) -> Result<(GcObject, Value)> {
+    let env = interpreter.realm().environment.get_this_environment();
+    let (base, this) = match env.borrow().as_function_environment_record() {
+        Some(record) if record.has_super_binding() => {
+            (record.get_super_base(), record.get_this_binding())
+        }
+        _ => return Err(interpreter.construct_syntax_error("'super' keyword unexpected here")),
+    };
+    let this = this.or_else(|e| interpreter.throw_reference_error(e.to_string()))?;
+
+    match base {
+        Value::Object(ref base) => Ok((base.clone(), this)),
+        _ => Err(interpreter.construct_type_error(format!(
+            "Cannot access super property of {}",
+            base.display()
+        ))),
+    }
+}
+
+/// Resolves the name of a private member, like `#x` in `this.#x`, to the Private Name created by
+/// the innermost class that declares it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makeprivatereference
+pub(crate) fn resolve_private_name(name: &str, interpreter: &mut Context) -> Result<PrivateName> {
+    let resolved = interpreter
+        .realm()
+        .environment
+        .resolve_private_identifier(name);
+    resolved.ok_or_else(|| {
+        interpreter.construct_syntax_error(format!(
+            "Private field '#{}' must be declared in an enclosing class",
+            name
+        ))
+    })
+}
@@ -32,11 +32,7 @@ pub struct Identifier {
 
 impl Executable for Identifier {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        interpreter
-            .realm()
-            .environment
-            .get_binding_value(self.as_ref())
-            .ok_or_else(|| interpreter.construct_reference_error(self.as_ref()))
+        interpreter.get_binding_value(self.as_ref())
     }
 }
 
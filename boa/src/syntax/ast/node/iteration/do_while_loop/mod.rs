@@ -42,6 +42,10 @@ impl DoWhileLoop {
         self.label.as_ref().map(Box::as_ref)
     }
 
+    pub fn set_label(&mut self, label: Box<str>) {
+        self.label = Some(label);
+    }
+
     /// Creates a `DoWhileLoop` AST node.
     pub fn new<B, C>(body: B, condition: C) -> Self
     where
@@ -60,6 +64,9 @@ impl DoWhileLoop {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
+        if let Some(label) = self.label() {
+            write!(f, "{}: ", label)?;
+        }
         write!(f, "do")?;
         self.body().display(f, indentation)?;
         write!(f, "while ({})", self.cond())
@@ -68,57 +75,41 @@ impl DoWhileLoop {
 
 impl Executable for DoWhileLoop {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let mut result = self.body().run(interpreter)?;
-        match interpreter.executor().get_current_state() {
-            InterpreterState::Break(_label) => {
-                // TODO break to label.
-
-                // Loops 'consume' breaks.
-                interpreter
+        // The resume step is `0` if the condition was interrupted and `1` if the body was.
+        let (mut result, mut resume_cond) = match interpreter.executor().take_resume_point(self) {
+            Some(point) => (point.values()[0].clone(), point.step() == 0),
+            None => (Value::undefined(), false),
+        };
+        loop {
+            if !resume_cond {
+                let body = self.body().run(interpreter);
+                result = interpreter
                     .executor()
-                    .set_current_state(InterpreterState::Executing);
-                return Ok(result);
-            }
-            InterpreterState::Continue(_label) => {
-                // TODO continue to label;
-                interpreter
-                    .executor()
-                    .set_current_state(InterpreterState::Executing);
-                // after breaking out of the block, continue execution of the loop
-            }
-            InterpreterState::Return => {
-                return Ok(result);
-            }
-            InterpreterState::Executing => {
-                // Continue execution.
+                    .save_resume_point(body, self, 1, || vec![result.clone()])?;
+                match interpreter.executor().get_current_state() {
+                    InterpreterState::Break(label) => {
+                        handle_state_with_labels!(self, label, interpreter, break);
+                        break;
+                    }
+                    InterpreterState::Continue(label) => {
+                        handle_state_with_labels!(self, label, interpreter, continue);
+                    }
+                    InterpreterState::Return => {
+                        return Ok(result);
+                    }
+                    InterpreterState::Executing => {
+                        // Continue execution.
+                    }
+                }
             }
-        }
+            resume_cond = false;
 
-        while self.cond().run(interpreter)?.to_boolean() {
-            result = self.body().run(interpreter)?;
-            match interpreter.executor().get_current_state() {
-                InterpreterState::Break(_label) => {
-                    // TODO break to label.
-
-                    // Loops 'consume' breaks.
-                    interpreter
-                        .executor()
-                        .set_current_state(InterpreterState::Executing);
-                    break;
-                }
-                InterpreterState::Continue(_label) => {
-                    // TODO continue to label.
-                    interpreter
-                        .executor()
-                        .set_current_state(InterpreterState::Executing);
-                    // after breaking out of the block, continue execution of the loop
-                }
-                InterpreterState::Return => {
-                    return Ok(result);
-                }
-                InterpreterState::Executing => {
-                    // Continue execution.
-                }
+            let cond = self.cond().run(interpreter);
+            let cond = interpreter
+                .executor()
+                .save_resume_point(cond, self, 0, || vec![result.clone()])?;
+            if !cond.to_boolean() {
+                break;
             }
         }
         Ok(result)
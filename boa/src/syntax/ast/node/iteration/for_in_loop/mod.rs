@@ -0,0 +1,209 @@
+use crate::{
+    builtins::{iterable::IteratorRecord, object::for_in_iterator::ForInIterator},
+    environment::lexical_environment::new_declarative_environment,
+    exec::{Executable, InterpreterState},
+    syntax::ast::node::{iteration::IterableLoopInitializer, Node},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `for...in` statement iterates over the enumerable string keys of an object, including
+/// the inherited ones.
+///
+/// The keys of the object are visited before the keys of its prototype, and a key that is
+/// deleted before it is visited is skipped.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ForInOfStatement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/for...in
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ForInLoop {
+    variable: IterableLoopInitializer,
+    expr: Box<Node>,
+    body: Box<Node>,
+    label: Option<Box<str>>,
+}
+
+impl ForInLoop {
+    pub fn new<V, E, B>(variable: V, expr: E, body: B) -> Self
+    where
+        V: Into<IterableLoopInitializer>,
+        E: Into<Node>,
+        B: Into<Node>,
+    {
+        Self {
+            variable: variable.into(),
+            expr: Box::new(expr.into()),
+            body: Box::new(body.into()),
+            label: None,
+        }
+    }
+
+    pub fn variable(&self) -> &IterableLoopInitializer {
+        &self.variable
+    }
+
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+
+    pub fn body(&self) -> &Node {
+        &self.body
+    }
+
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_ref().map(Box::as_ref)
+    }
+
+    pub fn set_label(&mut self, label: Box<str>) {
+        self.label = Some(label);
+    }
+
+    pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        if let Some(label) = self.label() {
+            write!(f, "{}: ", label)?;
+        }
+        write!(f, "for ({} in {}) {{", self.variable, self.expr)?;
+        self.body().display(f, indentation + 1)?;
+        f.write_str("}")
+    }
+}
+
+impl fmt::Display for ForInLoop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<ForInLoop> for Node {
+    fn from(for_in: ForInLoop) -> Node {
+        Self::ForInLoop(for_in)
+    }
+}
+
+impl Executable for ForInLoop {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("ForIn", "exec");
+        // A resumed generator has already restored the environment of the iteration that was
+        // interrupted, so it goes straight back to the body, or to the binding of the value of
+        // the iteration. The resume step is `0` for the body and `1` for the binding, with the
+        // iterator, the result so far and the value being bound.
+        let (iterator, result, resume_body, resumed) = if let Some(point) =
+            interpreter.executor().take_resume_point(self)
+        {
+            let values = point.values();
+            let iterator = IteratorRecord::new(values[0].clone(), values[1].clone());
+            let resumed = values.get(3).cloned();
+            (iterator, values[2].clone(), point.step() == 0, resumed)
+        } else {
+            let object = self.expr().run(interpreter)?;
+            // Nothing is enumerated for `null` and `undefined`.
+            if object.is_null_or_undefined() {
+                return Ok(Value::undefined());
+            }
+            let object = object.to_object(interpreter)?;
+            let for_in_iterator = ForInIterator::create_for_in_iterator(interpreter, object.into());
+            let next_function = for_in_iterator
+                .get_property("next")
+                .map(|p| p.as_data_descriptor().unwrap().value())
+                .ok_or_else(|| {
+                    interpreter.construct_type_error("Could not find property `next`")
+                })?;
+            let iterator = IteratorRecord::new(for_in_iterator, next_function);
+            (iterator, Value::undefined(), false, None)
+        };
+
+        let result = self.run_iterations(iterator, result, resume_body, resumed, interpreter);
+
+        // The loop is always left with the environment of the last iteration, it's kept only
+        // for a suspended generator.
+        if !interpreter.executor().is_suspending() {
+            let _ = interpreter.realm_mut().environment.pop();
+        }
+
+        result
+    }
+}
+
+impl ForInLoop {
+    /// Runs the iterations of the loop, each one in a new environment.
+    ///
+    /// The environment of the last iteration is left on the stack.
+    fn run_iterations(
+        &self,
+        iterator: IteratorRecord,
+        mut result: Value,
+        mut resume_body: bool,
+        mut resumed: Option<Value>,
+        interpreter: &mut Context,
+    ) -> Result<Value> {
+        loop {
+            if !resume_body {
+                let next_result = if let Some(next_result) = resumed.take() {
+                    next_result
+                } else {
+                    {
+                        let env = &mut interpreter.realm_mut().environment;
+                        env.push(new_declarative_environment(Some(
+                            env.get_current_environment_ref().clone(),
+                        )));
+                    }
+                    let iterator_result = iterator.next(interpreter)?;
+                    if iterator_result.is_done() {
+                        break;
+                    }
+                    iterator_result.value()
+                };
+
+                let bound = self.variable().bind(next_result.clone(), interpreter);
+                interpreter
+                    .executor()
+                    .save_resume_point(bound, self, 1, || {
+                        vec![
+                            iterator.iterator_object().clone(),
+                            iterator.next_function().clone(),
+                            result.clone(),
+                            next_result,
+                        ]
+                    })?;
+            }
+            resume_body = false;
+
+            let body = self.body().run(interpreter);
+            result = interpreter
+                .executor()
+                .save_resume_point(body, self, 0, || {
+                    vec![
+                        iterator.iterator_object().clone(),
+                        iterator.next_function().clone(),
+                        result.clone(),
+                    ]
+                })?;
+            match interpreter.executor().get_current_state() {
+                InterpreterState::Break(label) => {
+                    handle_state_with_labels!(self, label, interpreter, break);
+                    break;
+                }
+                InterpreterState::Continue(label) => {
+                    handle_state_with_labels!(self, label, interpreter, continue);
+                }
+                InterpreterState::Return => {
+                    return Ok(result);
+                }
+                InterpreterState::Executing => {
+                    // Continue execution.
+                }
+            }
+            let _ = interpreter.realm_mut().environment.pop();
+        }
+        Ok(result)
+    }
+}
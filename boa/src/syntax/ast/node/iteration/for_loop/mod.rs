@@ -1,7 +1,7 @@
 use crate::{
     environment::lexical_environment::new_declarative_environment,
     exec::{Executable, InterpreterState},
-    syntax::ast::node::Node,
+    syntax::ast::node::{statement_list::instantiate_lexical_declarations, Node},
     BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -69,6 +69,9 @@ impl ForLoop {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
+        if let Some(label) = self.label() {
+            write!(f, "{}: ", label)?;
+        }
         f.write_str("for (")?;
         if let Some(init) = self.init() {
             fmt::Display::fmt(init, f)?;
@@ -101,50 +104,95 @@ impl Executable for ForLoop {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         // Create the block environment.
         let _timer = BoaProfiler::global().start_event("ForLoop", "exec");
-        {
+        // The resume step is `0` for the initializer, `1` for the condition, `2` for the body and
+        // `3` for the final expression. A resumed generator has already restored the environment.
+        let step = if let Some(point) = interpreter.executor().take_resume_point(self) {
+            point.step()
+        } else {
             let env = &mut interpreter.realm_mut().environment;
             env.push(new_declarative_environment(Some(
                 env.get_current_environment_ref().clone(),
             )));
+            if let Some(init) = self.init() {
+                let init = std::slice::from_ref(init);
+                if let Err(e) = instantiate_lexical_declarations(init, false, interpreter) {
+                    let _ = interpreter.realm_mut().environment.pop();
+                    return Err(e);
+                }
+            }
+            0
+        };
+
+        let result = self.run_loop(step, interpreter);
+
+        // pop the block env, unless it has to be kept for a suspended generator
+        if !interpreter.executor().is_suspending() {
+            let _ = interpreter.realm_mut().environment.pop();
         }
 
-        if let Some(init) = self.init() {
-            init.run(interpreter)?;
+        result
+    }
+}
+
+impl ForLoop {
+    /// Runs the loop from the resume step `step`, in the environment of the loop.
+    fn run_loop(&self, mut step: usize, interpreter: &mut Context) -> Result<Value> {
+        if step == 0 {
+            if let Some(init) = self.init() {
+                let init = init.run(interpreter);
+                interpreter
+                    .executor()
+                    .save_resume_point(init, self, 0, Vec::new)?;
+            }
+            step = 1;
         }
 
-        while self
-            .condition()
-            .map(|cond| cond.run(interpreter).map(|v| v.to_boolean()))
-            .transpose()?
-            .unwrap_or(true)
-        {
-            let result = self.body().run(interpreter)?;
-
-            match interpreter.executor().get_current_state() {
-                InterpreterState::Break(label) => {
-                    handle_state_with_labels!(self, label, interpreter, break);
-                    break;
-                }
-                InterpreterState::Continue(label) => {
-                    handle_state_with_labels!(self, label, interpreter, continue);
+        loop {
+            if step <= 1 {
+                if let Some(cond) = self.condition() {
+                    let cond = cond.run(interpreter);
+                    let cond = interpreter
+                        .executor()
+                        .save_resume_point(cond, self, 1, Vec::new)?;
+                    if !cond.to_boolean() {
+                        break;
+                    }
                 }
+            }
 
-                InterpreterState::Return => {
-                    return Ok(result);
-                }
-                InterpreterState::Executing => {
-                    // Continue execution.
+            if step <= 2 {
+                let result = self.body().run(interpreter);
+                let result = interpreter
+                    .executor()
+                    .save_resume_point(result, self, 2, Vec::new)?;
+
+                match interpreter.executor().get_current_state() {
+                    InterpreterState::Break(label) => {
+                        handle_state_with_labels!(self, label, interpreter, break);
+                        break;
+                    }
+                    InterpreterState::Continue(label) => {
+                        handle_state_with_labels!(self, label, interpreter, continue);
+                    }
+
+                    InterpreterState::Return => {
+                        return Ok(result);
+                    }
+                    InterpreterState::Executing => {
+                        // Continue execution.
+                    }
                 }
             }
 
             if let Some(final_expr) = self.final_expr() {
-                final_expr.run(interpreter)?;
+                let result = final_expr.run(interpreter);
+                interpreter
+                    .executor()
+                    .save_resume_point(result, self, 3, Vec::new)?;
             }
+            step = 1;
         }
 
-        // pop the block env
-        let _ = interpreter.realm_mut().environment.pop();
-
         Ok(Value::undefined())
     }
 }
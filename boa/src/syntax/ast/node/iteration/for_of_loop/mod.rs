@@ -1,8 +1,8 @@
 use crate::{
-    builtins::iterable::get_iterator,
-    environment::lexical_environment::{new_declarative_environment, VariableScope},
+    builtins::iterable::{get_iterator, IteratorRecord},
+    environment::lexical_environment::new_declarative_environment,
     exec::{Executable, InterpreterState},
-    syntax::ast::node::Node,
+    syntax::ast::node::{iteration::IterableLoopInitializer, Node},
     BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -14,26 +14,28 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct ForOfLoop {
-    variable: Box<Node>,
+    variable: IterableLoopInitializer,
     iterable: Box<Node>,
     body: Box<Node>,
+    label: Option<Box<str>>,
 }
 
 impl ForOfLoop {
     pub fn new<V, I, B>(variable: V, iterable: I, body: B) -> Self
     where
-        V: Into<Node>,
+        V: Into<IterableLoopInitializer>,
         I: Into<Node>,
         B: Into<Node>,
     {
         Self {
-            variable: Box::new(variable.into()),
+            variable: variable.into(),
             iterable: Box::new(iterable.into()),
             body: Box::new(body.into()),
+            label: None,
         }
     }
 
-    pub fn variable(&self) -> &Node {
+    pub fn variable(&self) -> &IterableLoopInitializer {
         &self.variable
     }
 
@@ -45,7 +47,18 @@ impl ForOfLoop {
         &self.body
     }
 
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_ref().map(Box::as_ref)
+    }
+
+    pub fn set_label(&mut self, label: Box<str>) {
+        self.label = Some(label);
+    }
+
     pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        if let Some(label) = self.label() {
+            write!(f, "{}: ", label)?;
+        }
         write!(f, "for ({} of {}) {{", self.variable, self.iterable)?;
         self.body().display(f, indentation + 1)?;
         f.write_str("}")
@@ -67,142 +80,154 @@ impl From<ForOfLoop> for Node {
 impl Executable for ForOfLoop {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("ForOf", "exec");
-        let iterable = self.iterable().run(interpreter)?;
-        let iterator = get_iterator(interpreter, iterable)?;
-        let mut result = Value::undefined();
+        // A resumed generator has already restored the environment of the iteration that was
+        // interrupted, so it goes straight back to the body, or to the binding of the value of
+        // the iteration. The resume step is `0` for the body and `1` for the binding, with the
+        // iterator, the result so far and the value being bound.
+        let (iterator, result, resume_body, resumed) =
+            if let Some(point) = interpreter.executor().take_resume_point(self) {
+                let values = point.values();
+                let iterator = IteratorRecord::new(values[0].clone(), values[1].clone());
+                let resumed = values.get(3).cloned();
+                (iterator, values[2].clone(), point.step() == 0, resumed)
+            } else {
+                let iterable = self.iterable().run(interpreter)?;
+                let iterator = get_iterator(interpreter, iterable)?;
+                (iterator, Value::undefined(), false, None)
+            };
+
+        let result = self.run_iterations(&iterator, result, resume_body, resumed, interpreter);
+
+        // The loop is always left with the environment of the last iteration, it's kept only
+        // for a suspended generator.
+        if !interpreter.executor().is_suspending() {
+            let _ = interpreter.realm_mut().environment.pop();
+        }
+
+        result
+    }
+}
 
+impl ForOfLoop {
+    /// Runs the iterations of the loop, each one in a new environment.
+    ///
+    /// The environment of the last iteration is left on the stack. The iterator is closed if
+    /// the loop ends before it's done, unless it ends because the iterator throws.
+    fn run_iterations(
+        &self,
+        iterator: &IteratorRecord,
+        mut result: Value,
+        mut resume_body: bool,
+        mut resumed: Option<Value>,
+        interpreter: &mut Context,
+    ) -> Result<Value> {
         loop {
-            {
-                let env = &mut interpreter.realm_mut().environment;
-                env.push(new_declarative_environment(Some(
-                    env.get_current_environment_ref().clone(),
-                )));
-            }
-            let iterator_result = iterator.next(interpreter)?;
-            if iterator_result.is_done() {
-                break;
-            }
-            let next_result = iterator_result.value();
-
-            match self.variable() {
-                Node::Identifier(ref name) => {
-                    let environment = &mut interpreter.realm_mut().environment;
-
-                    if environment.has_binding(name.as_ref()) {
-                        // Binding already exists
-                        environment.set_mutable_binding(name.as_ref(), next_result.clone(), true);
-                    } else {
-                        environment.create_mutable_binding(
-                            name.as_ref().to_owned(),
-                            true,
-                            VariableScope::Function,
-                        );
-                        environment.initialize_binding(name.as_ref(), next_result.clone());
-                    }
-                }
-                Node::VarDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        let environment = &mut interpreter.realm_mut().environment;
-
-                        if var.init().is_some() {
-                            return interpreter.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
-
-                        if environment.has_binding(var.name()) {
-                            environment.set_mutable_binding(var.name(), next_result, true);
-                        } else {
-                            environment.create_mutable_binding(
-                                var.name().to_owned(),
-                                false,
-                                VariableScope::Function,
-                            );
-                            environment.initialize_binding(var.name(), next_result);
-                        }
-                    }
-                    _ => {
-                        return interpreter.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
+            if !resume_body {
+                let next_result = if let Some(next_result) = resumed.take() {
+                    next_result
+                } else {
+                    {
+                        let env = &mut interpreter.realm_mut().environment;
+                        env.push(new_declarative_environment(Some(
+                            env.get_current_environment_ref().clone(),
+                        )));
                     }
-                },
-                Node::LetDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        let environment = &mut interpreter.realm_mut().environment;
-
-                        if var.init().is_some() {
-                            return interpreter.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
-
-                        environment.create_mutable_binding(
-                            var.name().to_owned(),
-                            false,
-                            VariableScope::Block,
-                        );
-                        environment.initialize_binding(var.name(), next_result);
+                    let iterator_result = iterator.next(interpreter)?;
+                    if iterator_result.is_done() {
+                        return Ok(result);
                     }
-                    _ => {
-                        return interpreter.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
-                    }
-                },
-                Node::ConstDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        let environment = &mut interpreter.realm_mut().environment;
-
-                        if var.init().is_some() {
-                            return interpreter.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
-
-                        environment.create_immutable_binding(
-                            var.name().to_owned(),
-                            false,
-                            VariableScope::Block,
-                        );
-                        environment.initialize_binding(var.name(), next_result);
-                    }
-                    _ => {
-                        return interpreter.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
-                    }
-                },
-                Node::Assign(_) => {
-                    return interpreter.throw_syntax_error(
-                        "a declaration in the head of a for-of loop can't have an initializer",
-                    );
-                }
-                _ => {
-                    return interpreter
-                        .throw_syntax_error("unknown left hand side in head of for-of loop")
+                    iterator_result.value()
+                };
+
+                let bound = self.variable().bind(next_result.clone(), interpreter);
+                let bound = interpreter
+                    .executor()
+                    .save_resume_point(bound, self, 1, || {
+                        vec![
+                            iterator.iterator_object().clone(),
+                            iterator.next_function().clone(),
+                            result.clone(),
+                            next_result,
+                        ]
+                    });
+                if let Err(error) = bound {
+                    return close_iterator(iterator, Err(error), interpreter);
                 }
             }
-
-            result = self.body().run(interpreter)?;
+            resume_body = false;
+
+            let body = self.body().run(interpreter);
+            let body = interpreter.executor().save_resume_point(body, self, 0, || {
+                vec![
+                    iterator.iterator_object().clone(),
+                    iterator.next_function().clone(),
+                    result.clone(),
+                ]
+            });
+            result = match body {
+                Ok(result) => result,
+                Err(error) => return close_iterator(iterator, Err(error), interpreter),
+            };
             match interpreter.executor().get_current_state() {
-                InterpreterState::Break(_label) => {
-                    // TODO break to label.
-
-                    // Loops 'consume' breaks.
-                    interpreter
-                        .executor()
-                        .set_current_state(InterpreterState::Executing);
+                InterpreterState::Break(label) => {
+                    handle_state_with_labels!(self, label, interpreter, break);
                     break;
                 }
-                InterpreterState::Continue(_label) => {
-                    // TODO continue to label.
-                    interpreter
-                        .executor()
-                        .set_current_state(InterpreterState::Executing);
-                    // after breaking out of the block, continue execution of the loop
+                InterpreterState::Continue(label) => {
+                    handle_state_with_labels!(self, label, interpreter, continue);
+                }
+                InterpreterState::Return => {
+                    break;
                 }
-                InterpreterState::Return => return Ok(result),
                 InterpreterState::Executing => {
                     // Continue execution.
                 }
             }
             let _ = interpreter.realm_mut().environment.pop();
         }
-        Ok(result)
+        close_iterator(iterator, Ok(result), interpreter)
+    }
+}
+
+/// Closes the iterator of a loop that ends with `completion` before the iterator is done.
+///
+/// A thrown error takes precedence over any error closing the iterator, and the iterator is
+/// kept open for a suspended generator.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iteratorclose
+fn close_iterator(
+    iterator: &IteratorRecord,
+    completion: Result<Value>,
+    interpreter: &mut Context,
+) -> Result<Value> {
+    match completion {
+        Ok(value) => {
+            // The `return` method runs like any other call, whatever the loop is left with.
+            let state = interpreter.executor().get_current_state().clone();
+            interpreter
+                .executor()
+                .set_current_state(InterpreterState::Executing);
+            let closed = iterator.close(interpreter);
+            interpreter.executor().set_current_state(state);
+            closed?;
+            Ok(value)
+        }
+        Err(error) if interpreter.executor().is_suspending() => Err(error),
+        // A generator closed by its `return` method returns once the iterator is closed.
+        Err(error) if interpreter.executor().is_interrupted() => {
+            let interruption = interpreter.executor().take_interruption();
+            iterator.close(interpreter)?;
+            if let Some(interruption) = interruption {
+                interpreter.executor().set_interruption(interruption);
+            }
+            Err(error)
+        }
+        Err(error) => {
+            let _ = iterator.close(interpreter);
+            Err(error)
+        }
     }
 }
@@ -1,14 +1,23 @@
 //! Iteration nodes
 
 pub use self::{
-    continue_node::Continue, do_while_loop::DoWhileLoop, for_loop::ForLoop, for_of_loop::ForOfLoop,
-    while_loop::WhileLoop,
+    continue_node::Continue, do_while_loop::DoWhileLoop, for_in_loop::ForInLoop, for_loop::ForLoop,
+    for_of_loop::ForOfLoop, while_loop::WhileLoop,
 };
+use crate::{
+    syntax::ast::node::pattern::{Binding, BindingKind},
+    Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
 mod tests;
 
-// Checking labels for break and continue is the same operation for `ForLoop`, `While` and `DoWhile`
+// Checking labels for break and continue is the same operation for all the loops.
 #[macro_use]
 macro_rules! handle_state_with_labels {
     ($self:ident, $label:ident, $interpreter:ident, $state:tt) => {{
@@ -30,8 +39,54 @@ macro_rules! handle_state_with_labels {
     }};
 }
 
+/// The target of each value in the head of a `for...in` or `for...of` loop.
+///
+/// It's a declaration of a single binding without an initializer, like `let [a, b]`, or the
+/// target of an assignment, like `a.b` or `{ c }`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ForInOfStatement
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum IterableLoopInitializer {
+    /// An assignment target, like `a` in `for (a in b)`.
+    Assign(Binding),
+    /// A `var` declaration, like `var a` in `for (var a of b)`.
+    Var(Binding),
+    /// A `let` declaration, like `let a` in `for (let a of b)`.
+    Let(Binding),
+    /// A `const` declaration, like `const a` in `for (const a of b)`.
+    Const(Binding),
+}
+
+impl IterableLoopInitializer {
+    /// Binds the value of the current iteration to the target.
+    pub(crate) fn bind(&self, value: Value, interpreter: &mut Context) -> Result<()> {
+        match self {
+            Self::Assign(binding) => binding.bind(value, BindingKind::Assign, interpreter),
+            Self::Var(binding) => binding.bind(value, BindingKind::Var, interpreter),
+            Self::Let(binding) => binding.bind(value, BindingKind::Let, interpreter),
+            Self::Const(binding) => binding.bind(value, BindingKind::Const, interpreter),
+        }
+    }
+}
+
+impl fmt::Display for IterableLoopInitializer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Assign(binding) => write!(f, "{}", binding),
+            Self::Var(binding) => write!(f, "var {}", binding),
+            Self::Let(binding) => write!(f, "let {}", binding),
+            Self::Const(binding) => write!(f, "const {}", binding),
+        }
+    }
+}
+
 pub mod continue_node;
 pub mod do_while_loop;
+pub mod for_in_loop;
 pub mod for_loop;
 pub mod for_of_loop;
 pub mod while_loop;
@@ -353,3 +353,136 @@ fn for_loop_continue_label() {
     "#;
     assert_eq!(&exec(scenario), "10");
 }
+
+#[test]
+fn continue_label_nested_loops() {
+    let scenario = r#"
+        var str = "";
+        outer: for (let x of ["a", "b", "c"]) {
+            let i = 0;
+            do {
+                for (let key in { y: 1, z: 2 }) {
+                    if (x === "b") continue outer;
+                    str += x + key;
+                }
+                i++;
+            } while (i < 2);
+        }
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"ayazayazcyczcycz\"");
+}
+
+#[test]
+fn break_label_block() {
+    let scenario = r#"
+        var str = "";
+        block: {
+            str += "a";
+            while (true) {
+                break block;
+            }
+            str += "b";
+        }
+        statement: if (str) {
+            str += "c";
+            break statement;
+        }
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"ac\"");
+}
+
+#[test]
+fn continue_multiple_labels() {
+    let scenario = r#"
+        var count = 0;
+        outer: inner: while (count < 5) {
+            count++;
+            while (true) {
+                if (count < 3) continue outer;
+                break inner;
+            }
+        }
+        count
+    "#;
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn for_in_loop_order() {
+    let scenario = r#"
+        var proto = { inherited: 1, shadowed: 2 };
+        var obj = Object.create(proto);
+        obj.b = 1;
+        obj.a = 2;
+        obj[2] = 3;
+        obj[0] = 4;
+        obj.shadowed = 5;
+        var keys = [];
+        for (var key in obj) {
+            keys.push(key);
+        }
+        keys.join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"0,2,b,a,shadowed,inherited\"");
+}
+
+#[test]
+fn for_in_loop_deleted_property() {
+    let scenario = r#"
+        var obj = { a: 1, b: 2, c: 3 };
+        var keys = [];
+        for (let key in obj) {
+            keys.push(key);
+            delete obj.c;
+        }
+        keys.join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"a,b\"");
+}
+
+#[test]
+fn for_in_loop_non_enumerable() {
+    let scenario = r#"
+        var keys = [];
+        for (const key in [1, 2]) {
+            keys.push(key);
+        }
+        for (const key in "ab") {
+            keys.push(key);
+        }
+        keys.join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"0,1,0,1\"");
+}
+
+#[test]
+fn for_in_loop_null() {
+    let scenario = r#"
+        var count = 0;
+        for (var key in null) {
+            count++;
+        }
+        for (var key in undefined) {
+            count++;
+        }
+        count;
+    "#;
+
+    assert_eq!(&exec(scenario), "0");
+}
+
+#[test]
+fn for_in_loop_assignment_target() {
+    let scenario = r#"
+        var obj = {};
+        for (obj.key in { a: 1 }) {}
+        obj.key;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"a\"");
+}
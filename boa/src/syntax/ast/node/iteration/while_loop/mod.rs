@@ -41,6 +41,10 @@ impl WhileLoop {
         self.label.as_ref().map(Box::as_ref)
     }
 
+    pub fn set_label(&mut self, label: Box<str>) {
+        self.label = Some(label);
+    }
+
     /// Creates a `WhileLoop` AST node.
     pub fn new<C, B>(condition: C, body: B) -> Self
     where
@@ -59,6 +63,9 @@ impl WhileLoop {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
+        if let Some(label) = self.label() {
+            write!(f, "{}: ", label)?;
+        }
         write!(f, "while ({}) ", self.cond())?;
         self.expr().display(f, indentation)
     }
@@ -66,9 +73,27 @@ impl WhileLoop {
 
 impl Executable for WhileLoop {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let mut result = Value::undefined();
-        while self.cond().run(interpreter)?.to_boolean() {
-            result = self.expr().run(interpreter)?;
+        // The resume step is `0` if the condition was interrupted and `1` if the body was.
+        let (mut result, mut resume_body) = match interpreter.executor().take_resume_point(self) {
+            Some(point) => (point.values()[0].clone(), point.step() == 1),
+            None => (Value::undefined(), false),
+        };
+        loop {
+            if !resume_body {
+                let cond = self.cond().run(interpreter);
+                let cond = interpreter
+                    .executor()
+                    .save_resume_point(cond, self, 0, || vec![result.clone()])?;
+                if !cond.to_boolean() {
+                    break;
+                }
+            }
+            resume_body = false;
+
+            let body = self.expr().run(interpreter);
+            result = interpreter
+                .executor()
+                .save_resume_point(body, self, 1, || vec![result.clone()])?;
             match interpreter.executor().get_current_state() {
                 InterpreterState::Break(label) => {
                     handle_state_with_labels!(self, label, interpreter, break);
@@ -1,6 +1,7 @@
 //! This module implements the `Node` structure, which composes the AST.
 
 pub mod array;
+pub mod await_expr;
 pub mod block;
 pub mod break_node;
 pub mod call;
@@ -9,41 +10,65 @@ pub mod declaration;
 pub mod field;
 pub mod identifier;
 pub mod iteration;
+pub mod module;
 pub mod new;
 pub mod object;
 pub mod operator;
+pub mod optional;
+pub mod pattern;
 pub mod return_smt;
 pub mod spread;
 pub mod statement_list;
+pub mod super_call;
 pub mod switch;
+pub mod template;
 pub mod throw;
 pub mod try_node;
+pub mod with;
+pub mod yield_expr;
 
 pub use self::{
     array::ArrayDecl,
+    await_expr::Await,
     block::Block,
     break_node::Break,
     call::Call,
     conditional::{ConditionalOp, If},
     declaration::{
-        ArrowFunctionDecl, ConstDecl, ConstDeclList, FunctionDecl, FunctionExpr, LetDecl,
-        LetDeclList, VarDecl, VarDeclList,
+        ArrowFunctionDecl, AsyncArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, Class,
+        ClassDecl, ClassElement, ClassElementName, ClassExpr, ConstDecl, ConstDeclList,
+        FunctionDecl, FunctionExpr, GeneratorDecl, GeneratorExpr, LetDecl, LetDeclList, VarDecl,
+        VarDeclList,
     },
-    field::{GetConstField, GetField},
+    field::{GetConstField, GetField, GetPrivateField, GetSuperConstField, GetSuperField},
     identifier::Identifier,
-    iteration::{Continue, DoWhileLoop, ForLoop, ForOfLoop, WhileLoop},
+    iteration::{
+        Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, IterableLoopInitializer, WhileLoop,
+    },
+    module::{
+        ExportDecl, ExportSpecifier, ImportDecl, ImportName, ImportSpecifier, ModuleItem,
+        ModuleItemList, DEFAULT_EXPORT_BINDING,
+    },
     new::New,
     object::Object,
-    operator::{Assign, BinOp, UnaryOp},
+    operator::{Assign, BinOp, PrivateIn, UnaryOp},
+    optional::{Optional, OptionalOperation, OptionalOperationKind},
+    pattern::{
+        ArrayPattern, Binding, BindingElement, BindingProperty, ObjectPattern, PropertyName,
+    },
     return_smt::Return,
     spread::Spread,
     statement_list::{RcStatementList, StatementList},
+    super_call::SuperCall,
     switch::{Case, Switch},
+    template::{TaggedTemplate, TemplateLit},
     throw::Throw,
     try_node::{Catch, Finally, Try},
+    with::With,
+    yield_expr::Yield,
 };
 use super::Const;
-use crate::{exec::Executable, BoaProfiler, Context, Result, Value};
+use crate::{exec::Executable, module::Module, BoaProfiler, Context, Result, Value};
 use gc::{unsafe_empty_trace, Finalize, Trace};
 use std::{
     cmp::Ordering,
@@ -62,9 +87,21 @@ pub enum Node {
     /// An arrow function expression node. [More information](./arrow_function/struct.ArrowFunctionDecl.html).
     ArrowFunctionDecl(ArrowFunctionDecl),
 
+    /// An async arrow function expression node. [More information](./declaration/struct.AsyncArrowFunctionDecl.html).
+    AsyncArrowFunctionDecl(AsyncArrowFunctionDecl),
+
+    /// An async function declaration node. [More information](./declaration/struct.AsyncFunctionDecl.html).
+    AsyncFunctionDecl(AsyncFunctionDecl),
+
+    /// An async function expression node. [More information](./declaration/struct.AsyncFunctionExpr.html).
+    AsyncFunctionExpr(AsyncFunctionExpr),
+
     /// An assignment operator node. [More information](./operator/struct.Assign.html).
     Assign(Assign),
 
+    /// An `await` expression. [More information](./await_expr/struct.Await.html).
+    Await(Await),
+
     /// A binary operator node. [More information](./operator/struct.BinOp.html).
     BinOp(BinOp),
 
@@ -77,6 +114,12 @@ pub enum Node {
     /// A function call. [More information](./expression/struct.Call.html).
     Call(Call),
 
+    /// A class declaration. [More information](./declaration/struct.ClassDecl.html).
+    ClassDecl(ClassDecl),
+
+    /// A class expression. [More information](./declaration/struct.ClassExpr.html).
+    ClassExpr(ClassExpr),
+
     /// A javascript conditional operand ( x ? y : z ). [More information](./conditional/struct.ConditionalOp.html).
     ConditionalOp(ConditionalOp),
 
@@ -107,15 +150,33 @@ pub enum Node {
     /// A function expressino node. [More information](./declaration/struct.FunctionExpr.html).
     FunctionExpr(FunctionExpr),
 
+    /// A generator function declaration node. [More information](./declaration/struct.GeneratorDecl.html).
+    GeneratorDecl(GeneratorDecl),
+
+    /// A generator function expression node. [More information](./declaration/struct.GeneratorExpr.html).
+    GeneratorExpr(GeneratorExpr),
+
     /// Provides access to an object types' constant properties. [More information](./declaration/struct.GetConstField.html).
     GetConstField(GetConstField),
 
     /// Provides access to object fields. [More information](./declaration/struct.GetField.html).
     GetField(GetField),
 
+    /// Provides access to a constant property of the parent class. [More information](./field/struct.GetSuperConstField.html).
+    GetSuperConstField(GetSuperConstField),
+
+    /// Provides access to a property of the parent class. [More information](./field/struct.GetSuperField.html).
+    GetSuperField(GetSuperField),
+
+    /// Provides access to a private member of an object. [More information](./field/struct.GetPrivateField.html).
+    GetPrivateField(GetPrivateField),
+
     /// A `for` statement. [More information](./iteration/struct.ForLoop.html).
     ForLoop(ForLoop),
 
+    /// A `for...in` statement. [More information](./iteration/struct.ForInLoop.html).
+    ForInLoop(ForInLoop),
+
     /// A `for...of` statement. [More information](./iteration/struct.ForOf.html).
     ForOfLoop(ForOfLoop),
 
@@ -128,18 +189,57 @@ pub enum Node {
     /// A local identifier node. [More information](./identifier/struct.Identifier.html).
     Identifier(Identifier),
 
+    /// The `import.meta` meta property, an object with information about the module.
+    ///
+    /// It's created the first time that the code of the module accesses it, with the
+    /// properties that the host adds to it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-ImportMeta
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import.meta
+    ImportMeta,
+
     /// A `new` expression. [More information](./expression/struct.New.html).
     New(New),
 
+    /// The `new.target` meta-property, which is the constructor `new` was applied to, or
+    /// `undefined` when the function was called without `new`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-NewTarget
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/new.target
+    NewTarget,
+
     /// An object. [More information](./object/struct.Object.html).
     Object(Object),
 
+    /// An optional chain. [More information](./optional/struct.Optional.html).
+    Optional(Optional),
+
+    /// Checks if an object has a private member, like `#x in obj`. [More information](./operator/struct.PrivateIn.html).
+    PrivateIn(PrivateIn),
+
     /// A return statement. [More information](./object/struct.Return.html).
     Return(Return),
 
     /// A switch {case} statement. [More information](./switch/struct.Switch.html).
     Switch(Switch),
 
+    /// A tagged template. [More information](./template/struct.TaggedTemplate.html).
+    TaggedTemplate(TaggedTemplate),
+
+    /// A template literal. [More information](./template/struct.TemplateLit.html).
+    TemplateLit(TemplateLit),
+
+    /// A call to the parent class constructor. [More information](./super_call/struct.SuperCall.html).
+    SuperCall(SuperCall),
+
     /// A spread (...x) statement. [More information](./spread/struct.Spread.html).
     Spread(Spread),
 
@@ -171,6 +271,12 @@ pub enum Node {
 
     /// A 'while {...}' node. [More information](./iteration/struct.WhileLoop.html).
     WhileLoop(WhileLoop),
+
+    /// A `with` statement. [More information](./with/struct.With.html).
+    With(With),
+
+    /// A `yield` expression. [More information](./yield_expr/struct.Yield.html).
+    Yield(Yield),
 }
 
 impl Display for Node {
@@ -189,14 +295,128 @@ impl Node {
     /// Returns a node ordering based on the hoistability of each node.
     pub(crate) fn hoistable_order(a: &Node, b: &Node) -> Ordering {
         match (a, b) {
-            (Node::FunctionDecl(_), Node::FunctionDecl(_)) => Ordering::Equal,
-            (_, Node::FunctionDecl(_)) => Ordering::Greater,
-            (Node::FunctionDecl(_), _) => Ordering::Less,
+            (
+                Node::FunctionDecl(_) | Node::GeneratorDecl(_) | Node::AsyncFunctionDecl(_),
+                Node::FunctionDecl(_) | Node::GeneratorDecl(_) | Node::AsyncFunctionDecl(_),
+            ) => Ordering::Equal,
+            (_, Node::FunctionDecl(_) | Node::GeneratorDecl(_) | Node::AsyncFunctionDecl(_)) => {
+                Ordering::Greater
+            }
+            (Node::FunctionDecl(_) | Node::GeneratorDecl(_) | Node::AsyncFunctionDecl(_), _) => {
+                Ordering::Less
+            }
 
             (_, _) => Ordering::Equal,
         }
     }
 
+    /// Collects the names declared by the `var` declarations of the statement, including the
+    /// ones of the statements nested in it, in source order.
+    ///
+    /// Nested functions are not searched, since they have a scope of their own.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-vardeclarednames
+    pub(crate) fn var_declared_names<'a>(&'a self, names: &mut Vec<&'a str>) {
+        match self {
+            Self::VarDeclList(list) => {
+                for decl in list.as_ref() {
+                    decl.binding().bound_names(names);
+                }
+            }
+            Self::Block(block) => {
+                for node in block.statements() {
+                    node.var_declared_names(names);
+                }
+            }
+            Self::If(if_smt) => {
+                if_smt.body().var_declared_names(names);
+                if let Some(node) = if_smt.else_node() {
+                    node.var_declared_names(names);
+                }
+            }
+            Self::WhileLoop(while_loop) => while_loop.expr().var_declared_names(names),
+            Self::DoWhileLoop(do_while) => do_while.body().var_declared_names(names),
+            Self::ForLoop(for_loop) => {
+                if let Some(init) = for_loop.init() {
+                    init.var_declared_names(names);
+                }
+                for_loop.body().var_declared_names(names);
+            }
+            Self::ForInLoop(for_in) => {
+                if let IterableLoopInitializer::Var(binding) = for_in.variable() {
+                    binding.bound_names(names);
+                }
+                for_in.body().var_declared_names(names);
+            }
+            Self::ForOfLoop(for_of) => {
+                if let IterableLoopInitializer::Var(binding) = for_of.variable() {
+                    binding.bound_names(names);
+                }
+                for_of.body().var_declared_names(names);
+            }
+            Self::Try(try_node) => {
+                let catch = try_node.catch().map(Catch::block);
+                let finally = try_node.finally();
+                for block in std::iter::once(try_node.block())
+                    .chain(catch)
+                    .chain(finally)
+                {
+                    for node in block.statements() {
+                        node.var_declared_names(names);
+                    }
+                }
+            }
+            Self::Switch(switch) => {
+                let cases = switch.cases().iter().map(|case| case.body().statements());
+                for statements in cases.chain(switch.default()) {
+                    for node in statements {
+                        node.var_declared_names(names);
+                    }
+                }
+            }
+            Self::With(with) => with.body().var_declared_names(names),
+            _ => {}
+        }
+    }
+
+    /// Collects the names declared by the statement in the scope of the statement list that
+    /// contains it: the names of `let`, `const` and `class` declarations, and the names of
+    /// function declarations if `functions` is `true`.
+    ///
+    /// Function declarations are lexical declarations in blocks, but at the top level of a
+    /// function or script they are like `var` declarations.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-lexicallydeclarednames
+    pub(crate) fn lexically_declared_names<'a>(
+        &'a self,
+        names: &mut Vec<&'a str>,
+        functions: bool,
+    ) {
+        match self {
+            Self::LetDeclList(list) => {
+                for decl in list.as_ref() {
+                    decl.binding().bound_names(names);
+                }
+            }
+            Self::ConstDeclList(list) => {
+                for decl in list.as_ref() {
+                    decl.binding().bound_names(names);
+                }
+            }
+            Self::ClassDecl(decl) => names.push(decl.name()),
+            Self::FunctionDecl(decl) if functions => names.push(decl.name()),
+            Self::GeneratorDecl(decl) if functions => names.push(decl.name()),
+            Self::AsyncFunctionDecl(decl) if functions => names.push(decl.name()),
+            _ => {}
+        }
+    }
+
     /// Creates a `This` AST node.
     pub fn this() -> Self {
         Self::This
@@ -215,8 +435,11 @@ impl Node {
             Self::Const(ref c) => write!(f, "{}", c),
             Self::ConditionalOp(ref cond_op) => Display::fmt(cond_op, f),
             Self::ForLoop(ref for_loop) => for_loop.display(f, indentation),
+            Self::ForInLoop(ref for_in) => for_in.display(f, indentation),
             Self::ForOfLoop(ref for_of) => for_of.display(f, indentation),
             Self::This => write!(f, "this"),
+            Self::ImportMeta => write!(f, "import.meta"),
+            Self::NewTarget => write!(f, "new.target"),
             Self::Try(ref try_catch) => try_catch.display(f, indentation),
             Self::Break(ref break_smt) => Display::fmt(break_smt, f),
             Self::Continue(ref cont) => Display::fmt(cont, f),
@@ -224,17 +447,35 @@ impl Node {
             Self::Block(ref block) => block.display(f, indentation),
             Self::Identifier(ref s) => Display::fmt(s, f),
             Self::New(ref expr) => Display::fmt(expr, f),
+            Self::Optional(ref optional) => Display::fmt(optional, f),
             Self::GetConstField(ref get_const_field) => Display::fmt(get_const_field, f),
             Self::GetField(ref get_field) => Display::fmt(get_field, f),
+            Self::GetSuperConstField(ref field) => Display::fmt(field, f),
+            Self::GetSuperField(ref field) => Display::fmt(field, f),
+            Self::GetPrivateField(ref field) => Display::fmt(field, f),
+            Self::PrivateIn(ref op) => Display::fmt(op, f),
+            Self::SuperCall(ref call) => Display::fmt(call, f),
+            Self::TemplateLit(ref template) => Display::fmt(template, f),
+            Self::TaggedTemplate(ref template) => Display::fmt(template, f),
+            Self::ClassDecl(ref decl) => decl.display(f, indentation),
+            Self::ClassExpr(ref expr) => expr.display(f, indentation),
             Self::WhileLoop(ref while_loop) => while_loop.display(f, indentation),
             Self::DoWhileLoop(ref do_while) => do_while.display(f, indentation),
             Self::If(ref if_smt) => if_smt.display(f, indentation),
+            Self::With(ref with) => with.display(f, indentation),
             Self::Switch(ref switch) => switch.display(f, indentation),
             Self::Object(ref obj) => obj.display(f, indentation),
             Self::ArrayDecl(ref arr) => Display::fmt(arr, f),
             Self::VarDeclList(ref list) => Display::fmt(list, f),
             Self::FunctionDecl(ref decl) => decl.display(f, indentation),
             Self::FunctionExpr(ref expr) => expr.display(f, indentation),
+            Self::GeneratorDecl(ref decl) => decl.display(f, indentation),
+            Self::GeneratorExpr(ref expr) => expr.display(f, indentation),
+            Self::Yield(ref r#yield) => Display::fmt(r#yield, f),
+            Self::AsyncFunctionDecl(ref decl) => decl.display(f, indentation),
+            Self::AsyncFunctionExpr(ref expr) => expr.display(f, indentation),
+            Self::AsyncArrowFunctionDecl(ref decl) => decl.display(f, indentation),
+            Self::Await(ref r#await) => Display::fmt(r#await, f),
             Self::ArrowFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::BinOp(ref op) => Display::fmt(op, f),
             Self::UnaryOp(ref op) => Display::fmt(op, f),
@@ -266,11 +507,20 @@ impl Executable for Node {
             Node::Identifier(ref identifier) => identifier.run(interpreter),
             Node::GetConstField(ref get_const_field_node) => get_const_field_node.run(interpreter),
             Node::GetField(ref get_field) => get_field.run(interpreter),
+            Node::GetSuperConstField(ref field) => field.run(interpreter),
+            Node::GetSuperField(ref field) => field.run(interpreter),
+            Node::GetPrivateField(ref field) => field.run(interpreter),
+            Node::PrivateIn(ref op) => op.run(interpreter),
+            Node::SuperCall(ref call) => call.run(interpreter),
+            Node::TemplateLit(ref template) => template.run(interpreter),
+            Node::TaggedTemplate(ref template) => template.run(interpreter),
             Node::WhileLoop(ref while_loop) => while_loop.run(interpreter),
             Node::DoWhileLoop(ref do_while) => do_while.run(interpreter),
             Node::ForLoop(ref for_loop) => for_loop.run(interpreter),
+            Node::ForInLoop(ref for_in_loop) => for_in_loop.run(interpreter),
             Node::ForOfLoop(ref for_of_loop) => for_of_loop.run(interpreter),
             Node::If(ref if_smt) => if_smt.run(interpreter),
+            Node::With(ref with) => with.run(interpreter),
             Node::ConditionalOp(ref op) => op.run(interpreter),
             Node::Switch(ref switch) => switch.run(interpreter),
             Node::Object(ref obj) => obj.run(interpreter),
@@ -280,9 +530,19 @@ impl Executable for Node {
             // <https://tc39.es/ecma262/#sec-createdynamicfunction>
             Node::FunctionExpr(ref function_expr) => function_expr.run(interpreter),
             Node::ArrowFunctionDecl(ref decl) => decl.run(interpreter),
+            Node::GeneratorDecl(ref decl) => decl.run(interpreter),
+            Node::GeneratorExpr(ref expr) => expr.run(interpreter),
+            Node::Yield(ref r#yield) => r#yield.run(interpreter),
+            Node::AsyncFunctionDecl(ref decl) => decl.run(interpreter),
+            Node::AsyncFunctionExpr(ref expr) => expr.run(interpreter),
+            Node::AsyncArrowFunctionDecl(ref decl) => decl.run(interpreter),
+            Node::Await(ref r#await) => r#await.run(interpreter),
+            Node::ClassDecl(ref decl) => decl.run(interpreter),
+            Node::ClassExpr(ref expr) => expr.run(interpreter),
             Node::BinOp(ref op) => op.run(interpreter),
             Node::UnaryOp(ref op) => op.run(interpreter),
             Node::New(ref call) => call.run(interpreter),
+            Node::Optional(ref optional) => optional.run(interpreter),
             Node::Return(ref ret) => ret.run(interpreter),
             Node::Throw(ref throw) => throw.run(interpreter),
             Node::Assign(ref op) => op.run(interpreter),
@@ -292,7 +552,26 @@ impl Executable for Node {
             Node::Spread(ref spread) => spread.run(interpreter),
             Node::This => {
                 // Will either return `this` binding or undefined
-                Ok(interpreter.realm().environment.get_this_binding())
+                let this = interpreter.realm().environment.get_this_binding();
+                this.or_else(|e| interpreter.throw_reference_error(e.to_string()))
+            }
+            Node::ImportMeta => {
+                // The module is found through the scope chain, since functions declared in the
+                // module can run after it was evaluated.
+                match Module::running(interpreter) {
+                    Some(module) => module.import_meta(interpreter).map(Value::from),
+                    None => interpreter.throw_syntax_error("import.meta is only valid in modules"),
+                }
+            }
+            Node::NewTarget => {
+                // Arrow functions don't have a `this` binding, they use the `new.target` of the
+                // enclosing function.
+                let env = interpreter.realm().environment.get_this_environment();
+                let new_target = env
+                    .borrow()
+                    .as_function_environment_record()
+                    .map(|env| env.new_target.clone());
+                Ok(new_target.unwrap_or_default())
             }
             Node::Try(ref try_node) => try_node.run(interpreter),
             Node::Break(ref break_node) => break_node.run(interpreter),
@@ -335,38 +614,42 @@ where
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq, Trace, Finalize)]
 pub struct FormalParameter {
-    name: Box<str>,
-    init: Option<Node>,
+    element: BindingElement,
     is_rest_param: bool,
 }
 
 impl FormalParameter {
     /// Creates a new formal parameter.
-    pub(in crate::syntax) fn new<N>(name: N, init: Option<Node>, is_rest_param: bool) -> Self
+    pub(in crate::syntax) fn new<B>(binding: B, init: Option<Node>, is_rest_param: bool) -> Self
     where
-        N: Into<Box<str>>,
+        B: Into<Binding>,
     {
         Self {
-            name: name.into(),
-            init,
+            element: BindingElement::new(binding, init),
             is_rest_param,
         }
     }
 
-    /// Gets the name of the formal parameter.
-    pub fn name(&self) -> &str {
-        &self.name
+    /// Gets the binding of the formal parameter, an identifier or a destructuring pattern.
+    pub fn binding(&self) -> &Binding {
+        self.element.binding()
     }
 
     /// Gets the initialization node of the formal parameter, if any.
     pub fn init(&self) -> Option<&Node> {
-        self.init.as_ref()
+        self.element.init()
     }
 
     /// Gets wether the parameter is a rest parameter.
     pub fn is_rest_param(&self) -> bool {
         self.is_rest_param
     }
+
+    /// Binds the parameter to `value`, or to its initializer if `value` is `undefined`.
+    pub(crate) fn bind(&self, value: Value, interpreter: &mut Context) -> Result<()> {
+        self.element
+            .bind(value, pattern::BindingKind::Let, interpreter)
+    }
 }
 
 impl Display for FormalParameter {
@@ -374,11 +657,7 @@ impl Display for FormalParameter {
         if self.is_rest_param {
             write!(f, "...")?;
         }
-        write!(f, "{}", self.name)?;
-        if let Some(n) = self.init.as_ref() {
-            write!(f, " = {}", n)?;
-        }
-        Ok(())
+        write!(f, "{}", self.element)
     }
 }
 
@@ -410,13 +689,27 @@ pub enum PropertyDefinition {
 
     /// Binds a property name to a JavaScript value.
     ///
+    /// A literal `__proto__` name, like in `{ __proto__: p }`, sets the prototype of the object
+    /// instead, if the value is an object or `null`.
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///  - [MDN documentation][mdn]
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-PropertyDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Property_definitions
-    Property(Box<str>, Node),
+    Property(PropertyName, Node),
+
+    /// A shorthand property with an initializer, like `{ a = 1 }`.
+    ///
+    /// It's only valid when the object literal is reinterpreted as an assignment pattern, like
+    /// `({ a = 1 } = b)`, where it assigns the default value if the property is `undefined`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-CoverInitializedName
+    CoverInitializedName(Box<str>, Node),
 
     /// A property of an object can also refer to a function or a getter or setter method.
     ///
@@ -426,7 +719,7 @@ pub enum PropertyDefinition {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Method_definitions
-    MethodDefinition(MethodDefinitionKind, Box<str>, FunctionExpr),
+    MethodDefinition(MethodDefinitionKind, PropertyName, FunctionExpr),
 
     /// The Rest/Spread Properties for ECMAScript proposal (stage 4) adds spread properties to object literals.
     /// It copies own enumerable properties from a provided object onto a new object.
@@ -454,7 +747,7 @@ impl PropertyDefinition {
     /// Creates a `Property` definition.
     pub fn property<N, V>(name: N, value: V) -> Self
     where
-        N: Into<Box<str>>,
+        N: Into<PropertyName>,
         V: Into<Node>,
     {
         Self::Property(name.into(), value.into())
@@ -463,7 +756,7 @@ impl PropertyDefinition {
     /// Creates a `MethodDefinition`.
     pub fn method_definition<N>(kind: MethodDefinitionKind, name: N, body: FunctionExpr) -> Self
     where
-        N: Into<Box<str>>,
+        N: Into<PropertyName>,
     {
         Self::MethodDefinition(kind, name.into(), body)
     }
@@ -531,7 +824,26 @@ pub enum MethodDefinitionKind {
     /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions#Method_definition_syntax
     Ordinary,
-    // TODO: support other method definition kinds, like `Generator`.
+
+    /// A generator method, defined with a `*` before its name.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-GeneratorMethod
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Method_definitions#Generator_methods
+    Generator,
+
+    /// An async method, defined with `async` before its name.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-AsyncMethod
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Method_definitions#Async_methods
+    Async,
 }
 
 unsafe impl Trace for MethodDefinitionKind {
@@ -0,0 +1,434 @@
+//! Module nodes, the items that are only allowed at the top level of a module.
+
+use super::{join_nodes, Node};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The name of the binding of an `export default` declaration, or of its function or class if
+/// it doesn't have a name.
+///
+/// It's not a valid identifier, so the code of the module can't refer to it.
+pub const DEFAULT_EXPORT_BINDING: &str = "*default*";
+
+/// The body of a module, a list of import declarations, export declarations and statements.
+///
+/// Module code is always strict mode code.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItemList
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Modules
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ModuleItemList {
+    items: Box<[ModuleItem]>,
+}
+
+impl ModuleItemList {
+    /// Gets the items of the module.
+    pub fn items(&self) -> &[ModuleItem] {
+        &self.items
+    }
+}
+
+impl<T> From<T> for ModuleItemList
+where
+    T: Into<Box<[ModuleItem]>>,
+{
+    fn from(items: T) -> Self {
+        Self {
+            items: items.into(),
+        }
+    }
+}
+
+impl fmt::Display for ModuleItemList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for item in self.items.iter() {
+            match item {
+                ModuleItem::ImportDeclaration(decl) => writeln!(f, "{};", decl)?,
+                ModuleItem::ExportDeclaration(decl) => {
+                    decl.display(f)?;
+                    writeln!(f)?;
+                }
+                ModuleItem::StatementListItem(node) => {
+                    node.display(f, 0)?;
+                    match node {
+                        Node::Block(_) | Node::If(_) | Node::Switch(_) | Node::WhileLoop(_) => {}
+                        _ => write!(f, ";")?,
+                    }
+                    writeln!(f)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An item of the body of a module.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItem
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ModuleItem {
+    /// An `import` declaration.
+    ImportDeclaration(ImportDecl),
+    /// An `export` declaration.
+    ExportDeclaration(ExportDecl),
+    /// A statement or a declaration that is not exported.
+    StatementListItem(Node),
+}
+
+impl From<ImportDecl> for ModuleItem {
+    fn from(decl: ImportDecl) -> Self {
+        Self::ImportDeclaration(decl)
+    }
+}
+
+impl From<ExportDecl> for ModuleItem {
+    fn from(decl: ExportDecl) -> Self {
+        Self::ExportDeclaration(decl)
+    }
+}
+
+impl From<Node> for ModuleItem {
+    fn from(node: Node) -> Self {
+        Self::StatementListItem(node)
+    }
+}
+
+/// The `import` declaration imports bindings that are exported by another module.
+///
+/// An `import` declaration without bindings, like `import "module";`, only makes sure that the
+/// other module is evaluated first.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportDecl {
+    specifiers: Box<[ImportSpecifier]>,
+    module: Box<str>,
+}
+
+impl ImportDecl {
+    /// Creates an `ImportDecl` AST node.
+    pub fn new<S, M>(specifiers: S, module: M) -> Self
+    where
+        S: Into<Box<[ImportSpecifier]>>,
+        M: Into<Box<str>>,
+    {
+        Self {
+            specifiers: specifiers.into(),
+            module: module.into(),
+        }
+    }
+
+    /// Gets the bindings that are imported.
+    pub fn specifiers(&self) -> &[ImportSpecifier] {
+        &self.specifiers
+    }
+
+    /// Gets the specifier of the module that the bindings are imported from.
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+}
+
+impl fmt::Display for ImportDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("import ")?;
+        let mut named = Vec::new();
+        let mut first = true;
+        for specifier in self.specifiers.iter() {
+            match specifier.import_name() {
+                ImportName::Namespace => {}
+                ImportName::Name(name) if name.as_ref() == "default" => {}
+                ImportName::Name(_) => {
+                    named.push(specifier);
+                    continue;
+                }
+            }
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+            fmt::Display::fmt(specifier, f)?;
+        }
+        if !named.is_empty() {
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+            f.write_str("{ ")?;
+            join_nodes(f, &named)?;
+            f.write_str(" }")?;
+        }
+        if !first {
+            f.write_str(" from ")?;
+        }
+        write!(f, "\"{}\"", self.module)
+    }
+}
+
+/// The name of an imported binding in the module that exports it.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ImportName {
+    /// The namespace object of the module, imported with `* as name`.
+    Namespace,
+    /// An exported binding, the default export is named `default`.
+    Name(Box<str>),
+}
+
+/// A binding that is imported by an `import` declaration.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportSpecifier
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportSpecifier {
+    import_name: ImportName,
+    local_name: Box<str>,
+}
+
+impl ImportSpecifier {
+    /// Creates an `ImportSpecifier` AST node.
+    pub fn new<L>(import_name: ImportName, local_name: L) -> Self
+    where
+        L: Into<Box<str>>,
+    {
+        Self {
+            import_name,
+            local_name: local_name.into(),
+        }
+    }
+
+    /// Gets the name of the binding in the module that exports it.
+    pub fn import_name(&self) -> &ImportName {
+        &self.import_name
+    }
+
+    /// Gets the name of the binding in the importing module.
+    pub fn local_name(&self) -> &str {
+        &self.local_name
+    }
+}
+
+impl fmt::Display for ImportSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.import_name {
+            ImportName::Namespace => write!(f, "* as {}", self.local_name),
+            ImportName::Name(ref name)
+                if name.as_ref() == "default" || *name == self.local_name =>
+            {
+                f.write_str(&self.local_name)
+            }
+            ImportName::Name(ref name) => write!(f, "{} as {}", name, self.local_name),
+        }
+    }
+}
+
+/// The `export` declaration exports bindings from a module, so that other modules can import
+/// them.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ExportDecl {
+    /// `export * from "module";` re-exports all the bindings of another module, except for its
+    /// default export, and `export * as name from "module";` exports its namespace object.
+    ReExportAll {
+        /// The name of the exported namespace object, if any.
+        export_name: Option<Box<str>>,
+        /// The specifier of the module that is re-exported.
+        module: Box<str>,
+    },
+    /// `export { a, b as c };` exports bindings of the module, and
+    /// `export { a, b as c } from "module";` re-exports bindings of another module.
+    Named {
+        /// The exported bindings.
+        specifiers: Box<[ExportSpecifier]>,
+        /// The specifier of the module that the bindings are re-exported from, if any.
+        module: Option<Box<str>>,
+    },
+    /// `export var`, `export let`, `export const`, or an exported function or class declaration.
+    Declaration(Node),
+    /// `export default`, followed by a function or class declaration.
+    ///
+    /// If the declaration doesn't have a name, it's named [`DEFAULT_EXPORT_BINDING`].
+    DefaultDeclaration(Node),
+    /// `export default`, followed by an expression.
+    DefaultExpression(Node),
+}
+
+impl ExportDecl {
+    /// Gets the names that the declaration exports.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-exportednames
+    pub fn export_names(&self) -> Vec<&str> {
+        match self {
+            Self::ReExportAll { export_name, .. } => export_name.as_deref().into_iter().collect(),
+            Self::Named { specifiers, .. } => specifiers
+                .iter()
+                .map(ExportSpecifier::export_name)
+                .collect(),
+            Self::Declaration(node) => declaration_names(node),
+            Self::DefaultDeclaration(_) | Self::DefaultExpression(_) => vec!["default"],
+        }
+    }
+
+    /// Implements the display formatting.
+    fn display(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("export ")?;
+        match self {
+            Self::ReExportAll {
+                export_name,
+                module,
+            } => {
+                f.write_str("*")?;
+                if let Some(export_name) = export_name {
+                    write!(f, " as {}", export_name)?;
+                }
+                write!(f, " from \"{}\";", module)
+            }
+            Self::Named { specifiers, module } => {
+                f.write_str("{ ")?;
+                join_nodes(f, specifiers)?;
+                f.write_str(" }")?;
+                if let Some(module) = module {
+                    write!(f, " from \"{}\"", module)?;
+                }
+                f.write_str(";")
+            }
+            Self::Declaration(node) => {
+                node.display(f, 0)?;
+                match node {
+                    Node::VarDeclList(_) | Node::LetDeclList(_) | Node::ConstDeclList(_) => {
+                        f.write_str(";")
+                    }
+                    _ => Ok(()),
+                }
+            }
+            Self::DefaultDeclaration(node) => {
+                f.write_str("default ")?;
+                let declaration = node.to_string();
+                // An anonymous declaration is displayed without its name.
+                let anonymous = format!(" {}", DEFAULT_EXPORT_BINDING);
+                f.write_str(&declaration.replacen(&anonymous, "", 1))
+            }
+            Self::DefaultExpression(node) => write!(f, "default {};", node),
+        }
+    }
+}
+
+impl fmt::Display for ExportDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f)
+    }
+}
+
+/// A binding that is exported by an `export` declaration.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportSpecifier
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ExportSpecifier {
+    local_name: Box<str>,
+    export_name: Box<str>,
+}
+
+impl ExportSpecifier {
+    /// Creates an `ExportSpecifier` AST node.
+    pub fn new<L, E>(local_name: L, export_name: E) -> Self
+    where
+        L: Into<Box<str>>,
+        E: Into<Box<str>>,
+    {
+        Self {
+            local_name: local_name.into(),
+            export_name: export_name.into(),
+        }
+    }
+
+    /// Gets the name of the binding that is exported.
+    ///
+    /// For a re-export, this is the name of the binding in the module that it's re-exported
+    /// from.
+    pub fn local_name(&self) -> &str {
+        &self.local_name
+    }
+
+    /// Gets the name that the binding is exported as.
+    pub fn export_name(&self) -> &str {
+        &self.export_name
+    }
+}
+
+impl fmt::Display for ExportSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.local_name == self.export_name {
+            f.write_str(&self.local_name)
+        } else {
+            write!(f, "{} as {}", self.local_name, self.export_name)
+        }
+    }
+}
+
+/// Gets the names of the bindings that a declaration creates.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-boundnames
+pub(crate) fn declaration_names(node: &Node) -> Vec<&str> {
+    let mut names = Vec::new();
+    match node {
+        Node::VarDeclList(list) => {
+            for decl in list.as_ref() {
+                decl.binding().bound_names(&mut names);
+            }
+        }
+        Node::LetDeclList(list) => {
+            for decl in list.as_ref() {
+                decl.binding().bound_names(&mut names);
+            }
+        }
+        Node::ConstDeclList(list) => {
+            for decl in list.as_ref() {
+                decl.binding().bound_names(&mut names);
+            }
+        }
+        Node::FunctionDecl(decl) => names.push(decl.name()),
+        Node::GeneratorDecl(decl) => names.push(decl.name()),
+        Node::AsyncFunctionDecl(decl) => names.push(decl.name()),
+        Node::ClassDecl(decl) => names.push(decl.name()),
+        _ => {}
+    }
+    names
+}
@@ -47,14 +47,32 @@ impl Executable for New {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("New", "exec");
 
-        let func_object = self.expr().run(interpreter)?;
-        let mut v_args = Vec::with_capacity(self.args().len());
-        for arg in self.args() {
-            v_args.push(arg.run(interpreter)?);
+        // The resume step is the index of the argument that was interrupted, with the
+        // constructor and the arguments that were already evaluated.
+        let (func_object, mut v_args, start) = match interpreter.executor().take_resume_point(self)
+        {
+            Some(point) => {
+                let values = point.values();
+                (values[0].clone(), values[1..].to_vec(), point.step())
+            }
+            None => (
+                self.expr().run(interpreter)?,
+                Vec::with_capacity(self.args().len()),
+                0,
+            ),
+        };
+        for (i, arg) in self.args().iter().enumerate().skip(start) {
+            let val = arg.run(interpreter);
+            let val = interpreter.executor().save_resume_point(val, self, i, || {
+                let mut values = vec![func_object.clone()];
+                values.extend_from_slice(&v_args);
+                values
+            })?;
+            v_args.push(val);
         }
 
         match func_object {
-            Value::Object(ref object) => object.construct(&v_args, interpreter),
+            Value::Object(ref object) => object.construct(&v_args, &func_object, interpreter),
             _ => Ok(Value::undefined()),
         }
     }
@@ -2,7 +2,8 @@
 
 use crate::{
     exec::Executable,
-    syntax::ast::node::{MethodDefinitionKind, Node, PropertyDefinition},
+    property::{Attribute, DataDescriptor, PropertyKey},
+    syntax::ast::node::{Identifier, MethodDefinitionKind, Node, PropertyDefinition, PropertyName},
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -11,6 +12,9 @@ use std::fmt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 /// Objects in JavaScript may be defined as an unordered collection of related data, of
 /// primitive or reference types, in the form of “key: value” pairs.
 ///
@@ -49,51 +53,122 @@ impl Object {
         indent: usize,
     ) -> fmt::Result {
         f.write_str("{\n")?;
+        let indentation = "    ".repeat(indent + 1);
         for property in self.properties().iter() {
+            f.write_str(&indentation)?;
             match property {
                 PropertyDefinition::IdentifierReference(key) => {
-                    write!(f, "{}    {},", indent, key)?;
+                    write!(f, "{}", key)?;
                 }
                 PropertyDefinition::Property(key, value) => {
-                    write!(f, "{}    {}: {},", indent, key, value)?;
+                    write!(f, "{}: {}", key, value)?;
+                }
+                PropertyDefinition::CoverInitializedName(key, init) => {
+                    write!(f, "{} = {}", key, init)?;
                 }
                 PropertyDefinition::SpreadObject(key) => {
-                    write!(f, "{}    ...{},", indent, key)?;
+                    write!(f, "...{}", key)?;
                 }
-                PropertyDefinition::MethodDefinition(_kind, _key, _node) => {
-                    // TODO: Implement display for PropertyDefinition::MethodDefinition.
-                    unimplemented!("Display for PropertyDefinition::MethodDefinition");
+                PropertyDefinition::MethodDefinition(kind, key, func) => {
+                    match kind {
+                        MethodDefinitionKind::Get => f.write_str("get ")?,
+                        MethodDefinitionKind::Set => f.write_str("set ")?,
+                        MethodDefinitionKind::Generator => f.write_str("*")?,
+                        MethodDefinitionKind::Async => f.write_str("async ")?,
+                        MethodDefinitionKind::Ordinary => {}
+                    }
+                    write!(f, "{}", key)?;
+                    func.display_method(f, indent + 1)?;
                 }
             }
+            f.write_str(",\n")?;
+        }
+        write!(f, "{}}}", "    ".repeat(indent))
+    }
+
+    /// Evaluates the name of a property, saving the object if the evaluation is interrupted.
+    fn property_key(
+        &self,
+        name: &PropertyName,
+        step: usize,
+        obj: &Value,
+        interpreter: &mut Context,
+    ) -> Result<PropertyKey> {
+        match name {
+            PropertyName::Literal(name) => Ok(name.clone().into()),
+            PropertyName::Computed(node) => {
+                let key = node.run(interpreter);
+                let key = interpreter
+                    .executor()
+                    .save_resume_point(key, self, step, || vec![obj.clone()])?;
+                key.to_property_key(interpreter)
+            }
         }
-        f.write_str("}")
     }
 }
 
 impl Executable for Object {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let global_val = &interpreter
-            .realm()
-            .environment
-            .get_global_object()
-            .expect("Could not get the global object");
-        let obj = Value::new_object(Some(global_val));
+        // The resume step is the index of the property that was interrupted, with the object,
+        // and the key of the property if it was already evaluated.
+        let (obj, start, mut saved_key) = match interpreter.executor().take_resume_point(self) {
+            Some(point) => {
+                let values = point.values();
+                (values[0].clone(), point.step(), values.get(1).cloned())
+            }
+            None => (interpreter.construct_object().into(), 0, None),
+        };
+        let object = obj.as_gc_object().expect("object literals are objects");
 
-        // TODO: Implement the rest of the property types.
-        for property in self.properties().iter() {
+        for (i, property) in self.properties().iter().enumerate().skip(start) {
+            let saved_key = saved_key.take();
             match property {
-                PropertyDefinition::Property(key, value) => {
-                    obj.set_field(key.clone(), value.run(interpreter)?);
+                PropertyDefinition::IdentifierReference(key) => {
+                    let value = Identifier::from(key.as_ref()).run(interpreter)?;
+                    obj.set_property(key.clone(), DataDescriptor::new(value, Attribute::all()));
                 }
-                PropertyDefinition::MethodDefinition(kind, name, func) => {
-                    if let MethodDefinitionKind::Ordinary = kind {
-                        obj.set_field(name.clone(), func.run(interpreter)?);
-                    } else {
-                        // TODO: Implement other types of MethodDefinitionKinds.
-                        unimplemented!("other types of property method definitions.");
+                PropertyDefinition::CoverInitializedName(..) => {
+                    return interpreter
+                        .throw_syntax_error("invalid shorthand property initializer");
+                }
+                // A `__proto__: value` property sets the prototype of the object, unless its
+                // name is computed.
+                PropertyDefinition::Property(PropertyName::Literal(name), value)
+                    if &**name == "__proto__" =>
+                {
+                    let value = value.run(interpreter);
+                    let value = interpreter
+                        .executor()
+                        .save_resume_point(value, self, i, || vec![obj.clone()])?;
+                    if value.is_object() || value.is_null() {
+                        object.borrow_mut().set_prototype_instance(value);
                     }
                 }
-                i => unimplemented!("{:?} type of property", i),
+                PropertyDefinition::Property(name, value) => {
+                    let key = match saved_key {
+                        Some(key) => key.to_property_key(interpreter)?,
+                        None => self.property_key(name, i, &obj, interpreter)?,
+                    };
+                    let value = value.run(interpreter);
+                    let value = interpreter
+                        .executor()
+                        .save_resume_point(value, self, i, || {
+                            vec![obj.clone(), key.clone().into()]
+                        })?;
+                    obj.set_property(key, DataDescriptor::new(value, Attribute::all()));
+                }
+                PropertyDefinition::MethodDefinition(kind, name, func) => {
+                    let key = self.property_key(name, i, &obj, interpreter)?;
+                    func.define_method(*kind, &object, key, true, interpreter);
+                }
+                PropertyDefinition::SpreadObject(node) => {
+                    let source = node.run(interpreter);
+                    let source =
+                        interpreter
+                            .executor()
+                            .save_resume_point(source, self, i, || vec![obj.clone()])?;
+                    object.copy_data_properties(&source, &[], interpreter)?;
+                }
             }
         }
 
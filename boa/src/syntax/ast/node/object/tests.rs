@@ -0,0 +1,97 @@
+use crate::{exec, syntax::Parser};
+
+#[test]
+fn object_getter_and_setter() {
+    let scenario = r#"
+        let obj = {
+            value: 1,
+            get double() { return this.value * 2; },
+            set double(v) { this.value = v / 2; },
+        };
+        obj.double = 10;
+        obj.value + ":" + obj.double;
+    "#;
+    assert_eq!(&exec(scenario), "\"5:10\"");
+}
+
+#[test]
+fn object_accessors_are_enumerable() {
+    let scenario = r#"
+        let obj = { get a() { return 1; }, set a(v) {} };
+        let keys = [];
+        for (let key in obj) {
+            keys.push(key);
+        }
+        keys.join() + ":" + obj.propertyIsEnumerable("a");
+    "#;
+    assert_eq!(&exec(scenario), "\"a:true\"");
+}
+
+#[test]
+fn object_computed_property_names() {
+    let scenario = r#"
+        let key = "b";
+        let sym = Symbol("sym");
+        let obj = { ["a" + key]: 1, [1 + 1]: 2, [sym]() { return 3; } };
+        obj.ab + obj[2] + obj[sym]() + ":" + obj[sym].name;
+    "#;
+    assert_eq!(&exec(scenario), "\"6:[sym]\"");
+}
+
+#[test]
+fn object_shorthand_methods() {
+    let scenario = r#"
+        let obj = {
+            x: 2,
+            method(y) { return this.x * y; },
+        };
+        obj.method(3) + ":" + obj.method.name + ":" + obj.method.hasOwnProperty("prototype");
+    "#;
+    assert_eq!(&exec(scenario), "\"6:method:false\"");
+}
+
+#[test]
+fn object_proto_property() {
+    let scenario = r#"
+        let proto = { inherited: 1 };
+        let obj = { __proto__: proto };
+        let computed = { ["__proto__"]: proto };
+        let empty = { __proto__: null };
+        obj.inherited + ":" + obj.hasOwnProperty("__proto__") + ":"
+            + computed.hasOwnProperty("__proto__") + ":" + Object.getPrototypeOf(empty);
+    "#;
+    assert_eq!(&exec(scenario), "\"1:false:true:null\"");
+}
+
+#[test]
+fn object_spread() {
+    let scenario = r#"
+        let source = { a: 1, b: 2 };
+        Object.defineProperty(source, "hidden", { value: 3, enumerable: false });
+        let obj = { b: 0, ...source, c: 3, ...null, ...undefined };
+        obj.a + obj.b + obj.c + ":" + obj.hidden;
+    "#;
+    assert_eq!(&exec(scenario), "\"6:undefined\"");
+}
+
+#[test]
+fn object_spread_runs_getters() {
+    let scenario = r#"
+        let calls = 0;
+        let obj = { ...{ get a() { calls += 1; return 1; } } };
+        obj.a = 2;
+        calls + ":" + obj.a;
+    "#;
+    assert_eq!(&exec(scenario), "\"1:2\"");
+}
+
+#[test]
+fn object_literal_display() {
+    let statements = Parser::new(&b"({ a: 1, get b() { return 2; }, [c]() {}, ...d });"[..])
+        .parse_all()
+        .expect("parsing failed");
+    let displayed = statements.to_string();
+    assert!(displayed.contains("get b() {"));
+    assert!(displayed.contains("[c]() {"));
+    assert!(displayed.contains("...d,"));
+}
@@ -1,5 +1,9 @@
 use crate::{
-    environment::lexical_environment::VariableScope, exec::Executable, syntax::ast::node::Node,
+    exec::Executable,
+    syntax::ast::node::{
+        pattern::{Binding, BindingKind},
+        Node,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -13,6 +17,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// Assignment operator (`=`), assigns the value of its right operand to its left operand.
 ///
+/// The left operand can also be an array or object pattern, like `[a, b] = [b, a]`, which
+/// destructures the value of the right operand.
+///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///  - [MDN documentation][mdn]
@@ -22,7 +29,7 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Assign {
-    lhs: Box<Node>,
+    lhs: Binding,
     rhs: Box<Node>,
 }
 
@@ -30,17 +37,17 @@ impl Assign {
     /// Creates an `Assign` AST node.
     pub(in crate::syntax) fn new<L, R>(lhs: L, rhs: R) -> Self
     where
-        L: Into<Node>,
+        L: Into<Binding>,
         R: Into<Node>,
     {
         Self {
-            lhs: Box::new(lhs.into()),
+            lhs: lhs.into(),
             rhs: Box::new(rhs.into()),
         }
     }
 
     /// Gets the left hand side of the assignment operation.
-    pub fn lhs(&self) -> &Node {
+    pub fn lhs(&self) -> &Binding {
         &self.lhs
     }
 
@@ -53,35 +60,16 @@ impl Assign {
 impl Executable for Assign {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Assign", "exec");
-        let val = self.rhs().run(interpreter)?;
-        match self.lhs() {
-            Node::Identifier(ref name) => {
-                let environment = &mut interpreter.realm_mut().environment;
-
-                if environment.has_binding(name.as_ref()) {
-                    // Binding already exists
-                    environment.set_mutable_binding(name.as_ref(), val.clone(), true);
-                } else {
-                    environment.create_mutable_binding(
-                        name.as_ref().to_owned(),
-                        true,
-                        VariableScope::Function,
-                    );
-                    environment.initialize_binding(name.as_ref(), val.clone());
-                }
-            }
-            Node::GetConstField(ref get_const_field) => {
-                let val_obj = get_const_field.obj().run(interpreter)?;
-                val_obj.set_field(get_const_field.field(), val.clone());
-            }
-            Node::GetField(ref get_field) => {
-                let object = get_field.obj().run(interpreter)?;
-                let field = get_field.field().run(interpreter)?;
-                let key = field.to_property_key(interpreter)?;
-                object.set_field(key, val.clone());
-            }
-            _ => (),
-        }
+        // The resume step is `1` if the left hand side was interrupted, with the value of the
+        // right hand side.
+        let val = match interpreter.executor().take_resume_point(self) {
+            Some(point) => point.values()[0].clone(),
+            None => self.rhs().run(interpreter)?,
+        };
+        let result = self.lhs.bind(val.clone(), BindingKind::Assign, interpreter);
+        interpreter
+            .executor()
+            .save_resume_point(result, self, 1, || vec![val.clone()])?;
         Ok(val)
     }
 }
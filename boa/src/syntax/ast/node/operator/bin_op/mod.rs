@@ -1,7 +1,8 @@
 use crate::{
     exec::Executable,
+    property::PropertyKey,
     syntax::ast::{
-        node::Node,
+        node::{field::resolve_private_name, Node},
         op::{self, AssignOp, BitOp, CompOp, LogOp, NumOp},
     },
     Context, Result, Value,
@@ -56,6 +57,21 @@ impl BinOp {
         &self.rhs
     }
 
+    /// Evaluates the left hand side and then the right hand side of the operation.
+    fn run_operands(&self, interpreter: &mut Context) -> Result<(Value, Value)> {
+        // The resume step is `1` if the right hand side was interrupted, with the value of the
+        // left hand side.
+        let x = match interpreter.executor().take_resume_point(self) {
+            Some(point) => point.values()[0].clone(),
+            None => self.lhs().run(interpreter)?,
+        };
+        let y = self.rhs().run(interpreter);
+        let y = interpreter
+            .executor()
+            .save_resume_point(y, self, 1, || vec![x.clone()])?;
+        Ok((x, y))
+    }
+
     /// Runs the assignment operators.
     fn run_assign(op: AssignOp, x: Value, y: Value, interpreter: &mut Context) -> Result<Value> {
         match op {
@@ -71,6 +87,20 @@ impl BinOp {
             AssignOp::Shl => x.shl(&y, interpreter),
             AssignOp::Shr => x.shr(&y, interpreter),
             AssignOp::Ushr => x.ushr(&y, interpreter),
+            // The logical assignment operators assign the right hand side, if they don't short
+            // circuit.
+            AssignOp::BoolAnd | AssignOp::BoolOr | AssignOp::Coalesce => Ok(y),
+        }
+    }
+
+    /// Returns `true` if the logical assignment operators don't assign to a target with the given
+    /// value, and don't evaluate the right hand side.
+    fn assign_short_circuits(op: AssignOp, x: &Value) -> bool {
+        match op {
+            AssignOp::BoolAnd => !x.to_boolean(),
+            AssignOp::BoolOr => x.to_boolean(),
+            AssignOp::Coalesce => !x.is_null_or_undefined(),
+            _ => false,
         }
     }
 }
@@ -79,8 +109,7 @@ impl Executable for BinOp {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         match self.op() {
             op::BinOp::Num(op) => {
-                let x = self.lhs().run(interpreter)?;
-                let y = self.rhs().run(interpreter)?;
+                let (x, y) = self.run_operands(interpreter)?;
                 match op {
                     NumOp::Add => x.add(&y, interpreter),
                     NumOp::Sub => x.sub(&y, interpreter),
@@ -91,8 +120,7 @@ impl Executable for BinOp {
                 }
             }
             op::BinOp::Bit(op) => {
-                let x = self.lhs().run(interpreter)?;
-                let y = self.rhs().run(interpreter)?;
+                let (x, y) = self.run_operands(interpreter)?;
                 match op {
                     BitOp::And => x.bitand(&y, interpreter),
                     BitOp::Or => x.bitor(&y, interpreter),
@@ -103,8 +131,7 @@ impl Executable for BinOp {
                 }
             }
             op::BinOp::Comp(op) => {
-                let x = self.lhs().run(interpreter)?;
-                let y = self.rhs().run(interpreter)?;
+                let (x, y) = self.run_operands(interpreter)?;
                 Ok(Value::from(match op {
                     CompOp::Equal => x.equals(&y, interpreter)?,
                     CompOp::NotEqual => !x.equals(&y, interpreter)?,
@@ -124,62 +151,144 @@ impl Executable for BinOp {
                         let key = x.to_property_key(interpreter)?;
                         interpreter.has_property(&y, &key)
                     }
-                    CompOp::InstanceOf => {
-                        if !y.is_object() {
-                            return interpreter.throw_type_error(format!(
-                                "right-hand side of 'instanceof' should be an object, got {}",
-                                y.get_type().as_str()
-                            ));
-                        }
-
-                        // spec: https://tc39.es/ecma262/#sec-instanceofoperator
-                        todo!("instanceof operator")
-                    }
+                    CompOp::InstanceOf => x.instance_of(&y, interpreter)?,
                 }))
             }
             op::BinOp::Log(op) => {
-                // turn a `Value` into a `bool`
-                let to_bool = |value| bool::from(&value);
-                Ok(match op {
-                    LogOp::And => Value::from(
-                        to_bool(self.lhs().run(interpreter)?)
-                            && to_bool(self.rhs().run(interpreter)?),
-                    ),
-                    LogOp::Or => Value::from(
-                        to_bool(self.lhs().run(interpreter)?)
-                            || to_bool(self.rhs().run(interpreter)?),
-                    ),
-                })
+                // The right hand side is only interrupted if it had to be evaluated.
+                if interpreter.executor().take_resume_point(self).is_none() {
+                    let x = self.lhs().run(interpreter)?;
+                    let short_circuits = match op {
+                        LogOp::And => !x.to_boolean(),
+                        LogOp::Or => x.to_boolean(),
+                        LogOp::Coalesce => !x.is_null_or_undefined(),
+                    };
+                    if short_circuits {
+                        return Ok(x);
+                    }
+                }
+                let y = self.rhs().run(interpreter);
+                interpreter
+                    .executor()
+                    .save_resume_point(y, self, 1, Vec::new)
             }
             op::BinOp::Assign(op) => match self.lhs() {
                 Node::Identifier(ref name) => {
-                    let v_a = interpreter
-                        .realm()
-                        .environment
-                        .get_binding_value(name.as_ref())
-                        .ok_or_else(|| interpreter.construct_reference_error(name.as_ref()))?;
-                    let v_b = self.rhs().run(interpreter)?;
+                    // The resume step is `1` if the right hand side was interrupted, with the
+                    // value of the binding.
+                    let v_a = if let Some(point) = interpreter.executor().take_resume_point(self) {
+                        point.values()[0].clone()
+                    } else {
+                        let v_a = interpreter.get_binding_value(name.as_ref())?;
+                        if Self::assign_short_circuits(op, &v_a) {
+                            return Ok(v_a);
+                        }
+                        v_a
+                    };
+                    let v_b = self.rhs().run(interpreter);
+                    let v_b = interpreter
+                        .executor()
+                        .save_resume_point(v_b, self, 1, || vec![v_a.clone()])?;
                     let value = Self::run_assign(op, v_a, v_b, interpreter)?;
-                    interpreter.realm_mut().environment.set_mutable_binding(
-                        name.as_ref(),
-                        value.clone(),
-                        true,
-                    );
+                    interpreter.set_mutable_binding(name.as_ref(), value.clone())?;
                     Ok(value)
                 }
                 Node::GetConstField(ref get_const_field) => {
-                    let v_r_a = get_const_field.obj().run(interpreter)?;
-                    let v_a = v_r_a.get_field(get_const_field.field());
-                    let v_b = self.rhs().run(interpreter)?;
+                    // The resume step is `1` if the right hand side was interrupted, with the
+                    // object and the value of its field.
+                    let (v_r_a, v_a) = if let Some(point) =
+                        interpreter.executor().take_resume_point(self)
+                    {
+                        (point.values()[0].clone(), point.values()[1].clone())
+                    } else {
+                        let v_r_a = get_const_field.obj().run(interpreter)?;
+                        let obj = v_r_a.to_object(interpreter)?;
+                        let v_a =
+                            obj.get(&get_const_field.field().into(), v_r_a.clone(), interpreter)?;
+                        if Self::assign_short_circuits(op, &v_a) {
+                            return Ok(v_a);
+                        }
+                        (v_r_a, v_a)
+                    };
+                    let obj = v_r_a.to_object(interpreter)?;
+                    let v_b = self.rhs().run(interpreter);
+                    let v_b = interpreter
+                        .executor()
+                        .save_resume_point(v_b, self, 1, || vec![v_r_a.clone(), v_a.clone()])?;
+                    let value = Self::run_assign(op, v_a, v_b, interpreter)?;
+                    let key: PropertyKey = get_const_field.field().into();
+                    let succeeded = obj.set(key.clone(), value.clone(), v_r_a, interpreter)?;
+                    interpreter.check_assignment(succeeded, &key)?;
+                    Ok(value)
+                }
+                Node::GetField(ref get_field) => {
+                    // The resume step is `1` if the right hand side was interrupted, with the
+                    // object, the key and the value of its field.
+                    let (v_r_a, key, v_a) =
+                        if let Some(point) = interpreter.executor().take_resume_point(self) {
+                            let values = point.values();
+                            (values[0].clone(), values[1].clone(), values[2].clone())
+                        } else {
+                            let v_r_a = get_field.obj().run(interpreter)?;
+                            let key = get_field
+                                .field()
+                                .run(interpreter)?
+                                .to_property_key(interpreter)?;
+                            let obj = v_r_a.to_object(interpreter)?;
+                            let v_a = obj.get(&key, v_r_a.clone(), interpreter)?;
+                            if Self::assign_short_circuits(op, &v_a) {
+                                return Ok(v_a);
+                            }
+                            (v_r_a, key.into(), v_a)
+                        };
+                    let obj = v_r_a.to_object(interpreter)?;
+                    let v_b = self.rhs().run(interpreter);
+                    let v_b = interpreter.executor().save_resume_point(v_b, self, 1, || {
+                        vec![v_r_a.clone(), key.clone(), v_a.clone()]
+                    })?;
+                    let value = Self::run_assign(op, v_a, v_b, interpreter)?;
+                    let key = key.to_property_key(interpreter)?;
+                    let succeeded = obj.set(key.clone(), value.clone(), v_r_a, interpreter)?;
+                    interpreter.check_assignment(succeeded, &key)?;
+                    Ok(value)
+                }
+                Node::GetPrivateField(ref get_private_field) => {
+                    // The resume step is `1` if the right hand side was interrupted, with the
+                    // object and the value of its private member.
+                    let name = resolve_private_name(get_private_field.field(), interpreter)?;
+                    let (v_r_a, v_a) =
+                        if let Some(point) = interpreter.executor().take_resume_point(self) {
+                            (point.values()[0].clone(), point.values()[1].clone())
+                        } else {
+                            let v_r_a = get_private_field.obj().run(interpreter)?;
+                            let v_a = v_r_a
+                                .to_object(interpreter)?
+                                .private_get(&name, interpreter)?;
+                            if Self::assign_short_circuits(op, &v_a) {
+                                return Ok(v_a);
+                            }
+                            (v_r_a, v_a)
+                        };
+                    let obj = v_r_a.to_object(interpreter)?;
+                    let v_b = self.rhs().run(interpreter);
+                    let v_b = interpreter
+                        .executor()
+                        .save_resume_point(v_b, self, 1, || vec![v_r_a.clone(), v_a.clone()])?;
                     let value = Self::run_assign(op, v_a, v_b, interpreter)?;
-                    v_r_a.set_field(get_const_field.field(), value.clone());
+                    obj.private_set(&name, value.clone(), interpreter)?;
                     Ok(value)
                 }
                 _ => Ok(Value::undefined()),
             },
             op::BinOp::Comma => {
-                self.lhs().run(interpreter)?;
-                Ok(self.rhs().run(interpreter)?)
+                // The resume step is `1` if the right hand side was interrupted.
+                if interpreter.executor().take_resume_point(self).is_none() {
+                    self.lhs().run(interpreter)?;
+                }
+                let result = self.rhs().run(interpreter);
+                interpreter
+                    .executor()
+                    .save_resume_point(result, self, 1, Vec::new)
             }
         }
     }
@@ -0,0 +1,82 @@
+use crate::{
+    exec::Executable,
+    syntax::ast::node::{field::resolve_private_name, Node},
+    Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `#x in obj` expression checks if an object has the private member `#x`, which is a way to
+/// check if the object is an instance of the class that declares it without throwing a
+/// `TypeError`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-relational-operators-runtime-semantics-evaluation
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/in
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct PrivateIn {
+    name: Box<str>,
+    target: Box<Node>,
+}
+
+impl PrivateIn {
+    /// Creates a `PrivateIn` AST node, the name of the private member doesn't include the `#`.
+    pub fn new<N, T>(name: N, target: T) -> Self
+    where
+        N: Into<Box<str>>,
+        T: Into<Node>,
+    {
+        Self {
+            name: name.into(),
+            target: Box::new(target.into()),
+        }
+    }
+
+    /// Gets the name of the private member, without the `#`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the expression of the object that is checked.
+    pub fn target(&self) -> &Node {
+        &self.target
+    }
+}
+
+impl Executable for PrivateIn {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let target = self.target().run(interpreter)?;
+        let object = match target {
+            Value::Object(ref object) => object,
+            _ => {
+                return interpreter.throw_type_error(format!(
+                    "Cannot use 'in' operator to search for '#{}' in {}",
+                    self.name(),
+                    target.display()
+                ))
+            }
+        };
+        let name = resolve_private_name(self.name(), interpreter)?;
+        let found = object.borrow().private_element_find(&name).is_some();
+        Ok(found.into())
+    }
+}
+
+impl fmt::Display for PrivateIn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{} in {}", self.name, self.target)
+    }
+}
+
+impl From<PrivateIn> for Node {
+    fn from(op: PrivateIn) -> Self {
+        Self::PrivateIn(op)
+    }
+}
@@ -26,3 +26,62 @@ fn assignmentoperator_rhs_throws_error() {
 
     assert_eq!(&exec(scenario), "\"ReferenceError: b is not defined\"");
 }
+
+#[test]
+fn logical_operators_return_operands() {
+    let scenario = r#"
+        `${0 || "a"} ${1 && "b"} ${"" && "c"} ${"d" || "e"}`;
+    "#;
+    assert_eq!(&exec(scenario), "\"a b  d\"");
+}
+
+#[test]
+fn nullish_coalescing() {
+    let scenario = r#"
+        let count = 0;
+        let results = [null ?? 1, undefined ?? 2, 0 ?? 3, "" ?? 4, false ?? count++];
+        results.join(",") + " " + count;
+    "#;
+    assert_eq!(&exec(scenario), "\"1,2,0,,false 0\"");
+}
+
+#[test]
+fn logical_assignment() {
+    let scenario = r#"
+        let a = 1, b = 0, c = null;
+        a &&= 2;
+        b ||= 3;
+        c ??= 4;
+        let obj = { d: 0, e: "x" };
+        obj.d ||= 5;
+        obj["e"] ??= 6;
+        let key = "f";
+        obj[key] ??= 7;
+        `${a} ${b} ${c} ${obj.d} ${obj.e} ${obj.f}`;
+    "#;
+    assert_eq!(&exec(scenario), "\"2 3 4 5 x 7\"");
+}
+
+#[test]
+fn logical_assignment_short_circuits() {
+    let scenario = r#"
+        let count = 0;
+        let a = 0, b = 1, c = 2;
+        a &&= count++;
+        b ||= count++;
+        c ??= count++;
+        `${a} ${b} ${c} ${count}`;
+    "#;
+    assert_eq!(&exec(scenario), "\"0 1 2 0\"");
+}
+
+#[test]
+fn compound_assignment_computed_member() {
+    let scenario = r#"
+        let obj = { a: 1 };
+        let key = "a";
+        obj[key] += 2;
+        obj[key];
+    "#;
+    assert_eq!(&exec(scenario), "3");
+}
@@ -1,6 +1,15 @@
 use crate::{
+    environment::private_environment_record::PrivateName,
     exec::Executable,
-    syntax::ast::{node::Node, op},
+    object::GcObject,
+    property::PropertyKey,
+    syntax::ast::{
+        node::{
+            field::{resolve_private_name, super_reference_base},
+            Node,
+        },
+        op,
+    },
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -47,33 +56,120 @@ impl UnaryOp {
     }
 }
 
+impl UnaryOp {
+    /// Evaluates the target of `delete` or of an update operator as a reference, so that the
+    /// object and the key of a property access are evaluated only once.
+    ///
+    /// Returns `None` if the target is not a reference, without evaluating it. The resume step is
+    /// `1` if the key of a computed property access was interrupted, with the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reference-record-specification-type
+    fn reference(&self, interpreter: &mut Context) -> Result<Option<Reference<'_>>> {
+        Ok(Some(match self.target() {
+            Node::Identifier(ref name) => Reference::Binding(name.as_ref()),
+            Node::GetConstField(ref get_const_field) => Reference::Property(
+                get_const_field.obj().run(interpreter)?,
+                get_const_field.field().into(),
+            ),
+            Node::GetField(ref get_field) => {
+                let obj = match interpreter.executor().take_resume_point(self) {
+                    Some(point) => point.values()[0].clone(),
+                    None => get_field.obj().run(interpreter)?,
+                };
+                let field = get_field.field().run(interpreter);
+                let field = interpreter
+                    .executor()
+                    .save_resume_point(field, self, 1, || vec![obj.clone()])?;
+                let key = field.to_property_key(interpreter)?;
+                Reference::Property(obj, key)
+            }
+            Node::GetPrivateField(ref get_private_field) => {
+                let obj = get_private_field.obj().run(interpreter)?;
+                let name = resolve_private_name(get_private_field.field(), interpreter)?;
+                Reference::PrivateField(obj, name)
+            }
+            Node::GetSuperConstField(ref get_super_field) => {
+                let (base, this) = super_reference_base(interpreter)?;
+                Reference::SuperProperty(base, this, get_super_field.field().into())
+            }
+            Node::GetSuperField(ref get_super_field) => {
+                let key = get_super_field
+                    .field()
+                    .run(interpreter)?
+                    .to_property_key(interpreter)?;
+                let (base, this) = super_reference_base(interpreter)?;
+                Reference::SuperProperty(base, this, key)
+            }
+            _ => return Ok(None),
+        }))
+    }
+
+    /// Runs `delete`, which returns `true` if the target is not a reference.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-delete-operator-runtime-semantics-evaluation
+    fn run_delete(&self, interpreter: &mut Context) -> Result<Value> {
+        if let Node::Optional(ref optional) = self.target() {
+            return Ok(Value::boolean(optional.run_delete(interpreter)?));
+        }
+        Ok(Value::boolean(match self.reference(interpreter)? {
+            Some(Reference::Binding(_)) => false,
+            Some(Reference::Property(obj, key)) => {
+                let deleted = delete_property(&obj, &key);
+                interpreter.check_deletion(deleted, &key)?;
+                deleted
+            }
+            // Deleting a private member is an early error.
+            Some(Reference::PrivateField(..)) => false,
+            Some(Reference::SuperProperty(..)) => {
+                return interpreter.throw_reference_error("cannot delete a super property")
+            }
+            None => {
+                self.target().run(interpreter)?;
+                true
+            }
+        }))
+    }
+
+    /// Runs an update operator, like `a++` or `--a`, adding `delta` to the value of the target.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-postfix-increment-operator
+    fn run_update(&self, delta: f64, prefix: bool, interpreter: &mut Context) -> Result<Value> {
+        let reference = match self.reference(interpreter)? {
+            Some(reference) => reference,
+            None => return interpreter.throw_syntax_error("invalid update expression target"),
+        };
+        let old_value = reference.get_value(interpreter)?.to_number(interpreter)?;
+        let new_value = old_value + delta;
+        reference.put_value(new_value.into(), interpreter)?;
+        Ok(Value::from(if prefix { new_value } else { old_value }))
+    }
+}
+
 impl Executable for UnaryOp {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        match self.op() {
+            op::UnaryOp::Delete => return self.run_delete(interpreter),
+            op::UnaryOp::IncrementPost => return self.run_update(1.0, false, interpreter),
+            op::UnaryOp::IncrementPre => return self.run_update(1.0, true, interpreter),
+            op::UnaryOp::DecrementPost => return self.run_update(-1.0, false, interpreter),
+            op::UnaryOp::DecrementPre => return self.run_update(-1.0, true, interpreter),
+            _ => {}
+        }
+
         let x = self.target().run(interpreter)?;
 
         Ok(match self.op() {
             op::UnaryOp::Minus => x.neg(interpreter)?,
             op::UnaryOp::Plus => Value::from(x.to_number(interpreter)?),
-            op::UnaryOp::IncrementPost => {
-                let ret = x.clone();
-                let result = x.to_number(interpreter)? + 1.0;
-                interpreter.set_value(self.target(), result.into())?;
-                ret
-            }
-            op::UnaryOp::IncrementPre => {
-                let result = x.to_number(interpreter)? + 1.0;
-                interpreter.set_value(self.target(), result.into())?
-            }
-            op::UnaryOp::DecrementPost => {
-                let ret = x.clone();
-                let result = x.to_number(interpreter)? - 1.0;
-                interpreter.set_value(self.target(), result.into())?;
-                ret
-            }
-            op::UnaryOp::DecrementPre => {
-                let result = x.to_number(interpreter)? - 1.0;
-                interpreter.set_value(self.target(), result.into())?
-            }
             op::UnaryOp::Not => x.not(interpreter)?.into(),
             op::UnaryOp::Tilde => {
                 let num_v_a = x.to_number(interpreter)?;
@@ -85,31 +181,12 @@ impl Executable for UnaryOp {
                 })
             }
             op::UnaryOp::Void => Value::undefined(),
-            op::UnaryOp::Delete => match *self.target() {
-                Node::GetConstField(ref get_const_field) => Value::boolean(
-                    get_const_field
-                        .obj()
-                        .run(interpreter)?
-                        .remove_property(get_const_field.field()),
-                ),
-                Node::GetField(ref get_field) => {
-                    let obj = get_field.obj().run(interpreter)?;
-                    let field = &get_field.field().run(interpreter)?;
-                    let res = obj.remove_property(field.to_string(interpreter)?.as_str());
-                    return Ok(Value::boolean(res));
-                }
-                Node::Identifier(_) => Value::boolean(false),
-                Node::ArrayDecl(_)
-                | Node::Block(_)
-                | Node::Const(_)
-                | Node::FunctionDecl(_)
-                | Node::FunctionExpr(_)
-                | Node::New(_)
-                | Node::Object(_)
-                | Node::UnaryOp(_) => Value::boolean(true),
-                _ => panic!("SyntaxError: wrong delete argument {}", self),
-            },
             op::UnaryOp::TypeOf => Value::from(x.get_type().as_str()),
+            op::UnaryOp::Delete
+            | op::UnaryOp::IncrementPost
+            | op::UnaryOp::IncrementPre
+            | op::UnaryOp::DecrementPost
+            | op::UnaryOp::DecrementPre => unreachable!(),
         })
     }
 }
@@ -125,3 +202,82 @@ impl From<UnaryOp> for Node {
         Self::UnaryOp(op)
     }
 }
+
+/// Deletes the property `key` of `obj` with its `[[Delete]]` internal method, returns `false` if
+/// the property can't be deleted.
+fn delete_property(obj: &Value, key: &PropertyKey) -> bool {
+    match obj.as_object_mut() {
+        Some(mut object) => object.delete(key),
+        None => true,
+    }
+}
+
+/// The reference that `delete` and the update operators work on.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-reference-record-specification-type
+enum Reference<'a> {
+    /// A binding, like `a`.
+    Binding(&'a str),
+    /// A property of a value, like `a.b` or `a[b]`.
+    Property(Value, PropertyKey),
+    /// A private member of a value, like `a.#b`.
+    PrivateField(Value, PrivateName),
+    /// A `super` property, like `super.a`, with the object it's looked up on and the `this`
+    /// value.
+    SuperProperty(GcObject, Value, PropertyKey),
+}
+
+impl Reference<'_> {
+    /// Gets the value of the reference.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getvalue
+    fn get_value(&self, interpreter: &mut Context) -> Result<Value> {
+        match self {
+            Self::Binding(name) => interpreter.get_binding_value(name),
+            Self::Property(obj, key) => {
+                obj.to_object(interpreter)?
+                    .get(key, obj.clone(), interpreter)
+            }
+            Self::PrivateField(obj, name) => {
+                obj.to_object(interpreter)?.private_get(name, interpreter)
+            }
+            Self::SuperProperty(base, this, key) => base.get(key, this.clone(), interpreter),
+        }
+    }
+
+    /// Sets the value of the reference.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-putvalue
+    fn put_value(&self, value: Value, interpreter: &mut Context) -> Result<()> {
+        match self {
+            Self::Binding(name) => interpreter.set_mutable_binding(name, value),
+            Self::Property(obj, key) => {
+                let succeeded = obj.to_object(interpreter)?.set(
+                    key.clone(),
+                    value,
+                    obj.clone(),
+                    interpreter,
+                )?;
+                interpreter.check_assignment(succeeded, key)
+            }
+            Self::PrivateField(obj, name) => {
+                obj.to_object(interpreter)?
+                    .private_set(name, value, interpreter)?;
+                Ok(())
+            }
+            Self::SuperProperty(base, this, key) => {
+                let succeeded = base.set(key.clone(), value, this.clone(), interpreter)?;
+                interpreter.check_assignment(succeeded, key)
+            }
+        }
+    }
+}
@@ -0,0 +1,293 @@
+//! Optional chaining node.
+
+use super::{call::run_callee, field::resolve_private_name, join_nodes, Node};
+use crate::{
+    exec::{Executable, InterpreterState},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The kind of an operation of an optional chain.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum OptionalOperationKind {
+    /// Gets a property by name, like `.b` or `?.b`.
+    GetConstField(Box<str>),
+
+    /// Gets a computed property, like `[b]` or `?.[b]`.
+    GetField(Box<Node>),
+
+    /// Gets a private member, like `.#b` or `?.#b`.
+    GetPrivateField(Box<str>),
+
+    /// Calls the function, like `(b)` or `?.(b)`.
+    Call(Box<[Node]>),
+}
+
+/// An operation of an optional chain.
+///
+/// A shorted operation is preceded by `?.`: the whole chain evaluates to `undefined` without
+/// performing the operation when its base is `null` or `undefined`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct OptionalOperation {
+    kind: OptionalOperationKind,
+    shorted: bool,
+}
+
+impl OptionalOperation {
+    /// Creates a new optional operation.
+    pub fn new(kind: OptionalOperationKind, shorted: bool) -> Self {
+        Self { kind, shorted }
+    }
+
+    /// Gets the kind of the operation.
+    pub fn kind(&self) -> &OptionalOperationKind {
+        &self.kind
+    }
+
+    /// Returns `true` if the operation is preceded by `?.`.
+    pub fn shorted(&self) -> bool {
+        self.shorted
+    }
+}
+
+impl fmt::Display for OptionalOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.shorted {
+            f.write_str("?.")?;
+        }
+        match &self.kind {
+            OptionalOperationKind::GetConstField(name) if self.shorted => f.write_str(name),
+            OptionalOperationKind::GetConstField(name) => write!(f, ".{}", name),
+            OptionalOperationKind::GetField(field) => write!(f, "[{}]", field),
+            OptionalOperationKind::GetPrivateField(name) if self.shorted => write!(f, "#{}", name),
+            OptionalOperationKind::GetPrivateField(name) => write!(f, ".#{}", name),
+            OptionalOperationKind::Call(args) => {
+                f.write_str("(")?;
+                join_nodes(f, args)?;
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// An optional chain accesses the properties of an object or calls a function, stopping at the
+/// first `?.` whose base is `null` or `undefined`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Optional {
+    target: Box<Node>,
+    chain: Box<[OptionalOperation]>,
+}
+
+impl Optional {
+    /// Creates an `Optional` AST node.
+    pub fn new<T, C>(target: T, chain: C) -> Self
+    where
+        T: Into<Node>,
+        C: Into<Box<[OptionalOperation]>>,
+    {
+        Self {
+            target: Box::new(target.into()),
+            chain: chain.into(),
+        }
+    }
+
+    /// Gets the expression at the start of the chain.
+    pub fn target(&self) -> &Node {
+        &self.target
+    }
+
+    /// Gets the operations of the chain.
+    pub fn chain(&self) -> &[OptionalOperation] {
+        &self.chain
+    }
+}
+
+impl Optional {
+    /// Evaluates the target and the first `len` operations of the chain, returning the `this`
+    /// value and the value of the last one, or `None` if the chain short-circuits.
+    ///
+    /// The `this` value of a chain that ends with a property access is the object of the access,
+    /// so `(a?.b)()` calls `b` with `a` as `this`.
+    pub(in crate::syntax::ast::node) fn run_reference(
+        &self,
+        len: usize,
+        interpreter: &mut Context,
+    ) -> Result<Option<(Value, Value)>> {
+        // The resume step is the index of the operation that was interrupted, with the `this`
+        // value and the base of the operation. An interrupted call also saves the index of the
+        // interrupted argument and the arguments that were already evaluated.
+        let (mut this, mut value, start, mut resuming, mut resumed_args) =
+            if let Some(point) = interpreter.executor().take_resume_point(self) {
+                let values = point.values();
+                let resumed_args = values.get(2).map(|arg| {
+                    let arg = arg.as_number().expect("argument index is a number") as usize;
+                    (arg, values[3..].to_vec())
+                });
+                let (this, value) = (values[0].clone(), values[1].clone());
+                (this, value, point.step(), true, resumed_args)
+            } else {
+                let (this, value) = run_callee(self.target(), interpreter)?;
+                (this, value, 0, false, None)
+            };
+
+        for (i, operation) in self.chain[..len].iter().enumerate().skip(start) {
+            // A resumed operation already checked its base.
+            if !resuming && operation.shorted() && value.is_null_or_undefined() {
+                return Ok(None);
+            }
+            resuming = false;
+            let (new_this, new_value) = match operation.kind() {
+                OptionalOperationKind::GetConstField(name) => {
+                    let new_value = value.to_object(interpreter)?.get(
+                        &name.as_ref().into(),
+                        value.clone(),
+                        interpreter,
+                    )?;
+                    (value, new_value)
+                }
+                OptionalOperationKind::GetPrivateField(name) => {
+                    let name = resolve_private_name(name, interpreter)?;
+                    let new_value = value
+                        .to_object(interpreter)?
+                        .private_get(&name, interpreter)?;
+                    (value, new_value)
+                }
+                OptionalOperationKind::GetField(field) => {
+                    let key = field.run(interpreter);
+                    let key = interpreter
+                        .executor()
+                        .save_resume_point(key, self, i, || vec![this.clone(), value.clone()])?
+                        .to_property_key(interpreter)?;
+                    let new_value =
+                        value
+                            .to_object(interpreter)?
+                            .get(&key, value.clone(), interpreter)?;
+                    (value, new_value)
+                }
+                OptionalOperationKind::Call(args) => {
+                    let (arg_start, mut v_args) = resumed_args.take().unwrap_or_default();
+                    let frame = |arg: usize, v_args: &[Value]| {
+                        let mut values = vec![this.clone(), value.clone(), Value::from(arg)];
+                        values.extend_from_slice(v_args);
+                        values
+                    };
+                    for (j, arg) in args.iter().enumerate().skip(arg_start) {
+                        if let Node::Spread(ref x) = arg {
+                            let val = x.run(interpreter);
+                            let val =
+                                interpreter
+                                    .executor()
+                                    .save_resume_point(val, self, i, || frame(j, &v_args))?;
+                            let mut vals = interpreter.extract_array_properties(&val).unwrap();
+                            v_args.append(&mut vals);
+                            break; // after spread we don't accept any new arguments
+                        }
+                        let val = arg.run(interpreter);
+                        let val = interpreter
+                            .executor()
+                            .save_resume_point(val, self, i, || frame(j, &v_args))?;
+                        v_args.push(val);
+                    }
+
+                    let result = interpreter.call(&value, &this, &v_args);
+
+                    // unset the early return flag
+                    interpreter
+                        .executor()
+                        .set_current_state(InterpreterState::Executing);
+
+                    (Value::undefined(), result?)
+                }
+            };
+            this = new_this;
+            value = new_value;
+        }
+
+        Ok(Some((this, value)))
+    }
+
+    /// Runs `delete` on the chain, which deletes the property that its last operation accesses.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-delete-operator-runtime-semantics-evaluation
+    pub(in crate::syntax::ast::node) fn run_delete(
+        &self,
+        interpreter: &mut Context,
+    ) -> Result<bool> {
+        let (last, operations) = self.chain.split_last().expect("empty optional chain");
+        // A chain that ends with a call is not a reference.
+        if let OptionalOperationKind::Call(_) = last.kind() {
+            self.run(interpreter)?;
+            return Ok(true);
+        }
+        let (this, base) = match self.run_reference(operations.len(), interpreter)? {
+            Some(reference) => reference,
+            None => return Ok(true),
+        };
+        if last.shorted() && base.is_null_or_undefined() {
+            return Ok(true);
+        }
+        let key = match last.kind() {
+            OptionalOperationKind::GetConstField(name) => name.as_ref().into(),
+            OptionalOperationKind::GetField(field) => {
+                let key = field.run(interpreter);
+                interpreter
+                    .executor()
+                    .save_resume_point(key, self, operations.len(), || {
+                        vec![this.clone(), base.clone()]
+                    })?
+                    .to_property_key(interpreter)?
+            }
+            // Deleting a private member is an early error.
+            OptionalOperationKind::GetPrivateField(_) | OptionalOperationKind::Call(_) => {
+                return Ok(false)
+            }
+        };
+        let deleted = base.to_object(interpreter)?.borrow_mut().delete(&key);
+        interpreter.check_deletion(deleted, &key)?;
+        Ok(deleted)
+    }
+}
+
+impl Executable for Optional {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Optional", "exec");
+        let value = self.run_reference(self.chain.len(), interpreter)?;
+        Ok(value.map_or_else(Value::undefined, |(_, value)| value))
+    }
+}
+
+impl fmt::Display for Optional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.target, f)?;
+        for operation in self.chain.iter() {
+            fmt::Display::fmt(operation, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Optional> for Node {
+    fn from(optional: Optional) -> Self {
+        Self::Optional(optional)
+    }
+}
@@ -0,0 +1,107 @@
+use crate::exec;
+
+#[test]
+fn optional_member_access() {
+    let scenario = r#"
+        let config = { server: { port: 8080 } };
+        let missing = null;
+        `${config?.server?.port} ${config.client?.port} ${missing?.server.port}`;
+    "#;
+    assert_eq!(&exec(scenario), "\"8080 undefined undefined\"");
+}
+
+#[test]
+fn optional_computed_member_access() {
+    let scenario = r#"
+        let key = "port";
+        let config = { server: { port: 8080 } };
+        `${config?.["server"]?.[key]} ${config.client?.[key]}`;
+    "#;
+    assert_eq!(&exec(scenario), "\"8080 undefined\"");
+}
+
+#[test]
+fn optional_call() {
+    let scenario = r#"
+        let obj = {
+            value: 10,
+            get: function () { return this.value; }
+        };
+        `${obj.get?.()} ${obj.missing?.()} ${obj?.get()}`;
+    "#;
+    assert_eq!(&exec(scenario), "\"10 undefined 10\"");
+}
+
+#[test]
+fn optional_chain_short_circuits() {
+    let scenario = r#"
+        let count = 0;
+        let a = undefined;
+        a?.b[count++].c(count++);
+        a?.[count++];
+        a?.(count++);
+        count;
+    "#;
+    assert_eq!(&exec(scenario), "0");
+}
+
+#[test]
+fn optional_chain_non_nullish_base() {
+    let scenario = r#"
+        let a = { b: undefined };
+        try {
+            a?.b.c;
+        } catch (e) {
+            e.name;
+        }
+    "#;
+    assert_eq!(&exec(scenario), "\"TypeError\"");
+}
+
+#[test]
+fn optional_call_with_yield() {
+    let scenario = r#"
+        function* gen(obj) {
+            return obj?.f(yield 1, yield 2)?.[yield 3];
+        }
+        let it = gen({ f: (a, b) => ({ ab: a + b }) });
+        it.next();
+        it.next("a");
+        it.next("b");
+        it.next("ab").value;
+    "#;
+    assert_eq!(&exec(scenario), "\"ab\"");
+}
+
+#[test]
+fn delete_optional_chain() {
+    let scenario = r#"
+        let a = { b: 1, c: { d: 2 } };
+        let missing = null;
+        let count = 0;
+        [
+            delete a?.b,
+            "b" in a,
+            delete missing?.b,
+            delete missing?.[count++],
+            delete a?.c.d,
+            "d" in a.c,
+            delete a?.["c"],
+            "c" in a,
+            count,
+        ].join();
+    "#;
+    assert_eq!(
+        &exec(scenario),
+        "\"true,false,true,true,true,false,true,false,0\""
+    );
+}
+
+#[test]
+fn parenthesized_optional_chain_call_this() {
+    let scenario = r#"
+        let b = { value: 7, m() { return this.value; } };
+        `${(b?.m)()} ${(b?.["m"])()}`;
+    "#;
+    assert_eq!(&exec(scenario), "\"7 7\"");
+}
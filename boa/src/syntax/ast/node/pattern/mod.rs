@@ -0,0 +1,638 @@
+//! Binding patterns, the targets of declarations, parameters, `catch` clauses and assignments.
+//!
+//! A binding is either a plain identifier, or a destructuring pattern that binds the properties
+//! of an object or the values of an iterable to several names. The targets of an assignment
+//! pattern can also be property accesses.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#prod-BindingPattern
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+
+use crate::{
+    builtins::{
+        iterable::{get_iterator, IteratorRecord},
+        Array,
+    },
+    environment::lexical_environment::VariableScope,
+    exec::Executable,
+    property::PropertyKey,
+    syntax::ast::node::{join_nodes, Identifier, Node},
+    Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The kind of declaration a binding creates its names with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BindingKind {
+    /// A `var` declaration, its names are function scoped and can be redeclared.
+    Var,
+    /// A `let` declaration, a parameter or a `catch` parameter, its names are block scoped.
+    Let,
+    /// A `const` declaration, its names are block scoped and immutable.
+    Const,
+    /// An assignment, its names are assigned, and created if they don't exist.
+    Assign,
+}
+
+/// The target of a binding, an identifier or a destructuring pattern.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ForBinding
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum Binding {
+    /// A single name.
+    Identifier(Identifier),
+    /// An object binding pattern, like `{ a, b: c }`.
+    Object(ObjectPattern),
+    /// An array binding pattern, like `[a, , c]`.
+    Array(ArrayPattern),
+    /// A property access, like `a.b` or `a[b]`, only valid as the target of an assignment.
+    Member(Box<Node>),
+}
+
+impl Binding {
+    /// Binds the names of the target to `value`, destructuring it if this is a pattern.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-bindinginitialization
+    pub(crate) fn bind(
+        &self,
+        value: Value,
+        kind: BindingKind,
+        interpreter: &mut Context,
+    ) -> Result<()> {
+        match self {
+            Self::Identifier(ident) => bind_name(ident.as_ref(), value, kind, interpreter),
+            Self::Object(pattern) => pattern.bind(value, kind, interpreter),
+            Self::Array(pattern) => pattern.bind(value, kind, interpreter),
+            Self::Member(node) => interpreter.set_value(node, value).map(|_| ()),
+        }
+    }
+
+    /// Collects the names bound by the target, in source order.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-boundnames
+    pub(crate) fn bound_names<'a>(&'a self, names: &mut Vec<&'a str>) {
+        match self {
+            Self::Identifier(ident) => names.push(ident.as_ref()),
+            Self::Object(pattern) => {
+                for property in pattern.properties.iter() {
+                    property.element.binding.bound_names(names);
+                }
+                if let Some(ref rest) = pattern.rest {
+                    rest.bound_names(names);
+                }
+            }
+            Self::Array(pattern) => {
+                for element in pattern.elements.iter().flatten() {
+                    element.binding.bound_names(names);
+                }
+                if let Some(ref rest) = pattern.rest {
+                    rest.bound_names(names);
+                }
+            }
+            Self::Member(_) => {}
+        }
+    }
+}
+
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identifier(ident) => fmt::Display::fmt(ident, f),
+            Self::Object(pattern) => fmt::Display::fmt(pattern, f),
+            Self::Array(pattern) => fmt::Display::fmt(pattern, f),
+            Self::Member(node) => fmt::Display::fmt(node, f),
+        }
+    }
+}
+
+impl From<Identifier> for Binding {
+    fn from(ident: Identifier) -> Self {
+        Self::Identifier(ident)
+    }
+}
+
+impl From<&str> for Binding {
+    fn from(name: &str) -> Self {
+        Self::Identifier(name.into())
+    }
+}
+
+impl From<Box<str>> for Binding {
+    fn from(name: Box<str>) -> Self {
+        Self::Identifier(name.into())
+    }
+}
+
+impl From<ObjectPattern> for Binding {
+    fn from(pattern: ObjectPattern) -> Self {
+        Self::Object(pattern)
+    }
+}
+
+impl From<ArrayPattern> for Binding {
+    fn from(pattern: ArrayPattern) -> Self {
+        Self::Array(pattern)
+    }
+}
+
+/// A binding with an optional default value, used when the bound value is `undefined`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingElement
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct BindingElement {
+    binding: Binding,
+    init: Option<Node>,
+}
+
+impl BindingElement {
+    /// Creates a new binding element.
+    pub(in crate::syntax) fn new<B, I>(binding: B, init: I) -> Self
+    where
+        B: Into<Binding>,
+        I: Into<Option<Node>>,
+    {
+        Self {
+            binding: binding.into(),
+            init: init.into(),
+        }
+    }
+
+    /// Gets the target of the binding.
+    pub fn binding(&self) -> &Binding {
+        &self.binding
+    }
+
+    /// Gets the default value of the binding, if any.
+    pub fn init(&self) -> Option<&Node> {
+        self.init.as_ref()
+    }
+
+    /// Binds `value`, or the default value if `value` is `undefined`.
+    pub(crate) fn bind(
+        &self,
+        value: Value,
+        kind: BindingKind,
+        interpreter: &mut Context,
+    ) -> Result<()> {
+        // The resume step is `0` if the binding was interrupted, with the value being bound.
+        let value = match (interpreter.executor().take_resume_point(self), self.init()) {
+            (Some(point), _) => point.values()[0].clone(),
+            (None, Some(init)) if value.is_undefined() => init.run(interpreter)?,
+            (None, _) => value,
+        };
+        let result = self.binding.bind(value.clone(), kind, interpreter);
+        interpreter
+            .executor()
+            .save_resume_point(result, self, 0, || vec![value])
+    }
+}
+
+impl fmt::Display for BindingElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.binding, f)?;
+        if let Some(ref init) = self.init {
+            write!(f, " = {}", init)?;
+        }
+        Ok(())
+    }
+}
+
+/// The name of a property in an object literal or in an object binding pattern.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum PropertyName {
+    /// A literal property name, like `a` in `{ a: b }`.
+    Literal(Box<str>),
+    /// A computed property name, like `[a]` in `{ [a]: b }`.
+    Computed(Node),
+}
+
+impl fmt::Display for PropertyName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(name) => f.write_str(name),
+            Self::Computed(node) => write!(f, "[{}]", node),
+        }
+    }
+}
+
+impl From<&str> for PropertyName {
+    fn from(name: &str) -> Self {
+        Self::Literal(name.into())
+    }
+}
+
+impl From<Box<str>> for PropertyName {
+    fn from(name: Box<str>) -> Self {
+        Self::Literal(name)
+    }
+}
+
+/// A property of an object binding pattern, like `a: b = 1` in `{ a: b = 1 }`.
+///
+/// The shorthand `{ a = 1 }` binds the property `a` to the name `a`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingProperty
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct BindingProperty {
+    key: PropertyName,
+    element: BindingElement,
+}
+
+impl BindingProperty {
+    /// Creates a new binding property.
+    pub(in crate::syntax) fn new<K>(key: K, element: BindingElement) -> Self
+    where
+        K: Into<PropertyName>,
+    {
+        Self {
+            key: key.into(),
+            element,
+        }
+    }
+
+    /// Gets the key of the property.
+    pub fn key(&self) -> &PropertyName {
+        &self.key
+    }
+
+    /// Gets the binding the value of the property is bound to.
+    pub fn element(&self) -> &BindingElement {
+        &self.element
+    }
+}
+
+impl fmt::Display for BindingProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.key, self.element.binding()) {
+            (PropertyName::Literal(key), Binding::Identifier(ident))
+                if **key == *ident.as_ref() =>
+            {
+                fmt::Display::fmt(&self.element, f)
+            }
+            _ => write!(f, "{}: {}", self.key, self.element),
+        }
+    }
+}
+
+/// An object binding pattern, it binds properties of an object.
+///
+/// The rest element collects the remaining own enumerable properties in a new object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment#Object_destructuring
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ObjectPattern {
+    properties: Box<[BindingProperty]>,
+    rest: Option<Box<Binding>>,
+}
+
+impl ObjectPattern {
+    /// Creates a new object binding pattern.
+    pub(in crate::syntax) fn new<P>(properties: P, rest: Option<Binding>) -> Self
+    where
+        P: Into<Box<[BindingProperty]>>,
+    {
+        Self {
+            properties: properties.into(),
+            rest: rest.map(Box::new),
+        }
+    }
+
+    /// Gets the properties of the pattern.
+    pub fn properties(&self) -> &[BindingProperty] {
+        &self.properties
+    }
+
+    /// Gets the binding the rest of the properties are bound to, if any.
+    pub fn rest(&self) -> Option<&Binding> {
+        self.rest.as_deref()
+    }
+
+    /// Binds the properties of `value`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-destructuring-binding-patterns-runtime-semantics-propertybindinginitialization
+    fn bind(&self, value: Value, kind: BindingKind, interpreter: &mut Context) -> Result<()> {
+        if value.is_null_or_undefined() {
+            interpreter.throw_type_error(format!("Cannot destructure {}", value.display()))?;
+        }
+        let object = value.to_object(interpreter)?;
+
+        // The resume step is the index of the property that was interrupted, or the number of
+        // properties for the rest element, with the keys of the properties before it. An
+        // interrupted binding also saves the key and the value of the property, and the rest
+        // element its object.
+        let (start, mut bound_keys, mut resumed) =
+            match interpreter.executor().take_resume_point(self) {
+                Some(point) => {
+                    let (keys, values) = point.values().split_at(point.step());
+                    let mut bound_keys = Vec::with_capacity(self.properties.len());
+                    for key in keys {
+                        bound_keys.push(key.to_property_key(interpreter)?);
+                    }
+                    let resumed = Some(values.to_vec()).filter(|values| !values.is_empty());
+                    (point.step(), bound_keys, resumed)
+                }
+                None => (0, Vec::with_capacity(self.properties.len()), None),
+            };
+        let frame = |bound_keys: &[PropertyKey], values: &[Value]| {
+            let mut frame: Vec<Value> = bound_keys.iter().map(Value::from).collect();
+            frame.extend_from_slice(values);
+            frame
+        };
+
+        for (i, property) in self.properties.iter().enumerate().skip(start) {
+            let (key, property_value) = if let Some(values) = resumed.take() {
+                (values[0].to_property_key(interpreter)?, values[1].clone())
+            } else {
+                let key = match property.key() {
+                    PropertyName::Literal(name) => PropertyKey::from(name.clone()),
+                    PropertyName::Computed(node) => {
+                        let key = node.run(interpreter);
+                        interpreter
+                            .executor()
+                            .save_resume_point(key, self, i, || frame(&bound_keys, &[]))?
+                            .to_property_key(interpreter)?
+                    }
+                };
+                let property_value = object.get(&key, value.clone(), interpreter)?;
+                (key, property_value)
+            };
+            let result = property
+                .element()
+                .bind(property_value.clone(), kind, interpreter);
+            interpreter
+                .executor()
+                .save_resume_point(result, self, i, || {
+                    frame(&bound_keys, &[key.clone().into(), property_value])
+                })?;
+            bound_keys.push(key);
+        }
+
+        if let Some(rest) = self.rest() {
+            let rest_object = if let Some(values) = resumed.take() {
+                values[0].clone()
+            } else {
+                let rest_object = interpreter.construct_object();
+                rest_object.copy_data_properties(&value, &bound_keys, interpreter)?;
+                rest_object.into()
+            };
+            let result = rest.bind(rest_object.clone(), kind, interpreter);
+            interpreter.executor().save_resume_point(
+                result,
+                self,
+                self.properties.len(),
+                || frame(&bound_keys, &[rest_object]),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ObjectPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        join_nodes(f, &self.properties)?;
+        if let Some(ref rest) = self.rest {
+            if !self.properties.is_empty() {
+                f.write_str(", ")?;
+            }
+            write!(f, "...{}", rest)?;
+        }
+        f.write_str("}")
+    }
+}
+
+/// An array binding pattern, it binds the values produced by an iterable.
+///
+/// Elements can be elided to skip values, and the rest element collects the remaining values in
+/// an array.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment#Array_destructuring
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ArrayPattern {
+    elements: Box<[Option<BindingElement>]>,
+    rest: Option<Box<Binding>>,
+}
+
+impl ArrayPattern {
+    /// Creates a new array binding pattern, `None` elements are elisions.
+    pub(in crate::syntax) fn new<E>(elements: E, rest: Option<Binding>) -> Self
+    where
+        E: Into<Box<[Option<BindingElement>]>>,
+    {
+        Self {
+            elements: elements.into(),
+            rest: rest.map(Box::new),
+        }
+    }
+
+    /// Gets the elements of the pattern, `None` elements are elisions.
+    pub fn elements(&self) -> &[Option<BindingElement>] {
+        &self.elements
+    }
+
+    /// Gets the binding the rest of the values are bound to, if any.
+    pub fn rest(&self) -> Option<&Binding> {
+        self.rest.as_deref()
+    }
+
+    /// Binds the values produced by iterating `value`.
+    ///
+    /// The iterator is closed if it's not exhausted by the pattern.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-iteratorbindinginitialization
+    fn bind(&self, value: Value, kind: BindingKind, interpreter: &mut Context) -> Result<()> {
+        // The resume step is the index of the element whose binding was interrupted, or the
+        // number of elements for the rest element, with the iterator, whether it's done and the
+        // value being bound.
+        let (iterator, mut done, start, mut resumed) =
+            match interpreter.executor().take_resume_point(self) {
+                Some(point) => {
+                    let values = point.values();
+                    let iterator = IteratorRecord::new(values[0].clone(), values[1].clone());
+                    let done = values[2].to_boolean();
+                    (iterator, done, point.step(), Some(values[3].clone()))
+                }
+                None => (get_iterator(interpreter, value)?, false, 0, None),
+            };
+        let frame = |done: bool, value: Value| {
+            vec![
+                iterator.iterator_object().clone(),
+                iterator.next_function().clone(),
+                done.into(),
+                value,
+            ]
+        };
+
+        let mut result = Ok(());
+        for (i, element) in self.elements.iter().enumerate().skip(start) {
+            let next = match resumed.take() {
+                Some(next) => next,
+                None if done => Value::undefined(),
+                None => {
+                    let next = iterator.next(interpreter)?;
+                    done = next.is_done();
+                    if done {
+                        Value::undefined()
+                    } else {
+                        next.value()
+                    }
+                }
+            };
+            if let Some(element) = element {
+                result = element.bind(next.clone(), kind, interpreter);
+                result = interpreter
+                    .executor()
+                    .save_resume_point(result, self, i, || frame(done, next));
+                if result.is_err() {
+                    break;
+                }
+            }
+        }
+
+        if result.is_ok() {
+            if let Some(rest) = self.rest() {
+                let array = if let Some(array) = resumed.take() {
+                    array
+                } else {
+                    let mut values = Vec::new();
+                    while !done {
+                        let next = iterator.next(interpreter)?;
+                        done = next.is_done();
+                        if !done {
+                            values.push(next.value());
+                        }
+                    }
+                    let array = Array::new_array(interpreter)?;
+                    Array::add_to_array_object(&array, &values, interpreter)?;
+                    array
+                };
+                result = rest.bind(array.clone(), kind, interpreter);
+                result = interpreter.executor().save_resume_point(
+                    result,
+                    self,
+                    self.elements.len(),
+                    || frame(done, array),
+                );
+            }
+        }
+
+        // A suspended generator is not an abrupt completion, the binding goes on when it's
+        // resumed.
+        if done || interpreter.executor().is_suspending() {
+            return result;
+        }
+        match result {
+            Ok(()) => iterator.close(interpreter),
+            // The error of the binding takes precedence over any error closing the iterator.
+            Err(error) => {
+                let _ = iterator.close(interpreter);
+                Err(error)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ArrayPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for (i, element) in self.elements.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            if let Some(element) = element {
+                fmt::Display::fmt(element, f)?;
+            }
+        }
+        if let Some(ref rest) = self.rest {
+            if !self.elements.is_empty() {
+                f.write_str(", ")?;
+            }
+            write!(f, "...{}", rest)?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// Creates the binding of `name` for the given kind of declaration, and initializes it.
+///
+/// An assignment to a name that doesn't exist is a `ReferenceError` in strict mode code.
+fn bind_name(name: &str, value: Value, kind: BindingKind, interpreter: &mut Context) -> Result<()> {
+    if matches!(kind, BindingKind::Var | BindingKind::Assign) {
+        if let Some(env) = interpreter.resolve_binding(name)? {
+            return interpreter.set_resolved_binding(&env, name, value);
+        } else if kind == BindingKind::Assign && interpreter.strict() {
+            return Err(interpreter.construct_reference_error(format!("{} is not defined", name)));
+        }
+    }
+    let environment = &mut interpreter.realm_mut().environment;
+    // The bindings of the lexical declarations of a scope are created when it is entered.
+    let declared = environment
+        .get_current_environment_ref()
+        .borrow()
+        .has_binding(name);
+    match kind {
+        BindingKind::Var => {
+            environment.create_mutable_binding(name.to_owned(), false, VariableScope::Function)
+        }
+        BindingKind::Assign => {
+            environment.create_mutable_binding(name.to_owned(), true, VariableScope::Function)
+        }
+        BindingKind::Let if !declared => {
+            environment.create_mutable_binding(name.to_owned(), false, VariableScope::Block)
+        }
+        BindingKind::Const if !declared => {
+            environment.create_immutable_binding(name.to_owned(), true, VariableScope::Block);
+        }
+        BindingKind::Let | BindingKind::Const => {}
+    }
+    environment.initialize_binding(name, value);
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use crate::exec;
+
+#[test]
+fn object_pattern() {
+    let scenario = r#"
+        var { a, b: c, d = 4, ...rest } = { a: 1, b: 2, x: 5, y: 6 };
+        [a, c, d, JSON.stringify(rest)].join(";");
+    "#;
+    assert_eq!(&exec(scenario), r#""1;2;4;{"x":5,"y":6}""#);
+}
+
+#[test]
+fn array_pattern() {
+    let scenario = r#"
+        let [a, , b = 3, ...c] = [1, 2, undefined, 4, 5];
+        [a, b, c.length, c[0], c[1]].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"1,3,2,4,5\"");
+}
+
+#[test]
+fn nested_pattern() {
+    let scenario = r#"
+        const key = "n";
+        const { [key]: [m, { o = 9 }], p: { q } = { q: 10 } } = { n: [7, {}] };
+        [m, o, q].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"7,9,10\"");
+}
+
+#[test]
+fn parameter_patterns() {
+    let scenario = r#"
+        function f({ x, y = 2 }, [z] = [3], ...[w]) {
+            return [x, y, z, w].join();
+        }
+        var g = ({ a: b }, [c]) => b + c;
+        [f({ x: 1 }, undefined, 7), f({ x: 1, y: 4 }, [5], 6), g({ a: 1 }, [2])].join(";");
+    "#;
+    assert_eq!(&exec(scenario), "\"1,2,3,7;1,4,5,6;3\"");
+}
+
+#[test]
+fn default_parameter_sees_previous_parameters() {
+    let scenario = r#"
+        function f(a, b = a + 1) {
+            return a + b;
+        }
+        f(1);
+    "#;
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn catch_and_for_of_patterns() {
+    let scenario = r#"
+        var log = [];
+        try {
+            throw { message: "boom" };
+        } catch ({ message }) {
+            log.push(message);
+        }
+        for (const [k, v] of [[1, 2], [3, 4]]) {
+            log.push(k + v);
+        }
+        log.join();
+    "#;
+    assert_eq!(&exec(scenario), "\"boom,3,7\"");
+}
+
+#[test]
+fn destructure_null() {
+    let scenario = r#"
+        try {
+            var { a } = null;
+        } catch (e) {
+            e.name;
+        }
+    "#;
+    assert_eq!(&exec(scenario), "\"TypeError\"");
+}
+
+#[test]
+fn array_pattern_closes_iterator() {
+    let scenario = r#"
+        var closed = false;
+        var iterable = {};
+        iterable[Symbol.iterator] = function () {
+            return {
+                next: function () { return { value: 1, done: false }; },
+                return: function () { closed = true; return {}; }
+            };
+        };
+        var [one] = iterable;
+        [one, closed].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"1,true\"");
+}
+
+#[test]
+fn array_assignment_pattern() {
+    let scenario = r#"
+        var a = 1, b = 2;
+        [a, b] = [b, a];
+        [a, b].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"2,1\"");
+}
+
+#[test]
+fn object_assignment_pattern() {
+    let scenario = r#"
+        var x, y, rest;
+        var obj = { x: 1, y: 2, z: 3 };
+        ({ x, y = 5, ...rest } = { x: obj.x, z: obj.z });
+        x + ";" + y + ";" + JSON.stringify(rest);
+    "#;
+    assert_eq!(&exec(scenario), r#""1;5;{"z":3}""#);
+}
+
+#[test]
+fn assignment_pattern_to_properties() {
+    let scenario = r#"
+        var o = {};
+        [o.a, o["b"] = 2, ...o.c] = [1, undefined, 3, 4];
+        ({ d: o.d } = { d: 5 });
+        [o.a, o.b, o.c.length, o.d].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"1,2,2,5\"");
+}
+
+#[test]
+fn for_of_assignment_pattern() {
+    let scenario = r#"
+        var k, v, log = [];
+        for ([k, v] of [[1, 2], [3, 4]]) {
+            log.push(k + v);
+        }
+        for ({ k } of [{ k: 5 }]) {
+            log.push(k);
+        }
+        log.join();
+    "#;
+    assert_eq!(&exec(scenario), "\"3,7,5\"");
+}
+
+#[test]
+fn shorthand_initializer_outside_pattern() {
+    let scenario = r#"
+        try {
+            ({ a = 1 });
+        } catch (e) {
+            e.name;
+        }
+    "#;
+    assert_eq!(&exec(scenario), "\"SyntaxError\"");
+}
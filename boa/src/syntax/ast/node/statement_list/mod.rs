@@ -1,8 +1,9 @@
 //! Statement list node.
 
 use crate::{
+    environment::lexical_environment::VariableScope,
     exec::{Executable, InterpreterState},
-    syntax::ast::node::Node,
+    syntax::ast::node::{declaration::function_decl::instantiate_web_compat_functions, Node},
     BoaProfiler, Context, Result, Value,
 };
 use gc::{unsafe_empty_trace, Finalize, Trace};
@@ -17,6 +18,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// Similar to `Node::Block` but without the braces.
 ///
+/// The list knows if it's strict mode code, which it runs as.
+///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///
@@ -26,6 +29,7 @@ use serde::{Deserialize, Serialize};
 pub struct StatementList {
     #[cfg_attr(feature = "serde", serde(flatten))]
     statements: Box<[Node]>,
+    strict: bool,
 }
 
 impl StatementList {
@@ -34,6 +38,16 @@ impl StatementList {
         &self.statements
     }
 
+    /// Checks if the statements are strict mode code.
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets whether the statements are strict mode code.
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -60,14 +74,48 @@ impl Executable for StatementList {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("StatementList", "exec");
 
+        let strict = interpreter.strict();
+        interpreter.set_strict(self.strict);
+        let result = self.run_statements(true, interpreter);
+        interpreter.set_strict(strict);
+        result
+    }
+}
+
+impl StatementList {
+    /// Runs the statements of a clause of a `switch` statement.
+    ///
+    /// The declarations of the clause are not instantiated, since they are instantiated with the
+    /// ones of the other clauses when the case block is entered.
+    pub(crate) fn run_case_clause(&self, interpreter: &mut Context) -> Result<Value> {
+        self.run_statements(false, interpreter)
+    }
+
+    /// Runs the statements, once the strictness of the list is set. The declarations of the
+    /// statements are instantiated first if `declarations` is `true`.
+    fn run_statements(&self, declarations: bool, interpreter: &mut Context) -> Result<Value> {
         // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
         // The return value is uninitialized, which means it defaults to Value::Undefined
         let mut obj = Value::default();
         interpreter
             .executor()
             .set_current_state(InterpreterState::Executing);
-        for (i, item) in self.statements().iter().enumerate() {
-            let val = item.run(interpreter)?;
+        let start = if let Some(point) = interpreter.executor().take_resume_point(self) {
+            point.step()
+        } else if declarations {
+            instantiate_lexical_declarations(self.statements(), false, interpreter)?;
+            if interpreter.annex_b() && !self.strict {
+                instantiate_web_compat_functions(self.statements(), interpreter);
+            }
+            0
+        } else {
+            0
+        };
+        for (i, item) in self.statements().iter().enumerate().skip(start) {
+            let val = item.run(interpreter);
+            let val = interpreter
+                .executor()
+                .save_resume_point(val, self, i, Vec::new)?;
             match interpreter.executor().get_current_state() {
                 InterpreterState::Return => {
                     // Early return.
@@ -94,6 +142,86 @@ impl Executable for StatementList {
     }
 }
 
+/// Creates the bindings of the `let`, `const` and `class` declarations of `statements` in the
+/// current environment, without initializing them. The function declarations are also lexical
+/// declarations if `functions` is `true`, which is the case in blocks.
+///
+/// The bindings are initialized when their declarations run, using them before that throws a
+/// `ReferenceError`. A name that the environment already declares is a `SyntaxError`, which can
+/// only happen for scripts that run one after another in the global environment.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-blockdeclarationinstantiation
+pub(crate) fn instantiate_lexical_declarations<'a, I>(
+    statements: I,
+    functions: bool,
+    interpreter: &mut Context,
+) -> Result<()>
+where
+    I: IntoIterator<Item = &'a Node>,
+{
+    let mut mutable_names = Vec::new();
+    let mut immutable_names = Vec::new();
+    for node in statements {
+        match node {
+            Node::LetDeclList(list) => {
+                for decl in list.as_ref() {
+                    decl.binding().bound_names(&mut mutable_names);
+                }
+            }
+            Node::ConstDeclList(list) => {
+                for decl in list.as_ref() {
+                    decl.binding().bound_names(&mut immutable_names);
+                }
+            }
+            Node::ClassDecl(decl) => mutable_names.push(decl.name()),
+            Node::FunctionDecl(_) | Node::GeneratorDecl(_) | Node::AsyncFunctionDecl(_)
+                if functions =>
+            {
+                // Sloppy mode code can declare the same function twice in a block.
+                let mut names = Vec::new();
+                node.lexically_declared_names(&mut names, true);
+                for name in names {
+                    if !mutable_names.contains(&name) {
+                        mutable_names.push(name);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let env = interpreter
+        .realm()
+        .environment
+        .get_current_environment_ref()
+        .clone();
+    for name in mutable_names.iter().chain(&immutable_names) {
+        let env = env.borrow();
+        let declared = match env.as_global_environment_record() {
+            Some(global) => {
+                global.has_lexical_declaration(name) || global.has_restricted_global_property(name)
+            }
+            None => env.has_binding(name),
+        };
+        if declared {
+            interpreter
+                .throw_syntax_error(format!("Identifier '{}' has already been declared", name))?;
+        }
+    }
+
+    let environment = &mut interpreter.realm_mut().environment;
+    for name in mutable_names {
+        environment.create_mutable_binding(name.to_owned(), false, VariableScope::Block);
+    }
+    for name in immutable_names {
+        environment.create_immutable_binding(name.to_owned(), true, VariableScope::Block);
+    }
+    Ok(())
+}
+
 impl<T> From<T> for StatementList
 where
     T: Into<Box<[Node]>>,
@@ -101,6 +229,7 @@ where
     fn from(stm: T) -> Self {
         Self {
             statements: stm.into(),
+            strict: false,
         }
     }
 }
@@ -0,0 +1,103 @@
+use crate::{
+    environment::lexical_environment::Environment,
+    exec::Executable,
+    syntax::ast::node::{join_nodes, Node},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `super(...)` call runs the constructor of the parent class from a derived class
+/// constructor, and binds its result as the `this` value.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-SuperCall
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct SuperCall {
+    args: Box<[Node]>,
+}
+
+impl SuperCall {
+    /// Creates a new `SuperCall` AST node.
+    pub fn new<A>(args: A) -> Self
+    where
+        A: Into<Box<[Node]>>,
+    {
+        Self { args: args.into() }
+    }
+
+    /// Gets the arguments of the super call.
+    pub fn args(&self) -> &[Node] {
+        &self.args
+    }
+}
+
+impl Executable for SuperCall {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("SuperCall", "exec");
+        let this_env: Environment = interpreter.realm().environment.get_this_environment();
+        let function_env = this_env
+            .borrow()
+            .as_function_environment_record()
+            .map(|record| (record.function.clone(), record.new_target.clone()));
+        let (function, new_target) = match function_env {
+            Some((function, new_target)) if !new_target.is_undefined() => (function, new_target),
+            _ => return interpreter.throw_syntax_error("'super' keyword unexpected here"),
+        };
+
+        let super_constructor = function.borrow().get_prototype_of();
+
+        let mut v_args = Vec::with_capacity(self.args().len());
+        for arg in self.args() {
+            if let Node::Spread(ref x) = arg {
+                let val = x.run(interpreter)?;
+                let mut vals = interpreter.extract_array_properties(&val).unwrap();
+                v_args.append(&mut vals);
+                break; // after spread we don't accept any new arguments
+            }
+            v_args.push(arg.run(interpreter)?);
+        }
+
+        let result = match super_constructor {
+            Value::Object(ref constructor) if constructor.borrow().is_constructable() => {
+                constructor.construct(&v_args, &new_target, interpreter)?
+            }
+            _ => return interpreter.throw_type_error("Super constructor is not a constructor"),
+        };
+
+        let bound = this_env
+            .borrow_mut()
+            .as_function_environment_record_mut()
+            .expect("this environment of a super call is a function environment")
+            .bind_this_value(result);
+        let this = bound.or_else(|e| interpreter.throw_reference_error(e.to_string()))?;
+
+        // The fields of the derived class are defined once the parent constructor returns.
+        if let Value::Object(ref object) = this {
+            object.initialize_instance_elements(&function, interpreter)?;
+        }
+        Ok(this)
+    }
+}
+
+impl fmt::Display for SuperCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("super(")?;
+        join_nodes(f, &self.args)?;
+        f.write_str(")")
+    }
+}
+
+impl From<SuperCall> for Node {
+    fn from(call: SuperCall) -> Self {
+        Self::SuperCall(call)
+    }
+}
@@ -1,8 +1,9 @@
 //! Switch node.
 //!
 use crate::{
+    environment::lexical_environment::new_declarative_environment,
     exec::{Executable, InterpreterState},
-    syntax::ast::node::Node,
+    syntax::ast::node::{statement_list::instantiate_lexical_declarations, Node},
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -69,6 +70,7 @@ pub struct Switch {
     val: Box<Node>,
     cases: Box<[Case]>,
     default: Option<StatementList>,
+    label: Option<Box<str>>,
 }
 
 impl Switch {
@@ -83,6 +85,7 @@ impl Switch {
             val: Box::new(val.into()),
             cases: cases.into(),
             default: default.map(D::into),
+            label: None,
         }
     }
 
@@ -101,12 +104,28 @@ impl Switch {
         self.default.as_ref().map(StatementList::statements)
     }
 
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_ref().map(Box::as_ref)
+    }
+
+    pub fn set_label(&mut self, label: Box<str>) {
+        self.label = Some(label);
+    }
+
+    /// Checks if a `break` statement with the given label ends this switch statement.
+    fn is_break_target(&self, label: Option<&str>) -> bool {
+        label.is_none() || label == self.label()
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
         indent: usize,
     ) -> fmt::Result {
+        if let Some(label) = self.label() {
+            write!(f, "{}: ", label)?;
+        }
         writeln!(f, "switch ({}) {{", self.val())?;
         for e in self.cases().iter() {
             writeln!(f, "{}case {}:", indent, e.condition())?;
@@ -123,7 +142,51 @@ impl Switch {
 
 impl Executable for Switch {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let val = self.val().run(interpreter)?;
+        // The resume step is `2 * i` for the condition of the case `i`, `2 * i + 1` for its body
+        // and `2 * n + j` for the statement `j` of the default case, if there are `n` cases.
+        // A resumed generator has already restored the environment of the case block.
+        let (val, start) = if let Some(point) = interpreter.executor().take_resume_point(self) {
+            (point.values()[0].clone(), point.step())
+        } else {
+            let val = self.val().run(interpreter)?;
+            let env = &mut interpreter.realm_mut().environment;
+            env.push(new_declarative_environment(Some(
+                env.get_current_environment_ref().clone(),
+            )));
+            let statements = self.cases().iter().map(|case| case.body().statements());
+            let statements = statements.chain(self.default()).flatten();
+            if let Err(e) = instantiate_lexical_declarations(statements, true, interpreter) {
+                let _ = interpreter.realm_mut().environment.pop();
+                return Err(e);
+            }
+            (val, 0)
+        };
+
+        let result = self.run_case_block(val, start, interpreter);
+
+        // pop the case block env, unless it has to be kept for a suspended generator
+        if !interpreter.executor().is_suspending() {
+            let _ = interpreter.realm_mut().environment.pop();
+        }
+
+        result
+    }
+}
+
+impl Switch {
+    /// Runs the clauses of the case block, in the environment of the block.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-caseblockevaluation
+    fn run_case_block(
+        &self,
+        val: Value,
+        mut start: usize,
+        interpreter: &mut Context,
+    ) -> Result<Value> {
+        let cases_len = self.cases().len();
         let mut result = Value::null();
         let mut matched = false;
         interpreter
@@ -134,33 +197,50 @@ impl Executable for Switch {
         // checking their conditions until a break is encountered.
         let mut fall_through: bool = false;
 
-        for case in self.cases().iter() {
-            let cond = case.condition();
-            let block = case.body();
-            if fall_through || val.strict_equals(&cond.run(interpreter)?) {
-                matched = true;
-                let result = block.run(interpreter)?;
-                match interpreter.executor().get_current_state() {
-                    InterpreterState::Return => {
-                        // Early return.
-                        return Ok(result);
-                    }
-                    InterpreterState::Break(_label) => {
-                        // TODO, break to a label.
-                        // Break statement encountered so therefore end switch statement.
+        for (i, case) in self.cases().iter().enumerate() {
+            if start >= 2 * (i + 1) {
+                continue;
+            }
+            let resume_body = start == 2 * i + 1;
+            start = 0;
+
+            if !resume_body && !fall_through {
+                let cond = case.condition().run(interpreter);
+                let cond = interpreter
+                    .executor()
+                    .save_resume_point(cond, self, 2 * i, || vec![val.clone()])?;
+                if !val.strict_equals(&cond) {
+                    continue;
+                }
+            }
+
+            matched = true;
+            let result = case.body().run_case_clause(interpreter);
+            let result =
+                interpreter
+                    .executor()
+                    .save_resume_point(result, self, 2 * i + 1, || vec![val.clone()])?;
+            match interpreter.executor().get_current_state() {
+                InterpreterState::Return => {
+                    // Early return.
+                    return Ok(result);
+                }
+                InterpreterState::Break(label) => {
+                    // Break statement encountered so therefore end switch statement.
+                    if self.is_break_target(label.as_deref()) {
                         interpreter
                             .executor()
                             .set_current_state(InterpreterState::Executing);
-                        break;
-                    }
-                    InterpreterState::Continue(_label) => {
-                        // TODO, continue to a label.
-                        break;
-                    }
-                    InterpreterState::Executing => {
-                        // Continuing execution / falling through to next case statement(s).
-                        fall_through = true;
                     }
+                    break;
+                }
+                InterpreterState::Continue(_label) => {
+                    // A continue always targets an enclosing loop.
+                    break;
+                }
+                InterpreterState::Executing => {
+                    // Continuing execution / falling through to next case statement(s).
+                    fall_through = true;
                 }
             }
         }
@@ -170,26 +250,40 @@ impl Executable for Switch {
                 interpreter
                     .executor()
                     .set_current_state(InterpreterState::Executing);
-                for (i, item) in default.iter().enumerate() {
-                    let val = item.run(interpreter)?;
+                let start = start.saturating_sub(2 * cases_len);
+                for (i, item) in default.iter().enumerate().skip(start) {
+                    let value = item.run(interpreter);
+                    let value = interpreter.executor().save_resume_point(
+                        value,
+                        self,
+                        2 * cases_len + i,
+                        || vec![val.clone()],
+                    )?;
                     match interpreter.executor().get_current_state() {
                         InterpreterState::Return => {
                             // Early return.
-                            result = val;
+                            result = value;
                             break;
                         }
-                        InterpreterState::Break(_label) => {
-                            // TODO, break to a label.
-
+                        InterpreterState::Break(label) => {
                             // Early break.
+                            if self.is_break_target(label.as_deref()) {
+                                interpreter
+                                    .executor()
+                                    .set_current_state(InterpreterState::Executing);
+                            }
+                            break;
+                        }
+                        InterpreterState::Continue(_label) => {
+                            // A continue always targets an enclosing loop.
                             break;
                         }
-                        _ => {
+                        InterpreterState::Executing => {
                             // Continue execution
                         }
                     }
                     if i == default.len() - 1 {
-                        result = val;
+                        result = value;
                     }
                 }
             }
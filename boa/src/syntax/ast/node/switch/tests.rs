@@ -206,3 +206,30 @@ fn bigger_switch_example() {
         assert_eq!(&exec(&scenario), val);
     }
 }
+
+#[test]
+fn break_to_label_switch() {
+    let scenario = r#"
+        let a = 0;
+        outer: for (let i = 0; i < 3; i++) {
+            switch (i) {
+                case 1:
+                    break outer;
+                default:
+                    break;
+            }
+            a++;
+        }
+
+        inner: switch (a) {
+            case 1:
+                for (;;) {
+                    break inner;
+                }
+                a = 10;
+        }
+
+        a;
+    "#;
+    assert_eq!(&exec(scenario), "1");
+}
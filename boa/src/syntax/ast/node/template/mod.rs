@@ -0,0 +1,329 @@
+//! Template literal nodes.
+
+use super::{call::run_callee, Node};
+use crate::{
+    builtins::Array,
+    exec::{Executable, InterpreterState},
+    property::{Attribute, DataDescriptor},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+use std::{
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// Template literals are string literals allowing embedded expressions.
+///
+/// The strings of the template are interleaved with the values of its substitutions, which are
+/// converted to strings.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-template-literals
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct TemplateLit {
+    strings: Box<[Box<str>]>,
+    exprs: Box<[Node]>,
+}
+
+impl TemplateLit {
+    /// Creates a `TemplateLit` AST node, there must be one more string than expressions.
+    pub fn new<S, E>(strings: S, exprs: E) -> Self
+    where
+        S: Into<Box<[Box<str>]>>,
+        E: Into<Box<[Node]>>,
+    {
+        let (strings, exprs) = (strings.into(), exprs.into());
+        debug_assert_eq!(strings.len(), exprs.len() + 1);
+        Self { strings, exprs }
+    }
+
+    /// Gets the strings of the template, with their escape sequences interpreted.
+    pub fn strings(&self) -> &[Box<str>] {
+        &self.strings
+    }
+
+    /// Gets the expressions of the substitutions of the template.
+    pub fn exprs(&self) -> &[Node] {
+        &self.exprs
+    }
+}
+
+impl Executable for TemplateLit {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("TemplateLit", "exec");
+        // The resume step is the index of the substitution that was interrupted, with the
+        // string that was already built.
+        let (mut result, start) = match interpreter.executor().take_resume_point(self) {
+            Some(point) => (
+                point.values()[0].to_string(interpreter)?.to_string(),
+                point.step(),
+            ),
+            None => (self.strings[0].to_string(), 0),
+        };
+        for (i, (expr, string)) in self.exprs.iter().zip(&self.strings[1..]).enumerate() {
+            if i < start {
+                continue;
+            }
+            let value = expr.run(interpreter);
+            let value = interpreter
+                .executor()
+                .save_resume_point(value, self, i, || vec![result.as_str().into()])?;
+            result.push_str(&value.to_string(interpreter)?);
+            result.push_str(string);
+        }
+        Ok(result.into())
+    }
+}
+
+impl fmt::Display for TemplateLit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("`")?;
+        for (i, string) in self.strings.iter().enumerate() {
+            // The characters that would end the string are escaped.
+            let string = string
+                .replace('\\', "\\\\")
+                .replace('`', "\\`")
+                .replace("${", "\\${");
+            f.write_str(&string)?;
+            if let Some(expr) = self.exprs.get(i) {
+                write!(f, "${{{}}}", expr)?;
+            }
+        }
+        f.write_str("`")
+    }
+}
+
+impl From<TemplateLit> for Node {
+    fn from(template: TemplateLit) -> Self {
+        Self::TemplateLit(template)
+    }
+}
+
+/// The number of tagged templates that were created, used to give each one its own site.
+static TEMPLATE_SITES: AtomicUsize = AtomicUsize::new(0);
+
+/// Identifies a tagged template in the source code, so that it always gets the same template
+/// object.
+///
+/// It's not part of the syntax, so all the sites are equal to each other.
+#[derive(Clone, Copy, Debug, Finalize)]
+struct TemplateSite(usize);
+
+impl TemplateSite {
+    /// Creates a new site for a tagged template.
+    fn next() -> Self {
+        Self(TEMPLATE_SITES.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl PartialEq for TemplateSite {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+unsafe impl Trace for TemplateSite {
+    unsafe_empty_trace!();
+}
+
+/// A tagged template calls its tag function with the strings of the template and the values of
+/// its substitutions.
+///
+/// The strings are passed as a frozen array of the cooked strings, with a `raw` property holding
+/// a frozen array of the raw strings. The same array is passed every time that the tagged
+/// template is evaluated. A cooked string is `undefined` if the raw string has an invalid escape
+/// sequence.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-tagged-templates
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals#tagged_templates
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct TaggedTemplate {
+    tag: Box<Node>,
+    raws: Box<[Box<str>]>,
+    cookeds: Box<[Option<Box<str>>]>,
+    exprs: Box<[Node]>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "TemplateSite::next"))]
+    site: TemplateSite,
+}
+
+impl TaggedTemplate {
+    /// Creates a `TaggedTemplate` AST node, there must be as many raw strings as cooked strings,
+    /// and one more of them than expressions.
+    pub fn new<T, R, C, E>(tag: T, raws: R, cookeds: C, exprs: E) -> Self
+    where
+        T: Into<Node>,
+        R: Into<Box<[Box<str>]>>,
+        C: Into<Box<[Option<Box<str>>]>>,
+        E: Into<Box<[Node]>>,
+    {
+        let (raws, cookeds, exprs) = (raws.into(), cookeds.into(), exprs.into());
+        debug_assert_eq!(raws.len(), cookeds.len());
+        debug_assert_eq!(raws.len(), exprs.len() + 1);
+        Self {
+            tag: Box::new(tag.into()),
+            raws,
+            cookeds,
+            exprs,
+            site: TemplateSite::next(),
+        }
+    }
+
+    /// Gets the tag function of the template.
+    pub fn tag(&self) -> &Node {
+        &self.tag
+    }
+
+    /// Gets the raw strings of the template.
+    pub fn raws(&self) -> &[Box<str>] {
+        &self.raws
+    }
+
+    /// Gets the cooked strings of the template.
+    pub fn cookeds(&self) -> &[Option<Box<str>>] {
+        &self.cookeds
+    }
+
+    /// Gets the expressions of the substitutions of the template.
+    pub fn exprs(&self) -> &[Node] {
+        &self.exprs
+    }
+
+    /// Gets the template object of the tagged template, creating it the first time.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-gettemplateobject
+    fn template_object(&self, interpreter: &mut Context) -> Result<Value> {
+        if let Some(template) = interpreter.realm().template_map.get(&self.site.0) {
+            return Ok(template.clone());
+        }
+
+        let cookeds: Vec<Value> = self
+            .cookeds
+            .iter()
+            .map(|cooked| cooked.as_deref().map_or_else(Value::undefined, Value::from))
+            .collect();
+        let raws: Vec<Value> = self
+            .raws
+            .iter()
+            .map(|raw| Value::from(raw.as_ref()))
+            .collect();
+        let template = frozen_array(interpreter, &cookeds)?;
+        let raw = frozen_array(interpreter, &raws)?;
+        template.set_property(
+            "raw",
+            DataDescriptor::new(
+                raw,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            ),
+        );
+
+        interpreter
+            .realm_mut()
+            .template_map
+            .insert(self.site.0, template.clone());
+        Ok(template)
+    }
+}
+
+/// Creates an array with the given elements, that can't be modified.
+fn frozen_array(interpreter: &mut Context, elements: &[Value]) -> Result<Value> {
+    let array = Array::new_array(interpreter)?;
+    for (i, element) in elements.iter().enumerate() {
+        array.set_property(
+            i,
+            DataDescriptor::new(
+                element.clone(),
+                Attribute::READONLY | Attribute::ENUMERABLE | Attribute::PERMANENT,
+            ),
+        );
+    }
+    array.set_property(
+        "length",
+        DataDescriptor::new(
+            elements.len(),
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        ),
+    );
+    if let Some(mut object) = array.as_object_mut() {
+        object.prevent_extensions();
+    }
+    Ok(array)
+}
+
+impl Executable for TaggedTemplate {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("TaggedTemplate", "exec");
+        // The resume step is the index of the substitution that was interrupted, with the `this`
+        // value, the function, the template object and the substitutions that were already
+        // evaluated.
+        let (this, func, mut args, start) =
+            if let Some(point) = interpreter.executor().take_resume_point(self) {
+                let values = point.values();
+                let args = values[2..].to_vec();
+                (values[0].clone(), values[1].clone(), args, point.step())
+            } else {
+                let (this, func) = run_callee(self.tag(), interpreter)?;
+                let template = self.template_object(interpreter)?;
+                (this, func, vec![template], 0)
+            };
+        let frame = |args: &[Value]| {
+            let mut values = vec![this.clone(), func.clone()];
+            values.extend_from_slice(args);
+            values
+        };
+        for (i, expr) in self.exprs.iter().enumerate().skip(start) {
+            let value = expr.run(interpreter);
+            let value = interpreter
+                .executor()
+                .save_resume_point(value, self, i, || frame(&args))?;
+            args.push(value);
+        }
+
+        let result = interpreter.call(&func, &this, &args);
+
+        // unset the early return flag
+        interpreter
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        result
+    }
+}
+
+impl fmt::Display for TaggedTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}`", self.tag)?;
+        for (i, raw) in self.raws.iter().enumerate() {
+            f.write_str(raw)?;
+            if let Some(expr) = self.exprs.get(i) {
+                write!(f, "${{{}}}", expr)?;
+            }
+        }
+        f.write_str("`")
+    }
+}
+
+impl From<TaggedTemplate> for Node {
+    fn from(template: TaggedTemplate) -> Self {
+        Self::TaggedTemplate(template)
+    }
+}
@@ -0,0 +1,95 @@
+use crate::exec;
+
+#[test]
+fn template_literal() {
+    let scenario = r#"
+        let a = 10;
+        `result: ${a} and ${a + 10}`;
+    "#;
+    assert_eq!(&exec(scenario), "\"result: 10 and 20\"");
+}
+
+#[test]
+fn nested_template_literal() {
+    let scenario = r#"
+        let items = ["a", "b"];
+        `list: ${items.map(item => `<${item}>`).join(`${","}`)}!`;
+    "#;
+    assert_eq!(&exec(scenario), "\"list: <a>,<b>!\"");
+}
+
+#[test]
+fn template_literal_object_substitution() {
+    let scenario = r#"
+        let obj = { toString() { return "obj"; } };
+        `${obj}${{}}`;
+    "#;
+    assert_eq!(&exec(scenario), "\"obj[object Object]\"");
+}
+
+#[test]
+fn tagged_template() {
+    let scenario = r#"
+        function tag(strings, ...values) {
+            return strings.join("|") + ":" + strings.raw.join("|") + ":" + values.join("|");
+        }
+        tag`a${1}\n${2 + 3}c`;
+    "#;
+    assert_eq!(&exec(scenario), "\"a|\n|c:a|\\n|c:1|5\"");
+}
+
+#[test]
+fn tagged_template_invalid_escape() {
+    let scenario = r#"
+        function tag(strings) {
+            return `${strings[0]} ${strings.raw[0]}`;
+        }
+        tag`\unicode`;
+    "#;
+    assert_eq!(&exec(scenario), "\"undefined \\unicode\"");
+}
+
+#[test]
+fn tagged_template_object_is_cached() {
+    let scenario = r#"
+        function tag(strings) {
+            return strings;
+        }
+        let objects = [];
+        for (let i = 0; i < 2; i++) {
+            objects.push(tag`x${i}y`);
+        }
+        let other = tag`x${0}y`;
+        `${objects[0] === objects[1]} ${objects[0] === other}`;
+    "#;
+    assert_eq!(&exec(scenario), "\"true false\"");
+}
+
+#[test]
+fn tagged_template_object_is_frozen() {
+    let scenario = r#"
+        function tag(strings) {
+            return strings;
+        }
+        let strings = tag`a${1}b`;
+        strings[0] = "c";
+        strings.raw[1] = "d";
+        strings.extra = true;
+        `${strings[0]} ${strings.raw[1]} ${strings.extra} ${strings.length}`;
+    "#;
+    assert_eq!(&exec(scenario), "\"a b undefined 2\"");
+}
+
+#[test]
+fn tagged_template_method_this() {
+    let scenario = r#"
+        let obj = {
+            prefix: "p",
+            tag(strings, value) {
+                return this.prefix + strings[0] + value;
+            }
+        };
+        obj.tag`-${1}`;
+    "#;
+    assert_eq!(&exec(scenario), "\"p-1\"");
+}
@@ -1,7 +1,7 @@
 use crate::{
-    environment::lexical_environment::{new_declarative_environment, VariableScope},
-    exec::Executable,
-    syntax::ast::node::{Block, Identifier, Node},
+    environment::lexical_environment::new_declarative_environment,
+    exec::{Executable, InterpreterState, Interruption},
+    syntax::ast::node::{pattern::BindingKind, Binding, Block, Node},
     BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -91,44 +91,119 @@ impl Try {
     }
 }
 
+impl Try {
+    /// Runs the `catch` block for the exception `err`, or gives it back if there is none.
+    ///
+    /// `step` is `0` when the `catch` block starts. A generator that is being resumed has already
+    /// restored its environment, the step is `5` if it was interrupted in the binding of the
+    /// parameter and `1` in the `catch` block.
+    fn run_catch(&self, err: Value, step: usize, interpreter: &mut Context) -> Result<Value> {
+        let catch = match self.catch() {
+            Some(catch) => catch,
+            None => return Err(err),
+        };
+
+        if step == 0 {
+            let env = &mut interpreter.realm_mut().environment;
+            env.push(new_declarative_environment(Some(
+                env.get_current_environment_ref().clone(),
+            )));
+        }
+        if step != 1 {
+            if let Some(param) = catch.parameter() {
+                let result = param.bind(err.clone(), BindingKind::Let, interpreter);
+                let result = interpreter
+                    .executor()
+                    .save_resume_point(result, self, 5, || vec![err]);
+                if let Err(err) = result {
+                    // Keep the environment for a suspended generator.
+                    if !interpreter.executor().is_suspending() {
+                        let _ = interpreter.realm_mut().environment.pop();
+                    }
+                    return Err(err);
+                }
+            }
+        }
+
+        let res = catch.block().run(interpreter);
+        let res = interpreter
+            .executor()
+            .save_resume_point(res, self, 1, Vec::new);
+
+        // pop the block env, unless it has to be kept for a suspended generator
+        if !interpreter.executor().is_suspending() {
+            let _ = interpreter.realm_mut().environment.pop();
+        }
+
+        res
+    }
+}
+
 impl Executable for Try {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Try", "exec");
-        let res = self.block().run(interpreter).map_or_else(
-            |err| {
-                if let Some(catch) = self.catch() {
-                    {
-                        let env = &mut interpreter.realm_mut().environment;
-                        env.push(new_declarative_environment(Some(
-                            env.get_current_environment_ref().clone(),
-                        )));
-
-                        if let Some(param) = catch.parameter() {
-                            env.create_mutable_binding(
-                                param.to_owned(),
-                                false,
-                                VariableScope::Block,
-                            );
-
-                            env.initialize_binding(param, err);
-                        }
-                    }
-
-                    let res = catch.block().run(interpreter);
 
-                    // pop the block env
-                    let _ = interpreter.realm_mut().environment.pop();
+        // The resume step is `0` for the `try` block, `1` for the `catch` block and `5` for the
+        // binding of its parameter, with the exception. The `finally` block saves the completion
+        // it has to give back after it finishes: `2` for a value, `3` for an exception and `4`
+        // when the generator is being closed by its `return` method.
+        let (step, value) = match interpreter.executor().take_resume_point(self) {
+            Some(point) => (point.step(), point.values().first().cloned()),
+            None => (0, None),
+        };
+        let value = value.unwrap_or_default();
 
-                    res
+        let (res, pending) = match step {
+            0 | 1 | 5 => {
+                let res = if step == 0 {
+                    let res = self.block().run(interpreter);
+                    let res = interpreter
+                        .executor()
+                        .save_resume_point(res, self, 0, Vec::new);
+                    match res {
+                        // Interruptions are not exceptions, they can't be caught.
+                        Err(err) if !interpreter.executor().is_interrupted() => {
+                            self.run_catch(err, 0, interpreter)
+                        }
+                        res => res,
+                    }
                 } else {
-                    Err(err)
+                    self.run_catch(value, step, interpreter)
+                };
+                if interpreter.executor().is_suspending() {
+                    return res;
                 }
-            },
-            Ok,
-        );
+                (res, interpreter.executor().take_interruption())
+            }
+            2 => (Ok(value), None),
+            3 => (Err(value), None),
+            _ => (Err(Value::undefined()), Some(Interruption::Return(value))),
+        };
 
         if let Some(finally) = self.finally() {
-            finally.run(interpreter)?;
+            let (step, value) = match (&pending, &res) {
+                (Some(Interruption::Return(value)), _) => (4, value.clone()),
+                (Some(Interruption::Yield(_)), _) | (Some(Interruption::Await(_)), _) => {
+                    unreachable!("suspensions return early")
+                }
+                (None, Ok(value)) => (2, value.clone()),
+                (None, Err(err)) => (3, err.clone()),
+            };
+            let result = finally.run(interpreter);
+            let result = interpreter
+                .executor()
+                .save_resume_point(result, self, step, || vec![value])?;
+
+            // A `return` in the `finally` block cancels the closing of the generator.
+            if pending.is_some()
+                && interpreter.executor().get_current_state() == &InterpreterState::Return
+            {
+                return Ok(result);
+            }
+        }
+
+        if let Some(interruption) = pending {
+            interpreter.executor().set_interruption(interruption);
         }
 
         res
@@ -151,7 +226,7 @@ impl From<Try> for Node {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Catch {
-    parameter: Option<Identifier>,
+    parameter: Option<Binding>,
     block: Block,
 }
 
@@ -160,7 +235,7 @@ impl Catch {
     pub(in crate::syntax) fn new<OI, I, B>(parameter: OI, block: B) -> Self
     where
         OI: Into<Option<I>>,
-        I: Into<Identifier>,
+        I: Into<Binding>,
         B: Into<Block>,
     {
         Self {
@@ -170,8 +245,8 @@ impl Catch {
     }
 
     /// Gets the parameter of the catch block.
-    pub fn parameter(&self) -> Option<&str> {
-        self.parameter.as_ref().map(Identifier::as_ref)
+    pub fn parameter(&self) -> Option<&Binding> {
+        self.parameter.as_ref()
     }
 
     /// Retrieves the catch execution block.
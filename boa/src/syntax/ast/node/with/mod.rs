@@ -0,0 +1,105 @@
+use crate::{
+    environment::lexical_environment::new_with_environment, exec::Executable,
+    syntax::ast::node::Node, BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `with` statement extends the scope chain for a statement.
+///
+/// The properties of the object become bindings of the statement, except the ones that the
+/// object lists in its `Symbol.unscopables` property. A function found in the object is called
+/// with the object as its `this` value.
+///
+/// The `with` statement is not allowed in strict mode code.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-WithStatement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/with
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct With {
+    expr: Box<Node>,
+    body: Box<Node>,
+}
+
+impl With {
+    /// Creates a `With` AST node.
+    pub fn new<E, B>(expr: E, body: B) -> Self
+    where
+        E: Into<Node>,
+        B: Into<Node>,
+    {
+        Self {
+            expr: Box::new(expr.into()),
+            body: Box::new(body.into()),
+        }
+    }
+
+    /// Gets the expression of the object of the statement.
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+
+    /// Gets the statement that is run with the object in its scope.
+    pub fn body(&self) -> &Node {
+        &self.body
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        write!(f, "with ({}) ", self.expr())?;
+        self.body().display(f, indentation)
+    }
+}
+
+impl Executable for With {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("With", "exec");
+        // A resumed generator has already restored the object environment of the statement.
+        if interpreter.executor().take_resume_point(self).is_none() {
+            let object = self.expr().run(interpreter)?.to_object(interpreter)?;
+            let unscopables = interpreter.well_known_symbols().unscopables_symbol();
+            let env = &mut interpreter.realm_mut().environment;
+            env.push(new_with_environment(
+                object.into(),
+                unscopables,
+                Some(env.get_current_environment_ref().clone()),
+            ));
+        }
+
+        let result = self.body().run(interpreter);
+        let result = interpreter
+            .executor()
+            .save_resume_point(result, self, 0, Vec::new);
+
+        // pop the object env, unless it has to be kept for a suspended generator
+        if !interpreter.executor().is_suspending() {
+            let _ = interpreter.realm_mut().environment.pop();
+        }
+
+        result
+    }
+}
+
+impl fmt::Display for With {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<With> for Node {
+    fn from(with: With) -> Self {
+        Self::With(with)
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    builtins::iterable::{create_iter_result_object, get_iterator, IteratorRecord},
+    exec::{Executable, Interruption, ResumeAction},
+    syntax::ast::node::Node,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `yield` keyword is used to pause and resume a generator function.
+///
+/// The value of the expression is returned to the caller of the generator's `next` method, and
+/// the `yield` expression itself evaluates to the value the generator is resumed with. With
+/// `yield*`, the generator delegates to another iterable until it is done.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Yield {
+    expr: Option<Box<Node>>,
+    delegate: bool,
+}
+
+impl Yield {
+    /// Creates a `Yield` AST node.
+    pub fn new<E, OE>(expr: OE, delegate: bool) -> Self
+    where
+        E: Into<Node>,
+        OE: Into<Option<E>>,
+    {
+        Self {
+            expr: expr.into().map(E::into).map(Box::new),
+            delegate,
+        }
+    }
+
+    /// Gets the expression of the `yield`, if any.
+    pub fn expr(&self) -> Option<&Node> {
+        self.expr.as_ref().map(Box::as_ref)
+    }
+
+    /// Checks if this is a `yield*` that delegates to another iterable.
+    pub fn delegate(&self) -> bool {
+        self.delegate
+    }
+
+    /// Suspends the generator, giving `iter_result` to the caller.
+    ///
+    /// `values` are given back when the generator is resumed.
+    fn suspend(&self, iter_result: Value, values: Vec<Value>, ctx: &mut Context) -> Result<Value> {
+        ctx.executor()
+            .set_interruption(Interruption::Yield(iter_result));
+        ctx.executor()
+            .save_resume_point(Err(Value::undefined()), self, 0, || values)
+    }
+
+    /// Closes the generator as if a `return` statement was evaluated.
+    fn close(value: Value, ctx: &mut Context) -> Result<Value> {
+        ctx.executor().set_interruption(Interruption::Return(value));
+        Err(Value::undefined())
+    }
+
+    /// Gets the action the generator is resumed with.
+    fn resume_action(ctx: &mut Context) -> ResumeAction {
+        ctx.executor()
+            .take_resume_action()
+            .expect("a resumed generator has a resume action")
+    }
+
+    /// Runs a `yield*` expression.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator-function-definitions-runtime-semantics-evaluation
+    fn run_delegate(&self, ctx: &mut Context) -> Result<Value> {
+        // The iterator is kept while the generator is suspended.
+        let (iterator, received) = if let Some(point) = ctx.executor().take_resume_point(self) {
+            let values = point.values();
+            let iterator = IteratorRecord::new(values[0].clone(), values[1].clone());
+            (iterator, Self::resume_action(ctx))
+        } else {
+            let iterable = self
+                .expr()
+                .expect("yield* always has an expression")
+                .run(ctx)?;
+            let iterator = get_iterator(ctx, iterable)?;
+            (iterator, ResumeAction::Next(Value::undefined()))
+        };
+        let iterator_object = iterator.iterator_object().clone();
+
+        let inner_result = match received {
+            ResumeAction::Next(value) => {
+                ctx.call(iterator.next_function(), &iterator_object, &[value])?
+            }
+            ResumeAction::Throw(exception) => {
+                let throw = iterator_object.get_field("throw", ctx)?;
+                if throw.is_null_or_undefined() {
+                    // The iterator can't handle the exception, close it before giving up.
+                    let r#return = iterator_object.get_field("return", ctx)?;
+                    if !r#return.is_null_or_undefined() {
+                        ctx.call(&r#return, &iterator_object, &[])?;
+                    }
+                    return ctx.throw_type_error("The iterator does not provide a 'throw' method");
+                }
+                ctx.call(&throw, &iterator_object, &[exception])?
+            }
+            ResumeAction::Return(value) => {
+                let r#return = iterator_object.get_field("return", ctx)?;
+                if r#return.is_null_or_undefined() {
+                    return Self::close(value, ctx);
+                }
+                let inner_result = ctx.call(&r#return, &iterator_object, &[value])?;
+                if !inner_result.is_object() {
+                    return ctx.throw_type_error("iterator result is not an object");
+                }
+                if inner_result.get_field("done", ctx)?.to_boolean() {
+                    return Self::close(inner_result.get_field("value", ctx)?, ctx);
+                }
+                let values = vec![iterator_object, iterator.next_function().clone()];
+                return self.suspend(inner_result, values, ctx);
+            }
+        };
+
+        if !inner_result.is_object() {
+            return ctx.throw_type_error("iterator result is not an object");
+        }
+        if inner_result.get_field("done", ctx)?.to_boolean() {
+            return inner_result.get_field("value", ctx);
+        }
+        // The result of the inner iterator is given to the caller as is.
+        let values = vec![iterator_object, iterator.next_function().clone()];
+        self.suspend(inner_result, values, ctx)
+    }
+}
+
+impl Executable for Yield {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Yield", "exec");
+        if self.delegate {
+            return self.run_delegate(interpreter);
+        }
+
+        // The generator is being resumed at this `yield`.
+        if interpreter.executor().take_resume_point(self).is_some() {
+            return match Self::resume_action(interpreter) {
+                ResumeAction::Next(value) => Ok(value),
+                ResumeAction::Throw(exception) => Err(exception),
+                ResumeAction::Return(value) => Self::close(value, interpreter),
+            };
+        }
+
+        let value = match self.expr() {
+            Some(expr) => expr.run(interpreter)?,
+            None => Value::undefined(),
+        };
+        let iter_result = create_iter_result_object(interpreter, value, false);
+        self.suspend(iter_result, Vec::new(), interpreter)
+    }
+}
+
+impl fmt::Display for Yield {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("yield")?;
+        if self.delegate {
+            f.write_str("*")?;
+        }
+        if let Some(expr) = self.expr() {
+            write!(f, " {}", expr)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Yield> for Node {
+    fn from(r#yield: Yield) -> Node {
+        Node::Yield(r#yield)
+    }
+}
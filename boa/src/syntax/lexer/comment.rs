@@ -31,7 +31,7 @@ impl<R> Tokenizer<R> for SingleLineComment {
 
         // Skip either to the end of the line or to the end of the input
         while let Some(ch) = cursor.peek()? {
-            if ch == '\n' {
+            if matches!(ch, '\n' | '\r' | '\u{2028}' | '\u{2029}') {
                 break;
             } else {
                 // Consume char.
@@ -45,6 +45,80 @@ impl<R> Tokenizer<R> for SingleLineComment {
     }
 }
 
+/// Lexes a hashbang comment, which is a single line comment starting with `#!` at the very start
+/// of the source code.
+///
+/// Assumes that the initial '#' is already consumed, and that it is followed by `!`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-hashbang
+pub(super) struct HashbangComment;
+
+impl<R> Tokenizer<R> for HashbangComment {
+    fn lex(&mut self, cursor: &mut Cursor<R>, start_pos: Position) -> Result<Token, Error>
+    where
+        R: Read,
+    {
+        let _timer = BoaProfiler::global().start_event("HashbangComment", "Lexing");
+
+        cursor.next_char()?.expect("! character vanished");
+        SingleLineComment.lex(cursor, start_pos)
+    }
+}
+
+/// Lexes an HTML-like opening comment, which is a single line comment starting with `<!--`.
+///
+/// Assumes that the initial '<' is already consumed, and that it is followed by `!--`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-html-like-comments
+pub(super) struct HtmlOpenComment;
+
+impl<R> Tokenizer<R> for HtmlOpenComment {
+    fn lex(&mut self, cursor: &mut Cursor<R>, start_pos: Position) -> Result<Token, Error>
+    where
+        R: Read,
+    {
+        let _timer = BoaProfiler::global().start_event("HtmlOpenComment", "Lexing");
+
+        for _ in 0..3 {
+            cursor
+                .next_char()?
+                .expect("<!-- comment character vanished");
+        }
+        SingleLineComment.lex(cursor, start_pos)
+    }
+}
+
+/// Lexes an HTML-like closing comment, which is a single line comment starting with `-->`.
+///
+/// It can only be preceded by whitespace and comments in its line. Assumes that the initial '-'
+/// is already consumed, and that it is followed by `->`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-annexB-SingleLineHTMLCloseComment
+pub(super) struct HtmlCloseComment;
+
+impl<R> Tokenizer<R> for HtmlCloseComment {
+    fn lex(&mut self, cursor: &mut Cursor<R>, start_pos: Position) -> Result<Token, Error>
+    where
+        R: Read,
+    {
+        let _timer = BoaProfiler::global().start_event("HtmlCloseComment", "Lexing");
+
+        for _ in 0..2 {
+            cursor.next_char()?.expect("--> comment character vanished");
+        }
+        SingleLineComment.lex(cursor, start_pos)
+    }
+}
+
 /// Lexes a block (multi-line) comment.
 ///
 /// Assumes that the initial '/*' is already consumed.
@@ -69,7 +143,7 @@ impl<R> Tokenizer<R> for MultiLineComment {
             if let Some(ch) = cursor.next_char()? {
                 if ch == '*' && cursor.next_is('/')? {
                     break;
-                } else if ch == '\n' {
+                } else if matches!(ch, '\n' | '\r' | '\u{2028}' | '\u{2029}') {
                     new_line = true;
                 }
             } else {
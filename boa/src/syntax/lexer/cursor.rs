@@ -1,15 +1,19 @@
 //! Module implementing the lexer cursor. This is used for managing the input byte stream.
 
 use crate::{profiler::BoaProfiler, syntax::ast::Position};
-use std::io::{self, Bytes, Error, ErrorKind, Read};
+use std::{
+    collections::VecDeque,
+    io::{self, Bytes, Error, Read},
+};
 
 /// Cursor over the source code.
 #[derive(Debug)]
 pub(super) struct Cursor<R> {
     iter: InnerIter<R>,
-    peeked: Option<Option<char>>,
+    peeked: VecDeque<Option<char>>,
     pos: Position,
     strict_mode: bool,
+    annex_b: bool,
 }
 
 impl<R> Cursor<R> {
@@ -42,6 +46,17 @@ impl<R> Cursor<R> {
     pub(super) fn set_strict_mode(&mut self, strict_mode: bool) {
         self.strict_mode = strict_mode
     }
+
+    /// Checks if the web compatibility syntax of Annex B of the specification is allowed.
+    #[inline]
+    pub(super) fn annex_b(&self) -> bool {
+        self.annex_b
+    }
+
+    #[inline]
+    pub(super) fn set_annex_b(&mut self, annex_b: bool) {
+        self.annex_b = annex_b
+    }
 }
 
 impl<R> Cursor<R>
@@ -53,9 +68,10 @@ where
     pub(super) fn new(inner: R) -> Self {
         Self {
             iter: InnerIter::new(inner.bytes()),
-            peeked: None,
+            peeked: VecDeque::with_capacity(3),
             pos: Position::new(1, 1),
             strict_mode: false,
+            annex_b: true,
         }
     }
 
@@ -64,14 +80,34 @@ where
     pub(super) fn peek(&mut self) -> Result<Option<char>, Error> {
         let _timer = BoaProfiler::global().start_event("cursor::peek()", "Lexing");
 
-        let iter = &mut self.iter;
-        if let Some(v) = self.peeked {
-            Ok(v)
-        } else {
-            let val = iter.next_char()?;
-            self.peeked = Some(val);
-            Ok(val)
+        self.peek_nth(0)
+    }
+
+    /// Peeks the character after the next character.
+    #[inline]
+    pub(super) fn peek_after(&mut self) -> Result<Option<char>, Error> {
+        let _timer = BoaProfiler::global().start_event("cursor::peek_after()", "Lexing");
+
+        self.peek_nth(1)
+    }
+
+    /// Peeks the `n`th character after the current one, starting from zero for the next
+    /// character.
+    pub(super) fn peek_nth(&mut self, n: usize) -> Result<Option<char>, Error> {
+        while self.peeked.len() <= n {
+            if let Some(None) = self.peeked.back() {
+                return Ok(None);
+            }
+            let val = self.iter.next_char()?;
+            self.peeked.push_back(val);
         }
+        Ok(self.peeked[n])
+    }
+
+    /// Takes the peeked character, if any.
+    #[inline]
+    fn take_peeked(&mut self) -> Option<Option<char>> {
+        self.peeked.pop_front()
     }
 
     /// Compares the character passed in to the next character, if they match true is returned and the buffer is incremented
@@ -81,7 +117,7 @@ where
 
         Ok(match self.peek()? {
             Some(next) if next == peek => {
-                let _ = self.peeked.take();
+                self.next_char()?;
                 true
             }
             _ => false,
@@ -106,26 +142,6 @@ where
         })
     }
 
-    /// Fills the buffer with all characters until the stop character is found.
-    ///
-    /// Note: It will not add the stop character to the buffer.
-    pub(super) fn take_until(&mut self, stop: char, buf: &mut String) -> io::Result<()> {
-        let _timer = BoaProfiler::global().start_event("cursor::take_until()", "Lexing");
-
-        loop {
-            if self.next_is(stop)? {
-                return Ok(());
-            } else if let Some(ch) = self.next_char()? {
-                buf.push(ch);
-            } else {
-                return Err(io::Error::new(
-                    ErrorKind::UnexpectedEof,
-                    format!("Unexpected end of file when looking for character {}", stop),
-                ));
-            }
-        }
-    }
-
     /// Fills the buffer with characters until the first character (x) for which the predicate (pred) is false
     /// (or the next character is none).
     ///
@@ -148,23 +164,12 @@ where
         }
     }
 
-    /// It will fill the buffer with checked ASCII bytes.
-    ///
-    /// This expects for the buffer to be fully filled. If it's not, it will fail with an
-    /// `UnexpectedEof` I/O error.
-    #[inline]
-    pub(super) fn fill_bytes(&mut self, buf: &mut [u8]) -> io::Result<()> {
-        let _timer = BoaProfiler::global().start_event("cursor::fill_bytes()", "Lexing");
-
-        self.iter.fill_bytes(buf)
-    }
-
     /// Retrieves the next UTF-8 character.
     #[inline]
     pub(crate) fn next_char(&mut self) -> Result<Option<char>, Error> {
         let _timer = BoaProfiler::global().start_event("cursor::next_char()", "Lexing");
 
-        let chr = match self.peeked.take() {
+        let chr = match self.take_peeked() {
             Some(v) => v,
             None => self.iter.next_char()?,
         };
@@ -174,7 +179,7 @@ where
                 // Try to take a newline if it's next, for windows "\r\n" newlines
                 // Otherwise, treat as a Mac OS9 bare '\r' newline
                 if self.peek()? == Some('\n') {
-                    self.peeked.take();
+                    self.take_peeked();
                 }
                 self.next_line();
             }
@@ -205,23 +210,6 @@ impl<R> InnerIter<R>
 where
     R: Read,
 {
-    /// It will fill the buffer with checked ASCII bytes.
-    ///
-    /// This expects for the buffer to be fully filled. If it's not, it will fail with an
-    /// `UnexpectedEof` I/O error.
-    #[inline]
-    fn fill_bytes(&mut self, buf: &mut [u8]) -> io::Result<()> {
-        for byte in buf.iter_mut() {
-            *byte = self.next_ascii()?.ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::UnexpectedEof,
-                    "unexpected EOF when filling buffer",
-                )
-            })?;
-        }
-        Ok(())
-    }
-
     /// Retrieves the next UTF-8 checked character.
     fn next_char(&mut self) -> io::Result<Option<char>> {
         let first_byte = match self.iter.next().transpose()? {
@@ -279,19 +267,4 @@ where
 
         Ok(Some(chr))
     }
-
-    /// Retrieves the next ASCII checked character.
-    #[inline]
-    fn next_ascii(&mut self) -> io::Result<Option<u8>> {
-        let next_byte = self.iter.next().transpose()?;
-
-        match next_byte {
-            Some(next) if next <= 0x7F => Ok(Some(next)),
-            None => Ok(None),
-            _ => Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "non-ASCII byte found",
-            )),
-        }
-    }
 }
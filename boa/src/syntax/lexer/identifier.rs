@@ -1,6 +1,6 @@
 //! This module implements lexing for identifiers (foo, myvar, etc.) used in the JavaScript programing language.
 
-use super::{Cursor, Error, Tokenizer};
+use super::{string::unicode_escape, Cursor, Error, Tokenizer};
 use crate::{
     profiler::BoaProfiler,
     syntax::{
@@ -8,11 +8,10 @@ use crate::{
         lexer::{Token, TokenKind},
     },
 };
-use std::io::Read;
+use std::{char::from_u32, io::Read};
+use unicode_xid::UnicodeXID;
 
-const STRICT_FORBIDDEN_IDENTIFIERS: [&str; 11] = [
-    "eval",
-    "arguments",
+const STRICT_FORBIDDEN_IDENTIFIERS: [&str; 8] = [
     "implements",
     "interface",
     "let",
@@ -20,10 +19,12 @@ const STRICT_FORBIDDEN_IDENTIFIERS: [&str; 11] = [
     "private",
     "protected",
     "public",
-    "static",
     "yield",
 ];
 
+/// The keywords that are identifiers when they contain escape sequences.
+const CONTEXTUAL_KEYWORDS: [&str; 4] = ["await", "let", "of", "yield"];
+
 /// Identifier lexing.
 ///
 /// More information:
@@ -51,18 +52,17 @@ impl<R> Tokenizer<R> for Identifier {
     {
         let _timer = BoaProfiler::global().start_event("Identifier", "Lexing");
 
-        let mut buf = self.init.to_string();
-
-        cursor.take_while_pred(&mut buf, &|c: char| {
-            c.is_alphabetic() || c.is_digit(10) || c == '_'
-        })?;
+        let (buf, contains_escape) = take_identifier_name(cursor, start_pos, self.init)?;
 
+        // A name with escape sequences is never a keyword or a literal. An escaped reserved word
+        // is still a valid `IdentifierName`, like in `o.i\u0066`, so the parser rejects it
+        // where an `Identifier` is expected.
         let tk = match buf.as_str() {
-            "true" => TokenKind::BooleanLiteral(true),
-            "false" => TokenKind::BooleanLiteral(false),
-            "null" => TokenKind::NullLiteral,
-            slice => {
-                if let Ok(keyword) = slice.parse() {
+            "true" if !contains_escape => TokenKind::BooleanLiteral(true),
+            "false" if !contains_escape => TokenKind::BooleanLiteral(false),
+            "null" if !contains_escape => TokenKind::NullLiteral,
+            slice => match slice.parse() {
+                Ok(keyword) if !contains_escape => {
                     if cursor.strict_mode() && keyword == Keyword::With {
                         return Err(Error::Syntax(
                             "using 'with' statement not allowed in strict mode".into(),
@@ -70,7 +70,8 @@ impl<R> Tokenizer<R> for Identifier {
                         ));
                     }
                     TokenKind::Keyword(keyword)
-                } else {
+                }
+                _ => {
                     if cursor.strict_mode() && STRICT_FORBIDDEN_IDENTIFIERS.contains(&slice) {
                         return Err(Error::Syntax(
                             format!(
@@ -83,9 +84,145 @@ impl<R> Tokenizer<R> for Identifier {
                     }
                     TokenKind::identifier(slice)
                 }
-            }
+            },
         };
 
         Ok(Token::new(tk, Span::new(start_pos, cursor.pos())))
     }
 }
+
+/// Private identifier lexing.
+///
+/// This lexes the name of a private member of a class, like `#x`, once the `#` has been consumed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-PrivateIdentifier
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Private_class_fields
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PrivateIdentifier;
+
+impl<R> Tokenizer<R> for PrivateIdentifier {
+    fn lex(&mut self, cursor: &mut Cursor<R>, start_pos: Position) -> Result<Token, Error>
+    where
+        R: Read,
+    {
+        let _timer = BoaProfiler::global().start_event("PrivateIdentifier", "Lexing");
+
+        let init = match cursor.next_char()? {
+            Some(c) if c == '\\' || is_identifier_start(c) => c,
+            _ => {
+                return Err(Error::syntax(
+                    "invalid character after '#', expected the name of a private member",
+                    start_pos,
+                ))
+            }
+        };
+        let (buf, _) = take_identifier_name(cursor, start_pos, init)?;
+
+        Ok(Token::new(
+            TokenKind::private_identifier(buf),
+            Span::new(start_pos, cursor.pos()),
+        ))
+    }
+}
+
+/// Lexes an identifier name whose first character, `init`, is already consumed. It is a `\` if
+/// the name starts with a Unicode escape sequence.
+///
+/// Returns the name with its escape sequences replaced by the characters they stand for, and
+/// whether it contains escape sequences.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-IdentifierName
+fn take_identifier_name<R>(
+    cursor: &mut Cursor<R>,
+    start_pos: Position,
+    init: char,
+) -> Result<(String, bool), Error>
+where
+    R: Read,
+{
+    let mut name = String::new();
+    let mut contains_escape = false;
+    let mut next = Some(init);
+    while let Some(ch) = next {
+        let ch = if ch == '\\' {
+            contains_escape = true;
+            let escaped = if cursor.next_is('u')? {
+                from_u32(unicode_escape(cursor)?)
+            } else {
+                None
+            };
+            match escaped {
+                Some(ch) if name.is_empty() && is_identifier_start(ch) => ch,
+                Some(ch) if !name.is_empty() && is_identifier_part(ch) => ch,
+                _ => {
+                    return Err(Error::syntax(
+                        "invalid escape sequence in identifier",
+                        start_pos,
+                    ))
+                }
+            }
+        } else {
+            ch
+        };
+        name.push(ch);
+
+        next = if cursor.next_is_pred(&|ch: char| ch == '\\' || is_identifier_part(ch))? {
+            cursor.next_char()?
+        } else {
+            None
+        };
+    }
+    Ok((name, contains_escape))
+}
+
+/// Checks if `name` is a reserved word, other than the contextual keywords that are only
+/// reserved in some code.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ReservedWord
+pub(crate) fn is_reserved_word(name: &str) -> bool {
+    matches!(name, "true" | "false" | "null")
+        || (name.parse::<Keyword>().is_ok() && !CONTEXTUAL_KEYWORDS.contains(&name))
+}
+
+/// Checks if `c` can be the first character of an identifier.
+///
+/// These are `$`, `_`, and the characters with the `XID_Start` Unicode property, which are the
+/// `ID_Start` characters that keep being identifier characters under NFKC normalization.
+///
+/// This deviates from the specification, which uses `ID_Start`: the few characters that are only
+/// in `ID_Start`, like U+309B, can't start an identifier.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-IdentifierStartChar
+pub(super) fn is_identifier_start(c: char) -> bool {
+    c == '$' || c == '_' || UnicodeXID::is_xid_start(c)
+}
+
+/// Checks if `c` can be part of an identifier, after its first character.
+///
+/// These are `$`, the zero width non-joiner and joiner, and the characters with the
+/// `XID_Continue` Unicode property.
+///
+/// Like in [`is_identifier_start`], this deviates from the specification, which uses
+/// `ID_Continue`: the few characters that are only in `ID_Continue`, like U+037A, can't be part
+/// of an identifier.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-IdentifierPartChar
+pub(super) fn is_identifier_part(c: char) -> bool {
+    c == '$' || c == '\u{200C}' || c == '\u{200D}' || UnicodeXID::is_xid_continue(c)
+}
@@ -30,9 +30,11 @@ pub mod token;
 mod tests;
 
 use self::{
-    comment::{MultiLineComment, SingleLineComment},
+    comment::{
+        HashbangComment, HtmlCloseComment, HtmlOpenComment, MultiLineComment, SingleLineComment,
+    },
     cursor::Cursor,
-    identifier::Identifier,
+    identifier::{is_identifier_start, Identifier, PrivateIdentifier},
     number::NumberLiteral,
     operator::Operator,
     regex::RegexLiteral,
@@ -43,6 +45,7 @@ use self::{
 use crate::syntax::ast::{Punctuator, Span};
 pub use crate::{profiler::BoaProfiler, syntax::ast::Position};
 pub use error::Error;
+pub(crate) use identifier::is_reserved_word;
 use std::io::Read;
 pub use token::{Token, TokenKind};
 
@@ -58,6 +61,11 @@ trait Tokenizer<R> {
 pub struct Lexer<R> {
     cursor: Cursor<R>,
     goal_symbol: InputElement,
+    /// For each `{` that is not closed yet, whether it starts a substitution in a template
+    /// literal, so that the `}` closing it is lexed as the rest of the template.
+    open_braces: Vec<bool>,
+    /// Whether only whitespace and comments were found since the start of the current line.
+    line_start: bool,
 }
 
 impl<R> Lexer<R> {
@@ -100,6 +108,21 @@ impl<R> Lexer<R> {
         self.cursor.set_strict_mode(strict_mode)
     }
 
+    /// Checks if the web compatibility syntax of Annex B of the specification is allowed, like
+    /// HTML-like comments and legacy octal literals.
+    #[inline]
+    pub(super) fn annex_b(&self) -> bool {
+        self.cursor.annex_b()
+    }
+
+    /// Allows or forbids the web compatibility syntax of Annex B of the specification.
+    ///
+    /// It is allowed by default.
+    #[inline]
+    pub(super) fn set_annex_b(&mut self, annex_b: bool) {
+        self.cursor.set_annex_b(annex_b)
+    }
+
     /// Creates a new lexer.
     #[inline]
     pub fn new(reader: R) -> Self
@@ -109,6 +132,8 @@ impl<R> Lexer<R> {
         Self {
             cursor: Cursor::new(reader),
             goal_symbol: Default::default(),
+            open_braces: Vec::new(),
+            line_start: true,
         }
     }
 
@@ -169,6 +194,24 @@ impl<R> Lexer<R> {
         }
     }
 
+    /// Checks if the `<` that was just consumed starts a `<!--` comment.
+    fn next_is_html_open_comment(&mut self) -> Result<bool, Error>
+    where
+        R: Read,
+    {
+        Ok(self.cursor.peek()? == Some('!')
+            && self.cursor.peek_after()? == Some('-')
+            && self.cursor.peek_nth(2)? == Some('-'))
+    }
+
+    /// Checks if the `-` that was just consumed starts a `-->` comment.
+    fn next_is_html_close_comment(&mut self) -> Result<bool, Error>
+    where
+        R: Read,
+    {
+        Ok(self.cursor.peek()? == Some('-') && self.cursor.peek_after()? == Some('>'))
+    }
+
     /// Retrieves the next token from the lexer.
     // We intentionally don't implement Iterator trait as Result<Option> is cleaner to handle.
     #[allow(clippy::should_implement_trait)]
@@ -196,11 +239,15 @@ impl<R> Lexer<R> {
                 Span::new(start, self.cursor.pos()),
             )),
             '"' | '\'' => StringLiteral::new(next_chr).lex(&mut self.cursor, start),
-            '`' => TemplateLiteral.lex(&mut self.cursor, start),
+            '`' => TemplateLiteral::new(false).lex(&mut self.cursor, start),
             _ if next_chr.is_digit(10) => NumberLiteral::new(next_chr).lex(&mut self.cursor, start),
-            _ if next_chr.is_alphabetic() || next_chr == '$' || next_chr == '_' => {
+            _ if next_chr == '\\' || is_identifier_start(next_chr) => {
                 Identifier::new(next_chr).lex(&mut self.cursor, start)
             }
+            '#' if start == Position::new(1, 1) && self.cursor.peek()? == Some('!') => {
+                HashbangComment.lex(&mut self.cursor, start)
+            }
+            '#' => PrivateIdentifier.lex(&mut self.cursor, start),
             ';' => Ok(Token::new(
                 Punctuator::Semicolon.into(),
                 Span::new(start, self.cursor.pos()),
@@ -222,10 +269,16 @@ impl<R> Lexer<R> {
                 Punctuator::Comma.into(),
                 Span::new(start, self.cursor.pos()),
             )),
-            '{' => Ok(Token::new(
-                Punctuator::OpenBlock.into(),
-                Span::new(start, self.cursor.pos()),
-            )),
+            '{' => {
+                self.open_braces.push(false);
+                Ok(Token::new(
+                    Punctuator::OpenBlock.into(),
+                    Span::new(start, self.cursor.pos()),
+                ))
+            }
+            '}' if self.open_braces.pop() == Some(true) => {
+                TemplateLiteral::new(true).lex(&mut self.cursor, start)
+            }
             '}' => Ok(Token::new(
                 Punctuator::CloseBlock.into(),
                 Span::new(start, self.cursor.pos()),
@@ -238,12 +291,14 @@ impl<R> Lexer<R> {
                 Punctuator::CloseBracket.into(),
                 Span::new(start, self.cursor.pos()),
             )),
-            '?' => Ok(Token::new(
-                Punctuator::Question.into(),
-                Span::new(start, self.cursor.pos()),
-            )),
             '/' => self.lex_slash_token(start),
-            '=' | '*' | '+' | '-' | '%' | '|' | '&' | '^' | '<' | '>' | '!' | '~' => {
+            '<' if self.annex_b() && self.next_is_html_open_comment()? => {
+                HtmlOpenComment.lex(&mut self.cursor, start)
+            }
+            '-' if self.annex_b() && self.line_start && self.next_is_html_close_comment()? => {
+                HtmlCloseComment.lex(&mut self.cursor, start)
+            }
+            '=' | '*' | '+' | '-' | '%' | '|' | '&' | '^' | '<' | '>' | '!' | '~' | '?' => {
                 Operator::new(next_chr).lex(&mut self.cursor, start)
             }
             _ => {
@@ -257,6 +312,16 @@ impl<R> Lexer<R> {
             }
         }?;
 
+        if let TokenKind::TemplateHead(_) | TokenKind::TemplateMiddle(_) = token.kind() {
+            self.open_braces.push(true);
+        }
+
+        match token.kind() {
+            TokenKind::Comment => {}
+            TokenKind::LineTerminator => self.line_start = true,
+            _ => self.line_start = false,
+        }
+
         if token.kind() == &TokenKind::Comment {
             // Skip comment
             self.next()
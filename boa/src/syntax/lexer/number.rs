@@ -1,6 +1,6 @@
 //! This module implements lexing for number literals (123, 787) used in the JavaScript programing language.
 
-use super::{Cursor, Error, TokenKind, Tokenizer};
+use super::{identifier::is_identifier_start, Cursor, Error, TokenKind, Tokenizer};
 use crate::{
     builtins::BigInt,
     profiler::BoaProfiler,
@@ -107,11 +107,55 @@ where
     }
 
     // Consume the decimal digits.
-    cursor.take_while_pred(buf, &|c: char| c.is_digit(kind.base()))?;
+    take_integer(buf, cursor, kind, true)?;
 
     Ok(())
 }
 
+/// Consumes the digits of `kind` until a non-digit character is encountered, pushing them to
+/// `buf`.
+///
+/// If `separators` is `true`, the digits can be separated by `_` numeric separators, which are
+/// not pushed to `buf`. A separator must be between two digits.
+///
+/// More information:
+///  - [ECMAScript Specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-NumericLiteralSeparator
+fn take_integer<R>(
+    buf: &mut String,
+    cursor: &mut Cursor<R>,
+    kind: &NumericKind,
+    separators: bool,
+) -> Result<(), Error>
+where
+    R: Read,
+{
+    let mut after_digit = matches!(buf.chars().last(), Some(c) if c.is_digit(kind.base()));
+    loop {
+        match cursor.peek()? {
+            Some(c) if c.is_digit(kind.base()) => {
+                cursor.next_char()?.expect("digit vanished");
+                buf.push(c);
+                after_digit = true;
+            }
+            Some('_') if separators => {
+                let before_digit =
+                    matches!(cursor.peek_after()?, Some(c) if c.is_digit(kind.base()));
+                if !after_digit || !before_digit {
+                    return Err(Error::syntax(
+                        "numeric separators are only allowed between digits",
+                        cursor.pos(),
+                    ));
+                }
+                cursor.next_char()?.expect("_ character vanished");
+                after_digit = false;
+            }
+            _ => return Ok(()),
+        }
+    }
+}
+
 /// Utility function for checking the NumericLiteral is not followed by an `IdentifierStart` or `DecimalDigit` character.
 ///
 /// More information:
@@ -122,10 +166,10 @@ fn check_after_numeric_literal<R>(cursor: &mut Cursor<R>) -> Result<(), Error>
 where
     R: Read,
 {
-    let pred = |ch: char| ch.is_ascii_alphanumeric() || ch == '$' || ch == '_';
+    let pred = |ch: char| ch.is_ascii_digit() || ch == '\\' || is_identifier_start(ch);
     if cursor.next_is_pred(&pred)? {
         Err(Error::syntax(
-            "a numeric literal must not be followed by an alphanumeric, $ or _ characters",
+            "a numeric literal must not be followed by an identifier character or a digit",
             cursor.pos(),
         ))
     } else {
@@ -145,6 +189,10 @@ impl<R> Tokenizer<R> for NumberLiteral {
         // Default assume the number is a base 10 integer.
         let mut kind = NumericKind::Integer(10);
 
+        // Legacy octal literals and decimal literals with a leading 0 can't be BigInts or have
+        // numeric separators.
+        let mut legacy = false;
+
         let c = cursor.peek();
 
         if self.init == '0' {
@@ -192,6 +240,11 @@ impl<R> Tokenizer<R> for NumberLiteral {
                                     "implicit octal literals are not allowed in strict mode",
                                     start_pos,
                                 ));
+                            } else if !cursor.annex_b() {
+                                return Err(Error::syntax(
+                                    "implicit octal literals are not allowed",
+                                    start_pos,
+                                ));
                             } else {
                                 // Remove the initial '0' from buffer.
                                 buf.pop();
@@ -199,6 +252,7 @@ impl<R> Tokenizer<R> for NumberLiteral {
                                 buf.push(cursor.next_char()?.expect("'0' character vanished"));
 
                                 kind = NumericKind::Integer(8);
+                                legacy = true;
                             }
                         } else if ch.is_digit(10) {
                             // Indicates a numerical digit comes after then 0 but it isn't an octal digit
@@ -209,8 +263,14 @@ impl<R> Tokenizer<R> for NumberLiteral {
                                     "leading 0's are not allowed in strict mode",
                                     start_pos,
                                 ));
+                            } else if !cursor.annex_b() {
+                                return Err(Error::syntax(
+                                    "leading 0's are not allowed",
+                                    start_pos,
+                                ));
                             } else {
                                 buf.push(cursor.next_char()?.expect("Number digit vanished"));
+                                legacy = true;
                             }
                         } // Else indicates that the symbol is a non-number.
                     }
@@ -226,13 +286,27 @@ impl<R> Tokenizer<R> for NumberLiteral {
         }
 
         // Consume digits until a non-digit character is encountered or all the characters are consumed.
-        cursor.take_while_pred(&mut buf, &|c: char| c.is_digit(kind.base()))?;
+        // A single 0 can't be followed by a separator either.
+        let separators = !legacy && (self.init != '0' || kind.base() != 10);
+        take_integer(&mut buf, cursor, &kind, separators)?;
+        if buf.is_empty() {
+            return Err(Error::syntax(
+                "expected digits after the prefix of a numeric literal",
+                cursor.pos(),
+            ));
+        }
 
         // The non-digit character could be:
         // 'n' To indicate a BigIntLiteralSuffix.
         // '.' To indicate a decimal seperator.
         // 'e' | 'E' To indicate an ExponentPart.
         match cursor.peek()? {
+            Some('n') if legacy => {
+                return Err(Error::syntax(
+                    "a BigInt literal can't be a legacy octal literal or start with a 0",
+                    start_pos,
+                ));
+            }
             Some('n') => {
                 // DecimalBigIntegerLiteral
                 // Lexing finished.
@@ -252,7 +326,7 @@ impl<R> Tokenizer<R> for NumberLiteral {
                     kind = NumericKind::Rational;
 
                     // Consume digits until a non-digit character is encountered or all the characters are consumed.
-                    cursor.take_while_pred(&mut buf, &|c: char| c.is_digit(kind.base()))?;
+                    take_integer(&mut buf, cursor, &kind, true)?;
 
                     // The non-digit character at this point must be an 'e' or 'E' to indicate an Exponent Part.
                     // Another '.' or 'n' is not allowed.
@@ -118,11 +118,30 @@ impl<R> Tokenizer<R> for Operator {
                 Ok(Punctuator::Mod)
             ),
             '|' => op!(cursor, start_pos, Ok(Punctuator::AssignOr), Ok(Punctuator::Or), {
-                Some('|') => Ok(Punctuator::BoolOr)
+                Some('|') => vop!(cursor, Ok(Punctuator::AssignBoolOr), Ok(Punctuator::BoolOr))
             }),
             '&' => op!(cursor, start_pos, Ok(Punctuator::AssignAnd), Ok(Punctuator::And), {
-                Some('&') => Ok(Punctuator::BoolAnd)
+                Some('&') => vop!(cursor, Ok(Punctuator::AssignBoolAnd), Ok(Punctuator::BoolAnd))
             }),
+            '?' => {
+                let punc = match cursor.peek()? {
+                    Some('?') => {
+                        cursor.next_char()?.expect("? token vanished");
+                        vop!(
+                            cursor,
+                            Ok(Punctuator::AssignCoalesce),
+                            Ok(Punctuator::Coalesce)
+                        )?
+                    }
+                    // `?.` followed by a decimal digit is a `?` followed by a numeric literal.
+                    Some('.') if !cursor.peek_after()?.map_or(false, |ch| ch.is_ascii_digit()) => {
+                        cursor.next_char()?.expect(". token vanished");
+                        Punctuator::Optional
+                    }
+                    _ => Punctuator::Question,
+                };
+                Ok(Token::new(punc.into(), Span::new(start_pos, cursor.pos())))
+            }
             '^' => op!(
                 cursor,
                 start_pos,
@@ -1,6 +1,6 @@
 //! This module implements lexing for regex literals used in the JavaScript programing language.
 
-use super::{Cursor, Error, Span, Tokenizer};
+use super::{identifier::is_identifier_part, Cursor, Error, Span, Tokenizer};
 use crate::{
     profiler::BoaProfiler,
     syntax::{
@@ -9,6 +9,7 @@ use crate::{
     },
 };
 use bitflags::bitflags;
+use regress::{Flags, Regex};
 use std::{
     fmt::{self, Display, Formatter},
     io::Read,
@@ -40,6 +41,8 @@ impl<R> Tokenizer<R> for RegexLiteral {
         let _timer = BoaProfiler::global().start_event("RegexLiteral", "Lexing");
 
         let mut body = String::new();
+        // A `/` in a character class doesn't end the body.
+        let mut in_class = false;
 
         // Lex RegularExpressionBody.
         loop {
@@ -53,7 +56,7 @@ impl<R> Tokenizer<R> for RegexLiteral {
                 }
                 Some(c) => {
                     match c {
-                        '/' => break, // RegularExpressionBody finished.
+                        '/' if !in_class => break, // RegularExpressionBody finished.
                         '\n' | '\r' | '\u{2028}' | '\u{2029}' => {
                             // Not allowed in Regex literal.
                             return Err(Error::syntax(
@@ -83,6 +86,14 @@ impl<R> Tokenizer<R> for RegexLiteral {
                                 ));
                             }
                         }
+                        '[' => {
+                            in_class = true;
+                            body.push(c);
+                        }
+                        ']' => {
+                            in_class = false;
+                            body.push(c);
+                        }
                         _ => body.push(c),
                     }
                 }
@@ -90,16 +101,354 @@ impl<R> Tokenizer<R> for RegexLiteral {
         }
 
         let mut flags = String::new();
-        let flags_start = cursor.pos();
-        cursor.take_while_pred(&mut flags, &char::is_alphabetic)?;
+        cursor.take_while_pred(&mut flags, &is_identifier_part)?;
+        let flags = parse_regex_flags(&flags, start_pos)?;
+
+        // The pattern is checked with the engine of `RegExp` objects, so that an invalid regular
+        // expression literal is an early error instead of an error when it is evaluated.
+        if let Err(error) = validate_pattern(&body, flags, cursor.annex_b()) {
+            return Err(Error::syntax(
+                format!("invalid regular expression literal: {}", error),
+                start_pos,
+            ));
+        }
 
         Ok(Token::new(
-            TokenKind::regular_expression_literal(body, parse_regex_flags(&flags, flags_start)?),
+            TokenKind::regular_expression_literal(body, flags),
             Span::new(start_pos, cursor.pos()),
         ))
     }
 }
 
+/// Checks the pattern of a regular expression with the engine of `RegExp` objects.
+fn validate_pattern(pattern: &str, flags: RegExpFlags, annex_b: bool) -> Result<(), String> {
+    let pattern = translate_pattern(pattern, flags.contains(RegExpFlags::UNICODE), annex_b)?;
+    match Regex::newf(&pattern, Flags::from(flags.to_string().as_str())) {
+        Ok(_) => Ok(()),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+/// Rewrites a pattern into one that the engine of `RegExp` objects accepts if and only if the
+/// pattern is valid.
+///
+/// The engine doesn't support named capture groups, so they become numbered groups. Outside of
+/// unicode patterns, the identity escapes of characters that are not identifier parts become
+/// the characters themselves, and the syntax that Annex B adds to patterns is rewritten into its
+/// standard equivalent: lone braces and brackets, `\c` without a control letter, legacy octal
+/// escapes, class escapes in the ranges of a class and quantified lookaheads.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-regular-expressions-patterns
+fn translate_pattern(pattern: &str, unicode: bool, annex_b: bool) -> Result<String, String> {
+    let annex_b = annex_b && !unicode;
+    let chars: Vec<char> = pattern.chars().collect();
+    let (group_count, group_names) = capture_groups(&chars)?;
+
+    let mut translated = String::with_capacity(pattern.len());
+    let mut in_class = false;
+    // Whether the previous atom of the class was a class escape like `\d`, `None` when the
+    // class has no atoms yet or the previous one was a `-`.
+    let mut class_escape = None;
+    // The position in `translated` of each open group, and whether the group is a lookahead.
+    let mut groups = Vec::new();
+    let mut i = 0;
+    while let Some(&c) = chars.get(i) {
+        i += 1;
+        match c {
+            '\\' => {
+                let escape =
+                    translate_escape(&chars[i..], in_class, unicode, group_count, &group_names)?;
+                let (escaped, len, is_class_escape) = match escape {
+                    Some(escape) => escape,
+                    None => translate_legacy_escape(&chars[i..], in_class, unicode, annex_b),
+                };
+                translated.push_str(&escaped);
+                i += len;
+                if in_class {
+                    class_escape = Some(is_class_escape);
+                }
+            }
+            '[' if !in_class => {
+                in_class = true;
+                class_escape = None;
+                translated.push(c);
+                if chars.get(i) == Some(&'^') {
+                    translated.push('^');
+                    i += 1;
+                }
+            }
+            ']' if in_class => {
+                in_class = false;
+                translated.push(c);
+            }
+            '-' if in_class => {
+                let next_is_class_escape = chars.get(i) == Some(&'\\')
+                    && matches!(chars.get(i + 1), Some('d' | 'D' | 's' | 'S' | 'w' | 'W'));
+                if annex_b && (class_escape == Some(true) || next_is_class_escape) {
+                    translated.push_str("\\-");
+                } else {
+                    translated.push(c);
+                }
+                class_escape = None;
+            }
+            _ if in_class => {
+                translated.push(c);
+                class_escape = Some(false);
+            }
+            '(' => {
+                let lookahead =
+                    chars.get(i) == Some(&'?') && matches!(chars.get(i + 1), Some('=' | '!'));
+                groups.push((translated.len(), lookahead));
+                translated.push(c);
+                if chars[i..].starts_with(&['?', '<'])
+                    && !matches!(chars.get(i + 2), Some('=' | '!'))
+                {
+                    let name_len = chars[i..].iter().position(|&c| c == '>').unwrap_or(0);
+                    i += name_len + 1;
+                }
+            }
+            ')' => match groups.pop() {
+                Some((start, true))
+                    if annex_b
+                        && (matches!(chars.get(i), Some('*' | '+' | '?'))
+                            || braced_quantifier_len(&chars[i..]).is_some()) =>
+                {
+                    translated.insert_str(start, "(?:");
+                    translated.push_str("))");
+                }
+                _ => translated.push(c),
+            },
+            '{' if annex_b => match braced_quantifier_len(&chars[i - 1..]) {
+                Some(len) => {
+                    translated.extend(&chars[i - 1..i - 1 + len]);
+                    i += len - 1;
+                }
+                None => translated.push_str("\\{"),
+            },
+            '}' | ']' if annex_b => {
+                translated.push('\\');
+                translated.push(c);
+            }
+            _ => translated.push(c),
+        }
+    }
+
+    Ok(translated)
+}
+
+/// Finds the number of capturing groups of a pattern, and the names of its named groups along
+/// with their numbers.
+fn capture_groups(pattern: &[char]) -> Result<(usize, Vec<(String, usize)>), String> {
+    let mut count = 0;
+    let mut names: Vec<(String, usize)> = Vec::new();
+    let mut in_class = false;
+    let mut chars = pattern.iter().copied();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' => in_class = true,
+            ']' => in_class = false,
+            '(' if !in_class => {
+                let mut rest = chars.clone();
+                if rest.next() != Some('?') {
+                    count += 1;
+                } else if rest.next() == Some('<') && !matches!(rest.next(), Some('=' | '!')) {
+                    count += 1;
+                    let name: String = chars.clone().skip(2).take_while(|&c| c != '>').collect();
+                    if name.is_empty() || !chars.clone().skip(2).any(|c| c == '>') {
+                        return Err("invalid capture group name".to_owned());
+                    }
+                    if names.iter().any(|(other, _)| *other == name) {
+                        return Err(format!("duplicate capture group name {}", name));
+                    }
+                    names.push((name, count));
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok((count, names))
+}
+
+/// Translates the escape that starts after the `\` at the start of `chars`, if it means the same
+/// with and without Annex B.
+///
+/// Gives back the translated escape, the number of characters it takes in the pattern and
+/// whether it's a class escape like `\d`.
+fn translate_escape(
+    chars: &[char],
+    in_class: bool,
+    unicode: bool,
+    group_count: usize,
+    group_names: &[(String, usize)],
+) -> Result<Option<(String, usize, bool)>, String> {
+    let escape = match chars.first() {
+        Some(&c @ ('d' | 'D' | 's' | 'S' | 'w' | 'W')) => (format!("\\{}", c), 1, true),
+        // The engine doesn't support the escapes of unicode patterns that use braces, a property
+        // escape is checked like any other class escape.
+        Some('p' | 'P') if unicode && chars.get(1) == Some(&'{') => {
+            let len = chars.iter().take_while(|&&c| c != '}').count();
+            let name = &chars[2..len];
+            if len == chars.len()
+                || name.is_empty()
+                || !name
+                    .iter()
+                    .all(|&c| c.is_ascii_alphanumeric() || c == '_' || c == '=')
+            {
+                return Err("invalid property name".to_owned());
+            }
+            ("\\w".to_owned(), len + 1, true)
+        }
+        Some('u') if unicode && chars.get(1) == Some(&'{') => {
+            let len = chars.iter().take_while(|&&c| c != '}').count();
+            let digits: String = chars[2..len].iter().collect();
+            let escape = match u32::from_str_radix(&digits, 16) {
+                Ok(code) if len == chars.len() || code > 0x0010_FFFF => {
+                    return Err("invalid unicode escape".to_owned())
+                }
+                Ok(code) if code <= 0xFF => format!("\\x{:02x}", code),
+                Ok(code) => std::char::from_u32(code)
+                    .map(String::from)
+                    .unwrap_or_else(|| format!("\\u{:04x}", code)),
+                Err(_) => return Err("invalid unicode escape".to_owned()),
+            };
+            (escape, len + 1, false)
+        }
+        // A named back reference becomes a numbered one.
+        Some('k') if !in_class && !group_names.is_empty() => {
+            let len = chars.iter().take_while(|&&c| c != '>').count();
+            let name: String = chars[1..len].iter().collect();
+            let number = match group_names
+                .iter()
+                .find(|(other, _)| Some(other.as_str()) == name.strip_prefix('<'))
+            {
+                Some((_, number)) if len < chars.len() => number,
+                _ => return Err(format!("invalid named reference {}", name)),
+            };
+            (format!("(?:\\{})", number), len + 1, false)
+        }
+        Some('1'..='9') if !in_class => {
+            let len = chars.iter().take_while(|c| c.is_ascii_digit()).count();
+            let digits: String = chars[..len].iter().collect();
+            match digits.parse::<usize>() {
+                Ok(number) if number <= group_count => (format!("\\{}", digits), len, false),
+                _ => return Ok(None),
+            }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(escape))
+}
+
+/// Translates an escape that isn't a back reference nor a class escape.
+///
+/// Without Annex B nor the unicode flag, the escape is only changed if it's the identity escape
+/// of a character that is not an identifier part.
+fn translate_legacy_escape(
+    chars: &[char],
+    in_class: bool,
+    unicode: bool,
+    annex_b: bool,
+) -> (String, usize, bool) {
+    let c = match chars.first() {
+        Some(&c) => c,
+        None => return ("\\".to_owned(), 0, false),
+    };
+    let escape = match c {
+        _ if unicode => format!("\\{}", c),
+        // A `\c` without a control letter is a `\` followed by a `c`, digits and `_` are only
+        // control letters in a class.
+        'c' if annex_b => match chars.get(1) {
+            Some(l) if l.is_ascii_alphabetic() => format!("\\c{}", l),
+            Some(&l) if in_class && (l.is_ascii_digit() || l == '_') => {
+                return (format!("\\x{:02x}", l as u32 % 32), 2, false);
+            }
+            _ => return ("\\\\c".to_owned(), 1, false),
+        },
+        '0' if !matches!(chars.get(1), Some('0'..='9')) => "\\0".to_owned(),
+        '8' | '9' if annex_b => c.to_string(),
+        '0'..='7' if annex_b => {
+            let max_len = if c <= '3' { 3 } else { 2 };
+            let len = chars
+                .iter()
+                .take(max_len)
+                .take_while(|c| matches!(c, '0'..='7'))
+                .count();
+            let value = chars[..len]
+                .iter()
+                .fold(0, |value, c| value * 8 + c.to_digit(8).unwrap_or(0));
+            return (format!("\\x{:02x}", value), len, false);
+        }
+        'x' if annex_b && !(chars.len() > 2 && chars[1..3].iter().all(char::is_ascii_hexdigit)) => {
+            "x".to_owned()
+        }
+        '^'
+        | '$'
+        | '\\'
+        | '.'
+        | '*'
+        | '+'
+        | '?'
+        | '('
+        | ')'
+        | '['
+        | ']'
+        | '{'
+        | '}'
+        | '|'
+        | '/'
+        | 'b'
+        | 'B'
+        | 'f'
+        | 'n'
+        | 'r'
+        | 't'
+        | 'v'
+        | 'c'
+        | 'x'
+        | 'u'
+        | 'k'
+        | '0'..='9' => {
+            format!("\\{}", c)
+        }
+        '-' if in_class => "\\-".to_owned(),
+        _ if annex_b || !is_identifier_part(c) => c.to_string(),
+        _ => format!("\\{}", c),
+    };
+    (escape, 1, false)
+}
+
+/// Gets the length of the braced quantifier `{n}`, `{n,}` or `{n,m}` at the start of `chars`.
+fn braced_quantifier_len(chars: &[char]) -> Option<usize> {
+    if chars.first() != Some(&'{') {
+        return None;
+    }
+    let digits = |start: usize| {
+        chars[start..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .count()
+    };
+    let min = digits(1);
+    if min == 0 {
+        return None;
+    }
+    let mut len = 1 + min;
+    if chars.get(len) == Some(&',') {
+        len += 1 + digits(len + 1);
+    }
+    if chars.get(len) == Some(&'}') {
+        Some(len + 1)
+    } else {
+        None
+    }
+}
+
 bitflags! {
     /// Flags of a regular expression.
     #[derive(Default)]
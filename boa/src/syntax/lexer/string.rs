@@ -9,10 +9,8 @@ use crate::{
     },
 };
 use std::{
-    char::{decode_utf16, from_u32},
-    convert::TryFrom,
+    char::from_u32,
     io::{self, ErrorKind, Read},
-    str,
 };
 
 /// String literal lexing.
@@ -57,9 +55,9 @@ impl<R> Tokenizer<R> for StringLiteral {
     {
         let _timer = BoaProfiler::global().start_event("StringLiteral", "Lexing");
 
-        let mut buf = String::new();
+        // The string is kept as UTF-16, so that escaped surrogate pairs are combined.
+        let mut buf = Vec::new();
         loop {
-            let next_chr_start = cursor.pos();
             let next_chr = cursor.next_char()?.ok_or_else(|| {
                 Error::from(io::Error::new(
                     ErrorKind::UnexpectedEof,
@@ -74,138 +72,144 @@ impl<R> Tokenizer<R> for StringLiteral {
                 '"' if self.terminator == StringTerminator::DoubleQuote => {
                     break;
                 }
+                '\n' | '\r' => {
+                    return Err(Error::syntax("unterminated string literal", start_pos));
+                }
                 '\\' => {
                     let _timer = BoaProfiler::global()
                         .start_event("StringLiteral - escape sequence", "Lexing");
 
+                    let escape_pos = cursor.pos();
                     let escape = cursor.next_char()?.ok_or_else(|| {
                         Error::from(io::Error::new(
                             ErrorKind::UnexpectedEof,
                             "unterminated escape sequence in string literal",
                         ))
                     })?;
-                    if escape != '\n' {
-                        let escaped_ch = match escape {
-                            'n' => '\n',
-                            'r' => '\r',
-                            't' => '\t',
-                            'b' => '\x08',
-                            'f' => '\x0c',
-                            '0' => '\0',
-                            'x' => {
-                                let mut nums = [0u8; 2];
-                                cursor.fill_bytes(&mut nums)?;
-                                let nums = str::from_utf8(&nums).expect("non-UTF-8 bytes found");
-
-                                let as_num = match u64::from_str_radix(&nums, 16) {
-                                    Ok(v) => v,
-                                    Err(_) => 0,
-                                };
-                                match from_u32(as_num as u32) {
-                                    Some(v) => v,
-                                    None => {
-                                        return Err(Error::syntax(
-                                            format!(
-                                                "{}: {} is not a valid Unicode scalar value",
-                                                cursor.pos(),
-                                                as_num
-                                            ),
-                                            cursor.pos(),
-                                        ))
-                                    }
-                                }
+                    let escaped_ch = match escape {
+                        // A line continuation is not part of the string.
+                        '\n' | '\r' | '\u{2028}' | '\u{2029}' => continue,
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'b' => '\x08',
+                        'f' => '\x0c',
+                        'v' => '\x0b',
+                        '0' if !cursor.next_is_pred(&|ch: char| ch.is_ascii_digit())? => '\0',
+                        '0'..='9' if cursor.annex_b() && !cursor.strict_mode() => {
+                            legacy_escape(cursor, escape)?
+                        }
+                        '0'..='9' => {
+                            return Err(Error::syntax(
+                                "octal escape sequences are not allowed",
+                                escape_pos,
+                            ))
+                        }
+                        'x' => hex_digits(cursor, 2)?.and_then(from_u32).ok_or_else(|| {
+                            Error::syntax("invalid hexadecimal escape sequence", escape_pos)
+                        })?,
+                        'u' => {
+                            let code_point = unicode_escape(cursor)?;
+                            if let Some(ch) = from_u32(code_point) {
+                                ch
+                            } else {
+                                // A surrogate is kept as a single code unit.
+                                buf.push(code_point as u16);
+                                continue;
                             }
-                            'u' => {
-                                // There are 2 types of codepoints. Surragate codepoints and
-                                // unicode codepoints. UTF-16 could be surrogate codepoints,
-                                // "\uXXXX\uXXXX" which make up a single unicode codepoint. We will
-                                //  need to loop to make sure we catch all UTF-16 codepoints
-
-                                // Support \u{X..X} (Unicode Codepoint)
-                                if cursor.next_is('{')? {
-                                    cursor.next_char()?.expect("{ character vanished"); // Consume the '{'.
-
-                                    // The biggest code point is 0x10FFFF
-                                    // TODO: use bytes for a bit better performance (using stack)
-                                    let mut code_point = String::with_capacity(6);
-                                    cursor.take_until('}', &mut code_point)?;
-
-                                    cursor.next_char()?.expect("} character vanished"); // Consume the '}'.
-
-                                    // We know this is a single unicode codepoint, convert to u32
-                                    let as_num =
-                                        u32::from_str_radix(&code_point, 16).map_err(|_| {
-                                            Error::syntax(
-                                                "malformed Unicode character escape sequence",
-                                                cursor.pos(),
-                                            )
-                                        })?;
-                                    if as_num > 0x10_FFFF {
-                                        return Err(Error::syntax("Unicode codepoint must not be greater than 0x10FFFF in escape sequence", cursor.pos()));
-                                    }
-                                    char::try_from(as_num).map_err(|_| {
-                                        Error::syntax(
-                                            "invalid Unicode escape sequence",
-                                            cursor.pos(),
-                                        )
-                                    })?
-                                } else {
-                                    let mut codepoints: Vec<u16> = vec![];
-                                    loop {
-                                        // Collect each character after \u e.g \uD83D will give "D83D"
-                                        let mut code_point = [0u8; 4];
-                                        cursor.fill_bytes(&mut code_point)?;
-
-                                        // Convert to u16
-                                        let as_num = match u16::from_str_radix(
-                                            str::from_utf8(&code_point)
-                                                .expect("the cursor returned invalid UTF-8"),
-                                            16,
-                                        ) {
-                                            Ok(v) => v,
-                                            Err(_) => 0,
-                                        };
-
-                                        codepoints.push(as_num);
-
-                                        // Check for another UTF-16 codepoint
-                                        if cursor.next_is('\\')? && cursor.next_is('u')? {
-                                            continue;
-                                        }
-                                        break;
-                                    }
-
-                                    // codepoints length should either be 1 (unicode codepoint) or
-                                    // 2 (surrogate codepoint). Rust's decode_utf16 will deal with
-                                    // it regardless
-                                    // TODO: do not panic with invalid code points.
-                                    decode_utf16(codepoints.iter().copied())
-                                        .next()
-                                        .expect("Could not get next codepoint")
-                                        .expect("Could not get next codepoint")
-                                }
-                            }
-                            '\'' | '"' | '\\' => escape,
-                            ch => {
-                                let details = format!(
-                                    "invalid escape sequence `{}` at line {}, column {}",
-                                    next_chr_start.line_number(),
-                                    next_chr_start.column_number(),
-                                    ch
-                                );
-                                return Err(Error::syntax(details, cursor.pos()));
-                            }
-                        };
-                        buf.push(escaped_ch);
-                    }
+                        }
+                        ch => ch,
+                    };
+                    buf.extend_from_slice(escaped_ch.encode_utf16(&mut [0; 2]));
                 }
-                next_ch => buf.push(next_ch),
+                next_ch => buf.extend_from_slice(next_ch.encode_utf16(&mut [0; 2])),
             }
         }
 
+        // A lone surrogate can't be part of a Rust string, it's replaced by U+FFFD.
         Ok(Token::new(
-            TokenKind::string_literal(buf),
+            TokenKind::string_literal(String::from_utf16_lossy(&buf)),
             Span::new(start_pos, cursor.pos()),
         ))
     }
 }
+
+/// Lexes the code point of a Unicode escape sequence, `\uXXXX` or `\u{X...}`, once its `\u` has
+/// been consumed.
+///
+/// The code point can be a surrogate, which is only allowed in string literals.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-UnicodeEscapeSequence
+pub(super) fn unicode_escape<R>(cursor: &mut Cursor<R>) -> Result<u32, Error>
+where
+    R: Read,
+{
+    let escape_pos = cursor.pos();
+    let code_point = if cursor.next_is('{')? {
+        let mut digits = String::new();
+        cursor.take_while_pred(&mut digits, &|ch: char| ch.is_ascii_hexdigit())?;
+        if cursor.next_is('}')? {
+            u32::from_str_radix(&digits, 16)
+                .ok()
+                .filter(|code_point| *code_point <= 0x10_FFFF)
+        } else {
+            None
+        }
+    } else {
+        hex_digits(cursor, 4)?
+    };
+    code_point.ok_or_else(|| Error::syntax("invalid Unicode escape sequence", escape_pos))
+}
+
+/// Lexes `count` hexadecimal digits.
+///
+/// Returns the value of the digits, or `None` if there are less than `count` of them.
+fn hex_digits<R>(cursor: &mut Cursor<R>, count: usize) -> Result<Option<u32>, Error>
+where
+    R: Read,
+{
+    let mut value = 0;
+    for _ in 0..count {
+        match cursor.peek()?.and_then(|ch| ch.to_digit(16)) {
+            Some(digit) => {
+                cursor.next_char()?;
+                value = value * 16 + digit;
+            }
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(value))
+}
+
+/// Lexes a legacy octal escape sequence like `\101`, or one of the `\8` and `\9` escape
+/// sequences, which are only allowed in non-strict code with the web compatibility syntax.
+///
+/// The first digit of the sequence is `first`, which is already consumed. An octal escape
+/// sequence takes as many octal digits as possible, as long as its value is at most `0o377`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-LegacyOctalEscapeSequence
+fn legacy_escape<R>(cursor: &mut Cursor<R>, first: char) -> Result<char, Error>
+where
+    R: Read,
+{
+    if first == '8' || first == '9' {
+        return Ok(first);
+    }
+
+    let max_digits = if first <= '3' { 3 } else { 2 };
+    let mut value = first.to_digit(8).expect("invalid octal digit");
+    for _ in 1..max_digits {
+        if !cursor.next_is_pred(&|ch: char| ch.is_digit(8))? {
+            break;
+        }
+        let digit = cursor.next_char()?.expect("octal digit vanished");
+        value = value * 8 + digit.to_digit(8).expect("invalid octal digit");
+    }
+    Ok(from_u32(value).expect("octal escape sequences are in the Latin-1 range"))
+}
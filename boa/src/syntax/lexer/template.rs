@@ -5,23 +5,40 @@ use crate::{
     profiler::BoaProfiler,
     syntax::{
         ast::{Position, Span},
-        lexer::{Token, TokenKind},
+        lexer::{token::TemplateString, Token, TokenKind},
     },
 };
-use std::io::{self, ErrorKind, Read};
+use std::{
+    char::from_u32,
+    io::{self, ErrorKind, Read},
+};
 
 /// Template literal lexing.
 ///
-/// Expects: Initial ` to already be consumed by cursor.
+/// Lexes a part of a template literal, up to the end of the literal or to the start of its next
+/// substitution.
+///
+/// Expects: Initial ` or the `}` closing the previous substitution to already be consumed by
+/// cursor.
 ///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///  - [MDN documentation][mdn]
 ///
-/// [spec]: https://tc39.es/ecma262/#sec-template-literals
+/// [spec]: https://tc39.es/ecma262/#sec-template-literal-lexical-components
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
 #[derive(Debug, Clone, Copy)]
-pub(super) struct TemplateLiteral;
+pub(super) struct TemplateLiteral {
+    after_substitution: bool,
+}
+
+impl TemplateLiteral {
+    /// Creates a new template literal lexer, `after_substitution` is `true` if the part to lex
+    /// comes after a substitution.
+    pub(super) fn new(after_substitution: bool) -> Self {
+        Self { after_substitution }
+    }
+}
 
 impl<R> Tokenizer<R> for TemplateLiteral {
     fn lex(&mut self, cursor: &mut Cursor<R>, start_pos: Position) -> Result<Token, Error>
@@ -30,23 +47,145 @@ impl<R> Tokenizer<R> for TemplateLiteral {
     {
         let _timer = BoaProfiler::global().start_event("TemplateLiteral", "Lexing");
 
-        let mut buf = String::new();
-        loop {
-            match cursor.next_char()? {
-                None => {
-                    return Err(Error::from(io::Error::new(
-                        ErrorKind::UnexpectedEof,
-                        "Unterminated template literal",
-                    )));
+        let mut raw = String::new();
+        // The cooked string is kept as UTF-16, so that escaped surrogate pairs are combined.
+        let mut cooked = Some(Vec::new());
+        let substitution = loop {
+            let ch = cursor.next_char()?.ok_or_else(|| {
+                Error::from(io::Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "unterminated template literal",
+                ))
+            })?;
+
+            match ch {
+                '`' => break false,
+                '$' if cursor.next_is('{')? => break true,
+                '\\' => {
+                    raw.push('\\');
+                    let escaped = template_escape(cursor, &mut raw)?;
+                    match (cooked.as_mut(), escaped) {
+                        (Some(cooked), Some(escaped)) => cooked.extend_from_slice(&escaped),
+                        _ => cooked = None,
+                    }
+                }
+                ch => {
+                    // Both `\r\n` and `\r` are normalized to `\n`.
+                    let ch = if ch == '\r' { '\n' } else { ch };
+                    raw.push(ch);
+                    if let Some(cooked) = cooked.as_mut() {
+                        cooked.extend(ch.encode_utf16(&mut [0; 2]).iter());
+                    }
                 }
-                Some('`') => break,                 // Template literal finished.
-                Some(next_ch) => buf.push(next_ch), // TODO when there is an expression inside the literal
             }
-        }
+        };
 
-        Ok(Token::new(
-            TokenKind::template_literal(buf),
-            Span::new(start_pos, cursor.pos()),
+        let string =
+            TemplateString::new(raw, cooked.map(|cooked| String::from_utf16_lossy(&cooked)));
+        let kind = match (self.after_substitution, substitution) {
+            (false, false) => TokenKind::TemplateNoSubstitution(string),
+            (false, true) => TokenKind::TemplateHead(string),
+            (true, true) => TokenKind::TemplateMiddle(string),
+            (true, false) => TokenKind::TemplateTail(string),
+        };
+        Ok(Token::new(kind, Span::new(start_pos, cursor.pos())))
+    }
+}
+
+/// Lexes the escape sequence after a `\` in a template literal, pushing its characters to `raw`.
+///
+/// Returns the UTF-16 code units of the escaped characters, or `None` if the escape sequence is
+/// not valid. Only the characters that are part of the escape sequence are consumed, so that
+/// the end of the template or of the next substitution is lexed as usual.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-TemplateEscapeSequence
+fn template_escape<R>(cursor: &mut Cursor<R>, raw: &mut String) -> Result<Option<Vec<u16>>, Error>
+where
+    R: Read,
+{
+    let escape = cursor.next_char()?.ok_or_else(|| {
+        Error::from(io::Error::new(
+            ErrorKind::UnexpectedEof,
+            "unterminated escape sequence in template literal",
         ))
+    })?;
+
+    let escaped = match escape {
+        // A line continuation is not part of the cooked string.
+        '\r' | '\n' | '\u{2028}' | '\u{2029}' => {
+            raw.push(if escape == '\r' { '\n' } else { escape });
+            return Ok(Some(Vec::new()));
+        }
+        'b' => '\x08',
+        'f' => '\x0c',
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        'v' => '\x0b',
+        '0' if !cursor.next_is_pred(&|ch: char| ch.is_ascii_digit())? => '\0',
+        'x' => {
+            raw.push('x');
+            return Ok(hex_digits(cursor, raw, 2)?
+                .and_then(from_u32)
+                .map(|ch| ch.encode_utf16(&mut [0; 2]).to_vec()));
+        }
+        'u' => {
+            raw.push('u');
+            let code_point = if cursor.next_is('{')? {
+                raw.push('{');
+                let mut digits = String::new();
+                cursor.take_while_pred(&mut digits, &|ch: char| ch.is_ascii_hexdigit())?;
+                raw.push_str(&digits);
+                if !cursor.next_is('}')? {
+                    return Ok(None);
+                }
+                raw.push('}');
+                u32::from_str_radix(&digits, 16)
+                    .ok()
+                    .filter(|code_point| *code_point <= 0x10_FFFF)
+            } else {
+                hex_digits(cursor, raw, 4)?
+            };
+            return Ok(code_point.map(|code_point| match from_u32(code_point) {
+                Some(ch) => ch.encode_utf16(&mut [0; 2]).to_vec(),
+                // A surrogate is kept as a single code unit.
+                None => vec![code_point as u16],
+            }));
+        }
+        // Decimal digits, other than a single `0`, are not valid escape sequences.
+        '0'..='9' => {
+            raw.push(escape);
+            return Ok(None);
+        }
+        ch => ch,
+    };
+
+    raw.push(escape);
+    Ok(Some(escaped.encode_utf16(&mut [0; 2]).to_vec()))
+}
+
+/// Lexes `count` hexadecimal digits, pushing them to `raw`.
+///
+/// Returns the value of the digits, or `None` if there are less than `count` of them.
+fn hex_digits<R>(
+    cursor: &mut Cursor<R>,
+    raw: &mut String,
+    count: usize,
+) -> Result<Option<u32>, Error>
+where
+    R: Read,
+{
+    let mut value = 0;
+    for _ in 0..count {
+        if !cursor.next_is_pred(&|ch: char| ch.is_ascii_hexdigit())? {
+            return Ok(None);
+        }
+        let digit = cursor.next_char()?.expect("hexadecimal digit vanished");
+        raw.push(digit);
+        value = value * 16 + digit.to_digit(16).expect("invalid hexadecimal digit");
     }
+    Ok(Some(value))
 }
@@ -93,6 +93,49 @@ impl From<BigInt> for Numeric {
     }
 }
 
+/// The strings of a part of a template literal, between its delimiters and substitutions.
+///
+/// The cooked string is the raw string with its escape sequences interpreted, and it's `None` if
+/// the raw string has an invalid escape sequence, which is only allowed in tagged templates.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-templatestrings
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Debug)]
+pub struct TemplateString {
+    raw: Box<str>,
+    cooked: Option<Box<str>>,
+}
+
+impl TemplateString {
+    /// Creates a new template string from its raw and cooked strings.
+    #[inline]
+    pub fn new<R, C>(raw: R, cooked: Option<C>) -> Self
+    where
+        R: Into<Box<str>>,
+        C: Into<Box<str>>,
+    {
+        Self {
+            raw: raw.into(),
+            cooked: cooked.map(C::into),
+        }
+    }
+
+    /// Gets the raw string, as it is written in the source code.
+    #[inline]
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// Gets the cooked string, if all the escape sequences of the raw string are valid.
+    #[inline]
+    pub fn cooked(&self) -> Option<&str> {
+        self.cooked.as_deref()
+    }
+}
+
 /// Represents the type of Token and the data it has inside.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, PartialEq, Debug)]
@@ -111,6 +154,9 @@ pub enum TokenKind {
     /// see: [`Keyword`](../keyword/enum.Keyword.html)
     Keyword(Keyword),
 
+    /// The name of a private member of a class, like `#x`, without the `#`.
+    PrivateIdentifier(Box<str>),
+
     /// A `null` literal.
     NullLiteral,
 
@@ -125,7 +171,17 @@ pub enum TokenKind {
     /// A string literal.
     StringLiteral(Box<str>),
 
-    TemplateLiteral(Box<str>),
+    /// A template literal without substitutions, like `` `a` ``.
+    TemplateNoSubstitution(TemplateString),
+
+    /// The start of a template literal, up to its first substitution, like `` `a${ ``.
+    TemplateHead(TemplateString),
+
+    /// A part of a template literal between two substitutions, like `}b${`.
+    TemplateMiddle(TemplateString),
+
+    /// The end of a template literal, after its last substitution, like `` }c` ``.
+    TemplateTail(TemplateString),
 
     /// A regular expression, consisting of body and flags.
     RegularExpressionLiteral(Box<str>, RegExpFlags),
@@ -185,6 +241,14 @@ impl TokenKind {
         Self::Keyword(keyword)
     }
 
+    /// Creates a `PrivateIdentifier` token type.
+    pub fn private_identifier<I>(ident: I) -> Self
+    where
+        I: Into<Box<str>>,
+    {
+        Self::PrivateIdentifier(ident.into())
+    }
+
     /// Creates a `NumericLiteral` token kind.
     pub fn numeric_literal<L>(lit: L) -> Self
     where
@@ -206,12 +270,9 @@ impl TokenKind {
         Self::StringLiteral(lit.into())
     }
 
-    /// Creates a `TemplateLiteral` token type.
-    pub fn template_literal<S>(lit: S) -> Self
-    where
-        S: Into<Box<str>>,
-    {
-        Self::TemplateLiteral(lit.into())
+    /// Creates a `TemplateNoSubstitution` token type.
+    pub fn template_no_substitution(string: TemplateString) -> Self {
+        Self::TemplateNoSubstitution(string)
     }
 
     /// Creates a `RegularExpressionLiteral` token kind.
@@ -241,13 +302,17 @@ impl Display for TokenKind {
             Self::EOF => write!(f, "end of file"),
             Self::Identifier(ref ident) => write!(f, "{}", ident),
             Self::Keyword(ref word) => write!(f, "{}", word),
+            Self::PrivateIdentifier(ref ident) => write!(f, "#{}", ident),
             Self::NullLiteral => write!(f, "null"),
             Self::NumericLiteral(Numeric::Rational(num)) => write!(f, "{}", num),
             Self::NumericLiteral(Numeric::Integer(num)) => write!(f, "{}", num),
             Self::NumericLiteral(Numeric::BigInt(ref num)) => write!(f, "{}n", num),
             Self::Punctuator(ref punc) => write!(f, "{}", punc),
             Self::StringLiteral(ref lit) => write!(f, "{}", lit),
-            Self::TemplateLiteral(ref lit) => write!(f, "{}", lit),
+            Self::TemplateNoSubstitution(ref string) => write!(f, "`{}`", string.raw()),
+            Self::TemplateHead(ref string) => write!(f, "`{}${{", string.raw()),
+            Self::TemplateMiddle(ref string) => write!(f, "}}{}${{", string.raw()),
+            Self::TemplateTail(ref string) => write!(f, "}}{}`", string.raw()),
             Self::RegularExpressionLiteral(ref body, ref flags) => write!(f, "/{}/{}", body, flags),
             Self::LineTerminator => write!(f, "line terminator"),
             Self::Comment => write!(f, "comment"),
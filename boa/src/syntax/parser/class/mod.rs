@@ -0,0 +1,477 @@
+//! Class definition parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes
+//! [spec]: https://tc39.es/ecma262/#sec-class-definitions
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{
+                ClassElement as ClassElementNode, ClassElementName, FunctionExpr,
+                MethodDefinitionKind, Node, PropertyName as PropertyNameNode,
+            },
+            Keyword, Punctuator,
+        },
+        lexer::{Error as LexError, Position, Token, TokenKind},
+        parser::{
+            cursor::SuperContext,
+            expression::{AssignmentExpression, LeftHandSideExpression, PropertyName},
+            function::{check_parameters, FormalParameters, FunctionBody},
+            statement::StatementList,
+            AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// The parsed parts of a class, shared by class declarations and class expressions.
+#[derive(Debug, Clone)]
+pub(in crate::syntax::parser) struct ClassTailNode {
+    pub(in crate::syntax::parser) super_ref: Option<Node>,
+    pub(in crate::syntax::parser) constructor: Option<FunctionExpr>,
+    pub(in crate::syntax::parser) elements: Vec<ClassElementNode>,
+}
+
+/// Class tail parsing.
+///
+/// This parses the optional `extends` clause and the body of the class.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassTail
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ClassTail {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassTail {
+    /// Creates a new `ClassTail` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassTail
+where
+    R: Read,
+{
+    type Output = ClassTailNode;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassTail", "Parsing");
+
+        // All parts of a class definition are strict mode code.
+        let strict_mode = cursor.strict_mode();
+        cursor.set_strict_mode(true);
+        let result = self.parse_tail(cursor);
+        cursor.set_strict_mode(strict_mode);
+
+        result
+    }
+}
+
+impl ClassTail {
+    fn parse_tail<R>(self, cursor: &mut Cursor<R>) -> Result<ClassTailNode, ParseError>
+    where
+        R: Read,
+    {
+        let super_ref = if cursor.next_if(Keyword::Extends)?.is_some() {
+            Some(LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?)
+        } else {
+            None
+        };
+
+        cursor.expect(Punctuator::OpenBlock, "class body")?;
+
+        cursor.push_private_environment();
+        let mut constructor = None;
+        let mut elements = Vec::new();
+        let mut private_names = Vec::new();
+        loop {
+            let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            match token.kind() {
+                TokenKind::Punctuator(Punctuator::CloseBlock) => break,
+                TokenKind::Punctuator(Punctuator::Semicolon) => {
+                    let _ = cursor.next()?;
+                    continue;
+                }
+                _ => {}
+            }
+            let position = token.span().start();
+
+            let element =
+                ClassElement::new(self.allow_yield, self.allow_await, super_ref.is_some())
+                    .parse(cursor)?;
+            match element {
+                ClassElementNode::MethodDefinition {
+                    kind,
+                    name: ClassElementName::PropertyName(PropertyNameNode::Literal(ref name)),
+                    is_static: false,
+                    ref method,
+                } if name.as_ref() == "constructor" => {
+                    match kind {
+                        MethodDefinitionKind::Ordinary => {}
+                        MethodDefinitionKind::Generator => {
+                            return Err(ParseError::lex(LexError::Syntax(
+                                "Class constructor may not be a generator".into(),
+                                position,
+                            )));
+                        }
+                        MethodDefinitionKind::Async => {
+                            return Err(ParseError::lex(LexError::Syntax(
+                                "Class constructor may not be an async method".into(),
+                                position,
+                            )));
+                        }
+                        MethodDefinitionKind::Get | MethodDefinitionKind::Set => {
+                            return Err(ParseError::lex(LexError::Syntax(
+                                "Class constructor may not be an accessor".into(),
+                                position,
+                            )));
+                        }
+                    }
+                    if constructor.is_some() {
+                        return Err(ParseError::lex(LexError::Syntax(
+                            "A class may only have one constructor".into(),
+                            position,
+                        )));
+                    }
+                    constructor = Some(method.clone());
+                    continue;
+                }
+                ClassElementNode::FieldDefinition {
+                    name: ClassElementName::PropertyName(PropertyNameNode::Literal(ref name)),
+                    ..
+                } if name.as_ref() == "constructor" => {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        "Classes may not have a field named 'constructor'".into(),
+                        position,
+                    )));
+                }
+                ClassElementNode::MethodDefinition {
+                    name: ClassElementName::PropertyName(PropertyNameNode::Literal(ref name)),
+                    is_static: true,
+                    ..
+                }
+                | ClassElementNode::FieldDefinition {
+                    name: ClassElementName::PropertyName(PropertyNameNode::Literal(ref name)),
+                    is_static: true,
+                    ..
+                } if name.as_ref() == "prototype" => {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        "Classes may not have a static property named 'prototype'".into(),
+                        position,
+                    )));
+                }
+                ClassElementNode::MethodDefinition {
+                    kind,
+                    name: ClassElementName::PrivateName(ref name),
+                    is_static,
+                    ..
+                } => declare_private_name(
+                    &mut private_names,
+                    name,
+                    PrivateNameKind::from_method(kind, is_static),
+                    position,
+                )?,
+                ClassElementNode::FieldDefinition {
+                    name: ClassElementName::PrivateName(ref name),
+                    ..
+                } => declare_private_name(
+                    &mut private_names,
+                    name,
+                    PrivateNameKind::Other,
+                    position,
+                )?,
+                _ => {}
+            }
+            elements.push(element);
+        }
+
+        cursor.expect(Punctuator::CloseBlock, "class body")?;
+        let private_names: Vec<_> = private_names.into_iter().map(|(name, _)| name).collect();
+        cursor.pop_private_environment(&private_names)?;
+
+        Ok(ClassTailNode {
+            super_ref,
+            constructor,
+            elements,
+        })
+    }
+}
+
+/// What a private name of a class is declared for, to check that it's only declared once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PrivateNameKind {
+    Getter { is_static: bool },
+    Setter { is_static: bool },
+    Other,
+}
+
+impl PrivateNameKind {
+    fn from_method(kind: MethodDefinitionKind, is_static: bool) -> Self {
+        match kind {
+            MethodDefinitionKind::Get => Self::Getter { is_static },
+            MethodDefinitionKind::Set => Self::Setter { is_static },
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Adds a private name declared by the body of a class.
+///
+/// It's an early error to declare the same private name twice, unless it's declared by a getter
+/// and a setter that are both static or both not static.
+fn declare_private_name(
+    names: &mut Vec<(Box<str>, PrivateNameKind)>,
+    name: &str,
+    kind: PrivateNameKind,
+    position: Position,
+) -> Result<(), ParseError> {
+    let existing = if let Some((_, existing)) = names
+        .iter_mut()
+        .find(|(declared, _)| declared.as_ref() == name)
+    {
+        existing
+    } else {
+        names.push((name.into(), kind));
+        return Ok(());
+    };
+    match (*existing, kind) {
+        (PrivateNameKind::Getter { is_static }, PrivateNameKind::Setter { is_static: other })
+        | (PrivateNameKind::Setter { is_static }, PrivateNameKind::Getter { is_static: other })
+            if is_static == other =>
+        {
+            *existing = PrivateNameKind::Other;
+            Ok(())
+        }
+        _ => Err(ParseError::lex(LexError::Syntax(
+            format!("Identifier '#{}' has already been declared", name).into(),
+            position,
+        ))),
+    }
+}
+
+/// Class element parsing.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElement
+#[derive(Debug, Clone, Copy)]
+struct ClassElement {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    derived: bool,
+}
+
+impl ClassElement {
+    /// Creates a new `ClassElement` parser, for a class that extends another one if `derived` is
+    /// `true`.
+    fn new<Y, A>(allow_yield: Y, allow_await: A, derived: bool) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            derived,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassElement
+where
+    R: Read,
+{
+    type Output = ClassElementNode;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassElement", "Parsing");
+
+        // `static`, `get`, `set` and `async` are modifiers unless they are the name of the method
+        // or of the field.
+        let is_modifier = |cursor: &mut Cursor<R>, name: &str| -> Result<bool, ParseError> {
+            let is_name = matches!(
+                cursor.peek(0)?.map(|tok| tok.kind()),
+                Some(TokenKind::Identifier(ident)) if ident.as_ref() == name
+            );
+            Ok(is_name
+                && !matches!(
+                    cursor.peek(1)?.map(|tok| tok.kind()),
+                    Some(TokenKind::Punctuator(Punctuator::OpenParen))
+                        | Some(TokenKind::Punctuator(Punctuator::Assign))
+                        | Some(TokenKind::Punctuator(Punctuator::Semicolon))
+                        | Some(TokenKind::Punctuator(Punctuator::CloseBlock))
+                        | None
+                ))
+        };
+
+        let is_static = if is_modifier(cursor, "static")? {
+            let _ = cursor.next()?;
+            true
+        } else {
+            false
+        };
+
+        if is_static && cursor.next_if(Punctuator::OpenBlock)?.is_some() {
+            return parse_static_block(cursor);
+        }
+
+        let kind = if cursor.next_if(Punctuator::Mul)?.is_some() {
+            MethodDefinitionKind::Generator
+        } else if is_modifier(cursor, "async")? && cursor.peek_after_no_lineterminator()?.is_some()
+        {
+            let _ = cursor.next()?;
+            MethodDefinitionKind::Async
+        } else if is_modifier(cursor, "get")? {
+            let _ = cursor.next()?;
+            MethodDefinitionKind::Get
+        } else if is_modifier(cursor, "set")? {
+            let _ = cursor.next()?;
+            MethodDefinitionKind::Set
+        } else {
+            MethodDefinitionKind::Ordinary
+        };
+
+        let name_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let name = match name_token.kind() {
+            TokenKind::PrivateIdentifier(name) if name.as_ref() == "constructor" => {
+                return Err(ParseError::lex(LexError::Syntax(
+                    "Classes may not have a private member named '#constructor'".into(),
+                    name_token.span().start(),
+                )));
+            }
+            TokenKind::PrivateIdentifier(name) => {
+                let name = ClassElementName::PrivateName(name.clone());
+                let _ = cursor.next()?;
+                name
+            }
+            // The computed name of a class element, like `[Symbol.iterator]`, is evaluated
+            // when the class is defined.
+            _ => ClassElementName::PropertyName(
+                PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?,
+            ),
+        };
+
+        // An element without modifiers that isn't followed by parameters is a field.
+        if kind == MethodDefinitionKind::Ordinary
+            && cursor.peek(0)?.map(Token::kind)
+                != Some(&TokenKind::Punctuator(Punctuator::OpenParen))
+        {
+            let initializer = if cursor.next_if(Punctuator::Assign)?.is_some() {
+                let allow_new_target = cursor.set_allow_new_target(true);
+                let allow_super = cursor.set_allow_super(SuperContext::Property);
+                let initializer = AssignmentExpression::new(true, false, false).parse(cursor);
+                cursor.set_allow_new_target(allow_new_target);
+                cursor.set_allow_super(allow_super);
+                Some(initializer?)
+            } else {
+                None
+            };
+            cursor.expect_semicolon("class field")?;
+            return Ok(ClassElementNode::field(name, is_static, initializer));
+        }
+
+        let params_start = cursor
+            .expect(Punctuator::OpenParen, "class element")?
+            .span()
+            .start();
+        let first_param = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+        let is_async = kind == MethodDefinitionKind::Async;
+        // Only the constructor of a derived class can call `super()`.
+        let is_constructor = !is_static
+            && kind == MethodDefinitionKind::Ordinary
+            && matches!(
+                name,
+                ClassElementName::PropertyName(PropertyNameNode::Literal(ref name))
+                    if name.as_ref() == "constructor"
+            );
+        let allow_new_target = cursor.set_allow_new_target(true);
+        let allow_super = cursor.set_allow_super(if is_constructor && self.derived {
+            SuperContext::Call
+        } else {
+            SuperContext::Property
+        });
+        let params = FormalParameters::new(false, is_async).parse(cursor)?;
+        cursor.expect(Punctuator::CloseParen, "class element")?;
+
+        match kind {
+            MethodDefinitionKind::Get if !params.is_empty() => {
+                return Err(ParseError::unexpected(
+                    first_param,
+                    "getter functions must have no arguments",
+                ));
+            }
+            MethodDefinitionKind::Set if params.len() != 1 => {
+                return Err(ParseError::unexpected(
+                    first_param,
+                    "setter functions must have one argument",
+                ));
+            }
+            _ => {}
+        }
+
+        cursor.expect(Punctuator::OpenBlock, "class element")?;
+        let body =
+            FunctionBody::new(kind == MethodDefinitionKind::Generator, is_async).parse(cursor)?;
+        cursor.set_allow_new_target(allow_new_target);
+        cursor.set_allow_super(allow_super);
+        check_parameters(
+            &params,
+            &body,
+            cursor.use_strict_directive(),
+            true,
+            params_start,
+        )?;
+        cursor.expect(Punctuator::CloseBlock, "class element")?;
+
+        Ok(ClassElementNode::new(
+            kind,
+            name,
+            is_static,
+            FunctionExpr::new(None, params, body),
+        ))
+    }
+}
+
+/// Parses the body of a static initialization block, after the `static {`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassStaticBlock
+fn parse_static_block<R>(cursor: &mut Cursor<R>) -> Result<ClassElementNode, ParseError>
+where
+    R: Read,
+{
+    // The block is the body of a function, so the enclosing labels and loops are not visible.
+    let labels = cursor.take_labels();
+    let breakables = cursor.take_breakables();
+    let allow_new_target = cursor.set_allow_new_target(true);
+    let allow_super = cursor.set_allow_super(SuperContext::Property);
+    let body = StatementList::new(false, false, false, true).parse(cursor);
+    cursor.restore_labels(labels);
+    cursor.restore_breakables(breakables);
+    cursor.set_allow_new_target(allow_new_target);
+    cursor.set_allow_super(allow_super);
+    let body = body?;
+
+    cursor.expect(Punctuator::CloseBlock, "static initialization block")?;
+    Ok(ClassElementNode::static_block(body))
+}
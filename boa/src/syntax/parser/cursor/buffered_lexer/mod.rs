@@ -92,6 +92,16 @@ where
         self.lexer.set_strict_mode(strict_mode)
     }
 
+    #[inline]
+    pub(super) fn annex_b(&self) -> bool {
+        self.lexer.annex_b()
+    }
+
+    #[inline]
+    pub(super) fn set_annex_b(&mut self, annex_b: bool) {
+        self.lexer.set_annex_b(annex_b)
+    }
+
     /// Fills the peeking buffer with the next token.
     ///
     /// It will not fill two line terminators one after the other.
@@ -4,7 +4,7 @@ mod buffered_lexer;
 use super::ParseError;
 use crate::syntax::{
     ast::Punctuator,
-    lexer::{InputElement, Lexer, Position, Token, TokenKind},
+    lexer::{Error as LexError, InputElement, Lexer, Position, Token, TokenKind},
 };
 use buffered_lexer::BufferedLexer;
 use std::io::Read;
@@ -16,12 +16,51 @@ pub(super) enum SemicolonResult<'s> {
     NotFound(&'s Token),
 }
 
+/// The kind of statement that a label applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum LabelKind {
+    /// A loop, which is also the target of `continue` statements with the label.
+    Iteration,
+    /// Another labelled statement, like `a` in `a: b: while (true) {}`.
+    Labelled,
+    /// Any other statement.
+    Other,
+}
+
+/// The kind of statement that a `break` statement without a label can leave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum BreakableKind {
+    /// A loop, which is also the target of `continue` statements without a label.
+    Iteration,
+    /// A `switch` statement.
+    Switch,
+}
+
+/// The uses of `super` that are allowed in the code that is being parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SuperContext {
+    /// `super` can't be used, like outside of methods.
+    None,
+    /// `super` properties can be used, like in methods and in class fields.
+    Property,
+    /// `super` properties and `super()` calls can be used, like in the constructors of derived
+    /// classes.
+    Call,
+}
+
 /// Token cursor.
 ///
 /// This internal structure gives basic testable operations to the parser.
 #[derive(Debug)]
 pub(super) struct Cursor<R> {
     buffered_lexer: BufferedLexer<R>,
+    labels: Vec<(Box<str>, LabelKind)>,
+    breakables: Vec<BreakableKind>,
+    allow_new_target: bool,
+    allow_super: SuperContext,
+    use_strict_directive: bool,
+    module: bool,
+    private_environments: Vec<Vec<(Box<str>, Position)>>,
 }
 
 impl<R> Cursor<R>
@@ -33,6 +72,13 @@ where
     pub(super) fn new(reader: R) -> Self {
         Self {
             buffered_lexer: Lexer::new(reader).into(),
+            labels: Vec::new(),
+            breakables: Vec::new(),
+            allow_new_target: false,
+            allow_super: SuperContext::None,
+            use_strict_directive: false,
+            module: false,
+            private_environments: Vec::new(),
         }
     }
 
@@ -66,6 +112,204 @@ where
         self.buffered_lexer.set_strict_mode(strict_mode)
     }
 
+    /// Checks if the web compatibility syntax and semantics of Annex B of the specification are
+    /// allowed.
+    #[inline]
+    pub(super) fn annex_b(&self) -> bool {
+        self.buffered_lexer.annex_b()
+    }
+
+    #[inline]
+    pub(super) fn set_annex_b(&mut self, annex_b: bool) {
+        self.buffered_lexer.set_annex_b(annex_b)
+    }
+
+    /// Checks if the code is parsed as a module, rather than as a script.
+    #[inline]
+    pub(super) fn module(&self) -> bool {
+        self.module
+    }
+
+    #[inline]
+    pub(super) fn set_module(&mut self, module: bool) {
+        self.module = module
+    }
+
+    /// Adds the label of the labelled statement that is being parsed.
+    #[inline]
+    pub(super) fn push_label(&mut self, label: Box<str>, kind: LabelKind) {
+        self.labels.push((label, kind));
+    }
+
+    /// Removes the label of the labelled statement that was parsed.
+    #[inline]
+    pub(super) fn pop_label(&mut self) {
+        self.labels.pop();
+    }
+
+    /// Checks if the statement that is being parsed is inside a statement with the given label.
+    #[inline]
+    pub(super) fn has_label(&self, label: &str) -> bool {
+        self.labels.iter().any(|(name, _)| name.as_ref() == label)
+    }
+
+    /// Gets the label of the loop targeted by a `continue` statement with the given label, if the
+    /// label applies to a loop.
+    ///
+    /// A loop only keeps its innermost label, so `continue a` targets the loop labelled `b` in
+    /// `a: b: while (true) {}`.
+    pub(super) fn continue_target(&self, label: &str) -> Option<&str> {
+        let index = self
+            .labels
+            .iter()
+            .rposition(|(name, _)| name.as_ref() == label)?;
+        self.labels[index..]
+            .iter()
+            .find(|(_, kind)| *kind != LabelKind::Labelled)
+            .filter(|(_, kind)| *kind == LabelKind::Iteration)
+            .map(|(name, _)| name.as_ref())
+    }
+
+    /// Removes all the labels, since they are not visible inside of a function body.
+    ///
+    /// They are put back by `restore_labels()` once the function body is parsed.
+    #[inline]
+    pub(super) fn take_labels(&mut self) -> Vec<(Box<str>, LabelKind)> {
+        std::mem::take(&mut self.labels)
+    }
+
+    /// Puts back the labels removed by `take_labels()`.
+    #[inline]
+    pub(super) fn restore_labels(&mut self, labels: Vec<(Box<str>, LabelKind)>) {
+        self.labels = labels;
+    }
+
+    /// Adds a statement that can be left with a `break` statement without a label, while its
+    /// body is being parsed.
+    #[inline]
+    pub(super) fn push_breakable(&mut self, kind: BreakableKind) {
+        self.breakables.push(kind);
+    }
+
+    /// Removes the statement added by the last `push_breakable()`.
+    #[inline]
+    pub(super) fn pop_breakable(&mut self) {
+        self.breakables.pop();
+    }
+
+    /// Checks if the statement that is being parsed is inside a loop or a `switch` statement.
+    #[inline]
+    pub(super) fn in_breakable(&self) -> bool {
+        !self.breakables.is_empty()
+    }
+
+    /// Checks if the statement that is being parsed is inside a loop.
+    #[inline]
+    pub(super) fn in_iteration(&self) -> bool {
+        self.breakables.contains(&BreakableKind::Iteration)
+    }
+
+    /// Removes all the enclosing loops and `switch` statements, since a function body can't
+    /// `break` out of them.
+    ///
+    /// They are put back by `restore_breakables()` once the function body is parsed.
+    #[inline]
+    pub(super) fn take_breakables(&mut self) -> Vec<BreakableKind> {
+        std::mem::take(&mut self.breakables)
+    }
+
+    /// Puts back the statements removed by `take_breakables()`.
+    #[inline]
+    pub(super) fn restore_breakables(&mut self, breakables: Vec<BreakableKind>) {
+        self.breakables = breakables;
+    }
+
+    /// Checks if `new.target` can be used, which is only inside of functions that are not arrow
+    /// functions.
+    #[inline]
+    pub(super) fn allow_new_target(&self) -> bool {
+        self.allow_new_target
+    }
+
+    /// Sets whether `new.target` can be used, and returns the previous setting so that it can be
+    /// restored once a function is parsed.
+    #[inline]
+    pub(super) fn set_allow_new_target(&mut self, allow: bool) -> bool {
+        std::mem::replace(&mut self.allow_new_target, allow)
+    }
+
+    /// Gets the uses of `super` that are allowed, which depend on the innermost function that is
+    /// not an arrow function.
+    #[inline]
+    pub(super) fn allow_super(&self) -> SuperContext {
+        self.allow_super
+    }
+
+    /// Sets the uses of `super` that are allowed, and returns the previous setting so that it can
+    /// be restored once a function is parsed.
+    #[inline]
+    pub(super) fn set_allow_super(&mut self, allow: SuperContext) -> SuperContext {
+        std::mem::replace(&mut self.allow_super, allow)
+    }
+
+    /// Returns if the most recently parsed directive prologue contained a `"use strict"`
+    /// directive.
+    #[inline]
+    pub(super) fn use_strict_directive(&self) -> bool {
+        self.use_strict_directive
+    }
+
+    /// Sets if the most recently parsed directive prologue contained a `"use strict"` directive.
+    #[inline]
+    pub(super) fn set_use_strict_directive(&mut self, use_strict: bool) {
+        self.use_strict_directive = use_strict;
+    }
+
+    /// Starts the scope of the private names declared in the body of a class.
+    #[inline]
+    pub(super) fn push_private_environment(&mut self) {
+        self.private_environments.push(Vec::new());
+    }
+
+    /// Records a use of the private name `#name`, which must be declared by an enclosing class.
+    ///
+    /// The class can declare the name after the use, so it is only checked once the class body
+    /// is parsed, by `pop_private_environment()`.
+    pub(super) fn use_private_identifier(
+        &mut self,
+        name: &str,
+        position: Position,
+    ) -> Result<(), ParseError> {
+        match self.private_environments.last_mut() {
+            Some(uses) => {
+                uses.push((name.into(), position));
+                Ok(())
+            }
+            None => Err(undeclared_private_identifier(name, position)),
+        }
+    }
+
+    /// Ends the scope of the private names declared in the body of a class.
+    ///
+    /// The uses of private names that the class doesn't declare must be declared by an enclosing
+    /// class, or they are an error.
+    pub(super) fn pop_private_environment(
+        &mut self,
+        declared: &[Box<str>],
+    ) -> Result<(), ParseError> {
+        let uses = self.private_environments.pop().unwrap_or_default();
+        for (name, position) in uses {
+            if declared.contains(&name) {
+                continue;
+            }
+            match self.private_environments.last_mut() {
+                Some(outer) => outer.push((name, position)),
+                None => return Err(undeclared_private_identifier(&name, position)),
+            }
+        }
+        Ok(())
+    }
+
     /// Returns an error if the next token is not of kind `kind`.
     ///
     /// Note: it will consume the next token only if the next token is the expected type.
@@ -151,6 +395,23 @@ where
         }
     }
 
+    /// Peeks the token after the next one, only if there is no line terminator between them.
+    ///
+    /// This is needed for productions like `async function`, where the `async` token can't be
+    /// followed by a line terminator.
+    #[inline]
+    pub(super) fn peek_after_no_lineterminator(&mut self) -> Result<Option<&Token>, ParseError> {
+        // Only one contiguous line terminator is stored, so the next token is at index 0 or 1.
+        let next = match self.buffered_lexer.peek(0, false)? {
+            Some(t) if t.kind() == &TokenKind::LineTerminator => 1,
+            _ => 0,
+        };
+        match self.buffered_lexer.peek(next + 1, false)? {
+            Some(t) if t.kind() == &TokenKind::LineTerminator => Ok(None),
+            t => Ok(t),
+        }
+    }
+
     /// Advance the cursor to the next token and retrieve it, only if it's of `kind` type.
     ///
     /// When the next token is a `kind` token, get the token, otherwise return `None`.
@@ -174,3 +435,15 @@ where
         })
     }
 }
+
+/// The error of a use of a private name that no enclosing class declares.
+fn undeclared_private_identifier(name: &str, position: Position) -> ParseError {
+    ParseError::lex(LexError::Syntax(
+        format!(
+            "Private field '#{}' must be declared in an enclosing class",
+            name
+        )
+        .into(),
+        position,
+    ))
+}
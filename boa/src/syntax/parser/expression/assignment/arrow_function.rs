@@ -7,17 +7,21 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Arrow_functions
 //! [spec]: https://tc39.es/ecma262/#sec-arrow-function-definitions
 
-use super::AssignmentExpression;
+use super::{assignment_target, AssignmentExpression};
 use crate::syntax::lexer::TokenKind;
 use crate::{
     syntax::{
         ast::{
-            node::{ArrowFunctionDecl, FormalParameter, Node, Return, StatementList},
-            Punctuator,
+            node::{
+                ArrowFunctionDecl, AsyncArrowFunctionDecl, Binding, BindingElement,
+                FormalParameter, Node, Return, StatementList,
+            },
+            op, Position, Punctuator,
         },
+        lexer::Error as LexError,
         parser::{
             error::{ErrorContext, ParseError, ParseResult},
-            function::{FormalParameters, FunctionBody},
+            function::{check_parameters, FormalParameters, FunctionBody},
             statement::BindingIdentifier,
             AllowAwait, AllowIn, AllowYield, Cursor, TokenParser,
         },
@@ -35,11 +39,12 @@ use std::io::Read;
 ///
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Arrow_functions
 /// [spec]: https://tc39.es/ecma262/#prod-ArrowFunction
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub(in crate::syntax::parser) struct ArrowFunction {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
+    params: Option<Box<[FormalParameter]>>,
 }
 
 impl ArrowFunction {
@@ -58,8 +63,37 @@ impl ArrowFunction {
             allow_in: allow_in.into(),
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
+            params: None,
         }
     }
+
+    /// Uses an already parsed parenthesized expression, like `({ a }, [b] = c)`, as the
+    /// parameters.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-CoverParenthesizedExpressionAndArrowParameterList
+    pub(in crate::syntax::parser) fn with_cover_parameters(
+        mut self,
+        expr: &Node,
+        position: Position,
+    ) -> Result<Self, ParseError> {
+        let mut exprs = Vec::new();
+        let mut expr = expr;
+        while let Node::BinOp(bin_op) = expr {
+            if bin_op.op() != op::BinOp::Comma {
+                break;
+            }
+            exprs.push(bin_op.rhs());
+            expr = bin_op.lhs();
+        }
+        exprs.push(expr);
+        exprs.reverse();
+
+        self.params = Some(parameters_from_expressions(&exprs, position)?);
+        Ok(self)
+    }
 }
 
 impl<R> TokenParser<R> for ArrowFunction
@@ -72,7 +106,9 @@ where
         let _timer = BoaProfiler::global().start_event("ArrowFunction", "Parsing");
 
         let next_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
-        let params = if let TokenKind::Punctuator(Punctuator::OpenParen) = &next_token.kind() {
+        let params = if let Some(params) = self.params {
+            params
+        } else if let TokenKind::Punctuator(Punctuator::OpenParen) = &next_token.kind() {
             // CoverParenthesizedExpressionAndArrowParameterList
             cursor.expect(Punctuator::OpenParen, "arrow function")?;
 
@@ -88,26 +124,123 @@ where
 
         cursor.peek_expect_no_lineterminator(0)?;
 
-        cursor.expect(TokenKind::Punctuator(Punctuator::Arrow), "arrow function")?;
-        let body = ConciseBody::new(self.allow_in).parse(cursor)?;
+        let arrow = cursor.expect(TokenKind::Punctuator(Punctuator::Arrow), "arrow function")?;
+        let body = ConciseBody::new(self.allow_in, false).parse(cursor)?;
+        check_parameters(
+            &params,
+            &body,
+            cursor.use_strict_directive(),
+            true,
+            arrow.span().start(),
+        )?;
         Ok(ArrowFunctionDecl::new(params, body))
     }
 }
 
+/// Async arrow function parsing.
+///
+/// The parameters of `async (a, b) => {}` can't be told apart from the arguments of a call to a
+/// function named `async` until the arrow is found, so they are parsed as a call and converted
+/// with `with_call_arguments()`. Otherwise, this parses `async a => {}`.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncArrowFunction
+#[derive(Debug, Clone)]
+pub(in crate::syntax::parser) struct AsyncArrowFunction {
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+    params: Option<Box<[FormalParameter]>>,
+}
+
+impl AsyncArrowFunction {
+    /// Creates a new `AsyncArrowFunction` parser.
+    pub(in crate::syntax::parser) fn new<I, Y>(allow_in: I, allow_yield: Y) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_yield: allow_yield.into(),
+            params: None,
+        }
+    }
+
+    /// Uses the arguments of an already parsed `async(...)` call as the parameters.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-CoverCallExpressionAndAsyncArrowHead
+    pub(in crate::syntax::parser) fn with_call_arguments(
+        mut self,
+        args: &[Node],
+        position: Position,
+    ) -> Result<Self, ParseError> {
+        let args = args.iter().collect::<Vec<_>>();
+        self.params = Some(parameters_from_expressions(&args, position)?);
+        Ok(self)
+    }
+}
+
+impl<R> TokenParser<R> for AsyncArrowFunction
+where
+    R: Read,
+{
+    type Output = AsyncArrowFunctionDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("AsyncArrowFunction", "Parsing");
+
+        let params = if let Some(params) = self.params {
+            params
+        } else {
+            cursor.expect(TokenKind::identifier("async"), "async arrow function")?;
+            let param = BindingIdentifier::new(self.allow_yield, true)
+                .parse(cursor)
+                .context("async arrow function")?;
+            Box::new([FormalParameter::new(param, None, false)])
+        };
+
+        cursor.peek_expect_no_lineterminator(0)?;
+
+        let arrow = cursor.expect(
+            TokenKind::Punctuator(Punctuator::Arrow),
+            "async arrow function",
+        )?;
+        let body = ConciseBody::new(self.allow_in, true).parse(cursor)?;
+        check_parameters(
+            &params,
+            &body,
+            cursor.use_strict_directive(),
+            true,
+            arrow.span().start(),
+        )?;
+        Ok(AsyncArrowFunctionDecl::new(params, body))
+    }
+}
+
 /// <https://tc39.es/ecma262/#prod-ConciseBody>
 #[derive(Debug, Clone, Copy)]
 struct ConciseBody {
     allow_in: AllowIn,
+    allow_await: AllowAwait,
 }
 
 impl ConciseBody {
     /// Creates a new `ConcideBody` parser.
-    fn new<I>(allow_in: I) -> Self
+    fn new<I, A>(allow_in: I, allow_await: A) -> Self
     where
         I: Into<AllowIn>,
+        A: Into<AllowAwait>,
     {
         Self {
             allow_in: allow_in.into(),
+            allow_await: allow_await.into(),
         }
     }
 }
@@ -122,15 +255,20 @@ where
         match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 let _ = cursor.next();
-                let body = FunctionBody::new(false, false).parse(cursor)?;
+                let body = FunctionBody::new(false, self.allow_await).parse(cursor)?;
                 cursor.expect(Punctuator::CloseBlock, "arrow function")?;
                 Ok(body)
             }
-            _ => Ok(StatementList::from(vec![Return::new(
-                ExpressionBody::new(self.allow_in, false).parse(cursor)?,
-                None,
-            )
-            .into()])),
+            _ => {
+                let mut body = StatementList::from(vec![Return::new(
+                    ExpressionBody::new(self.allow_in, self.allow_await).parse(cursor)?,
+                    None,
+                )
+                .into()]);
+                body.set_strict(cursor.strict_mode());
+                cursor.set_use_strict_directive(false);
+                Ok(body)
+            }
         }
     }
 }
@@ -166,3 +304,68 @@ where
         AssignmentExpression::new(self.allow_in, false, self.allow_await).parse(cursor)
     }
 }
+
+/// Converts the expressions of a cover grammar to the parameters of an arrow function.
+///
+/// The expressions are the parenthesized expression of `(a, b) => {}`, or the arguments of
+/// `async(a, b) => {}`, they must be valid bindings with an optional default value. Only the last
+/// one can be a spread, which becomes the rest parameter.
+fn parameters_from_expressions(
+    exprs: &[&Node],
+    position: Position,
+) -> Result<Box<[FormalParameter]>, ParseError> {
+    let invalid = || {
+        ParseError::lex(LexError::Syntax(
+            "Invalid arrow function parameter".into(),
+            position,
+        ))
+    };
+    exprs
+        .iter()
+        .enumerate()
+        .map(|(i, expr)| {
+            match expr {
+                Node::Spread(spread) if i == exprs.len() - 1 => {
+                    binding_from_expression(spread.val())
+                        .map(|binding| FormalParameter::new(binding, None, true))
+                }
+                Node::Assign(assign) if is_binding(assign.lhs()) => Some(FormalParameter::new(
+                    assign.lhs().clone(),
+                    Some(assign.rhs().clone()),
+                    false,
+                )),
+                expr => binding_from_expression(expr)
+                    .map(|binding| FormalParameter::new(binding, None, false)),
+            }
+            .ok_or_else(invalid)
+        })
+        .collect()
+}
+
+/// Converts an expression that was parsed before knowing it is a binding, like the `{ a: b }` of
+/// `({ a: b }) => b`, to the binding.
+///
+/// Unlike the target of an assignment, a binding can't contain property accesses.
+fn binding_from_expression(expr: &Node) -> Option<Binding> {
+    assignment_target(expr).filter(is_binding)
+}
+
+/// Checks that an assignment target only binds names.
+fn is_binding(target: &Binding) -> bool {
+    let element = |element: &BindingElement| is_binding(element.binding());
+    match target {
+        Binding::Identifier(_) => true,
+        Binding::Object(pattern) => {
+            pattern
+                .properties()
+                .iter()
+                .all(|property| element(property.element()))
+                && pattern.rest().map_or(true, is_binding)
+        }
+        Binding::Array(pattern) => {
+            pattern.elements().iter().flatten().all(element)
+                && pattern.rest().map_or(true, is_binding)
+        }
+        Binding::Member(_) => false,
+    }
+}
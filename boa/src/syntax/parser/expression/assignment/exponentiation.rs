@@ -65,6 +65,7 @@ where
             TokenKind::Keyword(Keyword::Delete)
                 | TokenKind::Keyword(Keyword::Void)
                 | TokenKind::Keyword(Keyword::TypeOf)
+                | TokenKind::Keyword(Keyword::Await)
                 | TokenKind::Punctuator(Punctuator::Add)
                 | TokenKind::Punctuator(Punctuator::Sub)
                 | TokenKind::Punctuator(Punctuator::Not)
@@ -10,14 +10,22 @@
 mod arrow_function;
 mod conditional;
 mod exponentiation;
+mod r#yield;
 
-use self::{arrow_function::ArrowFunction, conditional::ConditionalExpression};
+use self::{
+    arrow_function::{ArrowFunction, AsyncArrowFunction},
+    conditional::ConditionalExpression,
+    r#yield::YieldExpression,
+};
 use crate::syntax::lexer::{Error as LexError, InputElement, TokenKind};
 use crate::{
     syntax::{
         ast::{
-            node::{Assign, BinOp, Node},
-            Keyword, Punctuator,
+            node::{
+                ArrayPattern, Assign, BinOp, Binding, BindingElement, BindingProperty, Node,
+                ObjectPattern, PropertyDefinition,
+            },
+            Const, Keyword, Punctuator,
         },
         parser::{AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
     },
@@ -32,7 +40,7 @@ use std::io::Read;
 /// This can be one of the following:
 ///
 ///  - [`ConditionalExpression`](../conditional_operator/struct.ConditionalExpression.html)
-///  - `YieldExpression`
+///  - [`YieldExpression`](./yield/struct.YieldExpression.html)
 ///  - [`ArrowFunction`](../../function/arrow_function/struct.ArrowFunction.html)
 ///  - `AsyncArrowFunction`
 ///  - [`LeftHandSideExpression`][lhs] `=` `AssignmentExpression`
@@ -82,8 +90,32 @@ where
         let _timer = BoaProfiler::global().start_event("AssignmentExpression", "Parsing");
         cursor.set_goal(InputElement::Div);
 
+        // async a=>{}, there can't be a line terminator after `async`.
+        let is_async_arrow = matches!(
+            cursor.peek(0)?.map(|tok| tok.kind()),
+            Some(TokenKind::Identifier(ident)) if ident.as_ref() == "async"
+        ) && matches!(
+            cursor.peek_after_no_lineterminator()?.map(|tok| tok.kind()),
+            Some(TokenKind::Identifier(_))
+                | Some(TokenKind::Keyword(Keyword::Yield))
+                | Some(TokenKind::Keyword(Keyword::Await))
+        ) && matches!(
+            cursor.peek(2)?.map(|tok| tok.kind()),
+            Some(TokenKind::Punctuator(Punctuator::Arrow))
+        );
+        if is_async_arrow {
+            return AsyncArrowFunction::new(self.allow_in, self.allow_yield)
+                .parse(cursor)
+                .map(Node::AsyncArrowFunctionDecl);
+        }
+
         // Arrow function
         match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+            // yield, yield x or yield* x
+            TokenKind::Keyword(Keyword::Yield) if self.allow_yield.0 => {
+                return YieldExpression::new(self.allow_in, self.allow_await).parse(cursor);
+            }
+
             // a=>{}
             TokenKind::Identifier(_)
             | TokenKind::Keyword(Keyword::Yield)
@@ -172,17 +204,61 @@ where
 
         cursor.set_goal(InputElement::Div);
 
+        let start_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = start_token.span().start();
+        let is_parenthesized = start_token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen);
         let mut lhs = ConditionalExpression::new(self.allow_in, self.allow_yield, self.allow_await)
             .parse(cursor)?;
 
+        // ({a}, [b])=>{}, the parameters have been parsed as a parenthesized expression.
+        if is_parenthesized
+            && matches!(
+                cursor
+                    .peek_expect_no_lineterminator(0)
+                    .map(|tok| tok.kind()),
+                Ok(TokenKind::Punctuator(Punctuator::Arrow))
+            )
+        {
+            return ArrowFunction::new(self.allow_in, self.allow_yield, self.allow_await)
+                .with_cover_parameters(&lhs, start)?
+                .parse(cursor)
+                .map(Node::ArrowFunctionDecl);
+        }
+
+        // async (a,b)=>{}, the parameters have been parsed as the arguments of a call.
+        if let Node::Call(ref call) = lhs {
+            if matches!(call.expr(), Node::Identifier(ident) if ident.as_ref() == "async")
+                && matches!(
+                    cursor
+                        .peek_expect_no_lineterminator(0)
+                        .map(|tok| tok.kind()),
+                    Ok(TokenKind::Punctuator(Punctuator::Arrow))
+                )
+            {
+                return AsyncArrowFunction::new(self.allow_in, self.allow_yield)
+                    .with_call_arguments(call.args(), start)?
+                    .parse(cursor)
+                    .map(Node::AsyncArrowFunctionDecl);
+            }
+        }
+
         // Review if we are trying to assign to an invalid left hand side expression.
         // TODO: can we avoid cloning?
         if let Some(tok) = cursor.peek(0)?.cloned() {
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Assign) => {
                     cursor.next()?.expect("= token vanished"); // Consume the token.
-                    if is_assignable(&lhs) {
-                        lhs = Assign::new(lhs, self.parse(cursor)?).into();
+
+                    // A parenthesized object or array literal, like `({ a }) = b`, is not an
+                    // assignment pattern.
+                    let is_parenthesized_literal =
+                        is_parenthesized && matches!(lhs, Node::Object(_) | Node::ArrayDecl(_));
+                    let target = assignment_target(&lhs).filter(|target| {
+                        !is_parenthesized_literal
+                            && (!cursor.strict_mode() || !assigns_eval_or_arguments(target))
+                    });
+                    if let Some(target) = target {
+                        lhs = Assign::new(target, self.parse(cursor)?).into();
                     } else {
                         return Err(ParseError::lex(LexError::Syntax(
                             "Invalid left-hand side in assignment".into(),
@@ -192,7 +268,7 @@ where
                 }
                 TokenKind::Punctuator(p) if p.as_binop().is_some() && p != &Punctuator::Comma => {
                     cursor.next()?.expect("token vanished"); // Consume the token.
-                    if is_assignable(&lhs) {
+                    if is_assignable(&lhs, cursor.strict_mode()) {
                         let binop = p.as_binop().expect("binop disappeared");
                         let expr = self.parse(cursor)?;
 
@@ -212,10 +288,109 @@ where
     }
 }
 
-/// Returns true if as per spec[spec] the node can be assigned a value.
+/// Returns true if as per spec[spec] the node is a simple assignment target, which is the only
+/// kind of target of compound assignments and update expressions.
 ///
-/// [spec]: https://tc39.es/ecma262/#sec-assignment-operators-static-semantics-early-errors
+/// `eval` and `arguments` can't be assigned to in strict mode code.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-assignmenttargettype
 #[inline]
-pub(crate) fn is_assignable(node: &Node) -> bool {
-    !matches!(node, Node::Const(_) | Node::ArrayDecl(_))
+pub(crate) fn is_assignable(node: &Node, strict: bool) -> bool {
+    match node {
+        Node::Identifier(ident) => !strict || !matches!(ident.as_ref(), "eval" | "arguments"),
+        Node::GetConstField(_)
+        | Node::GetField(_)
+        | Node::GetPrivateField(_)
+        | Node::GetSuperConstField(_)
+        | Node::GetSuperField(_) => true,
+        _ => false,
+    }
+}
+
+/// Checks if assigning to the target assigns to `eval` or `arguments`, which is not allowed in
+/// strict mode code.
+pub(in crate::syntax::parser) fn assigns_eval_or_arguments(target: &Binding) -> bool {
+    let mut names = Vec::new();
+    target.bound_names(&mut names);
+    names
+        .iter()
+        .any(|name| matches!(*name, "eval" | "arguments"))
+}
+
+/// Converts the left hand side of an assignment, that was parsed as an expression, to the target
+/// of the assignment.
+///
+/// Array and object literals are reinterpreted as assignment patterns, like the `[a, b]` of
+/// `[a, b] = [b, a]`. Returns `None` if the expression can't be assigned to.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-destructuring-assignment
+pub(in crate::syntax::parser) fn assignment_target(expr: &Node) -> Option<Binding> {
+    // A nested assignment, like the `b = 1` of `[b = 1] = a`, is a target with a default value.
+    let element = |expr: &Node| match expr {
+        Node::Assign(assign) => Some(BindingElement::new(
+            assign.lhs().clone(),
+            assign.rhs().clone(),
+        )),
+        expr => Some(BindingElement::new(assignment_target(expr)?, None)),
+    };
+    // The rest element of an object pattern can't be a pattern itself.
+    let simple_target = |expr: &Node| match assignment_target(expr)? {
+        target @ Binding::Identifier(_) | target @ Binding::Member(_) => Some(target),
+        _ => None,
+    };
+
+    match expr {
+        Node::Identifier(ident) => Some(ident.clone().into()),
+        Node::GetConstField(_)
+        | Node::GetField(_)
+        | Node::GetPrivateField(_)
+        | Node::GetSuperConstField(_)
+        | Node::GetSuperField(_) => Some(Binding::Member(Box::new(expr.clone()))),
+        Node::Object(object) => {
+            let properties = object.properties();
+            let mut targets = Vec::with_capacity(properties.len());
+            let mut rest = None;
+            for (i, property) in properties.iter().enumerate() {
+                match property {
+                    PropertyDefinition::IdentifierReference(name) => targets.push(
+                        BindingProperty::new(&**name, BindingElement::new(name.clone(), None)),
+                    ),
+                    PropertyDefinition::CoverInitializedName(name, init) => {
+                        targets.push(BindingProperty::new(
+                            &**name,
+                            BindingElement::new(name.clone(), init.clone()),
+                        ))
+                    }
+                    PropertyDefinition::Property(name, value) => {
+                        targets.push(BindingProperty::new(name.clone(), element(value)?))
+                    }
+                    PropertyDefinition::SpreadObject(target) if i == properties.len() - 1 => {
+                        rest = Some(simple_target(target)?)
+                    }
+                    _ => return None,
+                }
+            }
+            Some(ObjectPattern::new(targets, rest).into())
+        }
+        Node::ArrayDecl(array) => {
+            let values = array.as_ref();
+            let mut elements = Vec::with_capacity(values.len());
+            let mut rest = None;
+            for (i, value) in values.iter().enumerate() {
+                match value {
+                    // Elisions are parsed as `undefined`.
+                    Node::Const(Const::Undefined) => elements.push(None),
+                    Node::Spread(spread) if i == values.len() - 1 => {
+                        rest = Some(assignment_target(spread.val())?)
+                    }
+                    value => elements.push(Some(element(value)?)),
+                }
+            }
+            Some(ArrayPattern::new(elements, rest).into())
+        }
+        _ => None,
+    }
 }
@@ -0,0 +1,87 @@
+//! Yield expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+//! [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+
+use super::AssignmentExpression;
+use crate::{
+    syntax::{
+        ast::{
+            node::{Node, Yield},
+            Keyword, Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{AllowAwait, AllowIn, Cursor, ParseResult, TokenParser},
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Yield expression parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct YieldExpression {
+    allow_in: AllowIn,
+    allow_await: AllowAwait,
+}
+
+impl YieldExpression {
+    /// Creates a new `YieldExpression` parser.
+    pub(super) fn new<I, A>(allow_in: I, allow_await: A) -> Self
+    where
+        I: Into<AllowIn>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for YieldExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("YieldExpression", "Parsing");
+        cursor.expect(Keyword::Yield, "yield expression")?;
+
+        // The operand is optional, it can't start on a new line.
+        let has_operand = match cursor.peek_expect_no_lineterminator(0) {
+            Ok(tok) => !matches!(
+                tok.kind(),
+                TokenKind::Punctuator(Punctuator::CloseParen)
+                    | TokenKind::Punctuator(Punctuator::CloseBracket)
+                    | TokenKind::Punctuator(Punctuator::CloseBlock)
+                    | TokenKind::Punctuator(Punctuator::Comma)
+                    | TokenKind::Punctuator(Punctuator::Semicolon)
+                    | TokenKind::Punctuator(Punctuator::Colon)
+                    | TokenKind::Keyword(Keyword::In)
+            ),
+            Err(_) => false,
+        };
+        if !has_operand {
+            return Ok(Yield::new::<Node, Option<_>>(None, false).into());
+        }
+
+        let delegate = cursor.next_if(Punctuator::Mul)?.is_some();
+        let expr =
+            AssignmentExpression::new(self.allow_in, true, self.allow_await).parse(cursor)?;
+
+        Ok(Yield::new(expr, delegate).into())
+    }
+}
@@ -12,15 +12,15 @@ use crate::{
     syntax::{
         ast::{
             node::{
-                field::{GetConstField, GetField},
+                field::{GetConstField, GetField, GetPrivateField},
                 Call, Node,
             },
             Punctuator,
         },
         lexer::TokenKind,
         parser::{
-            expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
-            TokenParser,
+            expression::{primary::TaggedTemplateLiteral, Expression},
+            AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
     BoaProfiler,
@@ -89,13 +89,18 @@ where
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     cursor.next()?.ok_or(ParseError::AbruptEnd)?; // We move the parser forward.
 
-                    match &cursor.next()?.ok_or(ParseError::AbruptEnd)?.kind() {
+                    let name_token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                    match name_token.kind() {
                         TokenKind::Identifier(name) => {
                             lhs = GetConstField::new(lhs, name.clone()).into();
                         }
                         TokenKind::Keyword(kw) => {
                             lhs = GetConstField::new(lhs, kw.to_string()).into();
                         }
+                        TokenKind::PrivateIdentifier(name) => {
+                            cursor.use_private_identifier(name, name_token.span().start())?;
+                            lhs = GetPrivateField::new(lhs, name.clone()).into();
+                        }
                         _ => {
                             return Err(ParseError::expected(
                                 vec![TokenKind::identifier("identifier")],
@@ -112,6 +117,13 @@ where
                     cursor.expect(Punctuator::CloseBracket, "call expression")?;
                     lhs = GetField::new(lhs, idx).into();
                 }
+                TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateHead(_) => {
+                    let first = cursor.next()?.expect("template token disappeared");
+                    lhs =
+                        TaggedTemplateLiteral::new(self.allow_yield, self.allow_await, first, lhs)
+                            .parse(cursor)?
+                            .into();
+                }
                 _ => break,
             }
         }
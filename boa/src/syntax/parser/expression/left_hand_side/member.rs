@@ -10,14 +10,20 @@ use crate::{
     syntax::{
         ast::{
             node::{
-                field::{GetConstField, GetField},
-                Call, New, Node,
+                field::{
+                    GetConstField, GetField, GetPrivateField, GetSuperConstField, GetSuperField,
+                },
+                Call, New, Node, SuperCall,
             },
             Keyword, Punctuator,
         },
-        lexer::TokenKind,
+        lexer::{Position, TokenKind},
         parser::{
-            expression::{primary::PrimaryExpression, Expression},
+            cursor::SuperContext,
+            expression::{
+                primary::{PrimaryExpression, TaggedTemplateLiteral},
+                Expression,
+            },
             AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
@@ -64,12 +70,37 @@ where
         let mut lhs = if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
             == &TokenKind::Keyword(Keyword::New)
         {
-            let _ = cursor.next().expect("new keyword disappeared");
-            let lhs = self.parse(cursor)?;
-            let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
-            let call_node = Call::new(lhs, args);
+            let token = cursor.next()?.expect("new keyword disappeared");
+            // `new.target` is the only member expression that starts with `new.`.
+            if cursor.next_if(Punctuator::Dot)?.is_some() {
+                cursor.expect(TokenKind::identifier("target"), "new.target")?;
+                if !cursor.allow_new_target() {
+                    return Err(ParseError::general(
+                        "new.target is only valid in functions",
+                        token.span().start(),
+                    ));
+                }
+                Node::NewTarget
+            } else {
+                let lhs = self.parse(cursor)?;
+                let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let call_node = Call::new(lhs, args);
 
-            Node::from(New::from(call_node))
+                Node::from(New::from(call_node))
+            }
+        } else if let Some(token) = cursor.next_if(Keyword::Super)? {
+            self.parse_super(cursor, token.span().start())?
+        } else if let Some(token) = cursor.next_if(Keyword::Import)? {
+            // `import.meta` is the only member expression that starts with `import`.
+            cursor.expect(Punctuator::Dot, "import.meta")?;
+            cursor.expect(TokenKind::identifier("meta"), "import.meta")?;
+            if !cursor.module() {
+                return Err(ParseError::general(
+                    "import.meta is only valid in modules",
+                    token.span().start(),
+                ));
+            }
+            Node::ImportMeta
         } else {
             PrimaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?
         };
@@ -87,6 +118,10 @@ where
                         TokenKind::Keyword(kw) => {
                             lhs = GetConstField::new(lhs, kw.to_string()).into()
                         }
+                        TokenKind::PrivateIdentifier(name) => {
+                            cursor.use_private_identifier(name, token.span().start())?;
+                            lhs = GetPrivateField::new(lhs, name.clone()).into()
+                        }
                         _ => {
                             return Err(ParseError::expected(
                                 vec![TokenKind::identifier("identifier")],
@@ -105,6 +140,13 @@ where
                     cursor.expect(Punctuator::CloseBracket, "member expression")?;
                     lhs = GetField::new(lhs, idx).into();
                 }
+                TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateHead(_) => {
+                    let first = cursor.next()?.expect("template token disappeared");
+                    lhs =
+                        TaggedTemplateLiteral::new(self.allow_yield, self.allow_await, first, lhs)
+                            .parse(cursor)?
+                            .into();
+                }
                 _ => break,
             }
         }
@@ -112,3 +154,74 @@ where
         Ok(lhs)
     }
 }
+
+impl MemberExpression {
+    /// Parses what follows the `super` keyword at `position`: a `SuperProperty` or a `SuperCall`.
+    ///
+    /// `super` properties can only be used in methods, and `super()` can only be called in the
+    /// constructors of derived classes.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-SuperProperty
+    fn parse_super<R>(self, cursor: &mut Cursor<R>, position: Position) -> ParseResult
+    where
+        R: Read,
+    {
+        let allow_super = cursor.allow_super();
+        let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        match token.kind() {
+            TokenKind::Punctuator(Punctuator::Dot)
+            | TokenKind::Punctuator(Punctuator::OpenBracket)
+                if allow_super == SuperContext::None =>
+            {
+                Err(ParseError::general(
+                    "'super' keyword unexpected here",
+                    position,
+                ))
+            }
+            TokenKind::Punctuator(Punctuator::OpenParen) if allow_super != SuperContext::Call => {
+                Err(ParseError::general(
+                    "'super' call unexpected here",
+                    position,
+                ))
+            }
+            TokenKind::Punctuator(Punctuator::Dot) => {
+                let _ = cursor.next()?.expect("dot punctuator token disappeared");
+                let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                match token.kind() {
+                    TokenKind::Identifier(name) => Ok(GetSuperConstField::new(name.clone()).into()),
+                    TokenKind::Keyword(kw) => Ok(GetSuperConstField::new(kw.to_string()).into()),
+                    _ => Err(ParseError::expected(
+                        vec![TokenKind::identifier("identifier")],
+                        token,
+                        "super property",
+                    )),
+                }
+            }
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                let _ = cursor
+                    .next()?
+                    .expect("open bracket punctuator token disappeared");
+                let idx =
+                    Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                cursor.expect(Punctuator::CloseBracket, "super property")?;
+                Ok(GetSuperField::new(idx).into())
+            }
+            TokenKind::Punctuator(Punctuator::OpenParen) => {
+                let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                Ok(SuperCall::new(args).into())
+            }
+            _ => Err(ParseError::expected(
+                vec![
+                    TokenKind::Punctuator(Punctuator::Dot),
+                    TokenKind::Punctuator(Punctuator::OpenBracket),
+                    TokenKind::Punctuator(Punctuator::OpenParen),
+                ],
+                token.clone(),
+                "super keyword",
+            )),
+        }
+    }
+}
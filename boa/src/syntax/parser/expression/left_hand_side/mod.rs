@@ -10,8 +10,9 @@
 mod arguments;
 mod call;
 mod member;
+mod optional;
 
-use self::{call::CallExpression, member::MemberExpression};
+use self::{call::CallExpression, member::MemberExpression, optional::OptionalExpression};
 use crate::{
     profiler::BoaProfiler,
     syntax::{
@@ -32,14 +33,14 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Expressions_and_Operators#Left-hand-side_expressions
 /// [spec]: https://tc39.es/ecma262/#prod-LeftHandSideExpression
 #[derive(Debug, Clone, Copy)]
-pub(super) struct LeftHandSideExpression {
+pub(in crate::syntax::parser) struct LeftHandSideExpression {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl LeftHandSideExpression {
     /// Creates a new `LeftHandSideExpression` parser.
-    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -63,10 +64,16 @@ where
         cursor.set_goal(InputElement::TemplateTail);
 
         // TODO: Implement NewExpression: new MemberExpression
-        let lhs = MemberExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let mut lhs = MemberExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
         if let Some(tok) = cursor.peek(0)? {
             if tok.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
-                return CallExpression::new(self.allow_yield, self.allow_await, lhs).parse(cursor);
+                lhs = CallExpression::new(self.allow_yield, self.allow_await, lhs).parse(cursor)?;
+            }
+        }
+        if let Some(tok) = cursor.peek(0)? {
+            if tok.kind() == &TokenKind::Punctuator(Punctuator::Optional) {
+                lhs = OptionalExpression::new(self.allow_yield, self.allow_await, lhs)
+                    .parse(cursor)?;
             }
         }
         Ok(lhs)
@@ -0,0 +1,141 @@
+//! Optional chaining parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+//! [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+
+#[cfg(test)]
+mod tests;
+
+use super::arguments::Arguments;
+use crate::{
+    syntax::{
+        ast::{
+            node::{Node, Optional, OptionalOperation, OptionalOperationKind},
+            Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{
+            expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
+            TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Parses an optional expression, the chain of operations following a member or call
+/// expression that contains a `?.`.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+#[derive(Debug)]
+pub(super) struct OptionalExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    target: Node,
+}
+
+impl OptionalExpression {
+    /// Creates a new `OptionalExpression` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, target: Node) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            target,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for OptionalExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("OptionalExpression", "Parsing");
+
+        let mut chain = Vec::new();
+        while let Some(token) = cursor.peek(0)? {
+            let shorted = match token.kind() {
+                TokenKind::Punctuator(Punctuator::Optional) => {
+                    let _ = cursor.next()?.expect("?. token vanished");
+                    true
+                }
+                TokenKind::Punctuator(Punctuator::Dot) => {
+                    let _ = cursor.next()?.expect("dot punctuator token disappeared");
+                    false
+                }
+                TokenKind::Punctuator(Punctuator::OpenBracket)
+                | TokenKind::Punctuator(Punctuator::OpenParen)
+                | TokenKind::TemplateNoSubstitution(_)
+                | TokenKind::TemplateHead(_) => false,
+                _ => break,
+            };
+
+            let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            let kind = match token.kind() {
+                TokenKind::Punctuator(Punctuator::OpenParen) => OptionalOperationKind::Call(
+                    Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?,
+                ),
+                TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    let _ = cursor
+                        .next()?
+                        .expect("open bracket punctuator token disappeared");
+                    let field =
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::CloseBracket, "optional chain")?;
+                    OptionalOperationKind::GetField(Box::new(field))
+                }
+                TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateHead(_) => {
+                    return Err(ParseError::unexpected(
+                        token.clone(),
+                        "tagged template cannot be used in optional chain",
+                    ));
+                }
+                TokenKind::Identifier(name) => {
+                    let name = name.clone();
+                    let _ = cursor.next()?.expect("identifier token disappeared");
+                    OptionalOperationKind::GetConstField(name)
+                }
+                TokenKind::Keyword(kw) => {
+                    let name = kw.to_string().into();
+                    let _ = cursor.next()?.expect("keyword token disappeared");
+                    OptionalOperationKind::GetConstField(name)
+                }
+                TokenKind::PrivateIdentifier(name) => {
+                    let name = name.clone();
+                    let token = cursor
+                        .next()?
+                        .expect("private identifier token disappeared");
+                    cursor.use_private_identifier(&name, token.span().start())?;
+                    OptionalOperationKind::GetPrivateField(name)
+                }
+                _ => {
+                    let token = cursor.next()?.expect("token disappeared");
+                    return Err(ParseError::expected(
+                        vec![TokenKind::identifier("identifier")],
+                        token,
+                        "optional chain",
+                    ));
+                }
+            };
+            chain.push(OptionalOperation::new(kind, shorted));
+        }
+
+        Ok(Optional::new(self.target, chain).into())
+    }
+}
@@ -0,0 +1,63 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            Call, GetConstField, Identifier, Optional, OptionalOperation, OptionalOperationKind,
+        },
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks optional member accesses and calls.
+#[test]
+fn check_optional_chain() {
+    check_parser(
+        "a?.b?.[c]?.()",
+        vec![Optional::new(
+            Identifier::from("a"),
+            vec![
+                OptionalOperation::new(OptionalOperationKind::GetConstField("b".into()), true),
+                OptionalOperation::new(
+                    OptionalOperationKind::GetField(Box::new(Identifier::from("c").into())),
+                    true,
+                ),
+                OptionalOperation::new(OptionalOperationKind::Call(Box::new([])), true),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// Checks the operations that follow an optional operation in the same chain.
+#[test]
+fn check_optional_chain_continuation() {
+    check_parser(
+        "a.b()?.c.d[0](1)",
+        vec![Optional::new(
+            Call::new(GetConstField::new(Identifier::from("a"), "b"), vec![]),
+            vec![
+                OptionalOperation::new(OptionalOperationKind::GetConstField("c".into()), true),
+                OptionalOperation::new(OptionalOperationKind::GetConstField("d".into()), false),
+                OptionalOperation::new(
+                    OptionalOperationKind::GetField(Box::new(Const::from(0).into())),
+                    false,
+                ),
+                OptionalOperation::new(
+                    OptionalOperationKind::Call(Box::new([Const::from(1).into()])),
+                    false,
+                ),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// Checks invalid optional chains.
+#[test]
+fn check_invalid_optional_chain() {
+    check_invalid("a?.b = 1");
+    check_invalid("a?.b += 1");
+    check_invalid("a?.`template`");
+    check_invalid("a?.b`template`");
+    check_invalid("new a?.b()");
+}
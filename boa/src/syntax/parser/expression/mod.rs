@@ -16,13 +16,18 @@ mod unary;
 mod update;
 
 use self::assignment::ExponentiationExpression;
-pub(super) use self::{assignment::AssignmentExpression, primary::Initializer};
-use super::{AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser};
-use crate::syntax::lexer::{InputElement, TokenKind};
+pub(super) use self::{
+    assignment::{assignment_target, AssignmentExpression},
+    left_hand_side::LeftHandSideExpression,
+    primary::{Initializer, PropertyName},
+};
+use super::{AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser};
+use crate::syntax::lexer::{InputElement, Token, TokenKind};
 use crate::{
     profiler::BoaProfiler,
     syntax::ast::{
-        node::{BinOp, Node},
+        node::{BinOp, Node, PrivateIn},
+        op::LogOp,
         Keyword, Punctuator,
     },
 };
@@ -136,23 +141,26 @@ expression!(
     "Expression"
 );
 
-/// Parses a logical `OR` expression.
+/// Parses a short circuit expression, which is either a logical `OR` expression or a nullish
+/// coalescing expression.
+///
+/// The `??` operator can't be mixed with the `&&` and `||` operators without parentheses.
 ///
 /// More information:
 ///  - [MDN documentation][mdn]
 ///  - [ECMAScript specification][spec]
 ///
-/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Logical_Operators#Logical_OR_2
-/// [spec]: https://tc39.es/ecma262/#prod-LogicalORExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Logical_Operators
+/// [spec]: https://tc39.es/ecma262/#prod-ShortCircuitExpression
 #[derive(Debug, Clone, Copy)]
-struct LogicalORExpression {
+struct ShortCircuitExpression {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
-impl LogicalORExpression {
-    /// Creates a new `LogicalORExpression` parser.
+impl ShortCircuitExpression {
+    /// Creates a new `ShortCircuitExpression` parser.
     pub(super) fn new<I, Y, A>(allow_in: I, allow_yield: Y, allow_await: A) -> Self
     where
         I: Into<AllowIn>,
@@ -165,56 +173,67 @@ impl LogicalORExpression {
             allow_await: allow_await.into(),
         }
     }
-}
-
-expression!(
-    LogicalORExpression,
-    LogicalANDExpression,
-    [Punctuator::BoolOr],
-    [allow_in, allow_yield, allow_await],
-    None::<InputElement>,
-    "LogicalOrExpression"
-);
 
-/// Parses a logical `AND` expression.
-///
-/// More information:
-///  - [MDN documentation][mdn]
-///  - [ECMAScript specification][spec]
-///
-/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Logical_Operators#Logical_AND_2
-/// [spec]: https://tc39.es/ecma262/#prod-LogicalANDExpression
-#[derive(Debug, Clone, Copy)]
-struct LogicalANDExpression {
-    allow_in: AllowIn,
-    allow_yield: AllowYield,
-    allow_await: AllowAwait,
-}
+    /// Parses the operand of a short circuit operator.
+    fn parse_operand<R>(self, cursor: &mut Cursor<R>) -> ParseResult
+    where
+        R: Read,
+    {
+        BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await).parse(cursor)
+    }
 
-impl LogicalANDExpression {
-    /// Creates a new `LogicalANDExpression` parser.
-    pub(super) fn new<I, Y, A>(allow_in: I, allow_yield: Y, allow_await: A) -> Self
+    /// Parses the rest of a logical `AND` expression, starting from its first operand.
+    fn parse_and<R>(self, cursor: &mut Cursor<R>, mut lhs: Node) -> ParseResult
     where
-        I: Into<AllowIn>,
-        Y: Into<AllowYield>,
-        A: Into<AllowAwait>,
+        R: Read,
     {
-        Self {
-            allow_in: allow_in.into(),
-            allow_yield: allow_yield.into(),
-            allow_await: allow_await.into(),
+        while cursor.next_if(Punctuator::BoolAnd)?.is_some() {
+            lhs = BinOp::new(LogOp::And, lhs, self.parse_operand(cursor)?).into();
         }
+        Ok(lhs)
     }
 }
 
-expression!(
-    LogicalANDExpression,
-    BitwiseORExpression,
-    [Punctuator::BoolAnd],
-    [allow_in, allow_yield, allow_await],
-    None::<InputElement>,
-    "LogicalANDExpression"
-);
+impl<R> TokenParser<R> for ShortCircuitExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("ShortCircuitExpression", "Parsing");
+
+        let mut lhs = self.parse_operand(cursor)?;
+        let mixed = if cursor.next_if(Punctuator::Coalesce)?.is_some() {
+            lhs = BinOp::new(LogOp::Coalesce, lhs, self.parse_operand(cursor)?).into();
+            while cursor.next_if(Punctuator::Coalesce)?.is_some() {
+                lhs = BinOp::new(LogOp::Coalesce, lhs, self.parse_operand(cursor)?).into();
+            }
+            [Punctuator::BoolAnd, Punctuator::BoolOr]
+        } else {
+            lhs = self.parse_and(cursor, lhs)?;
+            while cursor.next_if(Punctuator::BoolOr)?.is_some() {
+                let rhs = self.parse_operand(cursor)?;
+                lhs = BinOp::new(LogOp::Or, lhs, self.parse_and(cursor, rhs)?).into();
+            }
+            [Punctuator::Coalesce, Punctuator::Coalesce]
+        };
+
+        if let Some(tok) = cursor.peek(0)? {
+            if mixed
+                .iter()
+                .any(|punc| tok.kind() == &TokenKind::Punctuator(*punc))
+            {
+                return Err(ParseError::unexpected(
+                    tok.clone(),
+                    "`??` can't be mixed with `&&` or `||` without parentheses",
+                ));
+            }
+        }
+
+        Ok(lhs)
+    }
+}
 
 /// Parses a bitwise `OR` expression.
 ///
@@ -412,21 +431,59 @@ impl RelationalExpression {
     }
 }
 
-expression!(
-    RelationalExpression,
-    ShiftExpression,
-    [
-        Punctuator::LessThan,
-        Punctuator::GreaterThan,
-        Punctuator::LessThanOrEq,
-        Punctuator::GreaterThanOrEq,
-        Keyword::InstanceOf,
-        Keyword::In
-    ],
-    [allow_yield, allow_await],
-    None::<InputElement>,
-    "RelationoalExpression"
-);
+impl<R> TokenParser<R> for RelationalExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("RelationoalExpression", "Parsing");
+
+        let private_name = match cursor.peek(0)?.map(Token::kind) {
+            Some(TokenKind::PrivateIdentifier(name)) if self.allow_in.0 => Some(name.clone()),
+            _ => None,
+        };
+        let mut lhs = if let Some(name) = private_name {
+            // `#x in obj` checks if the object has the private member `#x`.
+            let token = cursor
+                .next()?
+                .expect("private identifier token disappeared");
+            cursor.use_private_identifier(&name, token.span().start())?;
+            cursor.expect(Keyword::In, "private member check")?;
+            let target = ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            PrivateIn::new(name, target).into()
+        } else {
+            ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?
+        };
+        while let Some(tok) = cursor.peek(0)? {
+            let op = match *tok.kind() {
+                TokenKind::Punctuator(op)
+                    if op == Punctuator::LessThan
+                        || op == Punctuator::GreaterThan
+                        || op == Punctuator::LessThanOrEq
+                        || op == Punctuator::GreaterThanOrEq =>
+                {
+                    op.as_binop()
+                }
+                TokenKind::Keyword(Keyword::InstanceOf) => Keyword::InstanceOf.as_binop(),
+                // `in` isn't a relational operator in the head of a `for` statement, where it
+                // starts a `for...in` loop.
+                TokenKind::Keyword(Keyword::In) if self.allow_in.0 => Keyword::In.as_binop(),
+                _ => break,
+            };
+            let _ = cursor.next().expect("token disappeared");
+            lhs = BinOp::new(
+                op.expect("Could not get binary operation."),
+                lhs,
+                ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
+            )
+            .into();
+        }
+
+        Ok(lhs)
+    }
+}
 
 /// Parses a bitwise shift expression.
 ///
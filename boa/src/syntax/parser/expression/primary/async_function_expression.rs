@@ -0,0 +1,92 @@
+//! Async function expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/async_function
+//! [spec]: https://tc39.es/ecma262/#prod-AsyncFunctionExpression
+
+use crate::{
+    syntax::{
+        ast::{node::AsyncFunctionExpr, Keyword, Punctuator},
+        lexer::TokenKind,
+        parser::{
+            cursor::SuperContext,
+            function::{check_parameters, FormalParameters, FunctionBody},
+            statement::BindingIdentifier,
+            Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Async function expression parsing.
+///
+/// The `async` and `function` tokens have already been consumed.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/async_function
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncFunctionExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct AsyncFunctionExpression;
+
+impl<R> TokenParser<R> for AsyncFunctionExpression
+where
+    R: Read,
+{
+    type Output = AsyncFunctionExpr;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("AsyncFunctionExpression", "Parsing");
+
+        let name = if let Some(token) = cursor.peek(0)? {
+            match token.kind() {
+                TokenKind::Identifier(_)
+                | TokenKind::Keyword(Keyword::Yield)
+                | TokenKind::Keyword(Keyword::Await) => {
+                    // `await` can't be the name of an async function expression.
+                    Some(BindingIdentifier::new(false, true).parse(cursor)?)
+                }
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        let params_start = cursor
+            .expect(Punctuator::OpenParen, "async function expression")?
+            .span()
+            .start();
+
+        let allow_new_target = cursor.set_allow_new_target(true);
+        let allow_super = cursor.set_allow_super(SuperContext::None);
+
+        let params = FormalParameters::new(false, true).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "async function expression")?;
+        cursor.expect(Punctuator::OpenBlock, "async function expression")?;
+
+        let body = FunctionBody::new(false, true).parse(cursor)?;
+
+        cursor.set_allow_new_target(allow_new_target);
+        cursor.set_allow_super(allow_super);
+
+        check_parameters(
+            &params,
+            &body,
+            cursor.use_strict_directive(),
+            false,
+            params_start,
+        )?;
+
+        cursor.expect(Punctuator::CloseBlock, "async function expression")?;
+
+        Ok(AsyncFunctionExpr::new(name, params, body))
+    }
+}
@@ -0,0 +1,83 @@
+//! Class expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/class
+//! [spec]: https://tc39.es/ecma262/#prod-ClassExpression
+
+use crate::{
+    syntax::{
+        ast::{node::ClassExpr, Keyword},
+        lexer::TokenKind,
+        parser::{
+            class::ClassTail, statement::BindingIdentifier, AllowAwait, AllowYield, Cursor,
+            ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Class expression parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/class
+/// [spec]: https://tc39.es/ecma262/#prod-ClassExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ClassExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassExpression {
+    /// Creates a new `ClassExpression` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassExpression
+where
+    R: Read,
+{
+    type Output = ClassExpr;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassExpression", "Parsing");
+
+        let name = match cursor.peek(0)?.map(|tok| tok.kind()) {
+            Some(TokenKind::Identifier(_))
+            | Some(TokenKind::Keyword(Keyword::Yield))
+            | Some(TokenKind::Keyword(Keyword::Await)) => {
+                let strict_mode = cursor.strict_mode();
+                cursor.set_strict_mode(true);
+                let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor);
+                cursor.set_strict_mode(strict_mode);
+                Some(name?)
+            }
+            _ => None,
+        };
+
+        let tail = ClassTail::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        Ok(ClassExpr::new(
+            name,
+            tail.super_ref,
+            tail.constructor,
+            tail.elements,
+        ))
+    }
+}
@@ -12,7 +12,8 @@ use crate::{
         ast::{node::FunctionExpr, Keyword, Punctuator},
         lexer::TokenKind,
         parser::{
-            function::{FormalParameters, FunctionBody},
+            cursor::SuperContext,
+            function::{check_parameters, FormalParameters, FunctionBody},
             statement::BindingIdentifier,
             Cursor, ParseError, TokenParser,
         },
@@ -55,7 +56,13 @@ where
             None
         };
 
-        cursor.expect(Punctuator::OpenParen, "function expression")?;
+        let params_start = cursor
+            .expect(Punctuator::OpenParen, "function expression")?
+            .span()
+            .start();
+
+        let allow_new_target = cursor.set_allow_new_target(true);
+        let allow_super = cursor.set_allow_super(SuperContext::None);
 
         let params = FormalParameters::new(false, false).parse(cursor)?;
 
@@ -64,6 +71,17 @@ where
 
         let body = FunctionBody::new(false, false).parse(cursor)?;
 
+        cursor.set_allow_new_target(allow_new_target);
+        cursor.set_allow_super(allow_super);
+
+        check_parameters(
+            &params,
+            &body,
+            cursor.use_strict_directive(),
+            false,
+            params_start,
+        )?;
+
         cursor.expect(Punctuator::CloseBlock, "function expression")?;
 
         Ok(FunctionExpr::new(name, params, body))
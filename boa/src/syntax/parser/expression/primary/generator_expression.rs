@@ -0,0 +1,92 @@
+//! Generator expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/function*
+//! [spec]: https://tc39.es/ecma262/#prod-GeneratorExpression
+
+use crate::{
+    syntax::{
+        ast::{node::GeneratorExpr, Keyword, Punctuator},
+        lexer::TokenKind,
+        parser::{
+            cursor::SuperContext,
+            function::{check_parameters, FormalParameters, FunctionBody},
+            statement::BindingIdentifier,
+            Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Generator expression parsing.
+///
+/// The `function` and `*` tokens have already been consumed.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/function*
+/// [spec]: https://tc39.es/ecma262/#prod-GeneratorExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct GeneratorExpression;
+
+impl<R> TokenParser<R> for GeneratorExpression
+where
+    R: Read,
+{
+    type Output = GeneratorExpr;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("GeneratorExpression", "Parsing");
+
+        let name = if let Some(token) = cursor.peek(0)? {
+            match token.kind() {
+                TokenKind::Identifier(_)
+                | TokenKind::Keyword(Keyword::Yield)
+                | TokenKind::Keyword(Keyword::Await) => {
+                    // `yield` can't be the name of a generator expression.
+                    Some(BindingIdentifier::new(true, false).parse(cursor)?)
+                }
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        let params_start = cursor
+            .expect(Punctuator::OpenParen, "generator expression")?
+            .span()
+            .start();
+
+        let allow_new_target = cursor.set_allow_new_target(true);
+        let allow_super = cursor.set_allow_super(SuperContext::None);
+
+        let params = FormalParameters::new(false, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "generator expression")?;
+        cursor.expect(Punctuator::OpenBlock, "generator expression")?;
+
+        let body = FunctionBody::new(true, false).parse(cursor)?;
+
+        cursor.set_allow_new_target(allow_new_target);
+        cursor.set_allow_super(allow_super);
+
+        check_parameters(
+            &params,
+            &body,
+            cursor.use_strict_directive(),
+            false,
+            params_start,
+        )?;
+
+        cursor.expect(Punctuator::CloseBlock, "generator expression")?;
+
+        Ok(GeneratorExpr::new(name, params, body))
+    }
+}
@@ -8,14 +8,20 @@
 //! [spec]: https://tc39.es/ecma262/#prod-PrimaryExpression
 
 mod array_initializer;
+mod async_function_expression;
+mod class_expression;
 mod function_expression;
+mod generator_expression;
 mod object_initializer;
+mod template;
 #[cfg(test)]
 mod tests;
 
 use self::{
-    array_initializer::ArrayLiteral, function_expression::FunctionExpression,
-    object_initializer::ObjectLiteral,
+    array_initializer::ArrayLiteral, async_function_expression::AsyncFunctionExpression,
+    class_expression::ClassExpression, function_expression::FunctionExpression,
+    generator_expression::GeneratorExpression, object_initializer::ObjectLiteral,
+    template::TemplateLiteral,
 };
 use super::Expression;
 use crate::{
@@ -26,10 +32,14 @@ use crate::{
             Const, Keyword, Punctuator,
         },
         lexer::{token::Numeric, InputElement, TokenKind},
-        parser::{AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
+        parser::{
+            statement::check_escaped_keyword, AllowAwait, AllowYield, Cursor, ParseError,
+            ParseResult, TokenParser,
+        },
     },
 };
-pub(in crate::syntax::parser) use object_initializer::Initializer;
+pub(in crate::syntax::parser) use object_initializer::{Initializer, PropertyName};
+pub(super) use template::TaggedTemplateLiteral;
 
 use std::io::Read;
 
@@ -75,7 +85,16 @@ where
         match tok.kind() {
             TokenKind::Keyword(Keyword::This) => Ok(Node::This),
             TokenKind::Keyword(Keyword::Function) => {
-                FunctionExpression.parse(cursor).map(Node::from)
+                if cursor.next_if(Punctuator::Mul)?.is_some() {
+                    GeneratorExpression.parse(cursor).map(Node::from)
+                } else {
+                    FunctionExpression.parse(cursor).map(Node::from)
+                }
+            }
+            TokenKind::Keyword(Keyword::Class) => {
+                ClassExpression::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Node::from)
             }
             TokenKind::Punctuator(Punctuator::OpenParen) => {
                 cursor.set_goal(InputElement::RegExp);
@@ -98,11 +117,32 @@ where
             }
             TokenKind::BooleanLiteral(boolean) => Ok(Const::from(*boolean).into()),
             TokenKind::NullLiteral => Ok(Const::Null.into()),
-            TokenKind::Identifier(ident) => Ok(Identifier::from(ident.as_ref()).into()), // TODO: IdentifierReference
+            TokenKind::Identifier(ident)
+                if ident.as_ref() == "async"
+                    && matches!(
+                        cursor
+                            .peek_expect_no_lineterminator(0)
+                            .map(|tok| tok.kind()),
+                        Ok(TokenKind::Keyword(Keyword::Function))
+                    ) =>
+            {
+                let _ = cursor.next()?;
+                AsyncFunctionExpression.parse(cursor).map(Node::from)
+            }
+            TokenKind::Identifier(ident) => {
+                // TODO: IdentifierReference
+                check_escaped_keyword(ident, tok.span().start())?;
+                Ok(Identifier::from(ident.as_ref()).into())
+            }
             TokenKind::StringLiteral(s) => Ok(Const::from(s.as_ref()).into()),
             TokenKind::NumericLiteral(Numeric::Integer(num)) => Ok(Const::from(*num).into()),
             TokenKind::NumericLiteral(Numeric::Rational(num)) => Ok(Const::from(*num).into()),
             TokenKind::NumericLiteral(Numeric::BigInt(num)) => Ok(Const::from(num.clone()).into()),
+            TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateHead(_) => {
+                TemplateLiteral::new(self.allow_yield, self.allow_await, tok)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::RegularExpressionLiteral(body, flags) => {
                 Ok(Node::from(New::from(Call::new(
                     Identifier::from("RegExp"),
@@ -9,16 +9,19 @@
 
 #[cfg(test)]
 mod tests;
-use crate::syntax::lexer::TokenKind;
 use crate::{
+    builtins::Number,
     syntax::{
         ast::{
             node::{self, FunctionExpr, MethodDefinitionKind, Node, Object},
             Punctuator,
         },
+        lexer::{token::Numeric, Error as LexError, TokenKind},
         parser::{
+            cursor::SuperContext,
             expression::AssignmentExpression,
-            function::{FormalParameters, FunctionBody},
+            function::{check_parameters, FormalParameters, FunctionBody},
+            statement::check_escaped_keyword,
             AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
@@ -63,14 +66,31 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ObjectLiteral", "Parsing");
         let mut elements = Vec::new();
+        let mut has_proto = false;
 
         loop {
             if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
                 break;
             }
 
-            elements
-                .push(PropertyDefinition::new(self.allow_yield, self.allow_await).parse(cursor)?);
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+            let element =
+                PropertyDefinition::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            // Only the `__proto__: value` properties with a literal name set the prototype.
+            if let node::PropertyDefinition::Property(node::PropertyName::Literal(name), _) =
+                &element
+            {
+                if &**name == "__proto__" {
+                    if has_proto {
+                        return Err(ParseError::lex(LexError::Syntax(
+                            "duplicate __proto__ fields are not allowed in object literals".into(),
+                            position,
+                        )));
+                    }
+                    has_proto = true;
+                }
+            }
+            elements.push(element);
 
             if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
                 break;
@@ -134,122 +154,246 @@ where
             return Ok(node::PropertyDefinition::SpreadObject(node));
         }
 
-        let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
-        if cursor.next_if(Punctuator::Colon)?.is_some() {
-            let val = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
-                .parse(cursor)?;
-            return Ok(node::PropertyDefinition::property(prop_name, val));
+        if cursor.next_if(Punctuator::Mul)?.is_some() {
+            return MethodDefinition::new(
+                self.allow_yield,
+                self.allow_await,
+                MethodDefinitionKind::Generator,
+            )
+            .parse(cursor);
         }
 
-        if cursor
-            .next_if(TokenKind::Punctuator(Punctuator::OpenParen))?
-            .is_some()
-            || ["get", "set"].contains(&prop_name.as_str())
-        {
-            return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name)
-                .parse(cursor);
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let name = match tok.kind() {
+            TokenKind::Identifier(name) => Some((name.clone(), tok.span().start())),
+            _ => None,
+        };
+        if let Some((name, position)) = name {
+            match cursor.peek(1)?.map(|tok| tok.kind()) {
+                // Shorthand properties, like `{ a }`, or `{ a = 1 }` if this is an assignment
+                // pattern.
+                Some(TokenKind::Punctuator(Punctuator::Comma))
+                | Some(TokenKind::Punctuator(Punctuator::CloseBlock)) => {
+                    check_escaped_keyword(&name, position)?;
+                    let _ = cursor.next()?;
+                    return Ok(node::PropertyDefinition::identifier_reference(name));
+                }
+                Some(TokenKind::Punctuator(Punctuator::Assign)) => {
+                    check_escaped_keyword(&name, position)?;
+                    let _ = cursor.next()?;
+                    let init =
+                        Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    return Ok(node::PropertyDefinition::CoverInitializedName(name, init));
+                }
+                // `get`, `set` and `async` are only modifiers when they are followed by the name
+                // of the method, otherwise they are the name of the property.
+                Some(TokenKind::Punctuator(Punctuator::OpenParen))
+                | Some(TokenKind::Punctuator(Punctuator::Colon)) => {}
+                _ => {
+                    let kind = match &*name {
+                        "get" => Some(MethodDefinitionKind::Get),
+                        "set" => Some(MethodDefinitionKind::Set),
+                        "async" => Some(MethodDefinitionKind::Async),
+                        _ => None,
+                    };
+                    if let Some(kind) = kind {
+                        let _ = cursor.next()?;
+                        return MethodDefinition::new(self.allow_yield, self.allow_await, kind)
+                            .parse(cursor);
+                    }
+                }
+            }
         }
 
-        let pos = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
-        Err(ParseError::general("expected property definition", pos))
+        let name = PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let next_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        match next_token.kind() {
+            TokenKind::Punctuator(Punctuator::Colon) => {
+                let _ = cursor.next()?;
+                let value = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                    .parse(cursor)?;
+                Ok(node::PropertyDefinition::property(name, value))
+            }
+            TokenKind::Punctuator(Punctuator::OpenParen) => MethodDefinition::new(
+                self.allow_yield,
+                self.allow_await,
+                MethodDefinitionKind::Ordinary,
+            )
+            .parse_after_name(cursor, name),
+            _ => Err(ParseError::general(
+                "expected property definition",
+                next_token.span().start(),
+            )),
+        }
     }
 }
 
-/// Parses a method definition.
+/// Parses the name of a property, which can be computed.
 ///
 /// More information:
 ///  - [ECMAScript specification][spec]
 ///
-/// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
-#[derive(Debug, Clone)]
-struct MethodDefinition {
+/// [spec]: https://tc39.es/ecma262/#prod-PropertyName
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct PropertyName {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
-    identifier: String,
 }
 
-impl MethodDefinition {
-    /// Creates a new `MethodDefinition` parser.
-    fn new<Y, A, I>(allow_yield: Y, allow_await: A, identifier: I) -> Self
+impl PropertyName {
+    /// Creates a new `PropertyName` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
-        I: Into<String>,
     {
         Self {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
-            identifier: identifier.into(),
         }
     }
 }
 
-impl<R> TokenParser<R> for MethodDefinition
+impl<R> TokenParser<R> for PropertyName
 where
     R: Read,
 {
-    type Output = node::PropertyDefinition;
+    type Output = node::PropertyName;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        let _timer = BoaProfiler::global().start_event("MethodDefinition", "Parsing");
+        let _timer = BoaProfiler::global().start_event("PropertyName", "Parsing");
 
-        let (methodkind, prop_name, params) = match self.identifier.as_str() {
-            idn @ "get" | idn @ "set" => {
-                let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
-                cursor.expect(
-                    TokenKind::Punctuator(Punctuator::OpenParen),
-                    "property method definition",
-                )?;
-                let first_param = cursor.peek(0)?.expect("current token disappeared").clone();
-                let params = FormalParameters::new(false, false).parse(cursor)?;
-                cursor.expect(Punctuator::CloseParen, "method definition")?;
-                if idn == "get" {
-                    if !params.is_empty() {
-                        return Err(ParseError::unexpected(
-                            first_param,
-                            "getter functions must have no arguments",
-                        ));
-                    }
-                    (MethodDefinitionKind::Get, prop_name, params)
-                } else {
-                    if params.len() != 1 {
-                        return Err(ParseError::unexpected(
-                            first_param,
-                            "setter functions must have one argument",
-                        ));
-                    }
-                    (MethodDefinitionKind::Set, prop_name, params)
-                }
+        let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        let name = match token.kind() {
+            TokenKind::Identifier(name) | TokenKind::StringLiteral(name) => name.clone(),
+            TokenKind::Keyword(keyword) => keyword.as_str().into(),
+            TokenKind::BooleanLiteral(boolean) => boolean.to_string().into(),
+            TokenKind::NullLiteral => "null".into(),
+            TokenKind::NumericLiteral(Numeric::Integer(num)) => num.to_string().into(),
+            TokenKind::NumericLiteral(Numeric::Rational(num)) => {
+                Number::to_native_string(*num).into()
             }
-            prop_name => {
-                let params = FormalParameters::new(false, false).parse(cursor)?;
-                cursor.expect(Punctuator::CloseParen, "method definition")?;
-                (
-                    MethodDefinitionKind::Ordinary,
-                    prop_name.to_string(),
-                    params,
-                )
+            TokenKind::NumericLiteral(Numeric::BigInt(num)) => num.to_string().into(),
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                let node = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                    .parse(cursor)?;
+                cursor.expect(Punctuator::CloseBracket, "computed property name")?;
+                return Ok(node::PropertyName::Computed(node));
+            }
+            _ => {
+                return Err(ParseError::expected(
+                    vec![
+                        TokenKind::identifier("identifier"),
+                        TokenKind::Punctuator(Punctuator::OpenBracket),
+                    ],
+                    token,
+                    "property name",
+                ))
             }
         };
+        Ok(node::PropertyName::Literal(name))
+    }
+}
 
-        cursor.expect(
-            TokenKind::Punctuator(Punctuator::OpenBlock),
-            "property method definition",
-        )?;
-        let body = FunctionBody::new(false, false).parse(cursor)?;
-        cursor.expect(
-            TokenKind::Punctuator(Punctuator::CloseBlock),
-            "property method definition",
+/// Parses a method definition, after the `get`, `set`, `async` or `*` that gives its kind.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
+#[derive(Debug, Clone, Copy)]
+struct MethodDefinition {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    kind: MethodDefinitionKind,
+}
+
+impl MethodDefinition {
+    /// Creates a new `MethodDefinition` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A, kind: MethodDefinitionKind) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            kind,
+        }
+    }
+
+    /// Parses the parameters and the body of the method, once its name is parsed.
+    fn parse_after_name<R>(
+        self,
+        cursor: &mut Cursor<R>,
+        name: node::PropertyName,
+    ) -> Result<node::PropertyDefinition, ParseError>
+    where
+        R: Read,
+    {
+        let generator = self.kind == MethodDefinitionKind::Generator;
+        let is_async = self.kind == MethodDefinitionKind::Async;
+
+        let params_start = cursor
+            .expect(Punctuator::OpenParen, "method definition")?
+            .span()
+            .start();
+        let first_param = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+        let allow_new_target = cursor.set_allow_new_target(true);
+        let allow_super = cursor.set_allow_super(SuperContext::Property);
+        let params = FormalParameters::new(false, is_async).parse(cursor)?;
+        cursor.expect(Punctuator::CloseParen, "method definition")?;
+        match self.kind {
+            MethodDefinitionKind::Get if !params.is_empty() => {
+                return Err(ParseError::unexpected(
+                    first_param,
+                    "getter functions must have no arguments",
+                ));
+            }
+            MethodDefinitionKind::Set if params.len() != 1 => {
+                return Err(ParseError::unexpected(
+                    first_param,
+                    "setter functions must have one argument",
+                ));
+            }
+            _ => {}
+        }
+
+        cursor.expect(Punctuator::OpenBlock, "method definition")?;
+        let body = FunctionBody::new(generator, is_async).parse(cursor)?;
+        cursor.set_allow_new_target(allow_new_target);
+        cursor.set_allow_super(allow_super);
+        check_parameters(
+            &params,
+            &body,
+            cursor.use_strict_directive(),
+            true,
+            params_start,
         )?;
+        cursor.expect(Punctuator::CloseBlock, "method definition")?;
 
         Ok(node::PropertyDefinition::method_definition(
-            methodkind,
-            prop_name,
+            self.kind,
+            name,
             FunctionExpr::new(None, params, body),
         ))
     }
 }
 
+impl<R> TokenParser<R> for MethodDefinition
+where
+    R: Read,
+{
+    type Output = node::PropertyDefinition;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("MethodDefinition", "Parsing");
+
+        let name = PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        self.parse_after_name(cursor, name)
+    }
+}
+
 /// Initializer parsing.
 ///
 /// More information:
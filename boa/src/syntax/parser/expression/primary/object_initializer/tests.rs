@@ -1,12 +1,12 @@
 use crate::syntax::{
     ast::{
         node::{
-            ConstDecl, ConstDeclList, FormalParameter, FunctionExpr, MethodDefinitionKind, Object,
-            PropertyDefinition,
+            ConstDecl, ConstDeclList, FormalParameter, FunctionExpr, Identifier,
+            MethodDefinitionKind, Object, PropertyDefinition, PropertyName, Yield,
         },
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks object literal parsing.
@@ -31,6 +31,25 @@ fn check_object_literal() {
     );
 }
 
+/// Checks shorthand properties.
+#[test]
+fn check_object_shorthand_property() {
+    let object_properties = vec![
+        PropertyDefinition::identifier_reference("a"),
+        PropertyDefinition::property("b", Const::from(1)),
+        PropertyDefinition::identifier_reference("c"),
+    ];
+
+    check_parser(
+        "const x = { a, b: 1, c };",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "x",
+            Some(Object::from(object_properties)),
+        )])
+        .into()],
+    );
+}
+
 /// Tests short function syntax.
 #[test]
 fn check_object_short_function() {
@@ -57,6 +76,28 @@ fn check_object_short_function() {
     );
 }
 
+/// Tests short generator method syntax.
+#[test]
+fn check_object_generator_method() {
+    let object_properties = vec![PropertyDefinition::method_definition(
+        MethodDefinitionKind::Generator,
+        "g",
+        FunctionExpr::new(None, vec![], vec![Yield::new(Const::from(1), false).into()]),
+    )];
+
+    check_parser(
+        "const x = {
+            *g() { yield 1; },
+        };
+        ",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "x",
+            Some(Object::from(object_properties)),
+        )])
+        .into()],
+    );
+}
+
 /// Testing short function syntax with arguments.
 #[test]
 fn check_object_short_function_arguments() {
@@ -140,3 +181,90 @@ fn check_object_setter() {
         .into()],
     );
 }
+
+/// Checks computed property names, of properties and of methods.
+#[test]
+fn check_object_computed_property_names() {
+    let object_properties = vec![
+        PropertyDefinition::property(
+            PropertyName::Computed(Identifier::from("a").into()),
+            Const::from(1),
+        ),
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Get,
+            PropertyName::Computed(Identifier::from("b").into()),
+            FunctionExpr::new(None, vec![], vec![]),
+        ),
+        PropertyDefinition::property("2", Const::from(3)),
+    ];
+
+    check_parser(
+        "const x = { [a]: 1, get [b]() {}, 2: 3 };",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "x",
+            Some(Object::from(object_properties)),
+        )])
+        .into()],
+    );
+}
+
+/// Checks that `get`, `set` and `async` can be the names of properties and methods.
+#[test]
+fn check_object_modifier_names() {
+    let object_properties = vec![
+        PropertyDefinition::property("get", Const::from(1)),
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Ordinary,
+            "set",
+            FunctionExpr::new(None, vec![], vec![]),
+        ),
+        PropertyDefinition::identifier_reference("async"),
+    ];
+
+    check_parser(
+        "const x = { get: 1, set() {}, async };",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "x",
+            Some(Object::from(object_properties)),
+        )])
+        .into()],
+    );
+}
+
+/// Checks spread properties.
+#[test]
+fn check_object_spread() {
+    let object_properties = vec![
+        PropertyDefinition::property("a", Const::from(1)),
+        PropertyDefinition::SpreadObject(Identifier::from("b").into()),
+    ];
+
+    check_parser(
+        "const x = { a: 1, ...b };",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "x",
+            Some(Object::from(object_properties)),
+        )])
+        .into()],
+    );
+}
+
+#[test]
+fn check_object_duplicate_proto() {
+    check_invalid("({ __proto__: a, __proto__: b })");
+    check_invalid("({ __proto__: a, '__proto__': b })");
+
+    // Only the properties that set the prototype count.
+    check_parser(
+        "({ __proto__: a, ['__proto__']: b, __proto__ })",
+        vec![Object::from(vec![
+            PropertyDefinition::property("__proto__", Identifier::from("a")),
+            PropertyDefinition::property(
+                PropertyName::Computed(Const::from("__proto__").into()),
+                Identifier::from("b"),
+            ),
+            PropertyDefinition::identifier_reference("__proto__"),
+        ])
+        .into()],
+    );
+}
@@ -0,0 +1,192 @@
+//! Template literal parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
+//! [spec]: https://tc39.es/ecma262/#sec-template-literals
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    profiler::BoaProfiler,
+    syntax::{
+        ast::{
+            node::{Node, TaggedTemplate, TemplateLit},
+            Punctuator,
+        },
+        lexer::{token::TemplateString, Error as LexError, Token, TokenKind},
+        parser::{expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, TokenParser},
+    },
+};
+
+use std::io::Read;
+
+/// Parses a template literal.
+///
+/// The first part of the template must already be consumed by the cursor, and is given to the
+/// parser.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
+/// [spec]: https://tc39.es/ecma262/#prod-TemplateLiteral
+#[derive(Debug, Clone)]
+pub(in crate::syntax::parser::expression) struct TemplateLiteral {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    first: Token,
+}
+
+impl TemplateLiteral {
+    /// Creates a new `TemplateLiteral` parser.
+    pub(in crate::syntax::parser::expression) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        first: Token,
+    ) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            first,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for TemplateLiteral
+where
+    R: Read,
+{
+    type Output = TemplateLit;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("TemplateLiteral", "Parsing");
+
+        let position = self.first.span().start();
+        let (parts, exprs) = parse_parts(cursor, self.first, self.allow_yield, self.allow_await)?;
+        let strings = parts
+            .iter()
+            .map(|part| part.cooked().map(Box::from))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| {
+                // Invalid escape sequences are only allowed in tagged templates.
+                ParseError::lex(LexError::Syntax(
+                    "invalid escape sequence in template literal".into(),
+                    position,
+                ))
+            })?;
+
+        Ok(TemplateLit::new(strings, exprs))
+    }
+}
+
+/// Parses the template of a tagged template.
+///
+/// The first part of the template must already be consumed by the cursor, and is given to the
+/// parser.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals#tagged_templates
+/// [spec]: https://tc39.es/ecma262/#sec-tagged-templates
+#[derive(Debug)]
+pub(in crate::syntax::parser::expression) struct TaggedTemplateLiteral {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    first: Token,
+    tag: Node,
+}
+
+impl TaggedTemplateLiteral {
+    /// Creates a new `TaggedTemplateLiteral` parser.
+    pub(in crate::syntax::parser::expression) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        first: Token,
+        tag: Node,
+    ) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            first,
+            tag,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for TaggedTemplateLiteral
+where
+    R: Read,
+{
+    type Output = TaggedTemplate;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("TaggedTemplateLiteral", "Parsing");
+
+        let (parts, exprs) = parse_parts(cursor, self.first, self.allow_yield, self.allow_await)?;
+        let raws: Vec<Box<str>> = parts.iter().map(|part| part.raw().into()).collect();
+        let cookeds: Vec<Option<Box<str>>> = parts
+            .iter()
+            .map(|part| part.cooked().map(Box::from))
+            .collect();
+
+        Ok(TaggedTemplate::new(self.tag, raws, cookeds, exprs))
+    }
+}
+
+/// Parses the substitutions of a template and the parts of the template between them, starting
+/// from the first part.
+fn parse_parts<R>(
+    cursor: &mut Cursor<R>,
+    first: Token,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+) -> Result<(Vec<TemplateString>, Vec<Node>), ParseError>
+where
+    R: Read,
+{
+    let mut parts = Vec::new();
+    let mut exprs = Vec::new();
+    match first.kind() {
+        TokenKind::TemplateNoSubstitution(string) => {
+            parts.push(string.clone());
+            return Ok((parts, exprs));
+        }
+        TokenKind::TemplateHead(string) => parts.push(string.clone()),
+        _ => return Err(ParseError::unexpected(first, "template literal")),
+    }
+
+    loop {
+        exprs.push(Expression::new(true, allow_yield, allow_await).parse(cursor)?);
+
+        let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        match token.kind() {
+            TokenKind::TemplateMiddle(string) => parts.push(string.clone()),
+            TokenKind::TemplateTail(string) => {
+                parts.push(string.clone());
+                return Ok((parts, exprs));
+            }
+            _ => {
+                return Err(ParseError::expected(
+                    vec![TokenKind::Punctuator(Punctuator::CloseBlock)],
+                    token,
+                    "template literal",
+                ))
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::syntax::{
+    ast::{
+        node::{BinOp, Call, GetConstField, Identifier, TaggedTemplate, TemplateLit},
+        op::NumOp,
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks a template literal without substitutions.
+#[test]
+fn check_template_no_substitution() {
+    check_parser(
+        "`hello\\nworld`",
+        vec![TemplateLit::new(vec!["hello\nworld".into()], vec![]).into()],
+    );
+}
+
+/// Checks a template literal with substitutions.
+#[test]
+fn check_template_substitutions() {
+    check_parser(
+        "`a${b}c${d + 1}`",
+        vec![TemplateLit::new(
+            vec!["a".into(), "c".into(), "".into()],
+            vec![
+                Identifier::from("b").into(),
+                BinOp::new(NumOp::Add, Identifier::from("d"), Const::from(1)).into(),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// Checks a template literal nested in a substitution of another one.
+#[test]
+fn check_nested_template() {
+    check_parser(
+        "`a${`b${c}`}d`",
+        vec![TemplateLit::new(
+            vec!["a".into(), "d".into()],
+            vec![TemplateLit::new(
+                vec!["b".into(), "".into()],
+                vec![Identifier::from("c").into()],
+            )
+            .into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks tagged templates, with the raw and cooked strings.
+#[test]
+fn check_tagged_template() {
+    check_parser(
+        "obj.tag`a\\u{62}${c}\\unicode`",
+        vec![TaggedTemplate::new(
+            GetConstField::new(Identifier::from("obj"), "tag"),
+            vec!["a\\u{62}".into(), "\\unicode".into()],
+            vec![Some("ab".into()), None],
+            vec![Identifier::from("c").into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks tagged templates in call expressions.
+#[test]
+fn check_tagged_template_call() {
+    check_parser(
+        "f()`a`",
+        vec![TaggedTemplate::new(
+            Call::new(Identifier::from("f"), vec![]),
+            vec!["a".into()],
+            vec![Some("a".into())],
+            vec![],
+        )
+        .into()],
+    );
+}
+
+/// Checks that invalid escape sequences are errors in untagged templates.
+#[test]
+fn check_invalid_escape() {
+    check_invalid("`\\unicode`");
+    check_invalid("`${a}\\x0`");
+}
+
+/// Checks unterminated substitutions.
+#[test]
+fn check_unterminated_substitution() {
+    check_invalid("`${a`");
+    check_invalid("`${a;}`");
+}
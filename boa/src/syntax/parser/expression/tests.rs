@@ -1,10 +1,10 @@
 use crate::syntax::{
-    ast::op::{AssignOp, BitOp, CompOp, NumOp},
+    ast::op::{AssignOp, BitOp, CompOp, LogOp, NumOp},
     ast::{
-        node::{BinOp, Identifier},
+        node::{Assign, BinOp, Identifier},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks numeric operations
@@ -182,6 +182,33 @@ fn check_assign_operations() {
         "a >>>= b",
         vec![BinOp::new(AssignOp::Ushr, Identifier::from("a"), Identifier::from("b")).into()],
     );
+    check_parser(
+        "a &&= b",
+        vec![BinOp::new(
+            AssignOp::BoolAnd,
+            Identifier::from("a"),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
+    check_parser(
+        "a ||= b",
+        vec![BinOp::new(
+            AssignOp::BoolOr,
+            Identifier::from("a"),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
+    check_parser(
+        "a ??= b",
+        vec![BinOp::new(
+            AssignOp::Coalesce,
+            Identifier::from("a"),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
     check_parser(
         "a %= 10 / 2",
         vec![BinOp::new(
@@ -236,3 +263,74 @@ fn check_relational_operations() {
         vec![BinOp::new(CompOp::In, Identifier::from("p"), Identifier::from("o")).into()],
     );
 }
+
+#[test]
+fn check_logical_operations() {
+    check_parser(
+        "a || b && c",
+        vec![BinOp::new(
+            LogOp::Or,
+            Identifier::from("a"),
+            BinOp::new(LogOp::And, Identifier::from("b"), Identifier::from("c")),
+        )
+        .into()],
+    );
+    check_parser(
+        "a ?? b ?? c | d",
+        vec![BinOp::new(
+            LogOp::Coalesce,
+            BinOp::new(
+                LogOp::Coalesce,
+                Identifier::from("a"),
+                Identifier::from("b"),
+            ),
+            BinOp::new(BitOp::Or, Identifier::from("c"), Identifier::from("d")),
+        )
+        .into()],
+    );
+    check_parser(
+        "(a || b) ?? c",
+        vec![BinOp::new(
+            LogOp::Coalesce,
+            BinOp::new(LogOp::Or, Identifier::from("a"), Identifier::from("b")),
+            Identifier::from("c"),
+        )
+        .into()],
+    );
+    check_parser(
+        "a ?? (b && c)",
+        vec![BinOp::new(
+            LogOp::Coalesce,
+            Identifier::from("a"),
+            BinOp::new(LogOp::And, Identifier::from("b"), Identifier::from("c")),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn check_mixed_coalesce_is_invalid() {
+    check_invalid("a || b ?? c");
+    check_invalid("a && b ?? c");
+    check_invalid("a ?? b || c");
+    check_invalid("a ?? b && c");
+}
+
+#[test]
+fn check_invalid_assignment_targets() {
+    check_invalid("a + b += 1");
+    check_invalid("f() += 1");
+    check_invalid("++1");
+    check_invalid("f()--");
+    check_invalid("'use strict'; eval = 1");
+    check_invalid("'use strict'; [arguments] = a");
+    check_invalid("'use strict'; arguments++");
+    check_invalid("'use strict'; --eval");
+    check_invalid("({ a }) = b");
+    check_invalid("([a]) = b");
+    check_invalid("(({ a })) = b");
+    check_parser(
+        "eval = 1",
+        vec![Assign::new(Identifier::from("eval"), Const::from(1)).into()],
+    );
+}
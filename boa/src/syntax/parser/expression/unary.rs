@@ -76,6 +76,20 @@ where
                         )));
                     }
                 }
+                let private_member = match val {
+                    Node::GetPrivateField(_) => true,
+                    Node::Optional(ref optional) => matches!(
+                        optional.chain().last().map(node::OptionalOperation::kind),
+                        Some(node::OptionalOperationKind::GetPrivateField(_))
+                    ),
+                    _ => false,
+                };
+                if private_member {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        "Private fields can not be deleted".into(),
+                        token_start,
+                    )));
+                }
 
                 Ok(node::UnaryOp::new(UnaryOp::Delete, val).into())
             }
@@ -87,6 +101,10 @@ where
                 cursor.next()?.expect("TypeOf keyword vanished"); // Consume the token.
                 Ok(node::UnaryOp::new(UnaryOp::TypeOf, self.parse(cursor)?).into())
             }
+            TokenKind::Keyword(Keyword::Await) if self.allow_await.0 => {
+                cursor.next()?.expect("Await keyword vanished"); // Consume the token.
+                Ok(node::Await::new(self.parse(cursor)?).into())
+            }
             TokenKind::Punctuator(Punctuator::Add) => {
                 cursor.next()?.expect("+ token vanished"); // Consume the token.
                 Ok(node::UnaryOp::new(UnaryOp::Plus, self.parse(cursor)?).into())
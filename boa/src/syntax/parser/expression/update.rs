@@ -5,12 +5,12 @@
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-update-expressions
 
-use super::left_hand_side::LeftHandSideExpression;
+use super::{assignment::is_assignable, left_hand_side::LeftHandSideExpression};
 use crate::{
     profiler::BoaProfiler,
     syntax::{
         ast::{node, op::UnaryOp, Node, Punctuator},
-        lexer::TokenKind,
+        lexer::{Error as LexError, Position, TokenKind},
         parser::{AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
     },
 };
@@ -55,28 +55,40 @@ where
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
         match tok.kind() {
             TokenKind::Punctuator(Punctuator::Inc) => {
-                cursor.next()?.expect("Punctuator::Inc token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::IncrementPre,
-                    LeftHandSideExpression::new(self.allow_yield, self.allow_await)
-                        .parse(cursor)?,
-                )
-                .into());
+                let position = cursor
+                    .next()?
+                    .expect("Punctuator::Inc token disappeared")
+                    .span()
+                    .start();
+                let target = LeftHandSideExpression::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)?;
+                check_target(&target, cursor.strict_mode(), position, "prefix")?;
+                return Ok(node::UnaryOp::new(UnaryOp::IncrementPre, target).into());
             }
             TokenKind::Punctuator(Punctuator::Dec) => {
-                cursor.next()?.expect("Punctuator::Dec token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::DecrementPre,
-                    LeftHandSideExpression::new(self.allow_yield, self.allow_await)
-                        .parse(cursor)?,
-                )
-                .into());
+                let position = cursor
+                    .next()?
+                    .expect("Punctuator::Dec token disappeared")
+                    .span()
+                    .start();
+                let target = LeftHandSideExpression::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)?;
+                check_target(&target, cursor.strict_mode(), position, "prefix")?;
+                return Ok(node::UnaryOp::new(UnaryOp::DecrementPre, target).into());
             }
             _ => {}
         }
 
+        let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
         let lhs = LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let strict = cursor.strict_mode();
         if let Some(tok) = cursor.peek(0)? {
+            if matches!(
+                tok.kind(),
+                TokenKind::Punctuator(Punctuator::Inc) | TokenKind::Punctuator(Punctuator::Dec)
+            ) {
+                check_target(&lhs, strict, position, "postfix")?;
+            }
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Inc) => {
                     cursor.next()?.expect("Punctuator::Inc token disappeared");
@@ -93,3 +105,24 @@ where
         Ok(lhs)
     }
 }
+
+/// Checks that the target of an update expression is a simple assignment target.
+fn check_target(
+    target: &Node,
+    strict: bool,
+    position: Position,
+    operation: &str,
+) -> Result<(), ParseError> {
+    if is_assignable(target, strict) {
+        Ok(())
+    } else {
+        Err(ParseError::lex(LexError::Syntax(
+            format!(
+                "Invalid left-hand side expression in {} operation",
+                operation
+            )
+            .into(),
+            position,
+        )))
+    }
+}
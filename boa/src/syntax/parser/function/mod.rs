@@ -14,12 +14,12 @@ use crate::{
     syntax::{
         ast::{
             node::{self},
-            Punctuator,
+            Position, Punctuator,
         },
-        lexer::{InputElement, TokenKind},
+        lexer::{Error as LexError, InputElement, TokenKind},
         parser::{
             expression::Initializer,
-            statement::{BindingIdentifier, StatementList},
+            statement::{check_redeclared_names, BindingTarget, StatementList},
             AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
         },
     },
@@ -154,8 +154,7 @@ where
         let _timer = BoaProfiler::global().start_event("BindingRestElement", "Parsing");
         cursor.expect(Punctuator::Spread, "rest parameter")?;
 
-        let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-        // TODO: BindingPattern
+        let param = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         Ok(Self::Output::new(param, None, true))
     }
@@ -198,9 +197,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("FormalParameter", "Parsing");
 
-        // TODO: BindingPattern
-
-        let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let param = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         let init = if let Some(t) = cursor.peek(0)? {
             // Check that this is an initilizer before attempting parse.
@@ -262,24 +259,94 @@ where
 
         let global_strict_mode = cursor.strict_mode();
         if let Some(tk) = cursor.peek(0)? {
-            match tk.kind() {
-                TokenKind::Punctuator(Punctuator::CloseBlock) => {
-                    return Ok(Vec::new().into());
-                }
-                TokenKind::StringLiteral(string) | TokenKind::TemplateLiteral(string) => {
-                    if string == &"use strict".into() {
-                        cursor.set_strict_mode(true);
-                    }
-                }
-                _ => {}
+            if tk.kind() == &TokenKind::Punctuator(Punctuator::CloseBlock) {
+                let mut stmlist = node::StatementList::from(Vec::new());
+                stmlist.set_strict(global_strict_mode);
+                return Ok(stmlist);
             }
         }
 
-        let stmlist =
-            StatementList::new(self.allow_yield, self.allow_await, true, true, true).parse(cursor);
+        let labels = cursor.take_labels();
+        let breakables = cursor.take_breakables();
+        let stmlist = StatementList::new(self.allow_yield, self.allow_await, true, true)
+            .parse_with_directives(cursor);
 
-        // Reset strict mode back to the global scope.
+        // Reset strict mode, labels and breakable statements back to the enclosing scope.
         cursor.set_strict_mode(global_strict_mode);
+        cursor.restore_labels(labels);
+        cursor.restore_breakables(breakables);
         stmlist
     }
 }
+
+/// Checks that the parameters of a function that is strict mode code don't have duplicate names,
+/// that a function with a `"use strict"` directive has a simple parameter list, and that the
+/// lexical declarations at the top level of the body don't redeclare a parameter.
+///
+/// The parameters of arrow functions and methods, given with `unique`, and parameter lists that
+/// are not simple can't have duplicate names either.
+///
+/// This can only be checked once the body of the function is parsed, since a `"use strict"`
+/// directive in the body makes the parameters strict mode code too. `use_strict_directive` tells
+/// if the directive prologue of the body contains that directive.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
+pub(in crate::syntax::parser) fn check_parameters(
+    params: &[node::FormalParameter],
+    body: &node::StatementList,
+    use_strict_directive: bool,
+    unique: bool,
+    position: Position,
+) -> Result<(), ParseError> {
+    if use_strict_directive && !is_simple_parameter_list(params) {
+        return Err(ParseError::lex(LexError::Syntax(
+            "\"use strict\" not allowed in a function with a non-simple parameter list".into(),
+            position,
+        )));
+    }
+    let mut names = Vec::new();
+    for param in params {
+        param.binding().bound_names(&mut names);
+    }
+    let mut lexical_names = Vec::new();
+    for node in body.statements() {
+        node.lexically_declared_names(&mut lexical_names, false);
+    }
+    check_redeclared_names(&names, &lexical_names, position)?;
+
+    if !body.strict() && !unique && is_simple_parameter_list(params) {
+        return Ok(());
+    }
+    for (i, name) in names.iter().enumerate() {
+        if names[..i].contains(name) {
+            let message = if body.strict() {
+                format!(
+                    "duplicate parameter name '{}' not allowed in strict mode",
+                    name
+                )
+            } else {
+                format!("duplicate parameter name '{}' not allowed here", name)
+            };
+            return Err(ParseError::lex(LexError::Syntax(message.into(), position)));
+        }
+    }
+    Ok(())
+}
+
+/// Checks if a parameter list is simple, that is, it only has names, without patterns, default
+/// values or a rest parameter.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-issimpleparameterlist
+fn is_simple_parameter_list(params: &[node::FormalParameter]) -> bool {
+    params.iter().all(|param| {
+        !param.is_rest_param()
+            && param.init().is_none()
+            && matches!(param.binding(), node::Binding::Identifier(_))
+    })
+}
@@ -1,9 +1,10 @@
 use crate::syntax::{
     ast::node::{
-        ArrowFunctionDecl, BinOp, FormalParameter, FunctionDecl, Identifier, Node, Return,
+        ArrowFunctionDecl, AsyncArrowFunctionDecl, Await, BinOp, Call, FormalParameter,
+        FunctionDecl, Identifier, Node, Return,
     },
     ast::op::NumOp,
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks basic function declaration parsing.
@@ -176,3 +177,45 @@ fn check_arrow_empty_return_semicolon_insertion() {
         .into()],
     );
 }
+
+/// Checks an async arrow function with parenthesized parameters.
+#[test]
+fn check_async_arrow() {
+    check_parser(
+        "async (a, ...b) => await a",
+        vec![AsyncArrowFunctionDecl::new(
+            vec![
+                FormalParameter::new("a", None, false),
+                FormalParameter::new("b", None, true),
+            ],
+            vec![Return::new(Await::new(Identifier::from("a")), None).into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks an async arrow function with a single parameter.
+#[test]
+fn check_async_arrow_single_param() {
+    check_parser(
+        "async a => {}",
+        vec![
+            AsyncArrowFunctionDecl::new(vec![FormalParameter::new("a", None, false)], vec![])
+                .into(),
+        ],
+    );
+}
+
+/// Checks that `async(a)` without an arrow is a call.
+#[test]
+fn check_async_call() {
+    check_parser(
+        "async(a)",
+        vec![Call::new(
+            Identifier::from("async"),
+            vec![Identifier::from("a").into()],
+        )
+        .into()],
+    );
+    check_invalid("async (a + 1) => {}");
+}
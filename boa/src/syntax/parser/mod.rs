@@ -1,17 +1,22 @@
 //! Boa parser implementation.
 
+mod class;
 mod cursor;
 pub mod error;
 mod expression;
 mod function;
+mod module;
 mod statement;
 #[cfg(test)]
 mod tests;
 
 pub use self::error::{ParseError, ParseResult};
-use crate::syntax::{ast::node::StatementList, lexer::TokenKind};
+use crate::syntax::ast::{
+    node::{FormalParameter, ModuleItemList, StatementList},
+    Position, Punctuator,
+};
 
-use cursor::Cursor;
+use cursor::{Cursor, SuperContext};
 
 use std::io::Read;
 
@@ -97,12 +102,105 @@ impl<R> Parser<R> {
         }
     }
 
+    /// Allows or forbids the web compatibility syntax of Annex B of the specification, like
+    /// HTML-like comments, legacy octal literals and duplicate function declarations in blocks.
+    ///
+    /// It is allowed by default, but never in modules.
+    pub fn set_annex_b(&mut self, annex_b: bool)
+    where
+        R: Read,
+    {
+        self.cursor.set_annex_b(annex_b);
+    }
+
     pub fn parse_all(&mut self) -> Result<StatementList, ParseError>
     where
         R: Read,
     {
         Script.parse(&mut self.cursor)
     }
+
+    /// Parses the source code as a module, rather than as a script.
+    ///
+    /// Module code is always strict mode code, and it can have `import` and `export`
+    /// declarations.
+    pub fn parse_module(&mut self) -> Result<ModuleItemList, ParseError>
+    where
+        R: Read,
+    {
+        module::Module.parse(&mut self.cursor)
+    }
+
+    /// Parses the source code of an `eval` call as a script.
+    ///
+    /// The code of a direct `eval` called from strict mode code is strict mode code, even
+    /// without a `"use strict"` directive. The code of a direct `eval` called from a function
+    /// that is not an arrow function can use `new.target`, and the code of a direct `eval`
+    /// called from the body of a class can use the `private_names` of the enclosing classes.
+    pub fn parse_eval(
+        &mut self,
+        strict: bool,
+        in_function: bool,
+        private_names: &[Box<str>],
+    ) -> Result<StatementList, ParseError>
+    where
+        R: Read,
+    {
+        self.cursor.set_strict_mode(strict);
+        self.cursor.set_allow_new_target(in_function);
+        // The uses of `super` are checked when the code is run, since the context of the `eval`
+        // call is not known here.
+        self.cursor.set_allow_super(SuperContext::Call);
+        self.cursor.push_private_environment();
+        let script = Script.parse(&mut self.cursor)?;
+        self.cursor.pop_private_environment(private_names)?;
+        Ok(script)
+    }
+}
+
+/// Parses the parameters and the body of a function created from source text at runtime, like
+/// the functions of the `Function` constructor.
+///
+/// The parameters and the body are parsed separately, so that neither of them can close the
+/// function early, as in `new Function("a) {", "}")`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createdynamicfunction
+pub fn parse_function(
+    params: &str,
+    body: &str,
+    annex_b: bool,
+) -> Result<(Box<[FormalParameter]>, StatementList), ParseError> {
+    let mut cursor = Cursor::new(body.as_bytes());
+    cursor.set_allow_new_target(true);
+    cursor.set_annex_b(annex_b);
+    let body = statement::StatementList::new(false, false, true, false)
+        .parse_with_directives(&mut cursor)?;
+    let use_strict_directive = cursor.use_strict_directive();
+
+    // The line terminator keeps a single line comment at the end of the parameters from
+    // hiding the closing parenthesis.
+    let params = format!("{}\n)", params);
+    let mut cursor = Cursor::new(params.as_bytes());
+    cursor.set_allow_new_target(true);
+    cursor.set_annex_b(annex_b);
+    cursor.set_strict_mode(body.strict());
+    let params = function::FormalParameters::new(false, false).parse(&mut cursor)?;
+    cursor.expect(Punctuator::CloseParen, "function parameters")?;
+    if let Some(token) = cursor.next()? {
+        return Err(ParseError::unexpected(token, "function parameters"));
+    }
+
+    function::check_parameters(
+        &params,
+        &body,
+        use_strict_directive,
+        false,
+        Position::new(1, 1),
+    )?;
+    Ok((params, body))
 }
 
 /// Parses a full script.
@@ -122,17 +220,7 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         match cursor.peek(0)? {
-            Some(tok) => {
-                match tok.kind() {
-                    TokenKind::StringLiteral(string) | TokenKind::TemplateLiteral(string) => {
-                        if string.as_ref() == "use strict" {
-                            cursor.set_strict_mode(true);
-                        }
-                    }
-                    _ => {}
-                }
-                ScriptBody.parse(cursor)
-            }
+            Some(_) => ScriptBody.parse(cursor),
             None => Ok(StatementList::from(Vec::new())),
         }
     }
@@ -154,6 +242,7 @@ where
     type Output = StatementList;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        self::statement::StatementList::new(false, false, false, false, true).parse(cursor)
+        self::statement::StatementList::new(false, false, false, false)
+            .parse_with_directives(cursor)
     }
 }
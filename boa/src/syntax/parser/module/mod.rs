@@ -0,0 +1,419 @@
+//! Module parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Modules
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{self, ExportDecl, ExportSpecifier, ImportDecl, ImportName},
+            Keyword, Punctuator,
+        },
+        lexer::{is_reserved_word, Error as LexError, Token, TokenKind},
+        parser::{
+            expression::AssignmentExpression,
+            statement::{
+                BindingIdentifier, ClassDeclaration, Declaration, HoistableDeclaration,
+                StatementListItem, VariableStatement,
+            },
+            Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+use rustc_hash::FxHashSet;
+use std::io::Read;
+
+/// Parses a full module.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-Module
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Module;
+
+impl<R> TokenParser<R> for Module
+where
+    R: Read,
+{
+    type Output = node::ModuleItemList;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("Module", "Parsing");
+        cursor.set_module(true);
+        cursor.set_strict_mode(true);
+        cursor.set_annex_b(false);
+
+        let mut items = Vec::new();
+        let mut export_names = FxHashSet::default();
+        while let Some(token) = cursor.peek(0)? {
+            let position = token.span().start();
+            let item = ModuleItem.parse(cursor)?;
+            if let node::ModuleItem::ExportDeclaration(ref decl) = item {
+                for name in decl.export_names() {
+                    if !export_names.insert(name.to_owned()) {
+                        return Err(ParseError::lex(LexError::Syntax(
+                            format!("duplicate export name '{}'", name).into(),
+                            position,
+                        )));
+                    }
+                }
+            }
+            items.push(item);
+
+            // move the cursor forward for any consecutive semicolon.
+            while cursor.next_if(Punctuator::Semicolon)?.is_some() {}
+        }
+
+        Ok(items.into())
+    }
+}
+
+/// Parses an item of the body of a module.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItem
+#[derive(Debug, Clone, Copy)]
+struct ModuleItem;
+
+impl<R> TokenParser<R> for ModuleItem
+where
+    R: Read,
+{
+    type Output = node::ModuleItem;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let kind = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind().clone();
+        match kind {
+            // `import.meta` starts an expression statement.
+            TokenKind::Keyword(Keyword::Import)
+                if cursor.peek(1)?.map(Token::kind)
+                    != Some(&TokenKind::Punctuator(Punctuator::Dot)) =>
+            {
+                ImportDeclaration.parse(cursor).map(node::ModuleItem::from)
+            }
+            TokenKind::Keyword(Keyword::Export) => {
+                ExportDeclaration.parse(cursor).map(node::ModuleItem::from)
+            }
+            _ => StatementListItem::new(false, false, false)
+                .parse(cursor)
+                .map(node::ModuleItem::from),
+        }
+    }
+}
+
+/// Parses an `import` declaration.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ImportDeclaration;
+
+impl<R> TokenParser<R> for ImportDeclaration
+where
+    R: Read,
+{
+    type Output = ImportDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ImportDeclaration", "Parsing");
+        cursor.expect(Keyword::Import, "import declaration")?;
+
+        // `import "module";`
+        if let Some(TokenKind::StringLiteral(_)) = cursor.peek(0)?.map(Token::kind) {
+            let module = module_specifier(cursor)?;
+            cursor.expect_semicolon("import declaration")?;
+            return Ok(ImportDecl::new(Vec::new(), module));
+        }
+
+        let mut specifiers = Vec::new();
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let has_default = !matches!(
+            tok.kind(),
+            TokenKind::Punctuator(Punctuator::Mul) | TokenKind::Punctuator(Punctuator::OpenBlock)
+        );
+        if has_default {
+            let local_name = BindingIdentifier::new(false, false).parse(cursor)?;
+            specifiers.push(node::ImportSpecifier::new(
+                ImportName::Name("default".into()),
+                local_name,
+            ));
+        }
+
+        if !has_default || cursor.next_if(Punctuator::Comma)?.is_some() {
+            let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+            match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Mul) => {
+                    cursor.expect(TokenKind::identifier("as"), "import declaration")?;
+                    let local_name = BindingIdentifier::new(false, false).parse(cursor)?;
+                    specifiers.push(node::ImportSpecifier::new(
+                        ImportName::Namespace,
+                        local_name,
+                    ));
+                }
+                TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                    while cursor.next_if(Punctuator::CloseBlock)?.is_none() {
+                        specifiers.push(ImportSpecifier.parse(cursor)?);
+                        if cursor.next_if(Punctuator::Comma)?.is_none() {
+                            cursor.expect(Punctuator::CloseBlock, "import declaration")?;
+                            break;
+                        }
+                    }
+                }
+                _ => {
+                    return Err(ParseError::expected(
+                        vec![
+                            TokenKind::Punctuator(Punctuator::Mul),
+                            TokenKind::Punctuator(Punctuator::OpenBlock),
+                        ],
+                        tok,
+                        "import declaration",
+                    ))
+                }
+            }
+        }
+
+        cursor.expect(TokenKind::identifier("from"), "import declaration")?;
+        let module = module_specifier(cursor)?;
+        cursor.expect_semicolon("import declaration")?;
+
+        Ok(ImportDecl::new(specifiers, module))
+    }
+}
+
+/// Parses a binding of the named imports of an `import` declaration.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportSpecifier
+#[derive(Debug, Clone, Copy)]
+struct ImportSpecifier;
+
+impl<R> TokenParser<R> for ImportSpecifier
+where
+    R: Read,
+{
+    type Output = node::ImportSpecifier;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        // `{ a }` imports the binding with the same name, as long as it's a valid identifier.
+        if cursor.peek(1)?.map(Token::kind) != Some(&TokenKind::identifier("as")) {
+            let local_name = BindingIdentifier::new(false, false).parse(cursor)?;
+            return Ok(node::ImportSpecifier::new(
+                ImportName::Name(local_name.clone()),
+                local_name,
+            ));
+        }
+
+        let import_name = module_export_name(cursor)?;
+        cursor.expect(TokenKind::identifier("as"), "import specifier")?;
+        let local_name = BindingIdentifier::new(false, false).parse(cursor)?;
+        Ok(node::ImportSpecifier::new(
+            ImportName::Name(import_name),
+            local_name,
+        ))
+    }
+}
+
+/// Parses an `export` declaration.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ExportDeclaration;
+
+impl<R> TokenParser<R> for ExportDeclaration
+where
+    R: Read,
+{
+    type Output = ExportDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ExportDeclaration", "Parsing");
+        cursor.expect(Keyword::Export, "export declaration")?;
+
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let is_async_function = matches!(
+            tok.kind(),
+            TokenKind::Identifier(ident) if ident.as_ref() == "async"
+        ) && matches!(
+            cursor.peek_after_no_lineterminator()?.map(Token::kind),
+            Some(TokenKind::Keyword(Keyword::Function))
+        );
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+
+        let decl = match tok.kind() {
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                let _ = cursor.next()?.expect("* token vanished");
+                let export_name = if cursor.next_if(TokenKind::identifier("as"))?.is_some() {
+                    Some(module_export_name(cursor)?)
+                } else {
+                    None
+                };
+                cursor.expect(TokenKind::identifier("from"), "export declaration")?;
+                let module = module_specifier(cursor)?;
+                cursor.expect_semicolon("export declaration")?;
+                ExportDecl::ReExportAll {
+                    export_name,
+                    module,
+                }
+            }
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                let _ = cursor.next()?.expect("{ token vanished");
+                let mut specifiers = Vec::new();
+                // The local names must be identifiers, unless the bindings are re-exported.
+                let mut local_name_error = None;
+                while cursor.next_if(Punctuator::CloseBlock)?.is_none() {
+                    let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+                    if local_name_error.is_none()
+                        && !matches!(tok.kind(), TokenKind::Identifier(name) if !is_reserved_word(name))
+                    {
+                        local_name_error = Some(tok.clone());
+                    }
+                    let local_name = module_export_name(cursor)?;
+                    let export_name = if cursor.next_if(TokenKind::identifier("as"))?.is_some() {
+                        module_export_name(cursor)?
+                    } else {
+                        local_name.clone()
+                    };
+                    specifiers.push(ExportSpecifier::new(local_name, export_name));
+                    if cursor.next_if(Punctuator::Comma)?.is_none() {
+                        cursor.expect(Punctuator::CloseBlock, "export declaration")?;
+                        break;
+                    }
+                }
+
+                let module = if cursor.next_if(TokenKind::identifier("from"))?.is_some() {
+                    Some(module_specifier(cursor)?)
+                } else if let Some(tok) = local_name_error {
+                    return Err(ParseError::unexpected(
+                        tok,
+                        "only identifiers can be exported without a from clause",
+                    ));
+                } else {
+                    None
+                };
+                cursor.expect_semicolon("export declaration")?;
+                ExportDecl::Named {
+                    specifiers: specifiers.into(),
+                    module,
+                }
+            }
+            TokenKind::Keyword(Keyword::Var) => {
+                ExportDecl::Declaration(VariableStatement::new(false, false).parse(cursor)?.into())
+            }
+            TokenKind::Keyword(Keyword::Default) => {
+                let _ = cursor.next()?.expect("default keyword vanished");
+                let is_async_function = matches!(
+                    cursor.peek(0)?.map(Token::kind),
+                    Some(TokenKind::Identifier(ident)) if ident.as_ref() == "async"
+                ) && matches!(
+                    cursor.peek_after_no_lineterminator()?.map(Token::kind),
+                    Some(TokenKind::Keyword(Keyword::Function))
+                );
+                match cursor.peek(0)?.map(Token::kind) {
+                    Some(TokenKind::Keyword(Keyword::Function)) => ExportDecl::DefaultDeclaration(
+                        HoistableDeclaration::new(false, false, true).parse(cursor)?,
+                    ),
+                    Some(TokenKind::Identifier(_)) if is_async_function => {
+                        ExportDecl::DefaultDeclaration(
+                            HoistableDeclaration::new(false, false, true).parse(cursor)?,
+                        )
+                    }
+                    Some(TokenKind::Keyword(Keyword::Class)) => ExportDecl::DefaultDeclaration(
+                        ClassDeclaration::new(false, false, true)
+                            .parse(cursor)?
+                            .into(),
+                    ),
+                    _ => {
+                        let expr = AssignmentExpression::new(true, false, false).parse(cursor)?;
+                        cursor.expect_semicolon("export declaration")?;
+                        ExportDecl::DefaultExpression(expr)
+                    }
+                }
+            }
+            TokenKind::Keyword(Keyword::Function)
+            | TokenKind::Keyword(Keyword::Class)
+            | TokenKind::Keyword(Keyword::Let)
+            | TokenKind::Keyword(Keyword::Const) => {
+                ExportDecl::Declaration(Declaration::new(false, false, true).parse(cursor)?)
+            }
+            TokenKind::Identifier(_) if is_async_function => {
+                ExportDecl::Declaration(Declaration::new(false, false, true).parse(cursor)?)
+            }
+            _ => {
+                return Err(ParseError::unexpected(
+                    tok.clone(),
+                    "expected a declaration or bindings to export",
+                ))
+            }
+        };
+
+        Ok(decl)
+    }
+}
+
+/// Parses the specifier of a module, a string literal.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleSpecifier
+fn module_specifier<R>(cursor: &mut Cursor<R>) -> Result<Box<str>, ParseError>
+where
+    R: Read,
+{
+    let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+    match tok.kind() {
+        TokenKind::StringLiteral(specifier) => Ok(specifier.clone()),
+        _ => Err(ParseError::expected(
+            vec![TokenKind::StringLiteral("module specifier".into())],
+            tok,
+            "module specifier",
+        )),
+    }
+}
+
+/// Parses the name of an imported or exported binding, which is any identifier name,
+/// including reserved words, or a string literal.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleExportName
+fn module_export_name<R>(cursor: &mut Cursor<R>) -> Result<Box<str>, ParseError>
+where
+    R: Read,
+{
+    let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+    match tok.kind() {
+        TokenKind::Identifier(name) | TokenKind::StringLiteral(name) => Ok(name.clone()),
+        TokenKind::Keyword(keyword) => Ok(keyword.as_str().into()),
+        TokenKind::BooleanLiteral(value) => Ok(value.to_string().into()),
+        TokenKind::NullLiteral => Ok("null".into()),
+        _ => Err(ParseError::expected(
+            vec![TokenKind::identifier("identifier")],
+            tok,
+            "module export name",
+        )),
+    }
+}
@@ -0,0 +1,171 @@
+//! Tests for module parsing.
+
+use crate::syntax::{
+    ast::{
+        node::{
+            BinOp, ConstDecl, ConstDeclList, ExportDecl, ExportSpecifier, Identifier, ImportDecl,
+            ImportName, ImportSpecifier, ModuleItem, ModuleItemList, Node, DEFAULT_EXPORT_BINDING,
+        },
+        op::NumOp,
+        Const,
+    },
+    parser::Parser,
+};
+
+/// Checks that the given JavaScript module gives the expected module items.
+#[track_caller]
+fn check_module<L>(js: &str, items: L)
+where
+    L: Into<Box<[ModuleItem]>>,
+{
+    assert_eq!(
+        Parser::new(js.as_bytes())
+            .parse_module()
+            .expect("failed to parse"),
+        ModuleItemList::from(items)
+    );
+}
+
+/// Checks that the given JavaScript module creates a parse error.
+#[track_caller]
+fn check_invalid_module(js: &str) {
+    assert!(Parser::new(js.as_bytes()).parse_module().is_err());
+}
+
+#[test]
+fn import_declarations() {
+    check_module(
+        r#"import "a";
+        import b from "b";
+        import * as c from "c";
+        import d, { e, f as g, "h i" as h, } from "d";"#,
+        vec![
+            ImportDecl::new(vec![], "a").into(),
+            ImportDecl::new(
+                vec![ImportSpecifier::new(
+                    ImportName::Name("default".into()),
+                    "b",
+                )],
+                "b",
+            )
+            .into(),
+            ImportDecl::new(vec![ImportSpecifier::new(ImportName::Namespace, "c")], "c").into(),
+            ImportDecl::new(
+                vec![
+                    ImportSpecifier::new(ImportName::Name("default".into()), "d"),
+                    ImportSpecifier::new(ImportName::Name("e".into()), "e"),
+                    ImportSpecifier::new(ImportName::Name("f".into()), "g"),
+                    ImportSpecifier::new(ImportName::Name("h i".into()), "h"),
+                ],
+                "d",
+            )
+            .into(),
+        ],
+    );
+}
+
+#[test]
+fn export_declarations() {
+    check_module(
+        r#"export const a = 1;
+        export { a as b, a as "c d" };
+        export * from "e";
+        export * as f from "f";
+        export { g as default } from "g";"#,
+        vec![
+            ExportDecl::Declaration(
+                ConstDeclList::from(vec![ConstDecl::new("a", Some(Const::from(1)))]).into(),
+            )
+            .into(),
+            ExportDecl::Named {
+                specifiers: vec![
+                    ExportSpecifier::new("a", "b"),
+                    ExportSpecifier::new("a", "c d"),
+                ]
+                .into(),
+                module: None,
+            }
+            .into(),
+            ExportDecl::ReExportAll {
+                export_name: None,
+                module: "e".into(),
+            }
+            .into(),
+            ExportDecl::ReExportAll {
+                export_name: Some("f".into()),
+                module: "f".into(),
+            }
+            .into(),
+            ExportDecl::Named {
+                specifiers: vec![ExportSpecifier::new("g", "default")].into(),
+                module: Some("g".into()),
+            }
+            .into(),
+        ],
+    );
+}
+
+#[test]
+fn export_default() {
+    check_module(
+        "export default 1 + 2;",
+        vec![ExportDecl::DefaultExpression(
+            BinOp::new(NumOp::Add, Const::from(1), Const::from(2)).into(),
+        )
+        .into()],
+    );
+
+    let items = Parser::new(b"export default function () {}".as_ref())
+        .parse_module()
+        .expect("failed to parse");
+    match items.items() {
+        [ModuleItem::ExportDeclaration(ExportDecl::DefaultDeclaration(Node::FunctionDecl(decl)))] =>
+        {
+            assert_eq!(decl.name(), DEFAULT_EXPORT_BINDING)
+        }
+        items => panic!("unexpected module items: {:?}", items),
+    }
+}
+
+#[test]
+fn duplicate_export_names() {
+    check_invalid_module("export const a = 1; export { a };");
+    check_invalid_module("export default 1; export default 2;");
+    check_invalid_module(r#"export * as a from "a"; export function a() {}"#);
+}
+
+#[test]
+fn export_string_without_from() {
+    check_invalid_module(r#"export { "a" };"#);
+    check_module(
+        r#"export { "a" } from "b";"#,
+        vec![ExportDecl::Named {
+            specifiers: vec![ExportSpecifier::new("a", "a")].into(),
+            module: Some("b".into()),
+        }
+        .into()],
+    );
+}
+
+#[test]
+fn import_meta() {
+    check_module("import.meta;", vec![Node::ImportMeta.into()]);
+    assert!(Parser::new(b"import.meta;".as_ref()).parse_all().is_err());
+}
+
+#[test]
+fn import_export_only_at_top_level() {
+    check_invalid_module(r#"{ import a from "a"; }"#);
+    check_invalid_module("function f() { export const a = 1; }");
+    assert!(Parser::new(br#"import a from "a";"#.as_ref())
+        .parse_all()
+        .is_err());
+}
+
+#[test]
+fn identifier_expression_export() {
+    check_module(
+        "export default a;",
+        vec![ExportDecl::DefaultExpression(Identifier::from("a").into()).into()],
+    );
+}
@@ -0,0 +1,329 @@
+//! Binding pattern parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+//! [spec]: https://tc39.es/ecma262/#prod-BindingPattern
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{self, ArrayPattern, Binding, ObjectPattern},
+            Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{
+            expression::{Initializer, PropertyName},
+            statement::BindingIdentifier,
+            AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Binding target parsing, a binding identifier or a binding pattern.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ForBinding
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct BindingTarget {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl BindingTarget {
+    /// Creates a new `BindingTarget` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for BindingTarget
+where
+    R: Read,
+{
+    type Output = Binding;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                ObjectBindingPattern::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Binding::from)
+            }
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                ArrayBindingPattern::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Binding::from)
+            }
+            _ => BindingIdentifier::new(self.allow_yield, self.allow_await)
+                .parse(cursor)
+                .map(Binding::from),
+        }
+    }
+}
+
+/// Binding element parsing, a binding target with an optional initializer.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingElement
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct BindingElement {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl BindingElement {
+    /// Creates a new `BindingElement` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for BindingElement
+where
+    R: Read,
+{
+    type Output = node::BindingElement;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("BindingElement", "Parsing");
+
+        let binding = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let init = match cursor.peek(0)? {
+            Some(t) if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) => {
+                Some(Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?)
+            }
+            _ => None,
+        };
+
+        Ok(node::BindingElement::new(binding, init))
+    }
+}
+
+/// Object binding pattern parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment#Object_destructuring
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
+#[derive(Debug, Clone, Copy)]
+struct ObjectBindingPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ObjectBindingPattern {
+    /// Creates a new `ObjectBindingPattern` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ObjectBindingPattern
+where
+    R: Read,
+{
+    type Output = ObjectPattern;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ObjectBindingPattern", "Parsing");
+        cursor.expect(Punctuator::OpenBlock, "object binding pattern")?;
+
+        let mut properties = Vec::new();
+        let mut rest = None;
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+
+            // The rest element must be the last one, without a trailing comma.
+            if cursor.next_if(Punctuator::Spread)?.is_some() {
+                rest =
+                    Some(BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?);
+                cursor.expect(Punctuator::CloseBlock, "object binding pattern")?;
+                break;
+            }
+
+            properties
+                .push(BindingProperty::new(self.allow_yield, self.allow_await).parse(cursor)?);
+
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+            cursor.expect(Punctuator::Comma, "object binding pattern")?;
+        }
+
+        Ok(ObjectPattern::new(properties, rest.map(Binding::from)))
+    }
+}
+
+/// Binding property parsing, a property of an object binding pattern.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingProperty
+#[derive(Debug, Clone, Copy)]
+struct BindingProperty {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl BindingProperty {
+    /// Creates a new `BindingProperty` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for BindingProperty
+where
+    R: Read,
+{
+    type Output = node::BindingProperty;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("BindingProperty", "Parsing");
+
+        // A single name binding, like `a` or `a = 1`, binds the property with the same name.
+        let is_single_name = matches!(
+            cursor.peek(0)?.map(|tok| tok.kind()),
+            Some(TokenKind::Identifier(_))
+        ) && !matches!(
+            cursor.peek(1)?.map(|tok| tok.kind()),
+            Some(TokenKind::Punctuator(Punctuator::Colon))
+        );
+        if is_single_name {
+            let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            let init = match cursor.peek(0)? {
+                Some(t) if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) => {
+                    Some(Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?)
+                }
+                _ => None,
+            };
+            return Ok(node::BindingProperty::new(
+                node::PropertyName::Literal(name.clone()),
+                node::BindingElement::new(name, init),
+            ));
+        }
+
+        let key = PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        cursor.expect(Punctuator::Colon, "object binding pattern")?;
+        let element = BindingElement::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        Ok(node::BindingProperty::new(key, element))
+    }
+}
+
+/// Array binding pattern parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment#Array_destructuring
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
+#[derive(Debug, Clone, Copy)]
+struct ArrayBindingPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ArrayBindingPattern {
+    /// Creates a new `ArrayBindingPattern` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ArrayBindingPattern
+where
+    R: Read,
+{
+    type Output = ArrayPattern;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ArrayBindingPattern", "Parsing");
+        cursor.expect(Punctuator::OpenBracket, "array binding pattern")?;
+
+        let mut elements = Vec::new();
+        let mut rest = None;
+        loop {
+            if cursor.next_if(Punctuator::CloseBracket)?.is_some() {
+                break;
+            }
+
+            // Elision
+            if cursor.next_if(Punctuator::Comma)?.is_some() {
+                elements.push(None);
+                continue;
+            }
+
+            // The rest element must be the last one, without a trailing comma.
+            if cursor.next_if(Punctuator::Spread)?.is_some() {
+                rest = Some(BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?);
+                cursor.expect(Punctuator::CloseBracket, "array binding pattern")?;
+                break;
+            }
+
+            elements.push(Some(
+                BindingElement::new(self.allow_yield, self.allow_await).parse(cursor)?,
+            ));
+
+            if cursor.next_if(Punctuator::CloseBracket)?.is_some() {
+                break;
+            }
+            cursor.expect(Punctuator::Comma, "array binding pattern")?;
+        }
+
+        Ok(ArrayPattern::new(elements, rest))
+    }
+}
@@ -0,0 +1,231 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            ArrayPattern, ArrowFunctionDecl, Assign, Binding, BindingElement, BindingProperty,
+            Block, Catch, ConstDecl, ConstDeclList, ForOfLoop, FormalParameter, FunctionDecl,
+            GetConstField, Identifier, IterableLoopInitializer, LetDecl, LetDeclList, Node,
+            ObjectPattern, PropertyName, Return, Try, VarDecl, VarDeclList,
+        },
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks object binding patterns with defaults, renaming and a rest element.
+#[test]
+fn object_pattern() {
+    check_parser(
+        "var { a, b: c, d = 1, ...e } = f;",
+        vec![VarDeclList::from(vec![VarDecl::new(
+            ObjectPattern::new(
+                vec![
+                    BindingProperty::new("a", BindingElement::new("a", None)),
+                    BindingProperty::new("b", BindingElement::new("c", None)),
+                    BindingProperty::new("d", BindingElement::new("d", Node::from(Const::from(1)))),
+                ],
+                Some("e".into()),
+            ),
+            Node::from(Identifier::from("f")),
+        )])
+        .into()],
+    );
+}
+
+/// Checks array binding patterns with elisions, defaults and a rest element.
+#[test]
+fn array_pattern() {
+    check_parser(
+        "let [a, , b = 2, ...c] = d;",
+        vec![LetDeclList::from(vec![LetDecl::new(
+            ArrayPattern::new(
+                vec![
+                    Some(BindingElement::new("a", None)),
+                    None,
+                    Some(BindingElement::new("b", Node::from(Const::from(2)))),
+                ],
+                Some("c".into()),
+            ),
+            Node::from(Identifier::from("d")),
+        )])
+        .into()],
+    );
+}
+
+/// Checks nested patterns and computed property names.
+#[test]
+fn nested_pattern() {
+    check_parser(
+        "const { [k]: [x, { y }] } = z;",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            ObjectPattern::new(
+                vec![BindingProperty::new(
+                    PropertyName::Computed(Identifier::from("k").into()),
+                    BindingElement::new(
+                        ArrayPattern::new(
+                            vec![
+                                Some(BindingElement::new("x", None)),
+                                Some(BindingElement::new(
+                                    ObjectPattern::new(
+                                        vec![BindingProperty::new(
+                                            "y",
+                                            BindingElement::new("y", None),
+                                        )],
+                                        None,
+                                    ),
+                                    None,
+                                )),
+                            ],
+                            None,
+                        ),
+                        None,
+                    ),
+                )],
+                None,
+            ),
+            Some(Identifier::from("z")),
+        )])
+        .into()],
+    );
+}
+
+/// Checks binding patterns in function, arrow function and `catch` parameters.
+#[test]
+fn parameter_patterns() {
+    check_parser(
+        "function f({ a }, [b] = c, ...[d]) {}",
+        vec![FunctionDecl::new(
+            Box::from("f"),
+            vec![
+                FormalParameter::new(
+                    ObjectPattern::new(
+                        vec![BindingProperty::new("a", BindingElement::new("a", None))],
+                        None,
+                    ),
+                    None,
+                    false,
+                ),
+                FormalParameter::new(
+                    ArrayPattern::new(vec![Some(BindingElement::new("b", None))], None),
+                    Some(Identifier::from("c").into()),
+                    false,
+                ),
+                FormalParameter::new(
+                    ArrayPattern::new(vec![Some(BindingElement::new("d", None))], None),
+                    None,
+                    true,
+                ),
+            ],
+            vec![],
+        )
+        .into()],
+    );
+    check_parser(
+        "({ a: b }, [c]) => b;",
+        vec![ArrowFunctionDecl::new(
+            vec![
+                FormalParameter::new(
+                    ObjectPattern::new(
+                        vec![BindingProperty::new("a", BindingElement::new("b", None))],
+                        None,
+                    ),
+                    None,
+                    false,
+                ),
+                FormalParameter::new(
+                    ArrayPattern::new(vec![Some(BindingElement::new("c", None))], None),
+                    None,
+                    false,
+                ),
+            ],
+            vec![Return::new(Identifier::from("b"), None).into()],
+        )
+        .into()],
+    );
+    check_parser(
+        "try {} catch ([e]) {}",
+        vec![Try::new(
+            vec![],
+            Some(Catch::new(
+                ArrayPattern::new(vec![Some(BindingElement::new("e", None))], None),
+                vec![],
+            )),
+            None,
+        )
+        .into()],
+    );
+}
+
+/// Checks that binding patterns require an initializer outside of a `for` statement head.
+#[test]
+fn pattern_without_initializer() {
+    check_invalid("var { a };");
+    check_invalid("let [a];");
+    check_invalid("const { a };");
+}
+
+/// Checks that the rest element must be last.
+#[test]
+fn rest_element_not_last() {
+    check_invalid("var [...a, b] = c;");
+    check_invalid("var { ...a, b } = c;");
+    check_invalid("({ a: 1 }) => a;");
+}
+
+/// Checks array and object literals reinterpreted as assignment patterns.
+#[test]
+fn assignment_pattern() {
+    check_parser(
+        "[a, b.c = 1] = d;",
+        vec![Assign::new(
+            ArrayPattern::new(
+                vec![
+                    Some(BindingElement::new("a", None)),
+                    Some(BindingElement::new(
+                        Binding::Member(Box::new(
+                            GetConstField::new(Identifier::from("b"), "c").into(),
+                        )),
+                        Node::from(Const::from(1)),
+                    )),
+                ],
+                None,
+            ),
+            Identifier::from("d"),
+        )
+        .into()],
+    );
+    check_parser(
+        "({ a, b = 1, ...c } = d);",
+        vec![Assign::new(
+            ObjectPattern::new(
+                vec![
+                    BindingProperty::new("a", BindingElement::new("a", None)),
+                    BindingProperty::new("b", BindingElement::new("b", Node::from(Const::from(1)))),
+                ],
+                Some("c".into()),
+            ),
+            Identifier::from("d"),
+        )
+        .into()],
+    );
+    check_parser(
+        "for ([a] of b) {}",
+        vec![ForOfLoop::new(
+            IterableLoopInitializer::Assign(
+                ArrayPattern::new(vec![Some(BindingElement::new("a", None))], None).into(),
+            ),
+            Identifier::from("b"),
+            Block::from(vec![]),
+        )
+        .into()],
+    );
+}
+
+/// Checks that only valid assignment targets are reinterpreted as patterns.
+#[test]
+fn invalid_assignment_pattern() {
+    check_invalid("[a + 1] = b;");
+    check_invalid("({ a: 1 } = b);");
+    check_invalid("for ([a] = b of c) {}");
+    check_invalid("for (let a, b of c) {}");
+    check_invalid("([a.b]) => a;");
+}
@@ -12,7 +12,7 @@ mod tests;
 
 use super::LabelIdentifier;
 
-use crate::syntax::lexer::TokenKind;
+use crate::syntax::lexer::{Error as LexError, TokenKind};
 use crate::{
     syntax::{
         ast::{node::Break, Keyword, Punctuator},
@@ -62,7 +62,10 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("BreakStatement", "Parsing");
-        cursor.expect(Keyword::Break, "break statement")?;
+        let start = cursor
+            .expect(Keyword::Break, "break statement")?
+            .span()
+            .start();
 
         let label = if let SemicolonResult::Found(tok) = cursor.peek_semicolon()? {
             match tok {
@@ -72,9 +75,24 @@ where
                 _ => {}
             }
 
+            if !cursor.in_breakable() {
+                return Err(ParseError::lex(LexError::Syntax(
+                    "illegal break statement, it is not inside of a loop or a switch statement"
+                        .into(),
+                    start,
+                )));
+            }
+
             None
         } else {
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
             let label = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            if !cursor.has_label(&label) {
+                return Err(ParseError::lex(LexError::Syntax(
+                    format!("undefined label '{}'", label).into(),
+                    position,
+                )));
+            }
             cursor.expect_semicolon("break statement")?;
 
             Some(label)
@@ -1,11 +1,18 @@
 use crate::syntax::{
     ast::{
-        node::{Block, Break, Node, WhileLoop},
+        node::{Block, Break, Case, Identifier, Node, Switch, WhileLoop},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
+/// Creates a `while (true)` loop with the given label.
+fn labelled_while<B: Into<Node>>(label: &str, body: B) -> Node {
+    let mut while_loop = WhileLoop::new(Const::from(true), body);
+    while_loop.set_label(label.into());
+    while_loop.into()
+}
+
 #[test]
 fn inline() {
     check_parser(
@@ -42,14 +49,13 @@ fn inline_block_semicolon_insertion() {
 #[test]
 fn new_line_semicolon_insertion() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             break test
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_while(
+            "test",
             Block::from(vec![Break::new("test").into()]),
-        )
-        .into()],
+        )],
     );
 }
 
@@ -68,39 +74,36 @@ fn inline_block() {
 #[test]
 fn new_line_block() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             break test;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_while(
+            "test",
             Block::from(vec![Break::new("test").into()]),
-        )
-        .into()],
+        )],
     );
 }
 
 #[test]
 fn reserved_label() {
     check_parser(
-        "while (true) {
+        "await: while (true) {
             break await;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_while(
+            "await",
             Block::from(vec![Break::new("await").into()]),
-        )
-        .into()],
+        )],
     );
 
     check_parser(
-        "while (true) {
+        "yield: while (true) {
             break yield;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_while(
+            "yield",
             Block::from(vec![Break::new("yield").into()]),
-        )
-        .into()],
+        )],
     );
 }
 
@@ -131,3 +134,28 @@ fn new_line_block_empty_semicolon_insertion() {
         .into()],
     );
 }
+
+#[test]
+fn undefined_label() {
+    check_invalid("while (true) { break test; }");
+    check_invalid("test: while (true) { (function () { break test; }); }");
+}
+
+#[test]
+fn outside_of_breakable() {
+    check_invalid("break;");
+    check_invalid("{ break; }");
+    check_invalid("while (true) { (function () { break; }); }");
+    check_parser(
+        "switch (a) { case 1: break; }",
+        vec![Switch::new::<_, _, Vec<Node>>(
+            Identifier::from("a"),
+            vec![Case::new(
+                Const::from(1),
+                vec![Break::new::<_, Box<str>>(None).into()],
+            )],
+            None,
+        )
+        .into()],
+    );
+}
@@ -10,7 +10,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::syntax::lexer::TokenKind;
+use crate::syntax::lexer::{Error as LexError, TokenKind};
 use crate::{
     syntax::{
         ast::{node::Continue, Keyword, Punctuator},
@@ -61,7 +61,10 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ContinueStatement", "Parsing");
-        cursor.expect(Keyword::Continue, "continue statement")?;
+        let start = cursor
+            .expect(Keyword::Continue, "continue statement")?
+            .span()
+            .start();
 
         let label = if let SemicolonResult::Found(tok) = cursor.peek_semicolon()? {
             match tok {
@@ -71,9 +74,32 @@ where
                 _ => {}
             }
 
+            if !cursor.in_iteration() {
+                return Err(ParseError::lex(LexError::Syntax(
+                    "illegal continue statement, it is not inside of a loop".into(),
+                    start,
+                )));
+            }
+
             None
         } else {
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
             let label = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            let label = match cursor.continue_target(&label) {
+                Some(target) => target.into(),
+                None if cursor.has_label(&label) => {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        format!("label '{}' does not denote an iteration statement", label).into(),
+                        position,
+                    )))
+                }
+                None => {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        format!("undefined label '{}'", label).into(),
+                        position,
+                    )))
+                }
+            };
             cursor.expect_semicolon("continue statement")?;
 
             Some(label)
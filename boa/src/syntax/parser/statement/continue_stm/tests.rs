@@ -1,11 +1,18 @@
 use crate::syntax::{
     ast::{
-        node::{Block, Continue, WhileLoop},
+        node::{Block, Continue, Node, WhileLoop},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
+/// Creates a `while (true)` loop with the given label.
+fn labelled_while<B: Into<Node>>(label: &str, body: B) -> Node {
+    let mut while_loop = WhileLoop::new(Const::from(true), body);
+    while_loop.set_label(label.into());
+    while_loop.into()
+}
+
 #[test]
 fn inline() {
     check_parser(
@@ -38,14 +45,13 @@ fn inline_block_semicolon_insertion() {
 #[test]
 fn new_line_semicolon_insertion() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             continue test
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_while(
+            "test",
             Block::from(vec![Continue::new("test").into()]),
-        )
-        .into()],
+        )],
     );
 }
 
@@ -64,39 +70,36 @@ fn inline_block() {
 #[test]
 fn new_line_block() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             continue test;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_while(
+            "test",
             Block::from(vec![Continue::new("test").into()]),
-        )
-        .into()],
+        )],
     );
 }
 
 #[test]
 fn reserved_label() {
     check_parser(
-        "while (true) {
+        "await: while (true) {
             continue await;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_while(
+            "await",
             Block::from(vec![Continue::new("await").into()]),
-        )
-        .into()],
+        )],
     );
 
     check_parser(
-        "while (true) {
+        "yield: while (true) {
             continue yield;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_while(
+            "yield",
             Block::from(vec![Continue::new("yield").into()]),
-        )
-        .into()],
+        )],
     );
 }
 
@@ -127,3 +130,27 @@ fn new_line_block_empty_semicolon_insertion() {
         .into()],
     );
 }
+
+#[test]
+fn multiple_labels() {
+    let mut block = Block::from(vec![labelled_while("inner", Continue::new("inner"))]);
+    block.set_label("outer".into());
+    check_parser(
+        "outer: inner: while (true) continue outer;",
+        vec![block.into()],
+    );
+}
+
+#[test]
+fn invalid_label() {
+    check_invalid("while (true) { continue test; }");
+    check_invalid("test: { while (true) { continue test; } }");
+    check_invalid("test: while (true) { (function () { continue test; }); }");
+}
+
+#[test]
+fn outside_of_iteration() {
+    check_invalid("continue;");
+    check_invalid("switch (a) { case 1: continue; }");
+    check_invalid("while (true) { (function () { continue; }); }");
+}
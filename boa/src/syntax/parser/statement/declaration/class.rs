@@ -0,0 +1,84 @@
+//! Class declaration parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+//! [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+
+use crate::{
+    syntax::{
+        ast::{node::ClassDecl, Keyword},
+        parser::{
+            class::ClassTail, AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use super::hoistable::declaration_name;
+use std::io::Read;
+
+/// Class declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+/// [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ClassDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    is_default: AllowDefault,
+}
+
+impl ClassDeclaration {
+    /// Creates a new `ClassDeclaration` parser.
+    pub(in crate::syntax::parser) fn new<Y, A, D>(
+        allow_yield: Y,
+        allow_await: A,
+        is_default: D,
+    ) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+        D: Into<AllowDefault>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            is_default: is_default.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassDeclaration
+where
+    R: Read,
+{
+    type Output = ClassDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassDeclaration", "Parsing");
+        cursor.expect(Keyword::Class, "class declaration")?;
+
+        // The class name is part of the class, so it is always strict mode code.
+        let strict_mode = cursor.strict_mode();
+        cursor.set_strict_mode(true);
+        let name = declaration_name(cursor, self.allow_yield, self.allow_await, self.is_default);
+        cursor.set_strict_mode(strict_mode);
+        let name = name?;
+
+        let tail = ClassTail::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        Ok(ClassDecl::new(
+            name,
+            tail.super_ref,
+            tail.constructor,
+            tail.elements,
+        ))
+    }
+}
@@ -7,9 +7,15 @@
 
 use crate::{
     syntax::{
-        ast::{node::FunctionDecl, Keyword, Node, Punctuator},
+        ast::{
+            node::{AsyncFunctionDecl, FunctionDecl, GeneratorDecl, DEFAULT_EXPORT_BINDING},
+            Keyword, Node, Punctuator,
+        },
+        lexer::TokenKind,
         parser::{
-            function::FormalParameters, function::FunctionBody, statement::BindingIdentifier,
+            cursor::SuperContext,
+            function::{check_parameters, FormalParameters, FunctionBody},
+            statement::BindingIdentifier,
             AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
@@ -25,7 +31,7 @@ use std::io::Read;
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-FunctionDeclaration
 #[derive(Debug, Clone, Copy)]
-pub(super) struct HoistableDeclaration {
+pub(in crate::syntax::parser) struct HoistableDeclaration {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     is_default: AllowDefault,
@@ -33,7 +39,11 @@ pub(super) struct HoistableDeclaration {
 
 impl HoistableDeclaration {
     /// Creates a new `HoistableDeclaration` parser.
-    pub(super) fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A, D>(
+        allow_yield: Y,
+        allow_await: A,
+        is_default: D,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -55,7 +65,25 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("HoistableDeclaration", "Parsing");
-        // TODO: check for generators and async functions + generators
+        // TODO: check for async generators
+        if matches!(
+            cursor.peek(0)?.map(|tok| tok.kind()),
+            Some(TokenKind::Identifier(ident)) if ident.as_ref() == "async"
+        ) {
+            return AsyncFunctionDeclaration::new(
+                self.allow_yield,
+                self.allow_await,
+                self.is_default,
+            )
+            .parse(cursor)
+            .map(Node::from);
+        }
+        if cursor.peek(1)?.map(|tok| tok.kind()) == Some(&TokenKind::Punctuator(Punctuator::Mul)) {
+            return GeneratorDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
+                .parse(cursor)
+                .map(Node::from);
+        }
+
         FunctionDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
             .parse(cursor)
             .map(Node::from)
@@ -102,20 +130,212 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         cursor.expect(Keyword::Function, "function declaration")?;
 
-        // TODO: If self.is_default, then this can be empty.
-        let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let name = declaration_name(cursor, self.allow_yield, self.allow_await, self.is_default)?;
+
+        let params_start = cursor
+            .expect(Punctuator::OpenParen, "function declaration")?
+            .span()
+            .start();
 
-        cursor.expect(Punctuator::OpenParen, "function declaration")?;
+        let allow_new_target = cursor.set_allow_new_target(true);
+        let allow_super = cursor.set_allow_super(SuperContext::None);
 
         let params = FormalParameters::new(false, false).parse(cursor)?;
 
         cursor.expect(Punctuator::CloseParen, "function declaration")?;
         cursor.expect(Punctuator::OpenBlock, "function declaration")?;
 
-        let body = FunctionBody::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let body = FunctionBody::new(false, false).parse(cursor)?;
+
+        cursor.set_allow_new_target(allow_new_target);
+        cursor.set_allow_super(allow_super);
+
+        check_parameters(
+            &params,
+            &body,
+            cursor.use_strict_directive(),
+            false,
+            params_start,
+        )?;
 
         cursor.expect(Punctuator::CloseBlock, "function declaration")?;
 
         Ok(FunctionDecl::new(name, params, body))
     }
 }
+
+/// Generator declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/function*
+/// [spec]: https://tc39.es/ecma262/#prod-GeneratorDeclaration
+#[derive(Debug, Clone, Copy)]
+struct GeneratorDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    is_default: AllowDefault,
+}
+
+impl GeneratorDeclaration {
+    /// Creates a new `GeneratorDeclaration` parser.
+    fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+        D: Into<AllowDefault>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            is_default: is_default.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for GeneratorDeclaration
+where
+    R: Read,
+{
+    type Output = GeneratorDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        cursor.expect(Keyword::Function, "generator declaration")?;
+        cursor.expect(Punctuator::Mul, "generator declaration")?;
+
+        let name = declaration_name(cursor, self.allow_yield, self.allow_await, self.is_default)?;
+
+        let params_start = cursor
+            .expect(Punctuator::OpenParen, "generator declaration")?
+            .span()
+            .start();
+
+        let allow_new_target = cursor.set_allow_new_target(true);
+        let allow_super = cursor.set_allow_super(SuperContext::None);
+
+        let params = FormalParameters::new(false, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "generator declaration")?;
+        cursor.expect(Punctuator::OpenBlock, "generator declaration")?;
+
+        let body = FunctionBody::new(true, false).parse(cursor)?;
+
+        cursor.set_allow_new_target(allow_new_target);
+        cursor.set_allow_super(allow_super);
+
+        check_parameters(
+            &params,
+            &body,
+            cursor.use_strict_directive(),
+            false,
+            params_start,
+        )?;
+
+        cursor.expect(Punctuator::CloseBlock, "generator declaration")?;
+
+        Ok(GeneratorDecl::new(name, params, body))
+    }
+}
+
+/// Async function declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncFunctionDeclaration
+#[derive(Debug, Clone, Copy)]
+struct AsyncFunctionDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    is_default: AllowDefault,
+}
+
+impl AsyncFunctionDeclaration {
+    /// Creates a new `AsyncFunctionDeclaration` parser.
+    fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+        D: Into<AllowDefault>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            is_default: is_default.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for AsyncFunctionDeclaration
+where
+    R: Read,
+{
+    type Output = AsyncFunctionDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        cursor.expect(TokenKind::identifier("async"), "async function declaration")?;
+        cursor.peek_expect_no_lineterminator(0)?;
+        cursor.expect(Keyword::Function, "async function declaration")?;
+
+        let name = declaration_name(cursor, self.allow_yield, self.allow_await, self.is_default)?;
+
+        let params_start = cursor
+            .expect(Punctuator::OpenParen, "async function declaration")?
+            .span()
+            .start();
+
+        let allow_new_target = cursor.set_allow_new_target(true);
+        let allow_super = cursor.set_allow_super(SuperContext::None);
+
+        let params = FormalParameters::new(false, true).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "async function declaration")?;
+        cursor.expect(Punctuator::OpenBlock, "async function declaration")?;
+
+        let body = FunctionBody::new(false, true).parse(cursor)?;
+
+        cursor.set_allow_new_target(allow_new_target);
+        cursor.set_allow_super(allow_super);
+
+        check_parameters(
+            &params,
+            &body,
+            cursor.use_strict_directive(),
+            false,
+            params_start,
+        )?;
+
+        cursor.expect(Punctuator::CloseBlock, "async function declaration")?;
+
+        Ok(AsyncFunctionDecl::new(name, params, body))
+    }
+}
+
+/// Parses the name of a declaration, which can be left out in an `export default` declaration.
+///
+/// A declaration without a name gets the [`DEFAULT_EXPORT_BINDING`] name.
+pub(super) fn declaration_name<R>(
+    cursor: &mut Cursor<R>,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    is_default: AllowDefault,
+) -> Result<Box<str>, ParseError>
+where
+    R: Read,
+{
+    let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+    match tok.kind() {
+        TokenKind::Punctuator(Punctuator::OpenParen)
+        | TokenKind::Punctuator(Punctuator::OpenBlock)
+        | TokenKind::Keyword(Keyword::Extends)
+            if is_default.0 =>
+        {
+            Ok(DEFAULT_EXPORT_BINDING.into())
+        }
+        _ => BindingIdentifier::new(allow_yield, allow_await).parse(cursor),
+    }
+}
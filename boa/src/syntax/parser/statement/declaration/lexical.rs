@@ -11,13 +11,13 @@ use crate::syntax::lexer::TokenKind;
 use crate::{
     syntax::{
         ast::{
-            node::{ConstDecl, ConstDeclList, LetDecl, LetDeclList, Node},
+            node::{Binding, ConstDecl, ConstDeclList, LetDecl, LetDeclList, Node},
             Keyword, Punctuator,
         },
         parser::{
             cursor::{Cursor, SemicolonResult},
             expression::Initializer,
-            statement::BindingIdentifier,
+            statement::BindingTarget,
             AllowAwait, AllowIn, AllowYield, ParseError, ParseResult, TokenParser,
         },
     },
@@ -155,6 +155,18 @@ where
                 LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
                     .parse(cursor)?;
 
+            // Outside of the head of a `for` statement, binding patterns need an initializer.
+            if self.const_init_required
+                && init.is_none()
+                && !matches!(ident, Binding::Identifier(_))
+            {
+                return Err(ParseError::expected(
+                    vec![TokenKind::Punctuator(Punctuator::Assign)],
+                    cursor.next()?.ok_or(ParseError::AbruptEnd)?,
+                    "lexical declaration",
+                ));
+            }
+
             if self.is_const {
                 if self.const_init_required {
                     if init.is_some() {
@@ -240,12 +252,12 @@ impl<R> TokenParser<R> for LexicalBinding
 where
     R: Read,
 {
-    type Output = (Box<str>, Option<Node>);
+    type Output = (Binding, Option<Node>);
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("LexicalBinding", "Parsing");
 
-        let ident = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let binding = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         let init = if let Some(t) = cursor.peek(0)? {
             if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
@@ -260,6 +272,6 @@ where
             None
         };
 
-        Ok((ident, init))
+        Ok((binding, init))
     }
 }
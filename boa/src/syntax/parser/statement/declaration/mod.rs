@@ -7,12 +7,17 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements#Declarations
 //! [spec]:https://tc39.es/ecma262/#sec-declarations-and-the-variable-statement
 
+mod class;
 mod hoistable;
 mod lexical;
 #[cfg(test)]
 mod tests;
 
-use self::{hoistable::HoistableDeclaration, lexical::LexicalDeclaration};
+pub(in crate::syntax::parser) use self::{
+    class::ClassDeclaration, hoistable::HoistableDeclaration,
+};
+
+use self::lexical::LexicalDeclaration;
 
 use crate::syntax::lexer::TokenKind;
 use crate::{
@@ -32,14 +37,18 @@ use std::io::Read;
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-Declaration
 #[derive(Debug, Clone, Copy)]
-pub(super) struct Declaration {
+pub(in crate::syntax::parser) struct Declaration {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     const_init_required: bool,
 }
 
 impl Declaration {
-    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, const_init_required: bool) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        const_init_required: bool,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -63,9 +72,14 @@ where
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         match tok.kind() {
-            TokenKind::Keyword(Keyword::Function) => {
+            TokenKind::Keyword(Keyword::Function) | TokenKind::Identifier(_) => {
                 HoistableDeclaration::new(self.allow_yield, self.allow_await, false).parse(cursor)
             }
+            TokenKind::Keyword(Keyword::Class) => {
+                ClassDeclaration::new(self.allow_yield, self.allow_await, false)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Keyword(Keyword::Const) | TokenKind::Keyword(Keyword::Let) => {
                 LexicalDeclaration::new(
                     true,
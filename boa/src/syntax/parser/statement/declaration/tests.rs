@@ -1,12 +1,15 @@
 use crate::syntax::{
     ast::{
         node::{
-            ConstDecl, ConstDeclList, FunctionDecl, LetDecl, LetDeclList, Node, VarDecl,
-            VarDeclList,
+            field::GetPrivateField, AsyncFunctionDecl, Await, ClassDecl, ClassElement,
+            ClassElementName, ConstDecl, ConstDeclList, FormalParameter, FunctionDecl,
+            FunctionExpr, GeneratorDecl, GetSuperConstField, Identifier, LetDecl, LetDeclList,
+            MethodDefinitionKind, Node, PrivateIn, PropertyName, Return, SuperCall, VarDecl,
+            VarDeclList, Yield,
         },
         Const,
     },
-    parser::tests::{check_invalid, check_parser},
+    parser::tests::{check_invalid, check_parser, strict},
 };
 
 /// Checks `var` declaration parsing.
@@ -192,3 +195,341 @@ fn function_declaration_keywords() {
         vec![FunctionDecl::new(Box::from("await"), vec![], vec![]).into()],
     );
 }
+
+/// Generator declaration parsing.
+#[test]
+fn generator_declaration() {
+    check_parser(
+        "function* gen() { yield; yield 1; yield* a; }",
+        vec![GeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![
+                Yield::new::<Node, Option<_>>(None, false).into(),
+                Yield::new(Const::from(1), false).into(),
+                Yield::new(Identifier::from("a"), true).into(),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// Checks that a `yield` followed by a line terminator has no operand.
+#[test]
+fn generator_declaration_yield_line_terminator() {
+    check_parser(
+        "function* gen() { yield\n1 }",
+        vec![GeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![
+                Yield::new::<Node, Option<_>>(None, false).into(),
+                Const::from(1).into(),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// Async function declaration parsing.
+#[test]
+fn async_function_declaration() {
+    check_parser(
+        "async function f(x) { return await x; }",
+        vec![AsyncFunctionDecl::new(
+            Box::from("f"),
+            vec![FormalParameter::new("x", None, false)],
+            vec![Return::new(Await::new(Identifier::from("x")), None).into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks that `async` followed by a line terminator isn't an async function declaration.
+#[test]
+fn async_function_declaration_line_terminator() {
+    check_parser(
+        "async\nfunction f() {}",
+        vec![
+            FunctionDecl::new(Box::from("f"), vec![], vec![]).into(),
+            Identifier::from("async").into(),
+        ],
+    );
+}
+
+/// Checks that a class constructor can't be an async method.
+#[test]
+fn class_declaration_async_constructor() {
+    check_invalid("class A { async constructor() {} }");
+}
+
+/// Checks class declaration parsing.
+#[test]
+fn class_declaration() {
+    check_parser(
+        "class A extends B {
+            constructor(x) { super(x); }
+            static create() {}
+            get value() { return super.value; }
+        }",
+        vec![ClassDecl::new(
+            "A",
+            Node::from(Identifier::from("B")),
+            FunctionExpr::new(
+                None,
+                vec![FormalParameter::new("x", None, false)],
+                strict(vec![
+                    SuperCall::new(vec![Identifier::from("x").into()]).into()
+                ]),
+            ),
+            vec![
+                ClassElement::new(
+                    MethodDefinitionKind::Ordinary,
+                    "create",
+                    true,
+                    FunctionExpr::new(None, vec![], strict(vec![])),
+                ),
+                ClassElement::new(
+                    MethodDefinitionKind::Get,
+                    "value",
+                    false,
+                    FunctionExpr::new(
+                        None,
+                        vec![],
+                        strict(vec![
+                            Return::new(GetSuperConstField::new("value"), None).into()
+                        ]),
+                    ),
+                ),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// Checks that `static`, `get` and `set` can be used as class method names.
+#[test]
+fn class_declaration_modifier_names() {
+    let method = |name: &str, is_static| {
+        ClassElement::new(
+            MethodDefinitionKind::Ordinary,
+            name,
+            is_static,
+            FunctionExpr::new(None, vec![], strict(vec![])),
+        )
+    };
+
+    check_parser(
+        "class A { static() {} get() {} static set() {} }",
+        vec![ClassDecl::new(
+            "A",
+            None,
+            None,
+            vec![
+                method("static", false),
+                method("get", false),
+                method("set", true),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// Checks that a class can't have more than one constructor.
+#[test]
+fn class_declaration_duplicate_constructor() {
+    check_invalid("class A { constructor() {} constructor() {} }");
+}
+
+/// Checks that class constructors can't be accessors.
+#[test]
+fn class_declaration_accessor_constructor() {
+    check_invalid("class A { get constructor() {} }");
+}
+
+/// Checks generator methods in classes.
+#[test]
+fn class_declaration_generator_method() {
+    check_parser(
+        "class A { *gen() { yield 1; } }",
+        vec![ClassDecl::new(
+            "A",
+            None,
+            None,
+            vec![ClassElement::new(
+                MethodDefinitionKind::Generator,
+                "gen",
+                false,
+                FunctionExpr::new(
+                    None,
+                    vec![],
+                    strict(vec![Yield::new(Const::from(1), false).into()]),
+                ),
+            )],
+        )
+        .into()],
+    );
+}
+
+/// Checks that class constructors can't be generators.
+#[test]
+fn class_declaration_generator_constructor() {
+    check_invalid("class A { *constructor() {} }");
+}
+
+/// Checks fields, private members and static initialization blocks in classes.
+#[test]
+fn class_declaration_fields_and_private_members() {
+    check_parser(
+        "class A {
+            x = 1;
+            static #y;
+            #m() { return #y in this; }
+            static {
+                this.#y;
+            }
+        }",
+        vec![ClassDecl::new(
+            "A",
+            None,
+            None,
+            vec![
+                ClassElement::field("x", false, Node::from(Const::from(1))),
+                ClassElement::field(ClassElementName::PrivateName("y".into()), true, None),
+                ClassElement::new(
+                    MethodDefinitionKind::Ordinary,
+                    ClassElementName::PrivateName("m".into()),
+                    false,
+                    FunctionExpr::new(
+                        None,
+                        vec![],
+                        strict(vec![
+                            Return::new(PrivateIn::new("y", Node::This), None).into()
+                        ]),
+                    ),
+                ),
+                ClassElement::static_block(strict(vec![
+                    GetPrivateField::new(Node::This, "y").into()
+                ])),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// Checks that the private names used in a class must be declared by an enclosing class.
+#[test]
+fn class_declaration_undeclared_private_name() {
+    check_invalid("class A { m() { return this.#x; } }");
+    check_invalid("class A { m() { return #x in this; } }");
+    check_invalid("this.#x");
+}
+
+/// Checks that the private names used in a nested class can be declared by the outer class.
+#[test]
+fn class_declaration_nested_private_name() {
+    check_parser(
+        "class A { #x; m() { class B { n(a) { return a.#x; } } } }",
+        vec![ClassDecl::new(
+            "A",
+            None,
+            None,
+            vec![
+                ClassElement::field(ClassElementName::PrivateName("x".into()), false, None),
+                ClassElement::new(
+                    MethodDefinitionKind::Ordinary,
+                    "m",
+                    false,
+                    FunctionExpr::new(
+                        None,
+                        vec![],
+                        strict(vec![ClassDecl::new(
+                            "B",
+                            None,
+                            None,
+                            vec![ClassElement::new(
+                                MethodDefinitionKind::Ordinary,
+                                "n",
+                                false,
+                                FunctionExpr::new(
+                                    None,
+                                    vec![FormalParameter::new("a", None, false)],
+                                    strict(vec![Return::new(
+                                        GetPrivateField::new(Identifier::from("a"), "x"),
+                                        None,
+                                    )
+                                    .into()]),
+                                ),
+                            )],
+                        )
+                        .into()]),
+                    ),
+                ),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// Checks that a private name can only be declared once, except by a getter and a setter.
+#[test]
+fn class_declaration_duplicate_private_name() {
+    check_invalid("class A { #x; #x() {} }");
+    check_invalid("class A { get #x() {} get #x() {} }");
+    check_invalid("class A { get #x() {} static set #x(v) {} }");
+    check_invalid("class A { get #x() {} set #x(v) {} #x; }");
+}
+
+/// Checks the early errors of the names of class elements.
+#[test]
+fn class_declaration_invalid_element_names() {
+    check_invalid("class A { #constructor() {} }");
+    check_invalid("class A { constructor = 1; }");
+    check_invalid("class A { static prototype = 1; }");
+}
+
+/// Checks computed names of class elements.
+#[test]
+fn class_declaration_computed_element_names() {
+    check_parser(
+        "class A { [a]() {} static ['constructor'] = 1; }",
+        vec![ClassDecl::new(
+            "A",
+            None,
+            None,
+            vec![
+                ClassElement::new(
+                    MethodDefinitionKind::Ordinary,
+                    ClassElementName::PropertyName(PropertyName::Computed(
+                        Identifier::from("a").into(),
+                    )),
+                    false,
+                    FunctionExpr::new(None, vec![], strict(vec![])),
+                ),
+                ClassElement::field(
+                    ClassElementName::PropertyName(PropertyName::Computed(
+                        Const::from("constructor").into(),
+                    )),
+                    true,
+                    Node::from(Const::from(1)),
+                ),
+            ],
+        )
+        .into()],
+    );
+    check_invalid("class A { [a, b]() {} }");
+    check_invalid("class A { [a() {} }");
+}
+
+/// Checks that private members can't be deleted.
+#[test]
+fn class_declaration_delete_private_member() {
+    check_invalid("class A { #x; m() { delete this.#x; } }");
+    check_invalid("class A { #x; m() { delete this?.#x; } }");
+}
+
+/// Checks that a static initialization block can't return.
+#[test]
+fn class_declaration_static_block_return() {
+    check_invalid("class A { static { return; } }");
+}
@@ -12,8 +12,10 @@ use crate::{
     syntax::{
         ast::{node::DoWhileLoop, Keyword, Punctuator},
         parser::{
-            expression::Expression, statement::Statement, AllowAwait, AllowReturn, AllowYield,
-            Cursor, ParseError, TokenParser,
+            cursor::{BreakableKind, Cursor},
+            expression::Expression,
+            statement::Statement,
+            AllowAwait, AllowReturn, AllowYield, ParseError, TokenParser,
         },
     },
     BoaProfiler,
@@ -65,8 +67,10 @@ where
         let _timer = BoaProfiler::global().start_event("DoWhileStatement", "Parsing");
         cursor.expect(Keyword::Do, "do while statement")?;
 
+        cursor.push_breakable(BreakableKind::Iteration);
         let body =
             Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        cursor.pop_breakable();
 
         let next_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
@@ -7,18 +7,19 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/for
 //! [spec]: https://tc39.es/ecma262/#sec-for-statement
 
-use crate::syntax::lexer::TokenKind;
+use crate::syntax::lexer::{Error as LexError, TokenKind};
 use crate::{
     syntax::{
         ast::{
-            node::{ForLoop, ForOfLoop, Node},
-            Const, Keyword, Punctuator,
+            node::{Binding, ForInLoop, ForLoop, ForOfLoop, IterableLoopInitializer, Node},
+            Const, Keyword, Position, Punctuator,
         },
         parser::{
-            expression::Expression,
+            cursor::{BreakableKind, Cursor},
+            expression::{assignment_target, Expression},
             statement::declaration::Declaration,
-            statement::{variable::VariableDeclarationList, Statement},
-            AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser,
+            statement::{check_redeclared_names, variable::VariableDeclarationList, Statement},
+            AllowAwait, AllowReturn, AllowYield, ParseError, TokenParser,
         },
     },
     BoaProfiler,
@@ -72,7 +73,9 @@ where
         cursor.expect(Keyword::For, "for statement")?;
         cursor.expect(Punctuator::OpenParen, "for statement")?;
 
-        let init = match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+        let init_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let init_position = init_token.span().start();
+        let init = match init_token.kind() {
             TokenKind::Keyword(Keyword::Var) => {
                 let _ = cursor.next()?;
                 Some(
@@ -85,26 +88,66 @@ where
                 Some(Declaration::new(self.allow_yield, self.allow_await, false).parse(cursor)?)
             }
             TokenKind::Punctuator(Punctuator::Semicolon) => None,
-            _ => Some(Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?),
+            _ => Some(Expression::new(false, self.allow_yield, self.allow_await).parse(cursor)?),
         };
 
-        // TODO: for..in, for..of
         match cursor.peek(0)? {
-            Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::In) => {
-                unimplemented!("for...in statement")
+            Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::In) && init.is_some() => {
+                let variable =
+                    iterable_loop_initializer(init.as_ref().unwrap(), init_position, "for-in")?;
+                let _ = cursor.next();
+                let expr =
+                    Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                cursor.expect(Punctuator::CloseParen, "for in statement")?;
+                cursor.push_breakable(BreakableKind::Iteration);
+                let body = Statement::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor)?;
+                cursor.pop_breakable();
+                check_loop_declaration(init.as_ref().unwrap(), &body, init_position)?;
+                return Ok(ForInLoop::new(variable, expr, body).into());
             }
             Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::Of) && init.is_some() => {
+                let variable =
+                    iterable_loop_initializer(init.as_ref().unwrap(), init_position, "for-of")?;
                 let _ = cursor.next();
                 let iterable =
                     Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
                 cursor.expect(Punctuator::CloseParen, "for of statement")?;
+                cursor.push_breakable(BreakableKind::Iteration);
                 let body = Statement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)?;
-                return Ok(ForOfLoop::new(init.unwrap(), iterable, body).into());
+                cursor.pop_breakable();
+                check_loop_declaration(init.as_ref().unwrap(), &body, init_position)?;
+                return Ok(ForOfLoop::new(variable, iterable, body).into());
             }
             _ => {}
         }
 
+        // Binding patterns need an initializer, except in the head of a for-in or for-of loop.
+        let is_pattern = |binding: &Binding| !matches!(binding, Binding::Identifier(_));
+        let missing_init = match init {
+            Some(Node::VarDeclList(ref list)) => list
+                .as_ref()
+                .iter()
+                .any(|decl| decl.init().is_none() && is_pattern(decl.binding())),
+            Some(Node::LetDeclList(ref list)) => list
+                .as_ref()
+                .iter()
+                .any(|decl| decl.init().is_none() && is_pattern(decl.binding())),
+            Some(Node::ConstDeclList(ref list)) => list
+                .as_ref()
+                .iter()
+                .any(|decl| decl.init().is_none() && is_pattern(decl.binding())),
+            _ => false,
+        };
+        if missing_init {
+            return Err(ParseError::expected(
+                vec![TokenKind::Punctuator(Punctuator::Assign)],
+                cursor.next()?.ok_or(ParseError::AbruptEnd)?,
+                "for statement",
+            ));
+        }
+
         cursor.expect(Punctuator::Semicolon, "for statement")?;
 
         let cond = if cursor.next_if(Punctuator::Semicolon)?.is_some() {
@@ -126,10 +169,80 @@ where
             Some(step)
         };
 
+        cursor.push_breakable(BreakableKind::Iteration);
         let body =
             Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        cursor.pop_breakable();
+        if let Some(ref init) = init {
+            check_loop_declaration(init, &body, init_position)?;
+        }
 
         // TODO: do not encapsulate the `for` in a block just to have an inner scope.
         Ok(ForLoop::new(init, cond, step, body).into())
     }
 }
+
+/// Checks that the names of a `let` or `const` declaration in the head of a loop, `init`, are not
+/// declared again by a `var` declaration of the body of the loop.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-for-in-and-for-of-statements-static-semantics-early-errors
+fn check_loop_declaration(init: &Node, body: &Node, position: Position) -> Result<(), ParseError> {
+    let mut bound_names = Vec::new();
+    init.lexically_declared_names(&mut bound_names, false);
+    let mut var_names = Vec::new();
+    body.var_declared_names(&mut var_names);
+    check_redeclared_names(&bound_names, &var_names, position)
+}
+
+/// Converts the head of a `for...in` or `for...of` loop to the target of each value, `kind` is
+/// the kind of loop used in error messages.
+///
+/// A declaration must have a single binding without an initializer, and an expression must be a
+/// valid assignment target.
+fn iterable_loop_initializer(
+    init: &Node,
+    position: Position,
+    kind: &'static str,
+) -> Result<IterableLoopInitializer, ParseError> {
+    let error = |message: &str| {
+        ParseError::lex(LexError::Syntax(
+            format!("{} in the head of a {} loop", message, kind).into(),
+            position,
+        ))
+    };
+    let single_binding = |bindings: Vec<(&Binding, bool)>| match bindings.as_slice() {
+        [(binding, false)] => Ok((*binding).clone()),
+        [(_, true)] => Err(error("a declaration can't have an initializer")),
+        _ => Err(error("only one variable can be declared")),
+    };
+
+    match init {
+        Node::VarDeclList(list) => single_binding(
+            list.as_ref()
+                .iter()
+                .map(|decl| (decl.binding(), decl.init().is_some()))
+                .collect(),
+        )
+        .map(IterableLoopInitializer::Var),
+        Node::LetDeclList(list) => single_binding(
+            list.as_ref()
+                .iter()
+                .map(|decl| (decl.binding(), decl.init().is_some()))
+                .collect(),
+        )
+        .map(IterableLoopInitializer::Let),
+        Node::ConstDeclList(list) => single_binding(
+            list.as_ref()
+                .iter()
+                .map(|decl| (decl.binding(), decl.init().is_some()))
+                .collect(),
+        )
+        .map(IterableLoopInitializer::Const),
+        expr => assignment_target(expr)
+            .map(IterableLoopInitializer::Assign)
+            .ok_or_else(|| error("invalid left-hand side")),
+    }
+}
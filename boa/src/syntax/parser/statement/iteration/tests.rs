@@ -1,13 +1,14 @@
 use crate::syntax::{
     ast::{
         node::{
-            field::GetConstField, BinOp, Block, Break, Call, DoWhileLoop, Identifier, UnaryOp,
-            VarDecl, VarDeclList, WhileLoop,
+            field::GetConstField, BinOp, Binding, Block, Break, Call, DoWhileLoop, ForInLoop,
+            ForLoop, Identifier, IterableLoopInitializer, Node, UnaryOp, VarDecl, VarDeclList,
+            WhileLoop,
         },
         op::{self, AssignOp, CompOp},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks do-while statement parsing.
@@ -136,3 +137,59 @@ fn do_while_spaces() {
         .into()],
     );
 }
+
+/// Checks `for...in` statement parsing.
+#[test]
+fn check_for_in() {
+    check_parser(
+        "for (var a in b) {}",
+        vec![ForInLoop::new(
+            IterableLoopInitializer::Var("a".into()),
+            Identifier::from("b"),
+            Block::from(vec![]),
+        )
+        .into()],
+    );
+    check_parser(
+        "for (a.b in c) {}",
+        vec![ForInLoop::new(
+            IterableLoopInitializer::Assign(Binding::Member(Box::new(
+                GetConstField::new(Identifier::from("a"), "b").into(),
+            ))),
+            Identifier::from("c"),
+            Block::from(vec![]),
+        )
+        .into()],
+    );
+}
+
+/// Checks that `in` is a relational operator in the initializer of a `for` statement only when
+/// it's parenthesized.
+#[test]
+fn check_for_in_initializer() {
+    check_parser(
+        "for (var a = (b in c);;) {}",
+        vec![ForLoop::new(
+            Some(
+                VarDeclList::from(vec![VarDecl::new(
+                    "a",
+                    Some(
+                        BinOp::new(CompOp::In, Identifier::from("b"), Identifier::from("c")).into(),
+                    ),
+                )])
+                .into(),
+            ),
+            Some(Const::from(true).into()),
+            None::<Node>,
+            Block::from(vec![]),
+        )
+        .into()],
+    );
+}
+
+/// Checks that the head of a `for...in` statement declares a single variable.
+#[test]
+fn check_invalid_for_in() {
+    check_invalid("for (let a, b in c) {}");
+    check_invalid("for (a + 1 in b) {}");
+}
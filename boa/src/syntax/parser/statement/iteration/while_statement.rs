@@ -2,8 +2,10 @@ use crate::{
     syntax::{
         ast::{node::WhileLoop, Keyword, Punctuator},
         parser::{
-            expression::Expression, statement::Statement, AllowAwait, AllowReturn, AllowYield,
-            Cursor, ParseError, TokenParser,
+            cursor::{BreakableKind, Cursor},
+            expression::Expression,
+            statement::Statement,
+            AllowAwait, AllowReturn, AllowYield, ParseError, TokenParser,
         },
     },
     BoaProfiler,
@@ -62,8 +64,10 @@ where
 
         cursor.expect(Punctuator::CloseParen, "while statement")?;
 
+        cursor.push_breakable(BreakableKind::Iteration);
         let body =
             Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        cursor.pop_breakable();
 
         Ok(WhileLoop::new(cond, body))
     }
@@ -1,16 +1,23 @@
+#[cfg(test)]
+mod tests;
+
 use std::io::Read;
 
 use super::{LabelIdentifier, Statement};
 use crate::{
     syntax::ast::Node,
     syntax::{
-        ast::Punctuator,
+        ast::{node::Block, Keyword, Punctuator},
+        lexer::{Error as LexError, TokenKind},
         parser::{
-            cursor::Cursor, error::ParseError, AllowAwait, AllowReturn, AllowYield, TokenParser,
+            cursor::{Cursor, LabelKind},
+            error::ParseError,
+            AllowAwait, AllowReturn, AllowYield, TokenParser,
         },
     },
     BoaProfiler,
 };
+
 /// Labelled Statement Parsing
 ///
 /// More information
@@ -49,18 +56,59 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("Label", "Parsing");
+        let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
         let name = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
         cursor.expect(Punctuator::Colon, "Labelled Statement")?;
-        let mut stmt =
+
+        if cursor.has_label(&name) {
+            return Err(ParseError::lex(LexError::Syntax(
+                format!("label '{}' has already been declared", name).into(),
+                position,
+            )));
+        }
+
+        let kind = match cursor.peek(0)?.map(|tok| tok.kind().clone()) {
+            Some(TokenKind::Keyword(Keyword::For))
+            | Some(TokenKind::Keyword(Keyword::While))
+            | Some(TokenKind::Keyword(Keyword::Do)) => LabelKind::Iteration,
+            Some(TokenKind::Identifier(_))
+            | Some(TokenKind::Keyword(Keyword::Yield))
+            | Some(TokenKind::Keyword(Keyword::Await))
+                if cursor.peek(1)?.map(|tok| tok.kind())
+                    == Some(&TokenKind::Punctuator(Punctuator::Colon)) =>
+            {
+                LabelKind::Labelled
+            }
+            _ => LabelKind::Other,
+        };
+
+        cursor.push_label(name.clone(), kind);
+        let stmt =
             Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        cursor.pop_label();
 
-        set_label_for_node(&mut stmt, name);
-        Ok(stmt)
+        Ok(set_label_for_node(stmt, name))
     }
 }
 
-fn set_label_for_node(stmt: &mut Node, name: Box<str>) {
-    if let Node::ForLoop(ref mut for_loop) = stmt {
-        for_loop.set_label(name)
+/// Sets the label of the statement, so that it is the target of `break` and `continue`
+/// statements with the label.
+///
+/// A statement that can't have a label, or that already has one, is wrapped in a labelled block.
+fn set_label_for_node(mut stmt: Node, name: Box<str>) -> Node {
+    match stmt {
+        Node::ForLoop(ref mut node) if node.label().is_none() => node.set_label(name),
+        Node::ForInLoop(ref mut node) if node.label().is_none() => node.set_label(name),
+        Node::ForOfLoop(ref mut node) if node.label().is_none() => node.set_label(name),
+        Node::WhileLoop(ref mut node) if node.label().is_none() => node.set_label(name),
+        Node::DoWhileLoop(ref mut node) if node.label().is_none() => node.set_label(name),
+        Node::Block(ref mut node) if node.label().is_none() => node.set_label(name),
+        Node::Switch(ref mut node) if node.label().is_none() => node.set_label(name),
+        _ => {
+            let mut block = Block::from(vec![stmt]);
+            block.set_label(name);
+            return block.into();
+        }
     }
+    stmt
 }
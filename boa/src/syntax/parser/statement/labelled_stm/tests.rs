@@ -0,0 +1,32 @@
+use crate::syntax::{
+    ast::{
+        node::{Block, Break, If, Node},
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+#[test]
+fn labelled_block() {
+    let mut block = Block::from(vec![Break::new("label").into()]);
+    block.set_label("label".into());
+    check_parser("label: { break label; }", vec![block.into()]);
+}
+
+#[test]
+fn labelled_statement() {
+    let mut block = Block::from(vec![If::new::<_, _, Node, _>(
+        Const::from(true),
+        Break::new("label"),
+        None,
+    )
+    .into()]);
+    block.set_label("label".into());
+    check_parser("label: if (true) break label;", vec![block.into()]);
+}
+
+#[test]
+fn duplicate_label() {
+    check_invalid("label: label: ;");
+    check_invalid("label: { label: ; }");
+}
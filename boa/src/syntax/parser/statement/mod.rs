@@ -7,6 +7,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements
 //! [spec]: https://tc39.es/ecma262/#sec-ecmascript-language-statements-and-declarations
 
+mod binding;
 mod block;
 mod break_stm;
 mod continue_stm;
@@ -20,12 +21,12 @@ mod switch;
 mod throw;
 mod try_stm;
 mod variable;
+mod with;
 
 use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::Declaration,
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -33,6 +34,12 @@ use self::{
     switch::SwitchStatement,
     throw::ThrowStatement,
     try_stm::TryStatement,
+    with::WithStatement,
+};
+
+pub(super) use self::{
+    binding::BindingTarget,
+    declaration::{ClassDeclaration, Declaration, HoistableDeclaration},
     variable::VariableStatement,
 };
 
@@ -40,8 +47,8 @@ use super::{AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser
 
 use crate::{
     syntax::{
-        ast::{node, Keyword, Node, Punctuator},
-        lexer::{Error as LexError, InputElement, TokenKind},
+        ast::{node, Const, Keyword, Node, Position, Punctuator},
+        lexer::{is_reserved_word, Error as LexError, InputElement, Token, TokenKind},
     },
     BoaProfiler,
 };
@@ -159,6 +166,11 @@ where
                     .parse(cursor)
                     .map(Node::from)
             }
+            TokenKind::Keyword(Keyword::With) => {
+                WithStatement::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Keyword(Keyword::Throw) => {
                 ThrowStatement::new(self.allow_yield, self.allow_await)
                     .parse(cursor)
@@ -174,7 +186,9 @@ where
                     .parse(cursor)
                     .map(Node::from)
             }
-            TokenKind::Identifier(_) => {
+            TokenKind::Identifier(_)
+            | TokenKind::Keyword(Keyword::Yield)
+            | TokenKind::Keyword(Keyword::Await) => {
                 // Labelled Statement check
                 cursor.set_goal(InputElement::Div);
                 let tok = cursor.peek(1)?;
@@ -215,7 +229,6 @@ pub(super) struct StatementList {
     allow_await: AllowAwait,
     allow_return: AllowReturn,
     break_when_closingbraces: bool,
-    in_block: bool,
 }
 
 impl StatementList {
@@ -225,7 +238,6 @@ impl StatementList {
         allow_await: A,
         allow_return: R,
         break_when_closingbraces: bool,
-        in_block: bool,
     ) -> Self
     where
         Y: Into<AllowYield>,
@@ -237,7 +249,6 @@ impl StatementList {
             allow_await: allow_await.into(),
             allow_return: allow_return.into(),
             break_when_closingbraces,
-            in_block,
         }
     }
 
@@ -252,11 +263,13 @@ impl StatementList {
     /// performance impact of this more general mechanism.
     ///
     /// Note that the last token which causes the parse to finish is not consumed.
+    ///
+    /// The statements are returned with the position where each of them starts.
     pub(crate) fn parse_generalised<R>(
         self,
         cursor: &mut Cursor<R>,
         break_nodes: &[TokenKind],
-    ) -> Result<node::StatementList, ParseError>
+    ) -> Result<(node::StatementList, Vec<Position>), ParseError>
     where
         R: Read,
     {
@@ -271,37 +284,63 @@ impl StatementList {
                 return Err(ParseError::AbruptEnd);
             }
 
-            let item = StatementListItem::new(
-                self.allow_yield,
-                self.allow_await,
-                self.allow_return,
-                self.in_block,
-            )
-            .parse(cursor)?;
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+            let item =
+                StatementListItem::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor)?;
 
-            items.push(item);
+            items.push((item, position));
 
             // move the cursor forward for any consecutive semicolon.
             while cursor.next_if(Punctuator::Semicolon)?.is_some() {}
         }
 
-        items.sort_by(Node::hoistable_order);
+        items.sort_by(|(a, _), (b, _)| Node::hoistable_order(a, b));
+        let (items, positions): (Vec<_>, Vec<_>) = items.into_iter().unzip();
 
-        Ok(items.into())
+        let mut list = node::StatementList::from(items);
+        list.set_strict(cursor.strict_mode());
+        Ok((list, positions))
     }
-}
 
-impl<R> TokenParser<R> for StatementList
-where
-    R: Read,
-{
-    type Output = node::StatementList;
+    /// Parses the statements of a script or of a function body, which start with a directive
+    /// prologue, the statements that are only a string literal.
+    ///
+    /// A `"use strict"` directive makes the rest of the code strict mode code.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-directive-prologues-and-the-use-strict-directive
+    pub(super) fn parse_with_directives<R>(
+        self,
+        cursor: &mut Cursor<R>,
+    ) -> Result<node::StatementList, ParseError>
+    where
+        R: Read,
+    {
+        self.parse_list(cursor, true)
+    }
 
-    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+    /// Parses the list of statements, starting with a directive prologue if `directives` is
+    /// `true`.
+    fn parse_list<R>(
+        self,
+        cursor: &mut Cursor<R>,
+        directives: bool,
+    ) -> Result<node::StatementList, ParseError>
+    where
+        R: Read,
+    {
         let _timer = BoaProfiler::global().start_event("StatementList", "Parsing");
         let mut items = Vec::new();
+        let mut positions = Vec::new();
+        let mut in_prologue = directives;
+        let mut use_strict = false;
 
         loop {
+            let strict_mode = cursor.strict_mode();
+            let mut use_strict_token = false;
             match cursor.peek(0)? {
                 Some(token) if token.kind() == &TokenKind::Punctuator(Punctuator::CloseBlock) => {
                     if self.break_when_closingbraces {
@@ -317,25 +356,166 @@ where
                         break;
                     }
                 }
+                Some(token) if in_prologue => match token.kind() {
+                    // The code after a `"use strict"` directive is lexed as strict mode code
+                    // before the directive is known to be a whole statement.
+                    TokenKind::StringLiteral(_) if is_use_strict(token) => {
+                        use_strict_token = true;
+                        cursor.set_strict_mode(true)
+                    }
+                    TokenKind::StringLiteral(_) => {}
+                    _ => in_prologue = false,
+                },
                 _ => {}
             }
 
-            let item = StatementListItem::new(
-                self.allow_yield,
-                self.allow_await,
-                self.allow_return,
-                self.in_block,
-            )
-            .parse(cursor)?;
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+            let item =
+                StatementListItem::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor)?;
+            // A string literal that is part of an expression ends the prologue, and isn't a
+            // directive.
+            if in_prologue && !matches!(item, Node::Const(Const::String(_))) {
+                in_prologue = false;
+                cursor.set_strict_mode(strict_mode);
+            } else if use_strict_token {
+                use_strict = true;
+            }
             items.push(item);
+            positions.push(position);
 
             // move the cursor forward for any consecutive semicolon.
             while cursor.next_if(Punctuator::Semicolon)?.is_some() {}
         }
 
+        // The statements of functions and scripts start with a directive prologue, the function
+        // declarations at their top level are like `var` declarations.
+        check_declared_names(
+            items.iter().zip(positions),
+            !directives,
+            !cursor.strict_mode() && cursor.annex_b(),
+        )?;
+
         items.sort_by(Node::hoistable_order);
 
-        Ok(items.into())
+        if directives {
+            cursor.set_use_strict_directive(use_strict);
+        }
+
+        let mut list = node::StatementList::from(items);
+        list.set_strict(cursor.strict_mode());
+        Ok(list)
+    }
+}
+
+/// Checks that the names of the lexical declarations of a statement list are not declared again
+/// in its scope, by a lexical declaration or by a `var` declaration.
+///
+/// The function declarations of blocks are lexical declarations if `lexical_functions` is `true`,
+/// but they can be declared twice if `duplicate_functions` is `true`, which is the case in sloppy
+/// mode code with the web compatibility syntax of Annex B.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///  - [ECMAScript specification, Annex B][annex]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-block-static-semantics-early-errors
+/// [annex]: https://tc39.es/ecma262/#sec-block-duplicates-allowed-static-semantics
+pub(super) fn check_declared_names<'a, I>(
+    items: I,
+    lexical_functions: bool,
+    duplicate_functions: bool,
+) -> Result<(), ParseError>
+where
+    I: IntoIterator<Item = (&'a Node, Position)>,
+    I::IntoIter: Clone,
+{
+    let items = items.into_iter();
+    let mut var_names = Vec::new();
+    for (node, _) in items.clone() {
+        node.var_declared_names(&mut var_names);
+        if !lexical_functions
+            && matches!(
+                node,
+                Node::FunctionDecl(_) | Node::GeneratorDecl(_) | Node::AsyncFunctionDecl(_)
+            )
+        {
+            node.lexically_declared_names(&mut var_names, true);
+        }
+    }
+
+    let mut lexical_names = Vec::new();
+    let mut function_names = Vec::new();
+    for (node, position) in items {
+        let mut names = Vec::new();
+        node.lexically_declared_names(&mut names, lexical_functions);
+        let is_function =
+            matches!(node, Node::FunctionDecl(_)) && lexical_functions && duplicate_functions;
+        for name in names {
+            let redeclared_function = is_function && function_names.contains(&name);
+            if (lexical_names.contains(&name) && !redeclared_function) || var_names.contains(&name)
+            {
+                return Err(ParseError::lex(LexError::Syntax(
+                    format!("Identifier '{}' has already been declared", name).into(),
+                    position,
+                )));
+            }
+            lexical_names.push(name);
+            if is_function {
+                function_names.push(name);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that none of `bound_names`, the names bound by a parameter list, a catch parameter or
+/// the declaration in the head of a `for` loop, is declared again by a declaration of the same
+/// scope that can't redeclare it, whose names are `declared_names`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
+pub(in crate::syntax::parser) fn check_redeclared_names(
+    bound_names: &[&str],
+    declared_names: &[&str],
+    position: Position,
+) -> Result<(), ParseError> {
+    if let Some(name) = bound_names
+        .iter()
+        .find(|name| declared_names.contains(name))
+    {
+        return Err(ParseError::lex(LexError::Syntax(
+            format!("Identifier '{}' has already been declared", name).into(),
+            position,
+        )));
+    }
+    Ok(())
+}
+
+impl<R> TokenParser<R> for StatementList
+where
+    R: Read,
+{
+    type Output = node::StatementList;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        self.parse_list(cursor, false)
+    }
+}
+
+/// Checks if a token is the string literal of a `"use strict"` directive, which can't contain
+/// escape sequences or line continuations.
+fn is_use_strict(token: &Token) -> bool {
+    let span = token.span();
+    match token.kind() {
+        TokenKind::StringLiteral(string) => {
+            string.as_ref() == "use strict"
+                && span.start().line_number() == span.end().line_number()
+                && span.end().column_number() - span.start().column_number() == 12
+        }
+        _ => false,
     }
 }
 
@@ -350,16 +530,15 @@ where
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements
 /// [spec]: https://tc39.es/ecma262/#prod-StatementListItem
 #[derive(Debug, Clone, Copy)]
-struct StatementListItem {
+pub(super) struct StatementListItem {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     allow_return: AllowReturn,
-    in_block: bool,
 }
 
 impl StatementListItem {
     /// Creates a new `StatementListItem` parser.
-    fn new<Y, A, R>(allow_yield: Y, allow_await: A, allow_return: R, in_block: bool) -> Self
+    pub(super) fn new<Y, A, R>(allow_yield: Y, allow_await: A, allow_return: R) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -369,7 +548,6 @@ impl StatementListItem {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
             allow_return: allow_return.into(),
-            in_block,
         }
     }
 }
@@ -382,20 +560,24 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("StatementListItem", "Parsing");
-        let strict_mode = cursor.strict_mode();
+        // `async function`, there can't be a line terminator after `async`.
+        let is_async_function = matches!(
+            cursor.peek(0)?.map(|tok| tok.kind()),
+            Some(TokenKind::Identifier(ident)) if ident.as_ref() == "async"
+        ) && matches!(
+            cursor.peek_after_no_lineterminator()?.map(|tok| tok.kind()),
+            Some(TokenKind::Keyword(Keyword::Function))
+        );
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         match *tok.kind() {
-            TokenKind::Keyword(Keyword::Function) => {
-                if strict_mode && self.in_block {
-                    return Err(ParseError::lex(LexError::Syntax(
-                        "Function declaration in blocks not allowed in strict mode".into(),
-                        tok.span().start(),
-                    )));
-                }
+            TokenKind::Keyword(Keyword::Function)
+            | TokenKind::Keyword(Keyword::Const)
+            | TokenKind::Keyword(Keyword::Let)
+            | TokenKind::Keyword(Keyword::Class) => {
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
             }
-            TokenKind::Keyword(Keyword::Const) | TokenKind::Keyword(Keyword::Let) => {
+            TokenKind::Identifier(_) if is_async_function => {
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
             }
             _ => {
@@ -454,7 +636,26 @@ where
         let next_token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
 
         match next_token.kind() {
-            TokenKind::Identifier(ref s) => Ok(s.clone()),
+            // `static` is a valid class element modifier, so the lexer can't reject it.
+            TokenKind::Identifier(ref s) if cursor.strict_mode() && s.as_ref() == "static" => {
+                Err(ParseError::lex(LexError::Syntax(
+                    "using future reserved keyword 'static' not allowed in strict mode".into(),
+                    next_token.span().start(),
+                )))
+            }
+            // `eval` and `arguments` can be referenced in strict mode code, but not bound.
+            TokenKind::Identifier(ref s)
+                if cursor.strict_mode() && matches!(s.as_ref(), "eval" | "arguments") =>
+            {
+                Err(ParseError::lex(LexError::Syntax(
+                    format!("binding '{}' not allowed in strict mode", s).into(),
+                    next_token.span().start(),
+                )))
+            }
+            TokenKind::Identifier(ref s) => {
+                check_escaped_keyword(s, next_token.span().start())?;
+                Ok(s.clone())
+            }
             TokenKind::Keyword(k @ Keyword::Yield) if !self.allow_yield.0 => {
                 if cursor.strict_mode() {
                     Err(ParseError::lex(LexError::Syntax(
@@ -483,3 +684,26 @@ where
         }
     }
 }
+
+/// Checks that the name of an `Identifier` token isn't a reserved word.
+///
+/// The lexer only gives a reserved word as an identifier token when it contains escape sequences,
+/// since that is a valid `IdentifierName`, but it can't be an `Identifier`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-identifier-names-static-semantics-early-errors
+pub(in crate::syntax::parser) fn check_escaped_keyword(
+    name: &str,
+    position: Position,
+) -> Result<(), ParseError> {
+    if is_reserved_word(name) {
+        Err(ParseError::lex(LexError::Syntax(
+            format!("keyword '{}' must not contain escape sequences", name).into(),
+            position,
+        )))
+    } else {
+        Ok(())
+    }
+}
@@ -6,8 +6,10 @@ use crate::{
         ast::{node, node::Switch, Keyword, Punctuator},
         lexer::TokenKind,
         parser::{
-            expression::Expression, statement::StatementList, AllowAwait, AllowReturn, AllowYield,
-            Cursor, ParseError, TokenParser,
+            cursor::{BreakableKind, Cursor},
+            expression::Expression,
+            statement::{check_declared_names, StatementList},
+            AllowAwait, AllowReturn, AllowYield, ParseError, TokenParser,
         },
     },
     BoaProfiler,
@@ -68,8 +70,10 @@ where
 
         cursor.expect(Punctuator::CloseParen, "switch statement")?;
 
+        cursor.push_breakable(BreakableKind::Switch);
         let (cases, default) =
             CaseBlock::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        cursor.pop_breakable();
 
         Ok(Switch::new(condition, cases, default))
     }
@@ -115,6 +119,8 @@ where
 
         let mut cases = Vec::new();
         let mut default = None;
+        let mut case_positions = Vec::new();
+        let mut default_positions = Vec::new();
 
         loop {
             match cursor.next()? {
@@ -125,16 +131,16 @@ where
 
                     cursor.expect(Punctuator::Colon, "switch case block")?;
 
-                    let statement_list = StatementList::new(
+                    let (statement_list, positions) = StatementList::new(
                         self.allow_yield,
                         self.allow_await,
                         self.allow_return,
                         true,
-                        false,
                     )
                     .parse_generalised(cursor, &CASE_BREAK_TOKENS)?;
 
                     cases.push(node::Case::new(cond, statement_list));
+                    case_positions.extend(positions);
                 }
                 Some(token) if token.kind() == &TokenKind::Keyword(Keyword::Default) => {
                     if default.is_some() {
@@ -147,16 +153,16 @@ where
 
                     cursor.expect(Punctuator::Colon, "switch default block")?;
 
-                    let statement_list = StatementList::new(
+                    let (statement_list, positions) = StatementList::new(
                         self.allow_yield,
                         self.allow_await,
                         self.allow_return,
                         true,
-                        false,
                     )
                     .parse_generalised(cursor, &CASE_BREAK_TOKENS)?;
 
                     default = Some(statement_list);
+                    default_positions = positions;
                 }
                 Some(token) if token.kind() == &TokenKind::Punctuator(Punctuator::CloseBlock) => {
                     break
@@ -176,6 +182,15 @@ where
             }
         }
 
+        // The declarations of all the clauses are in the scope of the case block.
+        let statements = cases
+            .iter()
+            .map(node::Case::body)
+            .chain(default.as_ref())
+            .flat_map(node::StatementList::statements)
+            .zip(case_positions.into_iter().chain(default_positions));
+        check_declared_names(statements, true, cursor.annex_b() && !cursor.strict_mode())?;
+
         Ok((cases.into_boxed_slice(), default))
     }
 }
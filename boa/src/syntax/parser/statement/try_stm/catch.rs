@@ -1,11 +1,11 @@
 use crate::{
     syntax::{
         ast::{
-            node::{self, Identifier},
+            node::{self, Binding},
             Keyword, Punctuator,
         },
         parser::{
-            statement::{block::Block, BindingIdentifier},
+            statement::{block::Block, check_redeclared_names, BindingTarget},
             AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser,
         },
     },
@@ -55,19 +55,45 @@ where
         let _timer = BoaProfiler::global().start_event("Catch", "Parsing");
         cursor.expect(Keyword::Catch, "try statement")?;
         let catch_param = if cursor.next_if(Punctuator::OpenParen)?.is_some() {
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
             let catch_param =
                 CatchParameter::new(self.allow_yield, self.allow_await).parse(cursor)?;
             cursor.expect(Punctuator::CloseParen, "catch in try statement")?;
+
+            // The names bound by a catch parameter that is a pattern must be unique.
+            let mut bound_names = Vec::new();
+            catch_param.bound_names(&mut bound_names);
+            for (i, name) in bound_names.iter().enumerate() {
+                check_redeclared_names(&[name], &bound_names[..i], position)?;
+            }
             Some(catch_param)
         } else {
             None
         };
 
         // Catch block
-        Ok(node::Catch::new::<_, Identifier, _>(
-            catch_param,
-            Block::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?,
-        ))
+        let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+        let block =
+            Block::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+
+        // The catch parameter can't be redeclared in the block, except by a `var` declaration
+        // if it isn't a pattern, with the web compatibility syntax of Annex B.
+        if let Some(ref param) = catch_param {
+            let mut bound_names = Vec::new();
+            param.bound_names(&mut bound_names);
+            let mut declared_names = Vec::new();
+            for node in block.statements() {
+                node.lexically_declared_names(&mut declared_names, true);
+            }
+            if !(cursor.annex_b() && matches!(param, Binding::Identifier(_))) {
+                for node in block.statements() {
+                    node.var_declared_names(&mut declared_names);
+                }
+            }
+            check_redeclared_names(&bound_names, &declared_names, position)?;
+        }
+
+        Ok(node::Catch::new::<_, Binding, _>(catch_param, block))
     }
 }
 
@@ -103,12 +129,9 @@ impl<R> TokenParser<R> for CatchParameter
 where
     R: Read,
 {
-    type Output = Identifier;
+    type Output = Binding;
 
-    fn parse(self, cursor: &mut Cursor<R>) -> Result<Identifier, ParseError> {
-        // TODO: should accept BindingPattern
-        BindingIdentifier::new(self.allow_yield, self.allow_await)
-            .parse(cursor)
-            .map(Identifier::from)
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Binding, ParseError> {
+        BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)
     }
 }
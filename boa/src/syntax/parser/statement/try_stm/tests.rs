@@ -3,7 +3,7 @@ use crate::syntax::{
         node::{Block, Catch, Finally, Identifier, Try, VarDecl, VarDeclList},
         Const,
     },
-    parser::tests::{check_invalid, check_parser},
+    parser::tests::{check_invalid, check_invalid_at, check_parser},
 };
 
 #[test]
@@ -116,6 +116,13 @@ fn check_inline_invalid_catch_parameter() {
     check_invalid("try {} catch(1) {}");
 }
 
+#[test]
+fn check_inline_invalid_catch_duplicate_parameters() {
+    check_invalid_at("try {} catch ([e, e]) {}", 1, 15);
+    check_invalid_at("try {} catch ({ a: e, b: [e] }) {}", 1, 15);
+    check_invalid_at("try {} catch ({ e, ...e }) {}", 1, 15);
+}
+
 #[test]
 fn check_invalide_try_no_catch_finally() {
     check_invalid("try {} let a = 10;");
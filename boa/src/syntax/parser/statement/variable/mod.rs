@@ -3,14 +3,14 @@
 use crate::{
     syntax::{
         ast::{
-            node::{VarDecl, VarDeclList},
+            node::{Binding, VarDecl, VarDeclList},
             Keyword, Punctuator,
         },
         lexer::TokenKind,
         parser::{
             cursor::{Cursor, SemicolonResult},
             expression::Initializer,
-            statement::BindingIdentifier,
+            statement::BindingTarget,
             AllowAwait, AllowIn, AllowYield, ParseError, TokenParser,
         },
     },
@@ -29,14 +29,14 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/var
 /// [spec]: https://tc39.es/ecma262/#prod-VariableStatement
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser::statement) struct VariableStatement {
+pub(in crate::syntax::parser) struct VariableStatement {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl VariableStatement {
     /// Creates a new `VariableStatement` parser.
-    pub(in crate::syntax::parser::statement) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -167,9 +167,7 @@ where
     type Output = VarDecl;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        // TODO: BindingPattern
-
-        let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let binding = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         let init = if let Some(t) = cursor.peek(0)? {
             if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
@@ -181,6 +179,15 @@ where
             None
         };
 
-        Ok(VarDecl::new(name, init))
+        // Only the head of a `for` statement can have a binding pattern without an initializer.
+        if init.is_none() && self.allow_in.0 && !matches!(binding, Binding::Identifier(_)) {
+            return Err(ParseError::expected(
+                vec![TokenKind::Punctuator(Punctuator::Assign)],
+                cursor.next()?.ok_or(ParseError::AbruptEnd)?,
+                "variable declaration",
+            ));
+        }
+
+        Ok(VarDecl::new(binding, init))
     }
 }
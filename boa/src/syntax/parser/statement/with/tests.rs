@@ -0,0 +1,50 @@
+use crate::syntax::{
+    ast::{
+        node::{Assign, Block, Identifier, Node, With},
+        Const,
+    },
+    parser::{tests::check_parser, Parser},
+};
+
+#[test]
+fn with_statement() {
+    check_parser(
+        "with (obj) { a = 1; }",
+        vec![With::new(
+            Identifier::from("obj"),
+            Block::from(vec![
+                Assign::new(Identifier::from("a"), Const::from(1)).into()
+            ]),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn with_without_block() {
+    check_parser(
+        "with (obj) a;",
+        vec![With::new(Identifier::from("obj"), Identifier::from("a")).into()],
+    );
+}
+
+#[test]
+fn with_in_strict_mode() {
+    assert!(Parser::new(b"'use strict'; with (obj) {}".as_ref())
+        .parse_all()
+        .is_err());
+    assert!(
+        Parser::new(b"function f() { 'use strict'; with (obj) {} }".as_ref())
+            .parse_all()
+            .is_err()
+    );
+    assert!(Parser::new(b"function f() { with (obj) {} }".as_ref())
+        .parse_all()
+        .is_ok());
+}
+
+#[test]
+fn with_display() {
+    let node: Node = With::new(Identifier::from("obj"), Block::from(Vec::new())).into();
+    assert_eq!(node.to_string(), "with (obj) {\n}");
+}
@@ -1,14 +1,17 @@
 //! Tests for the parser.
 
-use super::Parser;
-use crate::syntax::ast::{
-    node::{
-        field::GetConstField, ArrowFunctionDecl, Assign, BinOp, Call, FormalParameter,
-        FunctionDecl, Identifier, LetDecl, LetDeclList, New, Node, Return, StatementList, UnaryOp,
-        VarDecl, VarDeclList,
+use super::{ParseError, Parser};
+use crate::syntax::{
+    ast::{
+        node::{
+            field::GetConstField, ArrowFunctionDecl, Assign, BinOp, Call, FormalParameter,
+            FunctionDecl, Identifier, LetDecl, LetDeclList, New, Node, Return, StatementList,
+            UnaryOp, VarDecl, VarDeclList,
+        },
+        op::{self, CompOp, LogOp, NumOp},
+        Const, Position,
     },
-    op::{self, CompOp, LogOp, NumOp},
-    Const,
+    lexer::Error as LexError,
 };
 
 /// Checks that the given JavaScript string gives the expected expression.
@@ -26,12 +29,35 @@ where
     );
 }
 
+/// Creates a list of statements that are strict mode code.
+pub(super) fn strict<L>(statements: L) -> StatementList
+where
+    L: Into<Box<[Node]>>,
+{
+    let mut list = StatementList::from(statements);
+    list.set_strict(true);
+    list
+}
+
 /// Checks that the given javascript string creates a parse error.
 #[track_caller]
 pub(super) fn check_invalid(js: &str) {
     assert!(Parser::new(js.as_bytes()).parse_all().is_err());
 }
 
+/// Checks that the given javascript string creates a syntax error at the given line and column.
+#[track_caller]
+pub(super) fn check_invalid_at(js: &str, line: u32, column: u32) {
+    let position = match Parser::new(js.as_bytes()).parse_all() {
+        Err(ParseError::Lex {
+            err: LexError::Syntax(_, position),
+        })
+        | Err(ParseError::General { position, .. }) => position,
+        result => panic!("expected a syntax error, got {:?}", result),
+    };
+    assert_eq!(position, Position::new(line, column));
+}
+
 /// Should be parsed as `new Class().method()` instead of `new (Class().method())`
 #[test]
 fn check_construct_call_precedence() {
@@ -292,3 +318,252 @@ fn spread_in_arrow_function() {
         ],
     );
 }
+
+#[test]
+fn use_strict_directive() {
+    assert_eq!(
+        Parser::new(&b"\"use strict\"; a"[..])
+            .parse_all()
+            .expect("failed to parse"),
+        strict(vec![
+            Const::from("use strict").into(),
+            Identifier::from("a").into()
+        ])
+    );
+}
+
+#[test]
+fn use_strict_not_a_directive() {
+    check_parser(
+        "a; \"use strict\"",
+        vec![
+            Identifier::from("a").into(),
+            Const::from("use strict").into(),
+        ],
+    );
+    check_parser("'use\\x20strict'", vec![Const::from("use strict").into()]);
+}
+
+#[test]
+fn strict_mode_early_errors() {
+    check_invalid("\"use strict\"; 010");
+    check_invalid("\"use strict\"; with (a) {}");
+    check_invalid("'use strict'; function f(a, a) {}");
+    check_invalid("function f(a, a) { 'use strict'; }");
+    check_invalid("function f(a, [b, a]) { 'use strict'; }");
+    check_invalid("class A { m(a, a) {} }");
+    check_invalid("'use strict'; function f(eval) {}");
+    check_invalid("'use strict'; let arguments = 1;");
+}
+
+#[test]
+fn use_strict_with_non_simple_parameters() {
+    check_invalid("function f(a = 1) { \"use strict\" }");
+    check_invalid("'use strict'; function f(a = 1) { 'use strict'; }");
+    check_invalid("function f({ a }) { 'use strict'; }");
+    check_invalid("function f(...a) { 'a'; 'use strict'; }");
+    check_invalid("(a = 1) => { 'use strict'; }");
+    check_invalid("({ set a([b]) { 'use strict'; } })");
+    check_invalid("class A { m(a = 1) { 'use strict'; } }");
+
+    assert!(Parser::new(&b"'use strict'; function f(a = 1) {}"[..])
+        .parse_all()
+        .is_ok());
+    assert!(
+        Parser::new(&b"function f(a = 1) { 'a' + 'use strict'; }"[..])
+            .parse_all()
+            .is_ok()
+    );
+    assert!(
+        Parser::new(&b"function f() { 'use strict'; } (a = 1) => a"[..])
+            .parse_all()
+            .is_ok()
+    );
+}
+
+#[test]
+fn strict_mode_eval_and_arguments_references() {
+    assert_eq!(
+        Parser::new(&b"'use strict'; eval(arguments);"[..])
+            .parse_all()
+            .expect("failed to parse"),
+        strict(vec![
+            Const::from("use strict").into(),
+            Call::new(
+                Identifier::from("eval"),
+                vec![Identifier::from("arguments").into()],
+            )
+            .into(),
+        ])
+    );
+}
+
+#[test]
+fn sloppy_duplicate_parameters() {
+    check_parser(
+        "function f(a, a) {}",
+        vec![FunctionDecl::new(
+            Box::from("f"),
+            vec![
+                FormalParameter::new("a", None, false),
+                FormalParameter::new("a", None, false),
+            ],
+            vec![],
+        )
+        .into()],
+    );
+
+    check_invalid("(a, a) => {};");
+    check_invalid("async (a, [a]) => {};");
+    check_invalid("function f(a, a = 1) {}");
+    check_invalid("function f(a, ...a) {}");
+    check_invalid("(function ({ a }, a) {});");
+    check_invalid("({ m(a, a) {} });");
+    check_invalid("({ set m([a, a]) {} });");
+}
+
+#[test]
+fn lexical_redeclarations() {
+    check_invalid("let a; var a;");
+    check_invalid("var a; let a;");
+    check_invalid("let a; let a;");
+    check_invalid("const a = 1; let a;");
+    check_invalid("class a {} var a;");
+    check_invalid("let f; function f() {}");
+    check_invalid("{ let a; var a; }");
+    check_invalid("{ var a; } let a;");
+    check_invalid("function f() { let a; { var a; } }");
+    check_invalid("'use strict'; { function f() {} function f() {} }");
+    check_invalid("function f(a) { let a; }");
+    check_invalid("function f({ a }) { const a = 1; }");
+    check_invalid("(a) => { class a {} };");
+    check_invalid("try {} catch (e) { let e; }");
+    check_invalid("try {} catch ([e]) { var e; }");
+    check_invalid("for (let a of b) { var a; }");
+    check_invalid("for (const a in b) var a;");
+    check_invalid("for (let a;;) { var a; }");
+    check_invalid("switch (a) { case 1: let b; case 2: let b; }");
+    check_invalid("switch (a) { case 1: let b; default: var b; }");
+    check_invalid("switch (a) { default: const b = 1; class b {} }");
+    check_invalid("'use strict'; switch (a) { case 1: function f() {} default: function f() {} }");
+
+    assert!(Parser::new(&b"var a; var a; function a() {}"[..])
+        .parse_all()
+        .is_ok());
+    assert!(Parser::new(&b"{ function f() {} function f() {} }"[..])
+        .parse_all()
+        .is_ok());
+    assert!(Parser::new(&b"let a; { let a; }"[..]).parse_all().is_ok());
+    assert!(Parser::new(&b"function f(a) { var a; { let a; } }"[..])
+        .parse_all()
+        .is_ok());
+    assert!(Parser::new(&b"try {} catch (e) { var e; }"[..])
+        .parse_all()
+        .is_ok());
+    assert!(Parser::new(&b"for (let a of b) { let a; }"[..])
+        .parse_all()
+        .is_ok());
+    assert!(
+        Parser::new(&b"switch (a) { case 1: { let b; } default: let b; }"[..])
+            .parse_all()
+            .is_ok()
+    );
+    assert!(
+        Parser::new(&b"switch (a) { case 1: function f() {} default: function f() {} }"[..])
+            .parse_all()
+            .is_ok()
+    );
+}
+
+#[test]
+fn super_early_errors() {
+    check_invalid("super.a;");
+    check_invalid("super();");
+    check_invalid("function f() { super.a; }");
+    check_invalid("({ m() { function f() { super[a]; } } });");
+    check_invalid("({ f: function () { super.a; } });");
+    check_invalid("class A { constructor() { super(); } }");
+    check_invalid("class A extends B { m() { super(); } }");
+    check_invalid("class A extends B { a = super(); }");
+    check_invalid("class A extends B { constructor() { function f() { super(); } } }");
+    check_invalid("class A extends B { [super.a]() {} }");
+    check_invalid("({ m() { super(); } });");
+
+    for js in [
+        "({ m() { super.a; } });",
+        "({ get a() { return () => super[a]; } });",
+        "class A { m() { super.a; } static { super.a; } }",
+        "class A { a = super.b; }",
+        "class A extends B { constructor() { super(); () => super(); } }",
+        "({ m() { class A extends super.a { [super.b]() {} } } });",
+    ] {
+        assert!(
+            Parser::new(js.as_bytes()).parse_all().is_ok(),
+            "{} was rejected",
+            js
+        );
+    }
+}
+
+#[test]
+fn early_error_positions() {
+    check_invalid_at("var a;\nfunction f() {\n  return super.a;\n}", 3, 10);
+    check_invalid_at("let a = 1;\nclass A { m() { super(); } }", 2, 17);
+    check_invalid_at("a;\n(a, a) => {};", 2, 8);
+    check_invalid_at("a;\n({ m(a, a) {} });", 2, 5);
+    check_invalid_at(
+        "switch (a) {\n  case 1: let b;\n  default: let b;\n}",
+        3,
+        12,
+    );
+    check_invalid_at("a;\ntry {} catch ({ e, f: [e] }) {}", 2, 15);
+}
+
+#[test]
+fn escaped_reserved_words() {
+    for js in &[
+        r"o.i\u0066;",
+        r"o?.n\u{65}w;",
+        r"({ n\u0065w: 1, tru\u0065() {} });",
+        r"class A { n\u0065w() {} static i\u0066 = 1; }",
+        r"var l\u0065t;",
+    ] {
+        assert!(
+            Parser::new(js.as_bytes()).parse_all().is_ok(),
+            "{} was rejected",
+            js
+        );
+    }
+
+    check_invalid(r"var i\u0066;");
+    check_invalid(r"i\u0066 (a) {}");
+    check_invalid(r"n\u0065w A;");
+    check_invalid(r"tru\u0065;");
+    check_invalid(r"({ i\u0066 });");
+    check_invalid(r"({ i\u0066 = 1 } = {});");
+    check_invalid(r"function f(n\u0075ll) {}");
+    check_invalid(r"l: { br\u0065ak l; }");
+}
+
+#[test]
+fn new_target() {
+    check_parser(
+        "function f() { new.target; }",
+        vec![FunctionDecl::new(Box::from("f"), vec![], vec![Node::NewTarget]).into()],
+    );
+    check_parser(
+        "function f() { () => new.target; }",
+        vec![FunctionDecl::new(
+            Box::from("f"),
+            vec![],
+            vec![
+                ArrowFunctionDecl::new(vec![], vec![Return::new(Node::NewTarget, None).into()])
+                    .into(),
+            ],
+        )
+        .into()],
+    );
+    check_invalid("new.target;");
+    check_invalid("() => new.target;");
+    check_invalid("function f() { new.foo; }");
+}
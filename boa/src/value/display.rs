@@ -1,4 +1,5 @@
 use super::*;
+use crate::builtins::promise::PromiseState;
 
 /// This object is used for displaying a `Value`.
 #[derive(Debug, Clone, Copy)]
@@ -48,16 +49,23 @@ macro_rules! print_obj_value {
     };
     (props of $obj:expr, $display_fn:ident, $indent:expr, $encounters:expr, $print_internals:expr) => {
         print_obj_value!(impl $obj, |(key, val)| {
-            let v = &val
-                // FIXME: handle accessor descriptors
-                .as_data_descriptor()
-                .unwrap()
-                .value();
+            let v = match val {
+                PropertyDescriptor::Data(ref data) => data.value(),
+                PropertyDescriptor::Accessor(ref accessor) => {
+                    let kind = match (accessor.getter(), accessor.setter()) {
+                        (Some(_), Some(_)) => "[Getter/Setter]",
+                        (Some(_), None) => "[Getter]",
+                        (None, Some(_)) => "[Setter]",
+                        (None, None) => "undefined",
+                    };
+                    return format!("{:>width$}: {}", key, kind, width = $indent);
+                }
+            };
 
             format!(
                 "{:>width$}: {}",
                 key,
-                $display_fn(v, $encounters, $indent.wrapping_add(4), $print_internals),
+                $display_fn(&v, $encounters, $indent.wrapping_add(4), $print_internals),
                 width = $indent,
             )
         })
@@ -165,6 +173,17 @@ pub(crate) fn log_string_from(x: &Value, print_internals: bool, print_children:
                         format!("Map({})", size)
                     }
                 }
+                ObjectData::Promise(ref promise) => match promise.state() {
+                    PromiseState::Pending => String::from("Promise { <pending> }"),
+                    PromiseState::Fulfilled(ref value) => format!(
+                        "Promise {{ {} }}",
+                        log_string_from(value, print_internals, false)
+                    ),
+                    PromiseState::Rejected(ref reason) => format!(
+                        "Promise {{ <rejected> {} }}",
+                        log_string_from(reason, print_internals, false)
+                    ),
+                },
                 _ => display_obj(&x, print_internals),
             }
         }
@@ -188,8 +207,8 @@ pub(crate) fn display_obj(v: &Value, print_internals: bool) -> String {
 
     if let Value::Object(object) = v {
         if object.borrow().is_error() {
-            let name = v.get_field("name");
-            let message = v.get_field("message");
+            let name = v.get_data_field("name");
+            let message = v.get_data_field("message");
             return format!("{}: {}", name.display(), message.display());
         }
     }
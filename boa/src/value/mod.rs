@@ -154,7 +154,7 @@ impl Value {
         let _timer = BoaProfiler::global().start_event("new_object", "value");
 
         if let Some(global) = global {
-            let object_prototype = global.get_field("Object").get_field(PROTOTYPE);
+            let object_prototype = global.get_data_field("Object").get_data_field(PROTOTYPE);
 
             let object = Object::create(object_prototype);
             Self::object(object)
@@ -216,7 +216,7 @@ impl Value {
 
     /// Converts the `Value` to `JSON`.
     pub fn to_json(&self, interpreter: &mut Context) -> Result<JSONValue> {
-        let to_json = self.get_field("toJSON");
+        let to_json = self.get_field("toJSON", interpreter)?;
         if to_json.is_function() {
             let json_value = interpreter.call(&to_json, self, &[])?;
             return json_value.to_json(interpreter);
@@ -310,6 +310,28 @@ impl Value {
         matches!(self, Self::Object(o) if o.borrow().is_function())
     }
 
+    /// Returns true if the value is an object with a `[[Call]]` internal method.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iscallable
+    #[inline]
+    pub fn is_callable(&self) -> bool {
+        matches!(self, Self::Object(o) if o.borrow().is_callable())
+    }
+
+    /// Returns true if the value is an object with a `[[Construct]]` internal method.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isconstructor
+    #[inline]
+    pub fn is_constructor(&self) -> bool {
+        matches!(self, Self::Object(o) if o.borrow().is_constructable())
+    }
+
     /// Returns true if the value is undefined.
     #[inline]
     pub fn is_undefined(&self) -> bool {
@@ -462,21 +484,37 @@ impl Value {
         }
     }
 
-    /// Resolve the property in the object and get its value, or undefined if this is not an object or the field doesn't exist
-    /// get_field recieves a Property from get_prop(). It should then return the [[Get]] result value if that's set, otherwise fall back to [[Value]]
-    /// TODO: this function should use the get Value if its set
-    pub fn get_field<K>(&self, key: K) -> Self
+    /// Gets the value of a property of the object, or `undefined` if this is not an object or the
+    /// property doesn't exist.
+    ///
+    /// The getter of an accessor property is called with this value as `this`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-o-p
+    pub fn get_field<K>(&self, key: K, ctx: &mut Context) -> Result<Self>
     where
         K: Into<PropertyKey>,
     {
         let _timer = BoaProfiler::global().start_event("Value::get_field", "value");
-        let key = key.into();
+        match self {
+            Self::Object(ref object) => object.get(&key.into(), self.clone(), ctx),
+            _ => Ok(Value::undefined()),
+        }
+    }
+
+    /// Gets the value of a data property of the object, without running the getters of accessor
+    /// properties, which give `undefined`.
+    ///
+    /// This is used where no JavaScript can run, like when displaying a value.
+    pub(crate) fn get_data_field<K>(&self, key: K) -> Self
+    where
+        K: Into<PropertyKey>,
+    {
         match self.get_property(key) {
-            Some(ref desc) => match desc {
-                PropertyDescriptor::Accessor(_) => todo!(),
-                PropertyDescriptor::Data(desc) => desc.value(),
-            },
-            None => Value::undefined(),
+            Some(PropertyDescriptor::Data(ref desc)) => desc.value(),
+            _ => Value::undefined(),
         }
     }
 
@@ -492,9 +530,16 @@ impl Value {
             .unwrap_or(false)
     }
 
-    /// Set the field in the value
+    /// Sets the value of a property of the object, returning the value.
+    ///
+    /// The setter of an accessor property is called with this value as `this`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-o-p-v-throw
     #[inline]
-    pub fn set_field<K, V>(&self, key: K, value: V) -> Value
+    pub fn set_field<K, V>(&self, key: K, value: V, ctx: &mut Context) -> Result<Value>
     where
         K: Into<PropertyKey>,
         V: Into<Value>,
@@ -502,18 +547,10 @@ impl Value {
         let key = key.into();
         let value = value.into();
         let _timer = BoaProfiler::global().start_event("Value::set_field", "value");
-        if let Self::Object(ref obj) = *self {
-            if let PropertyKey::Index(index) = key {
-                if obj.borrow().is_array() {
-                    let len = self.get_field("length").as_number().unwrap() as u32;
-                    if len < index + 1 {
-                        self.set_field("length", index + 1);
-                    }
-                }
-            }
-            obj.borrow_mut().set(key, value.clone());
+        if let Self::Object(ref object) = *self {
+            object.set(key, value.clone(), self.clone(), ctx)?;
         }
-        value
+        Ok(value)
     }
 
     /// Set the kind of an object.
@@ -664,7 +701,11 @@ impl Value {
                 let mut object =
                     Object::with_prototype(prototype.into(), ObjectData::String(string.clone()));
                 // Make sure the correct length is set on our new string object
-                object.set("length".into(), string.chars().count().into());
+                object.insert_property(
+                    "length",
+                    string.chars().count(),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+                );
                 Ok(GcObject::new(object))
             }
             Value::Symbol(ref symbol) => {
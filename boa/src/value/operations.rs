@@ -589,6 +589,55 @@ impl Value {
             AbstractRelation::True | AbstractRelation::Undefined => Ok(false),
         }
     }
+
+    /// The `instanceof` operator checks if `target` considers the value an instance of itself.
+    ///
+    /// The check is done by the `Symbol.hasInstance` method of `target` if it has one, or else
+    /// by looking for the `prototype` of `target` in the prototype chain of the value.
+    ///
+    /// More Information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/instanceof
+    /// [spec]: https://tc39.es/ecma262/#sec-instanceofoperator
+    pub fn instance_of(&self, target: &Self, ctx: &mut Context) -> Result<bool> {
+        // 1. If Type(target) is not Object, throw a TypeError exception.
+        let object = match target {
+            Self::Object(ref object) => object,
+            _ => {
+                return Err(ctx.construct_type_error(format!(
+                    "right-hand side of 'instanceof' should be an object, got {}",
+                    target.get_type().as_str()
+                )))
+            }
+        };
+
+        // 2. Let instOfHandler be ? GetMethod(target, @@hasInstance).
+        let key = ctx.well_known_symbols().has_instance_symbol();
+        let handler = object.get(&key.into(), target.clone(), ctx)?;
+
+        // 3. If instOfHandler is not undefined, then
+        if !handler.is_null_or_undefined() {
+            if !handler.is_callable() {
+                return Err(
+                    ctx.construct_type_error(format!("{} is not a function", handler.display()))
+                );
+            }
+            // a. Return ! ToBoolean(? Call(instOfHandler, target, « V »)).
+            return Ok(ctx
+                .call(&handler, target, std::slice::from_ref(self))?
+                .to_boolean());
+        }
+
+        // 4. If IsCallable(target) is false, throw a TypeError exception.
+        if !target.is_callable() {
+            return Err(ctx.construct_type_error("right-hand side of 'instanceof' is not callable"));
+        }
+
+        // 5. Return ? OrdinaryHasInstance(target, V).
+        object.ordinary_has_instance(self, ctx)
+    }
 }
 
 /// The result of the [Abstract Relational Comparison][arc].
@@ -29,11 +29,18 @@ fn undefined() {
 
 #[test]
 fn get_set_field() {
+    let mut context = Context::new();
     let obj = Value::new_object(None);
     // Create string and convert it to a Value
     let s = Value::from("bar");
-    obj.set_field("foo", s);
-    assert_eq!(obj.get_field("foo").display().to_string(), "\"bar\"");
+    obj.set_field("foo", s, &mut context).unwrap();
+    assert_eq!(
+        obj.get_field("foo", &mut context)
+            .unwrap()
+            .display()
+            .to_string(),
+        "\"bar\""
+    );
 }
 
 #[test]
@@ -153,6 +153,9 @@ pub fn main() -> Result<(), std::io::Error> {
                 Ok(v) => println!("{}", v.display()),
                 Err(v) => eprintln!("Uncaught {}", v.display()),
             }
+            if let Err(v) = engine.run_jobs() {
+                eprintln!("Uncaught {}", v.display());
+            }
         }
     }
 
@@ -191,6 +194,9 @@ pub fn main() -> Result<(), std::io::Error> {
                                 eprintln!("{}: {}", "Uncaught".red(), v.display().to_string().red())
                             }
                         }
+                        if let Err(v) = engine.run_jobs() {
+                            eprintln!("{}: {}", "Uncaught".red(), v.display().to_string().red());
+                        }
                     }
                 }
 
@@ -4,11 +4,16 @@ use super::{
     Harness, Outcome, Phase, SuiteResult, Test, TestFlags, TestOutcomeResult, TestResult,
     TestSuite, CLI,
 };
-use boa::{parse, Context};
+use boa::{
+    module::{Module, SimpleModuleLoader},
+    parse,
+    syntax::Parser,
+    Context, Result, Value,
+};
 use colored::Colorize;
 use fxhash::FxHashSet;
 use once_cell::sync::Lazy;
-use std::{fs, panic, path::Path};
+use std::{borrow::Cow, cell::RefCell, fs, panic, path::Path, rc::Rc};
 
 /// List of ignored tests.
 static IGNORED: Lazy<FxHashSet<Box<str>>> = Lazy::new(|| {
@@ -25,6 +30,14 @@ static IGNORED: Lazy<FxHashSet<Box<str>>> = Lazy::new(|| {
     }
 });
 
+thread_local! {
+    /// The last message printed by an async test, with the `print` function.
+    static PRINTED: RefCell<Option<String>> = RefCell::new(None);
+}
+
+/// The message printed by `$DONE` when an async test completes successfully.
+const ASYNC_TEST_COMPLETE: &str = "Test262:AsyncTestComplete";
+
 impl TestSuite {
     /// Runs the test suite.
     pub(crate) fn run(&self, harness: &Harness) -> SuiteResult {
@@ -87,32 +100,27 @@ impl Test {
     pub(crate) fn run(&self, harness: &Harness) -> TestResult {
         // println!("Starting `{}`", self.name);
 
-        let result = if !self.flags.intersects(TestFlags::ASYNC | TestFlags::MODULE)
-            && !IGNORED.contains(&self.name)
-        {
+        let result = if !IGNORED.contains(&self.name) {
             let res = panic::catch_unwind(|| {
                 match self.expected_outcome {
                     Outcome::Positive => {
                         let mut passed = true;
 
-                        if self.flags.contains(TestFlags::RAW) {
-                            let mut engine = self.set_up_env(&harness, false);
-                            let res = engine.eval(&self.content);
-
-                            passed = res.is_ok()
+                        if self.flags.contains(TestFlags::MODULE) {
+                            let mut engine = self.set_up_env(&harness);
+                            passed = self.eval_module(&mut engine);
+                        } else if self.flags.contains(TestFlags::RAW) {
+                            let mut engine = self.set_up_env(&harness);
+                            passed = self.eval(&mut engine, false);
                         } else {
                             if self.flags.contains(TestFlags::STRICT) {
-                                let mut engine = self.set_up_env(&harness, true);
-                                let res = engine.eval(&self.content);
-
-                                passed = res.is_ok()
+                                let mut engine = self.set_up_env(&harness);
+                                passed = self.eval(&mut engine, true);
                             }
 
                             if passed && self.flags.contains(TestFlags::NO_STRICT) {
-                                let mut engine = self.set_up_env(&harness, false);
-                                let res = engine.eval(&self.content);
-
-                                passed = res.is_ok()
+                                let mut engine = self.set_up_env(&harness);
+                                passed = self.eval(&mut engine, false);
                             }
                         }
 
@@ -129,7 +137,23 @@ impl Test {
                             self.name
                         );
 
-                        parse(&self.content).is_err()
+                        if self.flags.contains(TestFlags::MODULE) {
+                            Parser::new(self.content.as_bytes()).parse_module().is_err()
+                        } else if self.flags.contains(TestFlags::RAW) {
+                            parse(&self.content).is_err()
+                        } else {
+                            (!self.flags.contains(TestFlags::STRICT)
+                                || parse(&self.code(true)).is_err())
+                                && (!self.flags.contains(TestFlags::NO_STRICT)
+                                    || parse(&self.code(false)).is_err())
+                        }
+                    }
+                    Outcome::Negative {
+                        phase: Phase::Resolution,
+                        error_type: _,
+                    } if self.flags.contains(TestFlags::MODULE) => {
+                        let mut engine = self.set_up_env(&harness);
+                        self.link_module(&mut engine).is_err()
                     }
                     Outcome::Negative {
                         phase: _,
@@ -165,8 +189,6 @@ impl Test {
 
             result
         } else {
-            // Ignoring async tests for now.
-            // TODO: implement async and add `harness/doneprintHandle.js` to the includes.
             print!("{}", ".".yellow());
             TestOutcomeResult::Ignored
         };
@@ -177,25 +199,88 @@ impl Test {
         }
     }
 
+    /// Gets the code of the test, with a `"use strict"` directive if it must run as strict mode
+    /// code.
+    fn code(&self, strict: bool) -> Cow<'_, str> {
+        if strict {
+            Cow::Owned(format!("\"use strict\";\n{}", self.content))
+        } else {
+            Cow::Borrowed(&self.content)
+        }
+    }
+
+    /// Runs the test code, and checks that it completes successfully.
+    fn eval(&self, engine: &mut Context, strict: bool) -> bool {
+        engine.eval(&self.code(strict)).is_ok() && self.complete(engine)
+    }
+
+    /// Runs the test as a module, and checks that it completes successfully.
+    fn eval_module(&self, engine: &mut Context) -> bool {
+        match self.link_module(engine) {
+            Ok(module) => module.evaluate(engine).is_ok() && self.complete(engine),
+            Err(_) => false,
+        }
+    }
+
+    /// Loads and links the test as a module, the modules that it imports are loaded from the
+    /// directory of the test.
+    fn link_module(&self, engine: &mut Context) -> Result<Module> {
+        let directory = self.path.parent().expect("a test is always in a directory");
+        let loader = Rc::new(SimpleModuleLoader::new(directory));
+        engine.set_module_loader(loader.clone());
+
+        let module = loader.load_path(
+            self.path.file_name().expect("a test is always a file"),
+            engine,
+        )?;
+        module.link(engine)?;
+        Ok(module)
+    }
+
+    /// Checks that the test completed, once its code ran successfully.
+    ///
+    /// Async tests also run the pending jobs, and must then have printed the completion message.
+    fn complete(&self, engine: &mut Context) -> bool {
+        if !self.flags.contains(TestFlags::ASYNC) {
+            return true;
+        }
+
+        engine.run_jobs().is_ok()
+            && PRINTED
+                .with(|printed| printed.borrow_mut().take())
+                .as_deref()
+                == Some(ASYNC_TEST_COMPLETE)
+    }
+
     /// Sets the environment up to run the test.
-    fn set_up_env(&self, harness: &Harness, strict: bool) -> Context {
+    fn set_up_env(&self, harness: &Harness) -> Context {
         // Create new Realm
         // TODO: in parallel.
         let mut engine = Context::new();
 
         // TODO: set up the environment.
 
-        if strict {
-            engine
-                .eval(r#""use strict";"#)
-                .expect("could not set strict mode");
-        }
-
         engine
             .eval(&harness.assert)
             .expect("could not run assert.js");
         engine.eval(&harness.sta).expect("could not run sta.js");
 
+        // Async tests report their completion by printing a message with `$DONE`.
+        if self.flags.contains(TestFlags::ASYNC) {
+            PRINTED.with(|printed| printed.borrow_mut().take());
+            engine
+                .register_global_function("print", 1, print)
+                .expect("could not register the print function");
+            engine
+                .eval(
+                    &harness
+                        .includes
+                        .get("doneprintHandle.js")
+                        .expect("could not find the doneprintHandle.js include file"),
+                )
+                .expect("could not run doneprintHandle.js");
+        }
+
         self.includes.iter().for_each(|include| {
             let res = engine.eval(
                 &harness
@@ -212,3 +297,10 @@ impl Test {
         engine
     }
 }
+
+/// The `print` function used by `harness/doneprintHandle.js`, it records the printed message.
+fn print(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+    let message = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+    PRINTED.with(|printed| *printed.borrow_mut() = Some(message.to_string()));
+    Ok(Value::undefined())
+}
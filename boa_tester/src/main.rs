@@ -196,15 +196,17 @@ struct Test {
     expected_outcome: Outcome,
     includes: Box<[Box<str>]>,
     locale: Locale,
+    path: Box<Path>,
     content: Box<str>,
 }
 
 impl Test {
     /// Creates a new test.
     #[inline]
-    fn new<N, C>(name: N, content: C, metadata: MetaData) -> Self
+    fn new<N, P, C>(name: N, path: P, content: C, metadata: MetaData) -> Self
     where
         N: Into<Box<str>>,
+        P: Into<Box<Path>>,
         C: Into<Box<str>>,
     {
         Self {
@@ -217,6 +219,7 @@ impl Test {
             expected_outcome: Outcome::from(metadata.negative),
             includes: metadata.includes,
             locale: metadata.locale,
+            path: path.into(),
             content: content.into(),
         }
     }